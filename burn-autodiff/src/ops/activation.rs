@@ -32,6 +32,120 @@ impl<B: Backend> ActivationOps<ADBackendDecorator<B>> for ADBackendDecorator<B>
         }
     }
 
+    fn gelu_tanh<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct GeluTanh<const D: usize>;
+
+        impl<const D: usize, B: Backend> Backward<B, D, 1> for GeluTanh<D> {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let input = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::gelu_tanh_backward(input, grad)
+                });
+            }
+        }
+
+        match GeluTanh::<D>
+            .prepare([tensor.node], [tensor.graph])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::gelu_tanh(tensor.primitive.clone());
+                prep.finish(tensor.primitive, output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::gelu_tanh(tensor.primitive)),
+        }
+    }
+
+    fn leaky_relu<const D: usize>(
+        tensor: ADTensor<B, D>,
+        negative_slope: B::FloatElem,
+    ) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct LeakyRelu<const D: usize>;
+
+        impl<const D: usize, B: Backend> Backward<B, D, 1> for LeakyRelu<D> {
+            type State = (B::TensorPrimitive<D>, B::FloatElem);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (input, negative_slope) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::leaky_relu_backward(input, grad, negative_slope)
+                });
+            }
+        }
+
+        match LeakyRelu::<D>
+            .prepare([tensor.node], [tensor.graph])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::leaky_relu(tensor.primitive.clone(), negative_slope);
+                prep.finish((tensor.primitive, negative_slope), output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::leaky_relu(tensor.primitive, negative_slope))
+            }
+        }
+    }
+
+    fn elu<const D: usize>(tensor: ADTensor<B, D>, alpha: B::FloatElem) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Elu<const D: usize>;
+
+        impl<const D: usize, B: Backend> Backward<B, D, 1> for Elu<D> {
+            type State = (B::TensorPrimitive<D>, B::FloatElem);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (input, alpha) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::elu_backward(input, grad, alpha)
+                });
+            }
+        }
+
+        match Elu::<D>.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let output = B::elu(tensor.primitive.clone(), alpha);
+                prep.finish((tensor.primitive, alpha), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::elu(tensor.primitive, alpha)),
+        }
+    }
+
+    fn softplus<const D: usize>(tensor: ADTensor<B, D>, beta: B::FloatElem) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Softplus<const D: usize>;
+
+        impl<const D: usize, B: Backend> Backward<B, D, 1> for Softplus<D> {
+            type State = (B::TensorPrimitive<D>, B::FloatElem);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (input, beta) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::softplus_backward(input, grad, beta)
+                });
+            }
+        }
+
+        match Softplus::<D>
+            .prepare([tensor.node], [tensor.graph])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::softplus(tensor.primitive.clone(), beta);
+                prep.finish((tensor.primitive, beta), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::softplus(tensor.primitive, beta)),
+        }
+    }
+
     fn relu<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
         #[derive(Debug)]
         struct Relu;
@@ -94,4 +94,34 @@ impl<B: Backend> BoolTensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B>
     ) -> <ADBackendDecorator<B> as Backend>::BoolTensorPrimitive<D> {
         B::bool_swap_dims(tensor, dim1, dim2)
     }
+
+    fn bool_permute<const D: usize>(
+        tensor: BoolTensor<B, D>,
+        axes: [usize; D],
+    ) -> BoolTensor<B, D> {
+        B::bool_permute(tensor, axes)
+    }
+
+    fn bool_expand<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<B, D1>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<B, D2> {
+        B::bool_expand(tensor, shape)
+    }
+
+    fn bool_any<const D: usize>(tensor: BoolTensor<B, D>) -> BoolTensor<B, 1> {
+        B::bool_any(tensor)
+    }
+
+    fn bool_any_dim<const D: usize>(tensor: BoolTensor<B, D>, dim: usize) -> BoolTensor<B, D> {
+        B::bool_any_dim(tensor, dim)
+    }
+
+    fn bool_all<const D: usize>(tensor: BoolTensor<B, D>) -> BoolTensor<B, 1> {
+        B::bool_all(tensor)
+    }
+
+    fn bool_all_dim<const D: usize>(tensor: BoolTensor<B, D>, dim: usize) -> BoolTensor<B, D> {
+        B::bool_all_dim(tensor, dim)
+    }
 }
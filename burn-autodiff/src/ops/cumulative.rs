@@ -0,0 +1,46 @@
+use burn_tensor::{backend::Backend, ElementConversion};
+
+/// Computes the cumulative sum of the tensor along the given dimension, starting from the end.
+pub(crate) fn reverse_cumsum<B: Backend, const D: usize>(
+    tensor: B::TensorPrimitive<D>,
+    dim: usize,
+) -> B::TensorPrimitive<D> {
+    B::flip(B::cumsum(B::flip(tensor, &[dim]), dim), &[dim])
+}
+
+/// Computes the cumulative log-sum-exp of the tensor along the given dimension, starting from
+/// the end.
+pub(crate) fn reverse_logcumsumexp<B: Backend, const D: usize>(
+    tensor: B::TensorPrimitive<D>,
+    dim: usize,
+) -> B::TensorPrimitive<D> {
+    B::flip(B::logcumsumexp(B::flip(tensor, &[dim]), dim), &[dim])
+}
+
+/// Computes the gradient of the cumulative product along the given dimension.
+///
+/// Before the first zero, the gradient is the reverse cumulative sum of `grad * output` divided
+/// by the input. From the first zero on, the outputs are zeros, so only the first zero has a
+/// gradient, computed with the cumulative product of the input where that zero is replaced by one.
+pub(crate) fn cumprod_backward<B: Backend, const D: usize>(
+    input: B::TensorPrimitive<D>,
+    output: B::TensorPrimitive<D>,
+    grad: B::TensorPrimitive<D>,
+    dim: usize,
+) -> B::TensorPrimitive<D> {
+    let zeros = B::equal_elem(input.clone(), 0.elem());
+    let zeros_float = B::bool_into_float(zeros.clone());
+    let first_zero = B::equal_elem(
+        B::mul(B::cumsum(zeros_float.clone(), dim), zeros_float),
+        1.elem(),
+    );
+
+    let grad_before = reverse_cumsum::<B, D>(B::mul(grad.clone(), output), dim);
+    let grad_before = B::div(grad_before, B::mask_fill(input.clone(), zeros, 1.elem()));
+
+    let output_without = B::cumprod(B::mask_fill(input, first_zero.clone(), 1.elem()), dim);
+    let grad_first = reverse_cumsum::<B, D>(B::mul(grad, output_without), dim);
+    let grad_first = B::mul(grad_first, B::bool_into_float(first_zero));
+
+    B::add(grad_before, grad_first)
+}
@@ -3,7 +3,11 @@ use crate::{
     ADBackendDecorator,
 };
 
-use burn_tensor::{backend::Backend, ops::IntTensorOps, Data, Reader, Shape};
+use burn_tensor::{
+    backend::Backend,
+    ops::{IntTensorOps, PadMode},
+    Data, Reader, Shape,
+};
 
 impl<B: Backend> IntTensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
     fn int_from_data<const D: usize>(
@@ -125,6 +129,20 @@ impl<B: Backend> IntTensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         B::int_div_scalar(lhs, rhs)
     }
 
+    fn int_remainder<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_remainder(lhs, rhs)
+    }
+
+    fn int_remainder_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<B, D> {
+        B::int_remainder_scalar(lhs, rhs)
+    }
+
     fn int_neg<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
         B::int_neg(tensor)
     }
@@ -159,6 +177,14 @@ impl<B: Backend> IntTensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         B::int_sum_dim(tensor, dim)
     }
 
+    fn int_prod<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1> {
+        B::int_prod(tensor)
+    }
+
+    fn int_prod_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        B::int_prod_dim(tensor, dim)
+    }
+
     fn int_mean<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1> {
         B::int_mean(tensor)
     }
@@ -321,4 +347,95 @@ impl<B: Backend> IntTensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
     ) -> <ADBackendDecorator<B> as Backend>::IntTensorPrimitive<D> {
         B::int_swap_dims(tensor, dim1, dim2)
     }
+
+    fn int_permute<const D: usize>(tensor: IntTensor<B, D>, axes: [usize; D]) -> IntTensor<B, D> {
+        B::int_permute(tensor, axes)
+    }
+
+    fn int_expand<const D1: usize, const D2: usize>(
+        tensor: IntTensor<B, D1>,
+        shape: Shape<D2>,
+    ) -> IntTensor<B, D2> {
+        B::int_expand(tensor, shape)
+    }
+
+    fn int_flip<const D: usize>(tensor: IntTensor<B, D>, axes: &[usize]) -> IntTensor<B, D> {
+        B::int_flip(tensor, axes)
+    }
+
+    fn int_roll<const D: usize>(
+        tensor: IntTensor<B, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> IntTensor<B, D> {
+        B::int_roll(tensor, shifts, dims)
+    }
+
+    fn int_pad<const D: usize>(
+        tensor: IntTensor<B, D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> IntTensor<B, D> {
+        B::int_pad(tensor, pads, mode)
+    }
+
+    fn int_cumsum<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_cumsum(tensor, dim)
+    }
+    fn int_cumprod<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_cumprod(tensor, dim)
+    }
+    fn int_cummax<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_cummax(tensor, dim)
+    }
+    fn int_cummax_with_indices<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+    ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        B::int_cummax_with_indices(tensor, dim)
+    }
+    fn int_sort<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_sort(tensor, dim, descending)
+    }
+    fn int_sort_with_indices<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        B::int_sort_with_indices(tensor, dim, descending)
+    }
+    fn int_argsort<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_argsort(tensor, dim, descending)
+    }
+    fn int_topk<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        k: usize,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_topk(tensor, dim, k)
+    }
+    fn int_topk_with_indices<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        k: usize,
+    ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        B::int_topk_with_indices(tensor, dim, k)
+    }
 }
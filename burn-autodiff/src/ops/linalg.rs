@@ -0,0 +1,420 @@
+use crate::{
+    grads::Gradients,
+    ops::{unary, Backward, Ops, OpsKind},
+    tensor::ADTensor,
+    ADBackendDecorator,
+};
+use burn_tensor::{backend::Backend, linalg, ops::LinalgOps, Bool, Tensor};
+
+impl<B: Backend> LinalgOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
+    fn cholesky<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Cholesky;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Cholesky {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let factor: Tensor<B, D> = Tensor::from_primitive(ops.state);
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let grad = Tensor::from_primitive(grad);
+                    let factor_t = factor.clone().transpose();
+
+                    // Φ(L^T gL), the lower triangular part with the diagonal halved.
+                    let phi = factor_t.clone().matmul(grad).tril(0);
+                    let phi = phi.clone().sub(diag_part(phi).div_scalar(2));
+
+                    // L^{-T} Φ L^{-1}
+                    let grad = linalg::solve(factor_t.clone(), phi);
+                    let grad = linalg::solve(factor_t, grad.transpose()).transpose();
+
+                    symmetric_part(grad).into_primitive()
+                });
+            }
+        }
+
+        let output = B::cholesky(tensor.primitive);
+
+        match Cholesky.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(output.clone(), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
+    fn qr<const D: usize>(tensor: ADTensor<B, D>) -> (ADTensor<B, D>, ADTensor<B, D>) {
+        // The gradient is a linear function of the gradients of `Q` and `R`, so each output is
+        // registered as its own node whose backward step handles the contribution of its gradient.
+        #[derive(Debug)]
+        struct QrQ;
+
+        #[derive(Debug)]
+        struct QrR;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for QrQ {
+            type State = (B::TensorPrimitive<D>, B::TensorPrimitive<D>);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (q, r) = ops.state;
+                let q: Tensor<B, D> = Tensor::from_primitive(q);
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let grad: Tensor<B, D> = Tensor::from_primitive(grad);
+                    let inner = q.clone().transpose().matmul(grad.clone()).neg();
+
+                    qr_backward(q, Tensor::from_primitive(r), inner, Some(grad)).into_primitive()
+                });
+            }
+        }
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for QrR {
+            type State = (B::TensorPrimitive<D>, B::TensorPrimitive<D>);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (q, r) = ops.state;
+                let r: Tensor<B, D> = Tensor::from_primitive(r);
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let grad: Tensor<B, D> = Tensor::from_primitive(grad);
+                    let inner = grad.matmul(r.clone().transpose());
+
+                    qr_backward(Tensor::from_primitive(q), r, inner, None).into_primitive()
+                });
+            }
+        }
+
+        let (q, r) = B::qr(tensor.primitive);
+
+        let q_output = match QrQ
+            .prepare([tensor.node.clone()], [tensor.graph.clone()])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish((q.clone(), r.clone()), q.clone()),
+            OpsKind::UnTracked(prep) => prep.finish(q.clone()),
+        };
+        let r_output = match QrR.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish((q, r.clone()), r),
+            OpsKind::UnTracked(prep) => prep.finish(r),
+        };
+
+        (q_output, r_output)
+    }
+
+    fn eigh<const D: usize>(tensor: ADTensor<B, D>) -> (ADTensor<B, D>, ADTensor<B, D>) {
+        #[derive(Debug)]
+        struct EighEigenvalues;
+
+        #[derive(Debug)]
+        struct EighEigenvectors;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for EighEigenvalues {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let eigenvectors: Tensor<B, D> = Tensor::from_primitive(ops.state);
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // V diag(gL) V^T
+                    let grad = eigenvectors.clone().mul(Tensor::from_primitive(grad));
+
+                    grad.matmul(eigenvectors.transpose()).into_primitive()
+                });
+            }
+        }
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for EighEigenvectors {
+            type State = (B::TensorPrimitive<D>, B::TensorPrimitive<D>);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (eigenvalues, eigenvectors) = ops.state;
+                let eigenvalues: Tensor<B, D> = Tensor::from_primitive(eigenvalues);
+                let eigenvectors: Tensor<B, D> = Tensor::from_primitive(eigenvectors);
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // V (skew(V^T gV) / 2E) V^T, where E_ij = L_j - L_i.
+                    let inner = eigenvectors
+                        .clone()
+                        .transpose()
+                        .matmul(Tensor::from_primitive(grad));
+                    let inner = skew_part(inner)
+                        .div_scalar(2)
+                        .div(eigenvalue_gaps(eigenvalues.clone(), eigenvalues));
+
+                    eigenvectors
+                        .clone()
+                        .matmul(inner)
+                        .matmul(eigenvectors.transpose())
+                        .into_primitive()
+                });
+            }
+        }
+
+        let (eigenvalues, eigenvectors) = B::eigh(tensor.primitive);
+
+        let eigenvalues_output = match EighEigenvalues
+            .prepare([tensor.node.clone()], [tensor.graph.clone()])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(eigenvectors.clone(), eigenvalues.clone()),
+            OpsKind::UnTracked(prep) => prep.finish(eigenvalues.clone()),
+        };
+        let eigenvectors_output = match EighEigenvectors
+            .prepare([tensor.node], [tensor.graph])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                prep.finish((eigenvalues, eigenvectors.clone()), eigenvectors)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(eigenvectors),
+        };
+
+        (eigenvalues_output, eigenvectors_output)
+    }
+
+    fn svd<const D: usize>(
+        tensor: ADTensor<B, D>,
+    ) -> (ADTensor<B, D>, ADTensor<B, D>, ADTensor<B, D>) {
+        #[derive(Debug)]
+        struct SvdU;
+
+        #[derive(Debug)]
+        struct SvdS;
+
+        #[derive(Debug)]
+        struct SvdVh;
+
+        type SvdState<B, const D: usize> = (
+            <B as Backend>::TensorPrimitive<D>,
+            <B as Backend>::TensorPrimitive<D>,
+            <B as Backend>::TensorPrimitive<D>,
+        );
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for SvdU {
+            type State = SvdState<B, D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (u, s, vh) = ops.state;
+                let u: Tensor<B, D> = Tensor::from_primitive(u);
+                let s: Tensor<B, D> = Tensor::from_primitive(s);
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let grad: Tensor<B, D> = Tensor::from_primitive(grad);
+                    let ut_grad = u.clone().transpose().matmul(grad.clone());
+
+                    // U (skew(U^T gU) / E) S + (I - U U^T) gU S^{-1}
+                    let inner = skew_part(ut_grad.clone())
+                        .div(singular_value_gaps(s.clone()))
+                        .mul(s.clone());
+                    let outer = grad.sub(u.clone().matmul(ut_grad)).div(s);
+                    let grad = u.matmul(inner).add(outer);
+
+                    grad.matmul(Tensor::from_primitive(vh)).into_primitive()
+                });
+            }
+        }
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for SvdS {
+            type State = SvdState<B, D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (u, _s, vh) = ops.state;
+                let u: Tensor<B, D> = Tensor::from_primitive(u);
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // U diag(gS) V^T
+                    let grad = u.mul(Tensor::from_primitive(grad));
+
+                    grad.matmul(Tensor::from_primitive(vh)).into_primitive()
+                });
+            }
+        }
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for SvdVh {
+            type State = SvdState<B, D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (u, s, vh) = ops.state;
+                let s: Tensor<B, D> = Tensor::from_primitive(s);
+                let vh: Tensor<B, D> = Tensor::from_primitive(vh);
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // The gradient of V is the transpose of the gradient of V^T.
+                    let grad: Tensor<B, D> = Tensor::from_primitive(grad);
+                    let vt_grad = vh.clone().matmul(grad.clone().transpose());
+
+                    // S (skew(V^T gV) / E) V^T + S^{-1} gV^T (I - V V^T)
+                    let inner = skew_part(vt_grad.clone())
+                        .div(singular_value_gaps(s.clone()))
+                        .mul(s.clone().transpose());
+                    let outer = grad
+                        .sub(vt_grad.transpose().matmul(vh.clone()))
+                        .div(s.transpose());
+                    let grad = inner.matmul(vh).add(outer);
+
+                    Tensor::from_primitive(u).matmul(grad).into_primitive()
+                });
+            }
+        }
+
+        let (u, s, vh) = B::svd(tensor.primitive);
+        let state = || (u.clone(), s.clone(), vh.clone());
+
+        let u_output = match SvdU
+            .prepare([tensor.node.clone()], [tensor.graph.clone()])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(state(), u.clone()),
+            OpsKind::UnTracked(prep) => prep.finish(u.clone()),
+        };
+        let s_output = match SvdS
+            .prepare([tensor.node.clone()], [tensor.graph.clone()])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(state(), s.clone()),
+            OpsKind::UnTracked(prep) => prep.finish(s.clone()),
+        };
+        let vh_output = match SvdVh.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(state(), vh.clone()),
+            OpsKind::UnTracked(prep) => prep.finish(vh.clone()),
+        };
+
+        (u_output, s_output, vh_output)
+    }
+
+    fn solve<const D: usize>(lhs: ADTensor<B, D>, rhs: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Solve;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 2> for Solve {
+            type State = (B::TensorPrimitive<D>, Option<B::TensorPrimitive<D>>);
+
+            fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+                let (lhs, output) = ops.state;
+                let [node_lhs, node_rhs] = ops.parents;
+
+                // Both gradients need A^{-T} G, which is computed once.
+                let grad = grads.consume::<B, D>(&ops.node);
+                let grad_rhs = linalg::solve::<B, D>(
+                    Tensor::from_primitive(lhs).transpose(),
+                    Tensor::from_primitive(grad),
+                );
+
+                if let Some(node) = node_lhs {
+                    let output = Tensor::from_primitive(output.unwrap());
+                    let grad_lhs = grad_rhs.clone().matmul(output.transpose()).neg();
+                    grads.register::<B, D>(node, grad_lhs.into_primitive());
+                }
+
+                if let Some(node) = node_rhs {
+                    grads.register::<B, D>(node, grad_rhs.into_primitive());
+                }
+            }
+        }
+
+        let lhs_tracked = lhs.is_tracked();
+        let output = B::solve(lhs.primitive.clone(), rhs.primitive);
+
+        match Solve
+            .prepare([lhs.node, rhs.node], [lhs.graph, rhs.graph])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                prep.finish((lhs.primitive, lhs_tracked.then(|| output.clone())), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
+    fn slogdet<const D: usize>(tensor: ADTensor<B, D>) -> (ADTensor<B, D>, ADTensor<B, D>) {
+        #[derive(Debug)]
+        struct Slogdet;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Slogdet {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let tensor: Tensor<B, D> = Tensor::from_primitive(ops.state);
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // g A^{-T}
+                    let inverse_t = linalg::inv(tensor.transpose());
+
+                    inverse_t.mul(Tensor::from_primitive(grad)).into_primitive()
+                });
+            }
+        }
+
+        let (sign, logabsdet) = B::slogdet(tensor.primitive.clone());
+
+        // The sign is piecewise constant, so it never has a gradient.
+        let sign = ADTensor::new(sign);
+        let logabsdet = match Slogdet.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(tensor.primitive, logabsdet),
+            OpsKind::UnTracked(prep) => prep.finish(logabsdet),
+        };
+
+        (sign, logabsdet)
+    }
+}
+
+/// Computes the gradient of the QR decomposition, `[gQ + Q copyltu(M)] R^{-T}`, where `M` is the
+/// given inner matrix `R gR^T - gQ^T Q` restricted to the contribution of one of the outputs.
+fn qr_backward<B: Backend, const D: usize>(
+    q: Tensor<B, D>,
+    r: Tensor<B, D>,
+    inner: Tensor<B, D>,
+    grad_q: Option<Tensor<B, D>>,
+) -> Tensor<B, D> {
+    // Mirror the upper triangular part, keeping the diagonal once.
+    let upper = inner.triu(0);
+    let inner = upper
+        .clone()
+        .add(upper.clone().transpose())
+        .sub(diag_part(upper));
+
+    let mut grad = q.matmul(inner);
+    if let Some(grad_q) = grad_q {
+        grad = grad.add(grad_q);
+    }
+
+    // X R^{-T} = (R^{-1} X^T)^T
+    linalg::solve(r, grad.transpose()).transpose()
+}
+
+/// The matrices with only their diagonal elements.
+fn diag_part<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let identity = Tensor::<B, D, Bool>::diag_mask(tensor.shape(), 0, &tensor.device())
+        .bool_not()
+        .float();
+
+    tensor.mul(identity)
+}
+
+/// `(X + X^T) / 2`
+fn symmetric_part<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor.clone().add(tensor.transpose()).div_scalar(2)
+}
+
+/// `X - X^T`
+fn skew_part<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor.clone().sub(tensor.transpose())
+}
+
+/// The matrices `E_ij = L_j - L_i` for the row vectors `L`, with ones on the diagonal so they can
+/// be used as divisors.
+fn eigenvalue_gaps<B: Backend, const D: usize>(
+    rows: Tensor<B, D>,
+    columns: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let gaps = rows.sub(columns.transpose());
+    let diagonal = Tensor::<B, D, Bool>::diag_mask(gaps.shape(), 0, &gaps.device()).bool_not();
+
+    gaps.mask_fill(diagonal, 1)
+}
+
+/// The matrices `E_jk = S_k^2 - S_j^2` for the singular values `S`, with ones on the diagonal.
+fn singular_value_gaps<B: Backend, const D: usize>(s: Tensor<B, D>) -> Tensor<B, D> {
+    let squared = s.powf(2.0);
+
+    eigenvalue_gaps(squared.clone(), squared)
+}
@@ -3,10 +3,16 @@ mod backward;
 mod base;
 mod bool_tensor;
 mod int_tensor;
+mod linalg;
 mod module;
 mod tensor;
 
+pub(crate) mod cumulative;
 pub(crate) mod maxmin;
+pub(crate) mod padding;
+pub(crate) mod product;
+pub(crate) mod sort;
+pub(crate) mod zero_grad;
 
 pub use backward::*;
 pub use base::*;
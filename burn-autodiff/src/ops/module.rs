@@ -149,6 +149,110 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn conv3d(
+        x: ADTensor<B, 5>,
+        weight: ADTensor<B, 5>,
+        bias: Option<ADTensor<B, 1>>,
+        options: ConvOptions<3>,
+    ) -> ADTensor<B, 5> {
+        #[derive(Debug)]
+        struct Conv3DWithBias;
+        #[derive(Debug)]
+        struct Conv3DNoBias;
+
+        impl<B: Backend> Backward<B, 5, 3> for Conv3DWithBias {
+            type State = (
+                B::TensorPrimitive<5>,
+                B::TensorPrimitive<5>,
+                B::TensorPrimitive<1>,
+                ConvOptions<3>,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 3>, grads: &mut Gradients) {
+                let [node_x, node_weight, node_bias] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+
+                let (x, weight, bias, options) = ops.state;
+                let backward = B::conv3d_backward(x, weight, Some(bias), grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 5>(node, backward.x_grad)
+                }
+                if let Some(node) = node_weight {
+                    grads.register::<B, 5>(node, backward.weights_grad)
+                }
+                if let Some(node) = node_bias {
+                    grads.register::<B, 1>(node, backward.bias_grad.unwrap())
+                }
+            }
+        }
+
+        impl<B: Backend> Backward<B, 5, 2> for Conv3DNoBias {
+            type State = (B::TensorPrimitive<5>, B::TensorPrimitive<5>, ConvOptions<3>);
+
+            fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+                let [node_x, node_weight] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+
+                let (x, weight, options) = ops.state;
+                let backward = B::conv3d_backward(x, weight, None, grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 5>(node, backward.x_grad)
+                }
+                if let Some(node) = node_weight {
+                    grads.register::<B, 5>(node, backward.weights_grad)
+                }
+            }
+        }
+
+        match bias {
+            Some(bias) => {
+                match Conv3DWithBias
+                    .prepare(
+                        [x.node, weight.node, bias.node],
+                        [x.graph, weight.graph, bias.graph],
+                    )
+                    .stateful()
+                {
+                    OpsKind::Tracked(prep) => prep.finish(
+                        (
+                            x.primitive.clone(),
+                            weight.primitive.clone(),
+                            bias.primitive.clone(),
+                            options.clone(),
+                        ),
+                        B::conv3d(x.primitive, weight.primitive, Some(bias.primitive), options),
+                    ),
+                    OpsKind::UnTracked(prep) => prep.finish(B::conv3d(
+                        x.primitive,
+                        weight.primitive,
+                        Some(bias.primitive),
+                        options,
+                    )),
+                }
+            }
+            None => {
+                match Conv3DNoBias
+                    .prepare([x.node, weight.node], [x.graph, weight.graph])
+                    .stateful()
+                {
+                    OpsKind::Tracked(prep) => prep.finish(
+                        (
+                            x.primitive.clone(),
+                            weight.primitive.clone(),
+                            options.clone(),
+                        ),
+                        B::conv3d(x.primitive, weight.primitive, None, options),
+                    ),
+                    OpsKind::UnTracked(prep) => {
+                        prep.finish(B::conv3d(x.primitive, weight.primitive, None, options))
+                    }
+                }
+            }
+        }
+    }
+
     fn conv_transpose2d(
         x: ADTensor<B, 4>,
         weight: ADTensor<B, 4>,
@@ -265,6 +369,122 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn conv_transpose3d(
+        x: ADTensor<B, 5>,
+        weight: ADTensor<B, 5>,
+        bias: Option<ADTensor<B, 1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> ADTensor<B, 5> {
+        #[derive(Debug)]
+        struct ConvTranspose3DWithBias;
+        #[derive(Debug)]
+        struct ConvTranspose3DNoBias;
+
+        impl<B: Backend> Backward<B, 5, 3> for ConvTranspose3DWithBias {
+            type State = (
+                B::TensorPrimitive<5>,
+                B::TensorPrimitive<5>,
+                B::TensorPrimitive<1>,
+                ConvTransposeOptions<3>,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 3>, grads: &mut Gradients) {
+                let [node_x, node_weight, node_bias] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+
+                let (x, weight, bias, options) = ops.state;
+                let backward = B::conv_transpose3d_backward(x, weight, Some(bias), grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 5>(node, backward.x_grad)
+                }
+                if let Some(node) = node_weight {
+                    grads.register::<B, 5>(node, backward.weights_grad)
+                }
+                if let Some(node) = node_bias {
+                    grads.register::<B, 1>(node, backward.bias_grad.unwrap())
+                }
+            }
+        }
+
+        impl<B: Backend> Backward<B, 5, 2> for ConvTranspose3DNoBias {
+            type State = (
+                B::TensorPrimitive<5>,
+                B::TensorPrimitive<5>,
+                ConvTransposeOptions<3>,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+                let [node_x, node_weight] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+
+                let (x, weight, options) = ops.state;
+                let backward = B::conv_transpose3d_backward(x, weight, None, grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 5>(node, backward.x_grad)
+                }
+                if let Some(node) = node_weight {
+                    grads.register::<B, 5>(node, backward.weights_grad)
+                }
+            }
+        }
+
+        match bias {
+            Some(bias) => {
+                match ConvTranspose3DWithBias
+                    .prepare(
+                        [x.node, weight.node, bias.node],
+                        [x.graph, weight.graph, bias.graph],
+                    )
+                    .stateful()
+                {
+                    OpsKind::Tracked(prep) => prep.finish(
+                        (
+                            x.primitive.clone(),
+                            weight.primitive.clone(),
+                            bias.primitive.clone(),
+                            options.clone(),
+                        ),
+                        B::conv_transpose3d(
+                            x.primitive,
+                            weight.primitive,
+                            Some(bias.primitive),
+                            options,
+                        ),
+                    ),
+                    OpsKind::UnTracked(prep) => prep.finish(B::conv_transpose3d(
+                        x.primitive,
+                        weight.primitive,
+                        Some(bias.primitive),
+                        options,
+                    )),
+                }
+            }
+            None => {
+                match ConvTranspose3DNoBias
+                    .prepare([x.node, weight.node], [x.graph, weight.graph])
+                    .stateful()
+                {
+                    OpsKind::Tracked(prep) => prep.finish(
+                        (
+                            x.primitive.clone(),
+                            weight.primitive.clone(),
+                            options.clone(),
+                        ),
+                        B::conv_transpose3d(x.primitive, weight.primitive, None, options),
+                    ),
+                    OpsKind::UnTracked(prep) => prep.finish(B::conv_transpose3d(
+                        x.primitive,
+                        weight.primitive,
+                        None,
+                        options,
+                    )),
+                }
+            }
+        }
+    }
+
     fn conv1d(
         x: ADTensor<B, 3>,
         weight: ADTensor<B, 3>,
@@ -639,6 +859,79 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         panic!("Can't differentiate avg pool 2d backward.");
     }
 
+    fn avg_pool3d(
+        x: ADTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> ADTensor<B, 5> {
+        #[derive(Debug)]
+        struct AvgPool3D;
+
+        impl<B: Backend> Backward<B, 5, 1> for AvgPool3D {
+            type State = (
+                B::TensorPrimitive<5>,
+                [usize; 3],
+                [usize; 3],
+                [usize; 3],
+                bool,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+                let (x, kernel_size, stride, padding, count_include_pad) = ops.state;
+
+                if let Some(node) = node_parent {
+                    let grad = B::avg_pool3d_backward(
+                        x,
+                        grad,
+                        kernel_size,
+                        stride,
+                        padding,
+                        count_include_pad,
+                    );
+                    grads.register::<B, 5>(node, grad);
+                }
+            }
+        }
+
+        match AvgPool3D.prepare([x.node], [x.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let output = B::avg_pool3d(
+                    x.primitive.clone(),
+                    kernel_size,
+                    stride,
+                    padding,
+                    count_include_pad,
+                );
+                prep.finish(
+                    (x.primitive, kernel_size, stride, padding, count_include_pad),
+                    output,
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::avg_pool3d(
+                x.primitive,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+            )),
+        }
+    }
+
+    fn avg_pool3d_backward(
+        _x: ADTensor<B, 5>,
+        _grad: ADTensor<B, 5>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _count_include_pad: bool,
+    ) -> ADTensor<B, 5> {
+        panic!("Can't differentiate avg pool 3d backward.");
+    }
+
     fn max_pool1d(
         x: ADTensor<B, 3>,
         kernel_size: usize,
@@ -829,6 +1122,98 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
     ) -> MaxPool2dBackward<ADBackendDecorator<B>> {
         panic!("Can't differentiate max pool2d with indices backward.");
     }
+
+    fn max_pool3d(
+        x: ADTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> ADTensor<B, 5> {
+        match MaxPool3D.prepare([x.node], [x.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let output = B::max_pool3d_with_indices(
+                    x.primitive.clone(),
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                );
+                prep.finish(
+                    (
+                        x.primitive,
+                        output.indices,
+                        kernel_size,
+                        stride,
+                        padding,
+                        dilation,
+                    ),
+                    output.output,
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::max_pool3d(
+                x.primitive,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+            )),
+        }
+    }
+
+    fn max_pool3d_with_indices(
+        x: ADTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<ADBackendDecorator<B>> {
+        match MaxPool3D.prepare([x.node], [x.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let output = B::max_pool3d_with_indices(
+                    x.primitive.clone(),
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                );
+
+                let output_tensor = prep.finish(
+                    (
+                        x.primitive,
+                        output.indices.clone(),
+                        kernel_size,
+                        stride,
+                        padding,
+                        dilation,
+                    ),
+                    output.output,
+                );
+
+                MaxPool3dWithIndices::new(output_tensor, output.indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let output =
+                    B::max_pool3d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+                let output_tensor = prep.finish(output.output);
+
+                MaxPool3dWithIndices::new(output_tensor, output.indices)
+            }
+        }
+    }
+
+    fn max_pool3d_with_indices_backward(
+        _x: ADTensor<B, 5>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _dilation: [usize; 3],
+        _output_grad: ADTensor<B, 5>,
+        _indices: IntTensor<B, 5>,
+    ) -> MaxPool3dBackward<ADBackendDecorator<B>> {
+        panic!("Can't differentiate max pool3d with indices backward.");
+    }
+
     fn adaptive_avg_pool1d(x: ADTensor<B, 3>, output_size: usize) -> ADTensor<B, 3> {
         #[derive(Debug)]
         struct AdaptiveAvgPool1D;
@@ -893,6 +1278,220 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
     ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<4> {
         panic!("Can't differentiate adaptive avg pool2d backward.");
     }
+
+    fn adaptive_avg_pool3d(x: ADTensor<B, 5>, output_size: [usize; 3]) -> ADTensor<B, 5> {
+        #[derive(Debug)]
+        struct AdaptiveAvgPool3D;
+
+        impl<B: Backend> Backward<B, 5, 1> for AdaptiveAvgPool3D {
+            type State = B::TensorPrimitive<5>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+
+                if let Some(node) = node_parent {
+                    let grad = B::adaptive_avg_pool3d_backward(ops.state, grad);
+                    grads.register::<B, 5>(node, grad);
+                }
+            }
+        }
+
+        match AdaptiveAvgPool3D.prepare([x.node], [x.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(
+                x.primitive.clone(),
+                B::adaptive_avg_pool3d(x.primitive, output_size),
+            ),
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::adaptive_avg_pool3d(x.primitive, output_size))
+            }
+        }
+    }
+
+    fn adaptive_avg_pool3d_backward(
+        _x: ADTensor<B, 5>,
+        _grad: ADTensor<B, 5>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<5> {
+        panic!("Can't differentiate adaptive avg pool3d backward.");
+    }
+
+    fn interpolate(
+        x: ADTensor<B, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> ADTensor<B, 4> {
+        #[derive(Debug)]
+        struct Interpolate;
+
+        impl<B: Backend> Backward<B, 4, 1> for Interpolate {
+            type State = (B::TensorPrimitive<4>, InterpolateOptions);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B, 4>(&ops.node);
+                let (x, options) = ops.state;
+
+                if let Some(node) = node_parent {
+                    let grad = B::interpolate_backward(x, grad, options);
+                    grads.register::<B, 4>(node, grad);
+                }
+            }
+        }
+
+        match Interpolate.prepare([x.node], [x.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(
+                (x.primitive.clone(), options.clone()),
+                B::interpolate(x.primitive, output_size, options),
+            ),
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::interpolate(x.primitive, output_size, options))
+            }
+        }
+    }
+
+    fn interpolate_backward(
+        _x: ADTensor<B, 4>,
+        _grad: ADTensor<B, 4>,
+        _options: InterpolateOptions,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<4> {
+        panic!("Can't differentiate interpolate backward.");
+    }
+
+    fn scaled_dot_product_attention(
+        query: ADTensor<B, 4>,
+        key: ADTensor<B, 4>,
+        value: ADTensor<B, 4>,
+        bias: Option<ADTensor<B, 4>>,
+        mask: Option<B::BoolTensorPrimitive<4>>,
+        options: AttentionOptions,
+    ) -> ADTensor<B, 4> {
+        #[derive(Debug)]
+        struct ScaledDotProductAttention;
+
+        impl<B: Backend> Backward<B, 4, 3> for ScaledDotProductAttention {
+            type State = (
+                B::TensorPrimitive<4>,
+                B::TensorPrimitive<4>,
+                B::TensorPrimitive<4>,
+                Option<B::TensorPrimitive<4>>,
+                Option<B::BoolTensorPrimitive<4>>,
+                AttentionOptions,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 3>, grads: &mut Gradients) {
+                let [node_query, node_key, node_value] = ops.parents;
+                let grad = grads.consume::<B, 4>(&ops.node);
+
+                let (query, key, value, bias, mask, options) = ops.state;
+                let backward = B::scaled_dot_product_attention_backward(
+                    query, key, value, bias, mask, grad, options,
+                );
+
+                if let Some(node) = node_query {
+                    grads.register::<B, 4>(node, backward.query_grad)
+                }
+                if let Some(node) = node_key {
+                    grads.register::<B, 4>(node, backward.key_grad)
+                }
+                if let Some(node) = node_value {
+                    grads.register::<B, 4>(node, backward.value_grad)
+                }
+            }
+        }
+
+        // The bias is considered constant, so only its primitive is used.
+        let bias = bias.map(|bias| bias.primitive);
+
+        match ScaledDotProductAttention
+            .prepare(
+                [query.node, key.node, value.node],
+                [query.graph, key.graph, value.graph],
+            )
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (
+                    query.primitive.clone(),
+                    key.primitive.clone(),
+                    value.primitive.clone(),
+                    bias.clone(),
+                    mask.clone(),
+                    options.clone(),
+                ),
+                B::scaled_dot_product_attention(
+                    query.primitive,
+                    key.primitive,
+                    value.primitive,
+                    bias,
+                    mask,
+                    options,
+                ),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::scaled_dot_product_attention(
+                query.primitive,
+                key.primitive,
+                value.primitive,
+                bias,
+                mask,
+                options,
+            )),
+        }
+    }
+
+    fn scaled_dot_product_attention_backward(
+        _query: ADTensor<B, 4>,
+        _key: ADTensor<B, 4>,
+        _value: ADTensor<B, 4>,
+        _bias: Option<ADTensor<B, 4>>,
+        _mask: Option<B::BoolTensorPrimitive<4>>,
+        _output_grad: ADTensor<B, 4>,
+        _options: AttentionOptions,
+    ) -> AttentionBackward<ADBackendDecorator<B>> {
+        panic!("Can't differentiate scaled dot-product attention backward.");
+    }
+
+    fn segment_reduce(
+        tensor: ADTensor<B, 2>,
+        offsets: IntTensor<B, 1>,
+        reduction: SegmentReduction,
+    ) -> ADTensor<B, 2> {
+        #[derive(Debug)]
+        struct SegmentReduce;
+
+        impl<B: Backend> Backward<B, 2, 1> for SegmentReduce {
+            type State = (B::TensorPrimitive<2>, IntTensor<B, 1>, SegmentReduction);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (tensor, offsets, reduction) = ops.state;
+
+                unary::<B, 2, 2, _>(ops.parents, ops.node, grads, |grad| {
+                    B::segment_reduce_backward(tensor, offsets, grad, reduction)
+                });
+            }
+        }
+
+        match SegmentReduce
+            .prepare([tensor.node], [tensor.graph])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (tensor.primitive.clone(), offsets.clone(), reduction),
+                B::segment_reduce(tensor.primitive, offsets, reduction),
+            ),
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::segment_reduce(tensor.primitive, offsets, reduction))
+            }
+        }
+    }
+
+    fn segment_reduce_backward(
+        _tensor: ADTensor<B, 2>,
+        _offsets: IntTensor<B, 1>,
+        _output_grad: ADTensor<B, 2>,
+        _reduction: SegmentReduction,
+    ) -> ADTensor<B, 2> {
+        panic!("Can't differentiate segment reduce backward.");
+    }
 }
 
 #[derive(Debug)]
@@ -962,3 +1561,37 @@ impl<B: Backend> Backward<B, 4, 1> for MaxPool2D {
         }
     }
 }
+
+#[derive(Debug)]
+struct MaxPool3D;
+
+impl<B: Backend> Backward<B, 5, 1> for MaxPool3D {
+    type State = (
+        B::TensorPrimitive<5>,
+        IntTensor<B, 5>,
+        [usize; 3],
+        [usize; 3],
+        [usize; 3],
+        [usize; 3],
+    );
+
+    fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+        let [node_parent] = ops.parents;
+        let grad = grads.consume::<B, 5>(&ops.node);
+        let (x, indices, kernel_size, stride, padding, dilation) = ops.state;
+
+        if let Some(node) = node_parent {
+            let grad = B::max_pool3d_with_indices_backward(
+                x,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+                grad,
+                indices,
+            );
+
+            grads.register::<B, 5>(node, grad.x_grad);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use super::{unary, Backward, Ops};
+use crate::grads::Gradients;
+use burn_tensor::{backend::Backend, ops::PadMode};
+use std::ops::Range;
+
+/// Backward step of the pad operation.
+///
+/// The gradient of every padded element is accumulated into the element of the input it was
+/// copied from, the padding is undone one dimension at a time in the reverse order it was applied.
+#[derive(Debug)]
+pub(crate) struct Pad;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Pad {
+    type State = ([(usize, usize); D], PadMode);
+
+    fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+        let (pads, mode) = ops.state;
+
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            if let PadMode::Constant(_) = mode {
+                let shape = B::shape(&grad);
+                let ranges: [Range<usize>; D] = core::array::from_fn(|dim| {
+                    let (before, after) = pads[dim];
+                    before..shape.dims[dim] - after
+                });
+
+                return B::slice(grad, ranges);
+            }
+
+            (0..D).rev().fold(grad, |grad, dim| {
+                let (before, after) = pads[dim];
+                unpad_dim::<B, D>(grad, dim, before, after, mode)
+            })
+        });
+    }
+}
+
+fn unpad_dim<B: Backend, const D: usize>(
+    grad: B::TensorPrimitive<D>,
+    dim: usize,
+    before: usize,
+    after: usize,
+    mode: PadMode,
+) -> B::TensorPrimitive<D> {
+    if before == 0 && after == 0 {
+        return grad;
+    }
+
+    let size = B::shape(&grad).dims[dim] - before - after;
+    let part = |range: Range<usize>| B::slice(grad.clone(), ranges_dim::<B, D>(&grad, dim, range));
+
+    let mut output = part(before..before + size);
+
+    if before > 0 {
+        let head = part(0..before);
+        let (head, range) = match mode {
+            PadMode::Reflect => (B::flip(head, &[dim]), 1..before + 1),
+            PadMode::Replicate => (B::sum_dim(head, dim), 0..1),
+            PadMode::Circular => (head, size - before..size),
+            PadMode::Constant(_) => unreachable!("Constant padding is undone on all dimensions"),
+        };
+        output = accumulate::<B, D>(output, dim, range, head);
+    }
+
+    if after > 0 {
+        let tail = part(before + size..before + size + after);
+        let (tail, range) = match mode {
+            PadMode::Reflect => (B::flip(tail, &[dim]), size - 1 - after..size - 1),
+            PadMode::Replicate => (B::sum_dim(tail, dim), size - 1..size),
+            PadMode::Circular => (tail, 0..after),
+            PadMode::Constant(_) => unreachable!("Constant padding is undone on all dimensions"),
+        };
+        output = accumulate::<B, D>(output, dim, range, tail);
+    }
+
+    output
+}
+
+fn accumulate<B: Backend, const D: usize>(
+    tensor: B::TensorPrimitive<D>,
+    dim: usize,
+    range: Range<usize>,
+    value: B::TensorPrimitive<D>,
+) -> B::TensorPrimitive<D> {
+    let ranges = ranges_dim::<B, D>(&tensor, dim, range);
+    let current = B::slice(tensor.clone(), ranges.clone());
+
+    B::slice_assign(tensor, ranges, B::add(current, value))
+}
+
+fn ranges_dim<B: Backend, const D: usize>(
+    tensor: &B::TensorPrimitive<D>,
+    dim: usize,
+    range: Range<usize>,
+) -> [Range<usize>; D] {
+    let mut ranges = B::shape(tensor).dims.map(|size| 0..size);
+    ranges[dim] = range;
+    ranges
+}
@@ -0,0 +1,24 @@
+use burn_tensor::{backend::Backend, ElementConversion};
+
+/// Computes the gradient of the product along the given dimension.
+///
+/// Each element gets the product of the other elements. Without zeros, it is the output divided
+/// by the element. With a single zero, only the zero has a gradient: the product of the other
+/// elements. With more zeros, the gradient is zero everywhere.
+pub(crate) fn prod_dim_backward<B: Backend, const D: usize>(
+    input: B::TensorPrimitive<D>,
+    output: B::TensorPrimitive<D>,
+    grad: B::TensorPrimitive<D>,
+    dim: usize,
+) -> B::TensorPrimitive<D> {
+    let zeros = B::equal_elem(input.clone(), 0.elem());
+    let zeros_float = B::bool_into_float(zeros.clone());
+    let single_zero = B::equal_elem(B::sum_dim(zeros_float.clone(), dim), 1.elem());
+    let input = B::mask_fill(input, zeros, 1.elem());
+
+    let others = B::div(output, input.clone());
+    let others_zero = B::mul(B::prod_dim(input, dim), B::bool_into_float(single_zero));
+    let others_zero = B::mul(others_zero, zeros_float);
+
+    B::mul(grad, B::add(others, others_zero))
+}
@@ -0,0 +1,20 @@
+use super::{unary, Backward, Ops};
+use crate::grads::Gradients;
+use burn_tensor::{backend::Backend, Shape};
+
+#[derive(Debug)]
+pub(crate) struct SortDim;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for SortDim {
+    type State = (usize, B::IntTensorPrimitive<D>, Shape<D>);
+
+    fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let (dim, indices, shape) = ops.state;
+            let device = B::device(&grad);
+            let zeros = B::zeros(shape, &device);
+
+            B::scatter(dim, zeros, indices, grad)
+        });
+    }
+}
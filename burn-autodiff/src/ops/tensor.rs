@@ -10,10 +10,19 @@ use crate::{
 };
 
 use burn_tensor::{
-    backend::Backend, ops::TensorOps, Data, ElementConversion, Reader, Shape, Tensor,
+    backend::Backend,
+    ops::{PadMode, TensorOps},
+    Data, ElementConversion, Reader, Shape, Tensor,
 };
 
-use super::maxmin::MaxMinDim;
+use super::{
+    cumulative::{cumprod_backward, reverse_cumsum, reverse_logcumsumexp},
+    maxmin::MaxMinDim,
+    padding::Pad,
+    product::prod_dim_backward,
+    sort::SortDim,
+    zero_grad::ZeroGrad,
+};
 
 impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
     fn from_data<const D: usize>(
@@ -312,6 +321,67 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn remainder<const D: usize>(lhs: ADTensor<B, D>, rhs: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Remainder;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 2> for Remainder {
+            type State = (Option<B::TensorPrimitive<D>>, BinaryOpsBroadcast<D>);
+
+            fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+                let (quotient, broadcast) = ops.state;
+
+                binary::<B, D, D, D, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| broadcast.backward_lhs::<B>(grad),
+                    |grad| {
+                        let grad = B::mul(grad, B::neg(quotient.unwrap()));
+
+                        broadcast.backward_rhs::<B>(grad)
+                    },
+                );
+            }
+        }
+
+        let rhs_tracked = rhs.is_tracked();
+        let broadcast = BinaryOpsBroadcast::new::<B>(&lhs.primitive, &rhs.primitive);
+
+        match Remainder
+            .prepare([lhs.node, rhs.node], [lhs.graph, rhs.graph])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let quotient = rhs_tracked
+                    .then(|| B::floor(B::div(lhs.primitive.clone(), rhs.primitive.clone())));
+
+                prep.finish(
+                    (quotient, broadcast),
+                    B::remainder(lhs.primitive, rhs.primitive),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::remainder(lhs.primitive, rhs.primitive)),
+        }
+    }
+
+    fn remainder_scalar<const D: usize>(lhs: ADTensor<B, D>, rhs: FloatElem<B>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct RemainderScalar;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for RemainderScalar {
+            type State = ();
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| grad);
+            }
+        }
+
+        RemainderScalar
+            .prepare([lhs.node], [lhs.graph])
+            .stateless(B::remainder_scalar(lhs.primitive, rhs))
+    }
+
     fn div_scalar<const D: usize>(lhs: ADTensor<B, D>, rhs: FloatElem<B>) -> ADTensor<B, D> {
         #[derive(Debug)]
         struct DivScalar;
@@ -431,6 +501,141 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn permute<const D: usize>(tensor: ADTensor<B, D>, axes: [usize; D]) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Permute;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Permute {
+            type State = [usize; D];
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let axes = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| B::permute(grad, axes));
+            }
+        }
+
+        let output = B::permute(tensor.primitive, axes);
+
+        match Permute.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                // The gradient is permuted back with the inverse permutation.
+                let mut inverse = [0; D];
+                for (i, axis) in axes.iter().enumerate() {
+                    inverse[*axis] = i;
+                }
+
+                prep.finish(inverse, output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
+    fn expand<const D1: usize, const D2: usize>(
+        tensor: ADTensor<B, D1>,
+        shape: Shape<D2>,
+    ) -> ADTensor<B, D2> {
+        #[derive(Debug)]
+        struct Expand<const D1: usize>;
+
+        impl<B: Backend, const D1: usize, const D2: usize> Backward<B, D2, 1> for Expand<D1> {
+            type State = Shape<D1>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let shape_original = ops.state;
+
+                unary::<B, D2, D1, _>(ops.parents, ops.node, grads, |grad| {
+                    let shape_grad = B::shape(&grad);
+                    let offset = D2 - D1;
+                    let mut grad = grad;
+
+                    // Sum over the new dimensions and the ones that were expanded.
+                    for i in 0..D2 {
+                        if i < offset || shape_original.dims[i - offset] != shape_grad.dims[i] {
+                            grad = B::sum_dim(grad, i);
+                        }
+                    }
+
+                    B::reshape(grad, shape_original)
+                });
+            }
+        }
+
+        match Expand.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(
+                B::shape(&tensor.primitive),
+                B::expand(tensor.primitive, shape),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::expand(tensor.primitive, shape)),
+        }
+    }
+
+    fn flip<const D: usize>(tensor: ADTensor<B, D>, axes: &[usize]) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Flip;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Flip {
+            type State = Vec<usize>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let axes = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| B::flip(grad, &axes));
+            }
+        }
+
+        let output = B::flip(tensor.primitive, axes);
+
+        match Flip.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(axes.to_vec(), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
+    fn roll<const D: usize>(
+        tensor: ADTensor<B, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Roll;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Roll {
+            type State = (Vec<i64>, Vec<usize>);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (shifts, dims) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::roll(grad, &shifts, &dims)
+                });
+            }
+        }
+
+        let output = B::roll(tensor.primitive, shifts, dims);
+
+        match Roll.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let shifts = shifts.iter().map(|shift| -shift).collect();
+                prep.finish((shifts, dims.to_vec()), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
+    fn pad<const D: usize>(
+        tensor: ADTensor<B, D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> ADTensor<B, D> {
+        let output = B::pad(tensor.primitive, pads, mode);
+
+        match Pad.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish((pads, mode), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
     fn reshape<const D1: usize, const D2: usize>(
         tensor: ADTensor<B, D1>,
         shape: Shape<D2>,
@@ -995,6 +1200,188 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn prod<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, 1> {
+        #[derive(Debug)]
+        struct Prod<const D: usize>;
+
+        impl<B: Backend, const D: usize> Backward<B, 1, 1> for Prod<D> {
+            type State = (B::TensorPrimitive<D>, B::TensorPrimitive<1>);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (input, output) = ops.state;
+
+                unary::<B, 1, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let shape = B::shape(&input);
+                    let input = B::reshape(input, Shape::new([1, shape.num_elements()]));
+                    let output = B::reshape(output, Shape::new([1, 1]));
+                    let grad = B::reshape(grad, Shape::new([1, 1]));
+
+                    B::reshape(prod_dim_backward::<B, 2>(input, output, grad, 1), shape)
+                });
+            }
+        }
+
+        match Prod.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let output = B::prod(tensor.primitive.clone());
+                prep.finish((tensor.primitive, output.clone()), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::prod(tensor.primitive)),
+        }
+    }
+
+    fn prod_dim<const D: usize>(tensor: ADTensor<B, D>, dim: usize) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct ProdDim;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for ProdDim {
+            type State = (B::TensorPrimitive<D>, B::TensorPrimitive<D>, usize);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (input, output, dim) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    prod_dim_backward::<B, D>(input, output, grad, dim)
+                });
+            }
+        }
+
+        match ProdDim.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let output = B::prod_dim(tensor.primitive.clone(), dim);
+                prep.finish((tensor.primitive, output.clone(), dim), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::prod_dim(tensor.primitive, dim)),
+        }
+    }
+
+    fn cumsum<const D: usize>(tensor: ADTensor<B, D>, dim: usize) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct CumSum;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for CumSum {
+            type State = usize;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let dim = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    reverse_cumsum::<B, D>(grad, dim)
+                });
+            }
+        }
+
+        match CumSum.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(dim, B::cumsum(tensor.primitive, dim)),
+            OpsKind::UnTracked(prep) => prep.finish(B::cumsum(tensor.primitive, dim)),
+        }
+    }
+
+    fn cumprod<const D: usize>(tensor: ADTensor<B, D>, dim: usize) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct CumProd;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for CumProd {
+            type State = (B::TensorPrimitive<D>, B::TensorPrimitive<D>, usize);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (input, output, dim) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    cumprod_backward::<B, D>(input, output, grad, dim)
+                });
+            }
+        }
+
+        match CumProd.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let output = B::cumprod(tensor.primitive.clone(), dim);
+                prep.finish((tensor.primitive, output.clone(), dim), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::cumprod(tensor.primitive, dim)),
+        }
+    }
+
+    fn cummax<const D: usize>(tensor: ADTensor<B, D>, dim: usize) -> ADTensor<B, D> {
+        Self::cummax_with_indices(tensor, dim).0
+    }
+
+    fn cummax_with_indices<const D: usize>(
+        tensor: ADTensor<B, D>,
+        dim: usize,
+    ) -> (ADTensor<B, D>, IntTensor<B, D>) {
+        #[derive(Debug)]
+        struct CumMax;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for CumMax {
+            type State = (B::IntTensorPrimitive<D>, Shape<D>, usize);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (indices, shape, dim) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let zeros = B::zeros(shape, &B::device(&grad));
+                    B::scatter(dim, zeros, indices, grad)
+                });
+            }
+        }
+
+        match CumMax.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let shape = B::shape(&tensor.primitive);
+                let (tensor, indices) = B::cummax_with_indices(tensor.primitive, dim);
+                let tensor = prep.finish((indices.clone(), shape, dim), tensor);
+
+                (tensor, indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let (tensor, indices) = B::cummax_with_indices(tensor.primitive, dim);
+                let tensor = prep.finish(tensor);
+
+                (tensor, indices)
+            }
+        }
+    }
+
+    fn logcumsumexp<const D: usize>(tensor: ADTensor<B, D>, dim: usize) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct LogCumSumExp;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for LogCumSumExp {
+            type State = (B::TensorPrimitive<D>, B::TensorPrimitive<D>, usize);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (input, output, dim) = ops.state;
+
+                // grad_i = sum_{j >= i} grad_j * exp(x_i - y_j), computed in log space with the
+                // positive and negative parts of the gradient to stay numerically stable.
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let grad_pos = B::clamp_min(grad.clone(), 0.elem());
+                    let grad_neg = B::clamp_min(B::neg(grad), 0.elem());
+
+                    let reduce = |grad| {
+                        let grad = B::sub(B::log(grad), output.clone());
+                        let grad = reverse_logcumsumexp::<B, D>(grad, dim);
+                        B::exp(B::add(input.clone(), grad))
+                    };
+
+                    B::sub(reduce(grad_pos), reduce(grad_neg))
+                });
+            }
+        }
+
+        match LogCumSumExp
+            .prepare([tensor.node], [tensor.graph])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::logcumsumexp(tensor.primitive.clone(), dim);
+                prep.finish((tensor.primitive, output.clone(), dim), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::logcumsumexp(tensor.primitive, dim)),
+        }
+    }
+
     fn to_full_precision<const D: usize>(
         tensor: &ADTensor<B, D>,
     ) -> ADTensor<B::FullPrecisionBackend, D> {
@@ -1302,6 +1689,36 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn floor<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        ZeroGrad
+            .prepare([tensor.node], [tensor.graph])
+            .stateless(B::floor(tensor.primitive))
+    }
+
+    fn ceil<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        ZeroGrad
+            .prepare([tensor.node], [tensor.graph])
+            .stateless(B::ceil(tensor.primitive))
+    }
+
+    fn round<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        ZeroGrad
+            .prepare([tensor.node], [tensor.graph])
+            .stateless(B::round(tensor.primitive))
+    }
+
+    fn trunc<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        ZeroGrad
+            .prepare([tensor.node], [tensor.graph])
+            .stateless(B::trunc(tensor.primitive))
+    }
+
+    fn sign<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        ZeroGrad
+            .prepare([tensor.node], [tensor.graph])
+            .stateless(B::sign(tensor.primitive))
+    }
+
     fn cat<const D: usize>(tensors: Vec<ADTensor<B, D>>, dim: usize) -> ADTensor<B, D> {
         #[derive(new, Debug)]
         struct CatStep<B: Backend, const D: usize> {
@@ -1325,12 +1742,15 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                 self.nodes
                     .into_iter()
                     .zip(self.dim_sizes)
-                    .filter_map(|(node, dim_size)| node.map(|node| (node, dim_size)))
                     .for_each(|(node, dim_size)| {
                         let mut ranges = ranges.clone();
                         ranges[self.dim] = current_index..dim_size + current_index;
                         current_index += dim_size;
-                        grads.register::<B, D>(node, B::slice(grad.clone(), ranges));
+
+                        // The tensors that don't require grad still take their part of the output.
+                        if let Some(node) = node {
+                            grads.register::<B, D>(node, B::slice(grad.clone(), ranges));
+                        }
                     });
             }
 
@@ -1434,6 +1854,70 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
     ) -> <ADBackendDecorator<B> as Backend>::IntTensorPrimitive<D> {
         B::into_int(tensor.primitive)
     }
+
+    fn sort<const D: usize>(
+        tensor: ADTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> ADTensor<B, D> {
+        Self::sort_with_indices(tensor, dim, descending).0
+    }
+
+    fn sort_with_indices<const D: usize>(
+        tensor: ADTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (ADTensor<B, D>, IntTensor<B, D>) {
+        match SortDim.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let shape = B::shape(&tensor.primitive);
+                let (tensor, indices) = B::sort_with_indices(tensor.primitive, dim, descending);
+                let tensor = prep.finish((dim, indices.clone(), shape), tensor);
+
+                (tensor, indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let (tensor, indices) = B::sort_with_indices(tensor.primitive, dim, descending);
+                let tensor = prep.finish(tensor);
+
+                (tensor, indices)
+            }
+        }
+    }
+
+    fn argsort<const D: usize>(
+        tensor: ADTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        B::argsort(tensor.primitive, dim, descending)
+    }
+
+    fn topk<const D: usize>(tensor: ADTensor<B, D>, dim: usize, k: usize) -> ADTensor<B, D> {
+        Self::topk_with_indices(tensor, dim, k).0
+    }
+
+    fn topk_with_indices<const D: usize>(
+        tensor: ADTensor<B, D>,
+        dim: usize,
+        k: usize,
+    ) -> (ADTensor<B, D>, IntTensor<B, D>) {
+        match SortDim.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let shape = B::shape(&tensor.primitive);
+                let (tensor, indices) = B::topk_with_indices(tensor.primitive, dim, k);
+                let tensor = prep.finish((dim, indices.clone(), shape), tensor);
+
+                (tensor, indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let (tensor, indices) = B::topk_with_indices(tensor.primitive, dim, k);
+                let tensor = prep.finish(tensor);
+
+                (tensor, indices)
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,18 @@
+use super::{unary, Backward, Ops};
+use crate::grads::Gradients;
+use burn_tensor::backend::Backend;
+
+/// Backward step of piecewise constant operations, such as rounding, whose gradient is zero
+/// everywhere it is defined.
+#[derive(Debug)]
+pub(crate) struct ZeroGrad;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for ZeroGrad {
+    type State = ();
+
+    fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            B::zeros(B::shape(&grad), &B::device(&grad))
+        });
+    }
+}
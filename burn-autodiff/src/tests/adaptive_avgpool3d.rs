@@ -0,0 +1,73 @@
+#[burn_tensor_testgen::testgen(ad_adaptive_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_avg_pool3d;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_adaptive_avg_pool3d_simple() {
+        let test = AdaptiveAvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            size: [3, 5, 4],
+            output_size: [2, 3, 2],
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [
+                [0.125, 0.125, 0.125, 0.125],
+                [0.2083, 0.2083, 0.2083, 0.2083],
+                [0.0833, 0.0833, 0.0833, 0.0833],
+                [0.2083, 0.2083, 0.2083, 0.2083],
+                [0.125, 0.125, 0.125, 0.125],
+            ],
+            [
+                [0.25, 0.25, 0.25, 0.25],
+                [0.4167, 0.4167, 0.4167, 0.4167],
+                [0.1667, 0.1667, 0.1667, 0.1667],
+                [0.4167, 0.4167, 0.4167, 0.4167],
+                [0.25, 0.25, 0.25, 0.25],
+            ],
+            [
+                [0.125, 0.125, 0.125, 0.125],
+                [0.2083, 0.2083, 0.2083, 0.2083],
+                [0.0833, 0.0833, 0.0833, 0.0833],
+                [0.2083, 0.2083, 0.2083, 0.2083],
+                [0.125, 0.125, 0.125, 0.125],
+            ],
+        ]]]));
+    }
+
+    struct AdaptiveAvgPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        size: [usize; 3],
+        output_size: [usize; 3],
+    }
+
+    impl AdaptiveAvgPool3dTestCase {
+        fn assert_output(self, x_grad: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.size[0],
+                self.size[1],
+                self.size[2],
+            ]);
+            let x = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let output = adaptive_avg_pool3d(x.clone(), self.output_size);
+            let grads = output.backward();
+            let x_grad_actual = x.grad(&grads).unwrap();
+
+            x_grad
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.into_data(), 3);
+        }
+    }
+}
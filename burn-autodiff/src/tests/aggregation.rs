@@ -118,4 +118,70 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([[18.0, 18.0], [71.0, 71.0]]), 5);
     }
+
+    #[test]
+    fn should_diff_prod() {
+        let data = Data::<f32, 2>::from([[1.0, 2.0], [-3.0, 4.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().prod();
+        let grads = tensor_2.backward();
+
+        let grad = tensor_1.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[-24.0, -12.0], [8.0, -6.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_prod_dim() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 7.0], [-2.0, -3.0]]);
+        let data_2 = Data::<f32, 2>::from([[4.0, -7.0], [2.0, 3.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3 = tensor_1.clone().prod_dim(1).mul(tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[-21.0, -3.0], [-15.0, -10.0]]), 5);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[7.0, 7.0], [6.0, 6.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_prod_with_zeros() {
+        let data = Data::<f32, 2>::from([[1.0, 0.0], [-3.0, 4.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().prod();
+        let grads = tensor_2.backward();
+
+        let grad = tensor_1.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.0, -12.0], [0.0, 0.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_prod_dim_with_zeros() {
+        let data = Data::<f32, 2>::from([[1.0, 0.0, 3.0], [0.0, 2.0, 0.0], [2.0, 5.0, -1.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().prod_dim(1);
+        let grads = tensor_2.sum().backward();
+
+        let grad = tensor_1.grad(&grads).unwrap();
+
+        grad.to_data().assert_approx_eq(
+            &Data::from([[0.0, 3.0, 0.0], [0.0, 0.0, 0.0], [-5.0, -2.0, 10.0]]),
+            5,
+        );
+    }
 }
@@ -0,0 +1,174 @@
+#[burn_tensor_testgen::testgen(ad_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::scaled_dot_product_attention;
+    use burn_tensor::ops::AttentionOptions;
+    use burn_tensor::{activation, Bool, Data, Distribution, Shape, Tensor};
+
+    #[test]
+    fn test_scaled_dot_product_attention_backward() {
+        let query =
+            TestADTensor::from_floats([[[[1.0, 0.0], [0.0, 1.0]], [[0.5, -0.5], [1.0, 1.0]]]])
+                .require_grad();
+        let key = TestADTensor::from_floats([[
+            [[1.0, 2.0], [0.0, 1.0], [-1.0, 0.5]],
+            [[0.2, 0.3], [1.0, -1.0], [0.0, 0.0]],
+        ]])
+        .require_grad();
+        let value = TestADTensor::from_floats([[
+            [[1.0, 0.0], [0.0, 1.0], [2.0, 2.0]],
+            [[-1.0, 1.0], [0.5, 0.5], [3.0, 0.0]],
+        ]])
+        .require_grad();
+        let mask = Tensor::<TestADBackend, 4, Bool>::from_data(Data::from([[[
+            [false, false, true],
+            [false, false, false],
+        ]]]));
+        let output_weights =
+            TestADTensor::from_floats([[[[1.0, -1.0], [0.5, 2.0]], [[2.0, 0.0], [-1.0, 1.0]]]]);
+
+        let output = scaled_dot_product_attention(
+            query.clone(),
+            key.clone(),
+            value.clone(),
+            None,
+            Some(mask),
+            AttentionOptions::new(1.0 / 2.0f64.sqrt(), -1.0e4),
+        );
+        let grads = output.mul(output_weights).sum().backward();
+
+        let query_grad = query.grad(&grads).unwrap();
+        let key_grad = key.grad(&grads).unwrap();
+        let value_grad = value.grad(&grads).unwrap();
+
+        query_grad.to_data().assert_approx_eq(
+            &Data::from([[
+                [[0.3128, 0.3128], [-0.9129, -0.6966]],
+                [[0.3708, -0.6025], [0.1294, 0.1712]],
+            ]]),
+            3,
+        );
+        key_grad.to_data().assert_approx_eq(
+            &Data::from([[
+                [[0.3128, -0.4802], [-0.3128, 0.0475], [0.0, 0.4327]],
+                [[0.3695, 0.8330], [0.2409, -0.2226], [-0.6104, -0.6104]],
+            ]]),
+            3,
+        );
+        value_grad.to_data().assert_approx_eq(
+            &Data::from([[
+                [[0.9416, 0.4176], [0.4643, 0.2059], [0.0941, 0.3765]],
+                [[0.2290, 0.4159], [1.0630, 0.2920], [-0.2920, 0.2920]],
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention_backward_long_sequences() {
+        let [batch_size, n_heads, seq_length_1, seq_length_2, d_k, d_v] = [2, 2, 70, 130, 4, 3];
+        let shape_query = [batch_size, n_heads, seq_length_1, d_k];
+        let shape_key = [batch_size, n_heads, seq_length_2, d_k];
+        let shape_value = [batch_size, n_heads, seq_length_2, d_v];
+        let query = TestADTensor::random(shape_query, Distribution::Default);
+        let key = TestADTensor::random(shape_key, Distribution::Default);
+        let value = TestADTensor::random(shape_value, Distribution::Default);
+        let bias = TestADTensor::random(
+            [1, n_heads, seq_length_1, seq_length_2],
+            Distribution::Default,
+        );
+        let mask = TestADTensor::random(
+            [batch_size, 1, seq_length_1, seq_length_2],
+            Distribution::Default,
+        )
+        .lower_elem(0.3);
+        let output_weights = TestADTensor::random(
+            [batch_size, n_heads, seq_length_1, d_v],
+            Distribution::Default,
+        );
+
+        let query_1 = query.clone().require_grad();
+        let key_1 = key.clone().require_grad();
+        let value_1 = value.clone().require_grad();
+        let output = scaled_dot_product_attention(
+            query_1.clone(),
+            key_1.clone(),
+            value_1.clone(),
+            Some(bias.clone()),
+            Some(mask.clone()),
+            AttentionOptions::new(0.5, -1.0e4),
+        );
+        let grads_1 = output.mul(output_weights.clone()).sum().backward();
+
+        let query_2 = query.require_grad();
+        let key_2 = key.require_grad();
+        let value_2 = value.require_grad();
+        let scores = query_2
+            .clone()
+            .matmul(key_2.clone().transpose())
+            .mul_scalar(0.5)
+            .add(bias)
+            .mask_fill(mask, -1.0e4);
+        let output = activation::softmax(scores, 3).matmul(value_2.clone());
+        let grads_2 = output.mul(output_weights).sum().backward();
+
+        for (tensor_1, tensor_2) in [(query_1, query_2), (key_1, key_2), (value_1, value_2)] {
+            tensor_1
+                .grad(&grads_1)
+                .unwrap()
+                .into_data()
+                .assert_approx_eq(&tensor_2.grad(&grads_2).unwrap().into_data(), 3);
+        }
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention_backward_with_dropout() {
+        let shape_scores = [2, 2, 70, 30];
+        let query = TestADTensor::random([2, 2, 70, 4], Distribution::Default);
+        let key = TestADTensor::random([2, 2, 30, 4], Distribution::Default);
+        let value = TestADTensor::random([2, 2, 30, 3], Distribution::Default);
+        let mask = TestADTensor::random([2, 1, 70, 30], Distribution::Default).lower_elem(0.3);
+        let output_weights = TestADTensor::random([2, 2, 70, 3], Distribution::Default);
+        let options = AttentionOptions::new(0.5, -1.0e4).with_dropout(0.2, 7);
+
+        let query_1 = query.clone().require_grad();
+        let key_1 = key.clone().require_grad();
+        let value_1 = value.clone().require_grad();
+        let output = scaled_dot_product_attention(
+            query_1.clone(),
+            key_1.clone(),
+            value_1.clone(),
+            None,
+            Some(mask.clone()),
+            options.clone(),
+        );
+        let grads_1 = output.mul(output_weights.clone()).sum().backward();
+
+        let num_scores = shape_scores.iter().product();
+        let factors = (0..num_scores)
+            .map(|index| options.dropout_factor(index) as f32)
+            .collect();
+        let factors =
+            TestADTensor::from_data(Data::new(factors, Shape::new(shape_scores)).convert());
+
+        let query_2 = query.require_grad();
+        let key_2 = key.require_grad();
+        let value_2 = value.require_grad();
+        let scores = query_2
+            .clone()
+            .matmul(key_2.clone().transpose())
+            .mul_scalar(0.5)
+            .mul(factors)
+            .mask_fill(mask, -1.0e4);
+        let output = activation::softmax(scores, 3).matmul(value_2.clone());
+        let grads_2 = output.mul(output_weights).sum().backward();
+
+        for (tensor_1, tensor_2) in [(query_1, query_2), (key_1, key_2), (value_1, value_2)] {
+            tensor_1
+                .grad(&grads_1)
+                .unwrap()
+                .into_data()
+                .assert_approx_eq(&tensor_2.grad(&grads_2).unwrap().into_data(), 3);
+        }
+    }
+}
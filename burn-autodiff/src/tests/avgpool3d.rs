@@ -0,0 +1,143 @@
+#[burn_tensor_testgen::testgen(ad_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::avg_pool3d;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_avg_pool3d_simple() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size: [2, 2, 2],
+            padding: [0, 0, 0],
+            stride: [1, 1, 1],
+            size: [3, 3, 3],
+            count_include_pad: true,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [
+                [0.125, 0.25, 0.125],
+                [0.25, 0.5, 0.25],
+                [0.125, 0.25, 0.125],
+            ],
+            [[0.25, 0.5, 0.25], [0.5, 1., 0.5], [0.25, 0.5, 0.25]],
+            [
+                [0.125, 0.25, 0.125],
+                [0.25, 0.5, 0.25],
+                [0.125, 0.25, 0.125],
+            ],
+        ]]]));
+    }
+
+    #[test]
+    fn test_avg_pool3d_complex() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size: [3, 2, 3],
+            padding: [1, 1, 1],
+            stride: [2, 1, 2],
+            size: [3, 4, 4],
+            count_include_pad: true,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [
+                [0.1111, 0.2222, 0.1111, 0.1111],
+                [0.1111, 0.2222, 0.1111, 0.1111],
+                [0.1111, 0.2222, 0.1111, 0.1111],
+                [0.1111, 0.2222, 0.1111, 0.1111],
+            ],
+            [
+                [0.2222, 0.4444, 0.2222, 0.2222],
+                [0.2222, 0.4444, 0.2222, 0.2222],
+                [0.2222, 0.4444, 0.2222, 0.2222],
+                [0.2222, 0.4444, 0.2222, 0.2222],
+            ],
+            [
+                [0.1111, 0.2222, 0.1111, 0.1111],
+                [0.1111, 0.2222, 0.1111, 0.1111],
+                [0.1111, 0.2222, 0.1111, 0.1111],
+                [0.1111, 0.2222, 0.1111, 0.1111],
+            ],
+        ]]]));
+    }
+
+    #[test]
+    fn test_avg_pool3d_complex_dont_include_pad() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size: [3, 2, 3],
+            padding: [1, 1, 1],
+            stride: [2, 1, 2],
+            size: [3, 4, 4],
+            count_include_pad: false,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [
+                [0.375, 0.625, 0.25, 0.25],
+                [0.25, 0.4167, 0.1667, 0.1667],
+                [0.25, 0.4167, 0.1667, 0.1667],
+                [0.375, 0.625, 0.25, 0.25],
+            ],
+            [
+                [0.75, 1.25, 0.5, 0.5],
+                [0.5, 0.8333, 0.3333, 0.3333],
+                [0.5, 0.8333, 0.3333, 0.3333],
+                [0.75, 1.25, 0.5, 0.5],
+            ],
+            [
+                [0.375, 0.625, 0.25, 0.25],
+                [0.25, 0.4167, 0.1667, 0.1667],
+                [0.25, 0.4167, 0.1667, 0.1667],
+                [0.375, 0.625, 0.25, 0.25],
+            ],
+        ]]]));
+    }
+
+    struct AvgPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        kernel_size: [usize; 3],
+        padding: [usize; 3],
+        stride: [usize; 3],
+        size: [usize; 3],
+        count_include_pad: bool,
+    }
+
+    impl AvgPool3dTestCase {
+        fn assert_output(self, x_grad: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.size[0],
+                self.size[1],
+                self.size[2],
+            ]);
+            let x = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let output = avg_pool3d(
+                x.clone(),
+                self.kernel_size,
+                self.stride,
+                self.padding,
+                self.count_include_pad,
+            );
+            let grads = output.backward();
+            let x_grad_actual = x.grad(&grads).unwrap();
+
+            x_grad
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.into_data(), 3);
+        }
+    }
+}
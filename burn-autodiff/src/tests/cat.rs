@@ -73,4 +73,20 @@ mod tests {
         assert_eq!(tensor_1.dims(), grad_1.dims());
         assert_eq!(tensor_2.dims(), grad_2.dims());
     }
+
+    #[test]
+    fn should_diff_cat_with_tensors_not_requiring_grad() {
+        let tensor_1 = TestADTensor::from_data([[2.0, -1.0], [5.0, 2.0]]);
+        let tensor_2 = TestADTensor::from_data([[5.0, 4.0], [-1.0, 4.0]]).require_grad();
+        let weights = TestADTensor::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]);
+
+        let tensor_3 = TestADTensor::cat(vec![tensor_1, tensor_2.clone()], 0).mul(weights);
+        let grads = tensor_3.backward();
+
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[5.0, 6.0], [7.0, 8.0]]), 3);
+    }
 }
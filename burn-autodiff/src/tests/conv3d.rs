@@ -0,0 +1,264 @@
+#[burn_tensor_testgen::testgen(ad_conv3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::conv3d, ops::ConvOptions, Data, Shape};
+
+    #[test]
+    fn test_conv3d_basic() {
+        let test = Conv3dTestCase {
+            batch_size: 2,
+            channels: [2, 2],
+            kernel_size: [2, 2, 2],
+            padding: [1, 1, 1],
+            stride: [1, 1, 1],
+            dilation: [1, 1, 1],
+            groups: 1,
+            size: [2, 3, 3],
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [
+                    [
+                        [[184., 184., 184.], [184., 184., 184.], [184., 184., 184.]],
+                        [[184., 184., 184.], [184., 184., 184.], [184., 184., 184.]],
+                    ],
+                    [
+                        [[312., 312., 312.], [312., 312., 312.], [312., 312., 312.]],
+                        [[312., 312., 312.], [312., 312., 312.], [312., 312., 312.]],
+                    ],
+                ],
+                [
+                    [
+                        [[184., 184., 184.], [184., 184., 184.], [184., 184., 184.]],
+                        [[184., 184., 184.], [184., 184., 184.], [184., 184., 184.]],
+                    ],
+                    [
+                        [[312., 312., 312.], [312., 312., 312.], [312., 312., 312.]],
+                        [[312., 312., 312.], [312., 312., 312.], [312., 312., 312.]],
+                    ],
+                ],
+            ]),
+            weight: TestTensor::from_floats([
+                [
+                    [[[954., 954.], [954., 954.]], [[954., 954.], [954., 954.]]],
+                    [
+                        [[1602., 1602.], [1602., 1602.]],
+                        [[1602., 1602.], [1602., 1602.]],
+                    ],
+                ],
+                [
+                    [[[954., 954.], [954., 954.]], [[954., 954.], [954., 954.]]],
+                    [
+                        [[1602., 1602.], [1602., 1602.]],
+                        [[1602., 1602.], [1602., 1602.]],
+                    ],
+                ],
+            ]),
+            bias: TestTensor::from_floats([96., 96.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv3d_groups() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels: [2, 4],
+            kernel_size: [2, 2, 2],
+            padding: [0, 0, 0],
+            stride: [1, 1, 1],
+            dilation: [1, 1, 1],
+            groups: 2,
+            size: [2, 3, 3],
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([[
+                [
+                    [[8., 18., 10.], [20., 44., 24.], [12., 26., 14.]],
+                    [[16., 34., 18.], [36., 76., 40.], [20., 42., 22.]],
+                ],
+                [
+                    [[40., 82., 42.], [84., 172., 88.], [44., 90., 46.]],
+                    [[48., 98., 50.], [100., 204., 104.], [52., 106., 54.]],
+                ],
+            ]]),
+            weight: TestTensor::from_floats([
+                [[[[8., 12.], [20., 24.]], [[44., 48.], [56., 60.]]]],
+                [[[[8., 12.], [20., 24.]], [[44., 48.], [56., 60.]]]],
+                [[[[80., 84.], [92., 96.]], [[116., 120.], [128., 132.]]]],
+                [[[[80., 84.], [92., 96.]], [[116., 120.], [128., 132.]]]],
+            ]),
+            bias: TestTensor::from_floats([4., 4., 4., 4.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv3d_complex() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels: [2, 2],
+            kernel_size: [2, 3, 2],
+            padding: [1, 0, 1],
+            stride: [2, 1, 2],
+            dilation: [1, 2, 1],
+            groups: 1,
+            size: [3, 5, 3],
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([[
+                [
+                    [
+                        [38., 36., 38.],
+                        [0., 0., 0.],
+                        [42., 40., 42.],
+                        [0., 0., 0.],
+                        [46., 44., 46.],
+                    ],
+                    [
+                        [26., 24., 26.],
+                        [0., 0., 0.],
+                        [30., 28., 30.],
+                        [0., 0., 0.],
+                        [34., 32., 34.],
+                    ],
+                    [
+                        [38., 36., 38.],
+                        [0., 0., 0.],
+                        [42., 40., 42.],
+                        [0., 0., 0.],
+                        [46., 44., 46.],
+                    ],
+                ],
+                [
+                    [
+                        [62., 60., 62.],
+                        [0., 0., 0.],
+                        [66., 64., 66.],
+                        [0., 0., 0.],
+                        [70., 68., 70.],
+                    ],
+                    [
+                        [50., 48., 50.],
+                        [0., 0., 0.],
+                        [54., 52., 54.],
+                        [0., 0., 0.],
+                        [58., 56., 58.],
+                    ],
+                    [
+                        [62., 60., 62.],
+                        [0., 0., 0.],
+                        [66., 64., 66.],
+                        [0., 0., 0.],
+                        [70., 68., 70.],
+                    ],
+                ],
+            ]]),
+            weight: TestTensor::from_floats([
+                [
+                    [
+                        [[16., 32.], [22., 44.], [28., 56.]],
+                        [[32., 64.], [44., 88.], [56., 112.]],
+                    ],
+                    [
+                        [[61., 122.], [67., 134.], [73., 146.]],
+                        [[122., 244.], [134., 268.], [146., 292.]],
+                    ],
+                ],
+                [
+                    [
+                        [[16., 32.], [22., 44.], [28., 56.]],
+                        [[32., 64.], [44., 88.], [56., 112.]],
+                    ],
+                    [
+                        [[61., 122.], [67., 134.], [73., 146.]],
+                        [[122., 244.], [134., 268.], [146., 292.]],
+                    ],
+                ],
+            ]),
+            bias: TestTensor::from_floats([4., 4.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    struct Conv3dTestCase {
+        batch_size: usize,
+        channels: [usize; 2],
+        kernel_size: [usize; 3],
+        padding: [usize; 3],
+        stride: [usize; 3],
+        dilation: [usize; 3],
+        groups: usize,
+        size: [usize; 3],
+    }
+
+    struct Grads {
+        x: TestTensor<5>,
+        weight: TestTensor<5>,
+        bias: TestTensor<1>,
+    }
+
+    impl Conv3dTestCase {
+        fn assert_grads(self, expected_grads: Grads) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels[0],
+                self.size[0],
+                self.size[1],
+                self.size[2],
+            ]);
+            let shape_weight = Shape::new([
+                self.channels[1],
+                self.channels[0] / self.groups,
+                self.kernel_size[0],
+                self.kernel_size[1],
+                self.kernel_size[2],
+            ]);
+            let weight = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_weight.num_elements())
+                    .reshape(shape_weight)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let bias = TestADTensor::from_data(
+                TestTensorInt::arange(0..self.channels[1])
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let x = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let output = conv3d(
+                x.clone(),
+                weight.clone(),
+                Some(bias.clone()),
+                ConvOptions::new(self.stride, self.padding, self.dilation, self.groups),
+            );
+            let grads = output.backward();
+
+            // Assert
+            let x_grad_actual = x.grad(&grads).unwrap();
+            let weight_grad_actual = weight.grad(&grads).unwrap();
+            let bias_grad_actual = bias.grad(&grads).unwrap();
+
+            expected_grads
+                .bias
+                .to_data()
+                .assert_approx_eq(&bias_grad_actual.to_data(), 3);
+            expected_grads
+                .x
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.to_data(), 3);
+            expected_grads
+                .weight
+                .to_data()
+                .assert_approx_eq(&weight_grad_actual.to_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,216 @@
+#[burn_tensor_testgen::testgen(ad_conv_transpose3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::conv_transpose3d, ops::ConvTransposeOptions, Data, Shape};
+
+    #[test]
+    fn test_conv_transpose3d_basic() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 2,
+            channels: [2, 2],
+            kernel_size: [2, 2, 2],
+            padding: [0, 0, 0],
+            padding_out: [0, 0, 0],
+            stride: [1, 1, 1],
+            dilation: [1, 1, 1],
+            groups: 1,
+            size: [2, 2, 2],
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [
+                    [[[120., 120.], [120., 120.]], [[120., 120.], [120., 120.]]],
+                    [[[376., 376.], [376., 376.]], [[376., 376.], [376., 376.]]],
+                ],
+                [
+                    [[[120., 120.], [120., 120.]], [[120., 120.], [120., 120.]]],
+                    [[[376., 376.], [376., 376.]], [[376., 376.], [376., 376.]]],
+                ],
+            ]),
+            weight: TestTensor::from_floats([
+                [
+                    [[[184., 184.], [184., 184.]], [[184., 184.], [184., 184.]]],
+                    [[[184., 184.], [184., 184.]], [[184., 184.], [184., 184.]]],
+                ],
+                [
+                    [[[312., 312.], [312., 312.]], [[312., 312.], [312., 312.]]],
+                    [[[312., 312.], [312., 312.]], [[312., 312.], [312., 312.]]],
+                ],
+            ]),
+            bias: TestTensor::from_floats([54., 54.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose3d_groups() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels: [2, 4],
+            kernel_size: [2, 2, 2],
+            padding: [0, 0, 0],
+            padding_out: [0, 0, 0],
+            stride: [1, 1, 1],
+            dilation: [1, 1, 1],
+            groups: 2,
+            size: [2, 2, 2],
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([[
+                [[[120., 120.], [120., 120.]], [[120., 120.], [120., 120.]]],
+                [[[376., 376.], [376., 376.]], [[376., 376.], [376., 376.]]],
+            ]]),
+            weight: TestTensor::from_floats([
+                [
+                    [[[28., 28.], [28., 28.]], [[28., 28.], [28., 28.]]],
+                    [[[28., 28.], [28., 28.]], [[28., 28.], [28., 28.]]],
+                ],
+                [
+                    [[[92., 92.], [92., 92.]], [[92., 92.], [92., 92.]]],
+                    [[[92., 92.], [92., 92.]], [[92., 92.], [92., 92.]]],
+                ],
+            ]),
+            bias: TestTensor::from_floats([27., 27., 27., 27.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv_transpose3d_complex() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels: [2, 2],
+            kernel_size: [2, 3, 2],
+            padding: [1, 0, 1],
+            padding_out: [1, 0, 0],
+            stride: [2, 1, 2],
+            dilation: [1, 2, 1],
+            groups: 1,
+            size: [2, 2, 3],
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([[
+                [
+                    [[90., 174., 84.], [90., 174., 84.]],
+                    [[144., 276., 132.], [144., 276., 132.]],
+                ],
+                [
+                    [[234., 462., 228.], [234., 462., 228.]],
+                    [[432., 852., 420.], [432., 852., 420.]],
+                ],
+            ]]),
+            weight: TestTensor::from_floats([
+                [
+                    [
+                        [[36., 32.], [36., 32.], [36., 32.]],
+                        [[48., 40.], [48., 40.], [48., 40.]],
+                    ],
+                    [
+                        [[36., 32.], [36., 32.], [36., 32.]],
+                        [[48., 40.], [48., 40.], [48., 40.]],
+                    ],
+                ],
+                [
+                    [
+                        [[84., 80.], [84., 80.], [84., 80.]],
+                        [[144., 136.], [144., 136.], [144., 136.]],
+                    ],
+                    [
+                        [[84., 80.], [84., 80.], [84., 80.]],
+                        [[144., 136.], [144., 136.], [144., 136.]],
+                    ],
+                ],
+            ]),
+            bias: TestTensor::from_floats([72., 72.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    struct ConvTranspose3dTestCase {
+        batch_size: usize,
+        channels: [usize; 2],
+        kernel_size: [usize; 3],
+        padding: [usize; 3],
+        padding_out: [usize; 3],
+        stride: [usize; 3],
+        dilation: [usize; 3],
+        groups: usize,
+        size: [usize; 3],
+    }
+
+    struct Grads {
+        x: TestTensor<5>,
+        weight: TestTensor<5>,
+        bias: TestTensor<1>,
+    }
+
+    impl ConvTranspose3dTestCase {
+        fn assert_grads(self, expected_grads: Grads) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels[0],
+                self.size[0],
+                self.size[1],
+                self.size[2],
+            ]);
+            let shape_weight = Shape::new([
+                self.channels[0],
+                self.channels[1] / self.groups,
+                self.kernel_size[0],
+                self.kernel_size[1],
+                self.kernel_size[2],
+            ]);
+            let weight = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_weight.num_elements())
+                    .reshape(shape_weight)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let bias = TestADTensor::from_data(
+                TestTensorInt::arange(0..self.channels[1])
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let x = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let output = conv_transpose3d(
+                x.clone(),
+                weight.clone(),
+                Some(bias.clone()),
+                ConvTransposeOptions::new(
+                    self.stride,
+                    self.padding,
+                    self.padding_out,
+                    self.dilation,
+                    self.groups,
+                ),
+            );
+            let grads = output.backward();
+
+            // Assert
+            let x_grad_actual = x.grad(&grads).unwrap();
+            let weight_grad_actual = weight.grad(&grads).unwrap();
+            let bias_grad_actual = bias.grad(&grads).unwrap();
+
+            expected_grads
+                .bias
+                .to_data()
+                .assert_approx_eq(&bias_grad_actual.to_data(), 3);
+            expected_grads
+                .x
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.to_data(), 3);
+            expected_grads
+                .weight
+                .to_data()
+                .assert_approx_eq(&weight_grad_actual.to_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+#[burn_tensor_testgen::testgen(ad_cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_cumsum() {
+        let tensor_1 = TestADTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[1.0, 2.0, 3.0], [0.0, 1.0, -1.0]]);
+
+        let tensor_3 = tensor_1.clone().cumsum(1).mul(tensor_2);
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[6.0, 5.0, 3.0], [0.0, 0.0, -1.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_cumprod() {
+        let tensor_1 = TestADTensor::from_floats([[1.0, 2.0, 3.0]]).require_grad();
+
+        let tensor_2 = tensor_1.clone().cumprod(1);
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[9.0, 4.0, 2.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_cumprod_with_zeros() {
+        let tensor_1 =
+            TestADTensor::from_floats([[2.0, 0.0, 3.0, 4.0], [0.0, 2.0, 0.0, 5.0]]).require_grad();
+
+        let tensor_2 = tensor_1.clone().cumprod(1);
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq(
+            &Data::from([[1.0, 32.0, 0.0, 0.0], [3.0, 0.0, 0.0, 0.0]]),
+            5,
+        );
+    }
+
+    #[test]
+    fn should_diff_cummax() {
+        let tensor_1 = TestADTensor::from_floats([[2.0, 1.0, 3.0], [1.0, 2.0, 0.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[1.0, 2.0, 3.0], [1.0, 2.0, 3.0]]);
+
+        let tensor_3 = tensor_1.clone().cummax(1).mul(tensor_2);
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[3.0, 0.0, 3.0], [1.0, 5.0, 0.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_logcumsumexp() {
+        let tensor_1 = TestADTensor::from_floats([[0.0, 1.0, 2.0], [0.0, 1.0, 2.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[1.0, -1.0, 2.0], [1.0, 1.0, 1.0]]);
+
+        let tensor_3 = tensor_1.clone().logcumsumexp(1).mul(tensor_2);
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq(
+            &Data::from([[0.91112, -0.24160, 1.33048], [1.35897, 0.97579, 0.66524]]),
+            4,
+        );
+    }
+}
@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(ad_einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::{einsum, Data, Tensor};
+
+    #[test]
+    fn should_diff_einsum_matmul() {
+        let data_1 = Data::from([[1.0, 7.0], [2.0, 3.0]]);
+        let data_2 = Data::from([[4.0, 7.0], [2.0, 3.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3: Tensor<TestADBackend, 2> =
+            einsum("ij,jk->ik", &[tensor_1.clone(), tensor_2.clone()]);
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[11.0, 5.0], [11.0, 5.0]]));
+        assert_eq!(grad_2.to_data(), Data::from([[3.0, 3.0], [10.0, 10.0]]));
+    }
+
+    #[test]
+    fn should_diff_einsum_trace() {
+        let data = Data::from([[1.0, 2.0], [3.0, 4.0]]);
+        let tensor = TestADTensor::from_data(data).require_grad();
+
+        let trace: Tensor<TestADBackend, 1> = einsum("ii->", &[tensor.clone().mul(tensor.clone())]);
+        let grads = trace.backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        assert_eq!(grad.to_data(), Data::from([[2.0, 0.0], [0.0, 8.0]]));
+    }
+
+    #[test]
+    fn should_diff_einsum_matrix_vector() {
+        let matrix = TestADTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]])).require_grad();
+        let vector = TestADTensor::from_data(Data::from([1.0, 2.0])).require_grad();
+
+        let output: Tensor<TestADBackend, 1> = einsum("ij,j->i", (matrix.clone(), vector.clone()));
+        let grads = output.backward();
+
+        let grad_matrix = matrix.grad(&grads).unwrap();
+        let grad_vector = vector.grad(&grads).unwrap();
+
+        assert_eq!(grad_matrix.to_data(), Data::from([[1.0, 2.0], [1.0, 2.0]]));
+        assert_eq!(grad_vector.to_data(), Data::from([4.0, 6.0]));
+    }
+}
@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(ad_elu)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data};
+
+    #[test]
+    fn should_diff_elu() {
+        let tensor_1 = TestADTensor::from_floats([[0.0, 1.0], [-3.0, 4.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[6.0, -0.5], [-3.0, 1.5]]).require_grad();
+
+        let x = tensor_1
+            .clone()
+            .matmul(activation::elu(tensor_2.clone(), 0.5));
+        let x = tensor_1.clone().matmul(x);
+        let grads = x.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[-16.3849, -16.3849], [30.0412, -8.1858]]), 3);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[-15.0, -4.549], [0.4232, 17.0]]), 3);
+    }
+}
@@ -0,0 +1,34 @@
+#[burn_tensor_testgen::testgen(ad_expand)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_expand() {
+        let tensor_1 = TestADTensor::from_floats([[1.0], [2.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            [[7.0, 8.0, 9.0], [10.0, 11.0, 12.0]],
+        ]);
+
+        let tensor_3 = tensor_1.clone().expand([2, 2, 3]);
+        let grads = tensor_3.mul(tensor_2).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[30.0], [48.0]]));
+    }
+
+    #[test]
+    fn should_diff_expand_without_new_dimensions() {
+        let tensor_1 = TestADTensor::from_floats([[1.0, 2.0, 3.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let tensor_3 = tensor_1.clone().expand([2, 3]);
+        let grads = tensor_3.mul(tensor_2).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[5.0, 7.0, 9.0]]));
+    }
+}
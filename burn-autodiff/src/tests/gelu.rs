@@ -22,4 +22,26 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([[-15.0000, -1.9895], [17.0000, 17.0000]]), 2);
     }
+
+    #[test]
+    fn should_diff_gelu_tanh() {
+        let tensor_1 = TestADTensor::from_floats([[0.0, 1.0], [-3.0, 4.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[6.0, -0.5], [-3.0, 1.5]]).require_grad();
+
+        let x = tensor_1
+            .clone()
+            .matmul(activation::gelu_tanh(tensor_2.clone()));
+        let x = tensor_1.clone().matmul(x);
+        let grads = x.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[-16.1412, -16.1412], [30.6245, -4.9737]]), 3);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[-15.0, -1.9895], [-0.1969, 19.1711]]), 3);
+    }
 }
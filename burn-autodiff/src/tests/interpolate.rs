@@ -0,0 +1,108 @@
+#[burn_tensor_testgen::testgen(ad_interpolate)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::interpolate;
+    use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_interpolate_nearest() {
+        let test = InterpolateTestCase {
+            height: 2,
+            width: 3,
+            height_out: 4,
+            width_out: 5,
+            mode: InterpolateMode::Nearest,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [4.0000, 4.0000, 2.0000],
+            [4.0000, 4.0000, 2.0000],
+        ]]]));
+    }
+
+    #[test]
+    fn test_interpolate_bilinear() {
+        let test = InterpolateTestCase {
+            height: 3,
+            width: 3,
+            height_out: 5,
+            width_out: 4,
+            mode: InterpolateMode::Bilinear,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [2.2000, 2.0000, 2.2000],
+            [2.4750, 2.2500, 2.4750],
+            [2.2000, 2.0000, 2.2000],
+        ]]]));
+    }
+
+    #[test]
+    fn test_interpolate_bilinear_align_corners() {
+        let test = InterpolateTestCase {
+            height: 3,
+            width: 4,
+            height_out: 2,
+            width_out: 3,
+            mode: InterpolateMode::Bilinear,
+            align_corners: true,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [1.0000, 0.5000, 0.5000, 1.0000],
+            [0.0000, 0.0000, 0.0000, 0.0000],
+            [1.0000, 0.5000, 0.5000, 1.0000],
+        ]]]));
+    }
+
+    #[test]
+    fn test_interpolate_bicubic() {
+        let test = InterpolateTestCase {
+            height: 3,
+            width: 3,
+            height_out: 5,
+            width_out: 4,
+            mode: InterpolateMode::Bicubic,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [2.1632, 2.2175, 2.1632],
+            [2.2849, 2.3422, 2.2849],
+            [2.1632, 2.2175, 2.1632],
+        ]]]));
+    }
+
+    struct InterpolateTestCase {
+        height: usize,
+        width: usize,
+        height_out: usize,
+        width_out: usize,
+        mode: InterpolateMode,
+        align_corners: bool,
+    }
+
+    impl InterpolateTestCase {
+        fn assert_output(self, x_grad: TestTensor<4>) {
+            let shape_x = Shape::new([1, 1, self.height, self.width]);
+            let x = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let options = InterpolateOptions::new(self.mode, self.align_corners);
+            let output = interpolate(x.clone(), [self.height_out, self.width_out], options);
+            let grads = output.backward();
+            let x_grad_actual = x.grad(&grads).unwrap();
+
+            x_grad
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.into_data(), 3);
+        }
+    }
+}
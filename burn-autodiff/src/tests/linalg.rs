@@ -0,0 +1,138 @@
+#[burn_tensor_testgen::testgen(ad_linalg)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data};
+
+    #[test]
+    fn should_diff_cholesky() {
+        let tensor = TestADTensor::from_data([[4.0, 2.0], [2.0, 3.0]]).require_grad();
+
+        // The trace of L L^T is the trace of A.
+        let factor = linalg::cholesky(tensor.clone());
+        let grads = factor.clone().mul(factor).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.0], [0.0, 1.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_qr() {
+        let tensor = TestADTensor::from_data([[3.0, 1.0], [4.0, 2.0], [0.0, 2.0]]).require_grad();
+        let weights = TestADTensor::from_data([[1.0, -2.0], [0.5, 3.0], [-1.0, 4.0]]);
+
+        let (q, r) = linalg::qr(tensor.clone());
+        let grads = q.matmul(r).mul(weights.clone()).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data().assert_approx_eq(&weights.into_data(), 3);
+    }
+
+    #[test]
+    fn should_diff_qr_r() {
+        let tensor = TestADTensor::from_data([[3.0, 1.0], [4.0, 2.0], [0.0, 2.0]]).require_grad();
+
+        // R^T R = A^T A, so the squared Frobenius norm of R is the one of A.
+        let (_q, r) = linalg::qr(tensor.clone());
+        let grads = r.clone().mul(r).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[6.0, 2.0], [8.0, 4.0], [0.0, 4.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_eigh() {
+        let tensor = TestADTensor::from_data([[2.0, 1.0], [1.0, 2.0]]).require_grad();
+        let weights = TestADTensor::from_data([[1.0, 2.0], [2.0, -3.0]]);
+
+        let (eigenvalues, eigenvectors) = linalg::eigh(tensor.clone());
+        let reconstructed = eigenvectors
+            .clone()
+            .mul(eigenvalues)
+            .matmul(eigenvectors.transpose());
+        let grads = reconstructed.mul(weights.clone()).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data().assert_approx_eq(&weights.into_data(), 3);
+    }
+
+    #[test]
+    fn should_diff_eigh_eigenvalues() {
+        let tensor = TestADTensor::from_data([[2.0, 1.0], [1.0, 2.0]]).require_grad();
+
+        // The gradient of the largest eigenvalue is the outer product of its eigenvector.
+        let (eigenvalues, _eigenvectors) = linalg::eigh(tensor.clone());
+        let grads = eigenvalues.slice([0..1, 1..2]).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.5, 0.5], [0.5, 0.5]]), 3);
+    }
+
+    #[test]
+    fn should_diff_svd() {
+        let tensor = TestADTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 7.0]]).require_grad();
+        let weights = TestADTensor::from_data([[1.0, -2.0, 0.5], [3.0, 0.0, -1.0]]);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+        let grads = u.mul(s).matmul(vh).mul(weights.clone()).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data().assert_approx_eq(&weights.into_data(), 3);
+    }
+
+    #[test]
+    fn should_diff_svd_nuclear_norm() {
+        let tensor = TestADTensor::from_data([[3.0, 0.0], [0.0, -4.0]]).require_grad();
+
+        let (_u, s, _vh) = linalg::svd(tensor.clone());
+        let grads = s.sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.0], [0.0, -1.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_solve() {
+        let lhs = TestADTensor::from_data([[2.0, 1.0], [1.0, 3.0]]).require_grad();
+        let rhs = TestADTensor::from_data([[3.0], [5.0]]).require_grad();
+
+        let solution = linalg::solve(lhs.clone(), rhs.clone());
+        let grads = solution.sum().backward();
+
+        let grad_lhs = lhs.grad(&grads).unwrap();
+        let grad_rhs = rhs.grad(&grads).unwrap();
+        grad_lhs
+            .to_data()
+            .assert_approx_eq(&Data::from([[-0.32, -0.56], [-0.16, -0.28]]), 3);
+        grad_rhs
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.4], [0.2]]), 3);
+    }
+
+    #[test]
+    fn should_diff_slogdet() {
+        let tensor = TestADTensor::from_data([[4.0, 7.0], [2.0, 6.0]]).require_grad();
+
+        let (_sign, logabsdet) = linalg::slogdet(tensor.clone());
+        let grads = logabsdet.sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.6, -0.2], [-0.7, 0.4]]), 3);
+    }
+
+    #[test]
+    fn should_diff_det() {
+        let tensor = TestADTensor::from_data([[1.0, 2.0], [3.0, 4.0]]).require_grad();
+
+        // The gradient of the determinant is its cofactor matrix.
+        let grads = linalg::det(tensor.clone()).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[4.0, -3.0], [-2.0, 1.0]]), 3);
+    }
+}
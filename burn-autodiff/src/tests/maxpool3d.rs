@@ -0,0 +1,123 @@
+#[burn_tensor_testgen::testgen(ad_max_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::max_pool3d, Data};
+
+    #[test]
+    fn test_max_pool3d_simple() {
+        let kernel_size = [2, 2, 2];
+        let padding = [0, 0, 0];
+        let stride = [1, 1, 1];
+        let dilation = [1, 1, 1];
+
+        let x = TestADTensor::from_floats([[[
+            [
+                [0.3238, 0.1508, 0.6509, 0.0724],
+                [0.5359, 0.3657, 0.0580, 0.5074],
+                [0.0375, 0.4336, 0.0699, 0.0907],
+                [0.4245, 0.8269, 0.1238, 0.2232],
+            ],
+            [
+                [0.6274, 0.9477, 0.5771, 0.3967],
+                [0.9763, 0.0466, 0.8585, 0.2896],
+                [0.1443, 0.1178, 0.3085, 0.8161],
+                [0.1807, 0.5816, 0.6389, 0.3724],
+            ],
+            [
+                [0.5477, 0.0628, 0.0596, 0.2060],
+                [0.6804, 0.4276, 0.3141, 0.5856],
+                [0.4532, 0.2998, 0.7944, 0.6990],
+                [0.2441, 0.5744, 0.5252, 0.8751],
+            ],
+        ]]])
+        .require_grad();
+        let x_grad_expected = TestADTensor::from_floats([[[
+            [
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 2.0, 0.0, 0.0],
+            ],
+            [
+                [0.0, 2.0, 0.0, 0.0],
+                [4.0, 0.0, 6.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+                [0.0, 1.0, 0.0, 0.0],
+            ],
+            [
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        ]]]);
+
+        let output = max_pool3d(x.clone(), kernel_size, stride, padding, dilation);
+        let grads = output.backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool3d_complex() {
+        let kernel_size = [2, 3, 2];
+        let padding = [1, 1, 0];
+        let stride = [1, 2, 2];
+        let dilation = [1, 1, 2];
+
+        let x = TestADTensor::from_floats([[[
+            [
+                [0.3238, 0.1508, 0.6509, 0.0724],
+                [0.5359, 0.3657, 0.0580, 0.5074],
+                [0.0375, 0.4336, 0.0699, 0.0907],
+                [0.4245, 0.8269, 0.1238, 0.2232],
+            ],
+            [
+                [0.6274, 0.9477, 0.5771, 0.3967],
+                [0.9763, 0.0466, 0.8585, 0.2896],
+                [0.1443, 0.1178, 0.3085, 0.8161],
+                [0.1807, 0.5816, 0.6389, 0.3724],
+            ],
+            [
+                [0.5477, 0.0628, 0.0596, 0.2060],
+                [0.6804, 0.4276, 0.3141, 0.5856],
+                [0.4532, 0.2998, 0.7944, 0.6990],
+                [0.2441, 0.5744, 0.5252, 0.8751],
+            ],
+        ]]])
+        .require_grad();
+        let x_grad_expected = TestADTensor::from_floats([[[
+            [
+                [0.0, 0.0, 1.0, 0.0],
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0],
+            ],
+            [
+                [0.0, 0.0, 0.0, 0.0],
+                [4.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0],
+            ],
+            [
+                [0.0, 0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0],
+            ],
+        ]]]);
+
+        let output = max_pool3d(x.clone(), kernel_size, stride, padding, dilation);
+        let grads = output.backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+}
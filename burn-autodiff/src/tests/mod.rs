@@ -3,26 +3,39 @@
 mod abs;
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod adaptive_avgpool3d;
 mod add;
 mod aggregation;
+mod attention;
 mod avgpool1d;
 mod avgpool2d;
+mod avgpool3d;
 mod backward;
 mod broadcast;
 mod cat;
 mod complex;
 mod conv1d;
 mod conv2d;
+mod conv3d;
 mod conv_transpose1d;
 mod conv_transpose2d;
+mod conv_transpose3d;
 mod cos;
 mod cross_entropy;
+mod cumulative;
 mod div;
+mod einsum;
+mod elu;
 mod erf;
 mod exp;
+mod expand;
+mod flip;
 mod gather_scatter;
 mod gelu;
 mod gradients;
+mod interpolate;
+mod leaky_relu;
+mod linalg;
 mod log;
 mod log1p;
 mod mask;
@@ -30,16 +43,25 @@ mod matmul;
 mod maxmin;
 mod maxpool1d;
 mod maxpool2d;
+mod maxpool3d;
 mod mul;
 mod multithread;
 mod neg;
+mod pad;
 mod pow;
+mod prelu;
 mod relu;
+mod remainder;
 mod reshape;
+mod roll;
+mod rounding;
+mod segment;
 mod select;
 mod sin;
 mod slice;
 mod softmax;
+mod softplus;
+mod sort;
 mod sqrt;
 mod sub;
 mod tanh;
@@ -58,18 +80,30 @@ macro_rules! testgen_all {
         // Activation
         burn_autodiff::testgen_ad_relu!();
         burn_autodiff::testgen_ad_gelu!();
+        burn_autodiff::testgen_ad_leaky_relu!();
+        burn_autodiff::testgen_ad_prelu!();
+        burn_autodiff::testgen_ad_elu!();
+        burn_autodiff::testgen_ad_softplus!();
 
         // Modules
         burn_autodiff::testgen_ad_conv1d!();
         burn_autodiff::testgen_ad_conv2d!();
+        burn_autodiff::testgen_ad_conv3d!();
         burn_autodiff::testgen_ad_conv_transpose1d!();
         burn_autodiff::testgen_ad_conv_transpose2d!();
+        burn_autodiff::testgen_ad_conv_transpose3d!();
         burn_autodiff::testgen_ad_max_pool1d!();
         burn_autodiff::testgen_ad_max_pool2d!();
+        burn_autodiff::testgen_ad_max_pool3d!();
         burn_autodiff::testgen_ad_avg_pool1d!();
         burn_autodiff::testgen_ad_avg_pool2d!();
+        burn_autodiff::testgen_ad_avg_pool3d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
+        burn_autodiff::testgen_ad_adaptive_avg_pool3d!();
+        burn_autodiff::testgen_ad_interpolate!();
+        burn_autodiff::testgen_ad_attention!();
+        burn_autodiff::testgen_ad_segment!();
         burn_autodiff::testgen_module_backward!();
 
         // Tensor
@@ -81,22 +115,32 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_cat!();
         burn_autodiff::testgen_ad_cos!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
+        burn_autodiff::testgen_ad_cumulative!();
         burn_autodiff::testgen_ad_div!();
+        burn_autodiff::testgen_ad_einsum!();
         burn_autodiff::testgen_ad_erf!();
         burn_autodiff::testgen_ad_exp!();
+        burn_autodiff::testgen_ad_expand!();
+        burn_autodiff::testgen_ad_flip!();
         burn_autodiff::testgen_ad_slice!();
         burn_autodiff::testgen_ad_gather_scatter!();
         burn_autodiff::testgen_ad_select!();
+        burn_autodiff::testgen_ad_linalg!();
         burn_autodiff::testgen_ad_log!();
         burn_autodiff::testgen_ad_log1p!();
         burn_autodiff::testgen_ad_mask!();
         burn_autodiff::testgen_ad_matmul!();
         burn_autodiff::testgen_ad_mul!();
         burn_autodiff::testgen_ad_neg!();
+        burn_autodiff::testgen_ad_pad!();
         burn_autodiff::testgen_ad_powf!();
+        burn_autodiff::testgen_ad_remainder!();
         burn_autodiff::testgen_ad_reshape!();
+        burn_autodiff::testgen_ad_roll!();
+        burn_autodiff::testgen_ad_rounding!();
         burn_autodiff::testgen_ad_sin!();
         burn_autodiff::testgen_ad_softmax!();
+        burn_autodiff::testgen_ad_sort!();
         burn_autodiff::testgen_ad_sqrt!();
         burn_autodiff::testgen_ad_abs!();
         burn_autodiff::testgen_ad_sub!();
@@ -0,0 +1,62 @@
+#[burn_tensor_testgen::testgen(ad_pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ops::PadMode, Data};
+
+    #[test]
+    fn should_diff_pad_constant() {
+        let grad = pad_grad([[1.0, 2.0, 3.0]], [(0, 0), (1, 1)], PadMode::Constant(2.0));
+
+        assert_eq!(grad, Data::from([[2.0, 3.0, 4.0]]));
+    }
+
+    #[test]
+    fn should_diff_pad_reflect() {
+        let grad = pad_grad([[1.0, 2.0, 3.0]], [(0, 0), (2, 1)], PadMode::Reflect);
+
+        assert_eq!(grad, Data::from([[3.0, 12.0, 6.0]]));
+    }
+
+    #[test]
+    fn should_diff_pad_replicate() {
+        let grad = pad_grad([[1.0, 2.0, 3.0]], [(0, 0), (2, 1)], PadMode::Replicate);
+
+        assert_eq!(grad, Data::from([[6.0, 4.0, 11.0]]));
+    }
+
+    #[test]
+    fn should_diff_pad_circular() {
+        let grad = pad_grad([[1.0, 2.0, 3.0]], [(0, 0), (2, 1)], PadMode::Circular);
+
+        assert_eq!(grad, Data::from([[9.0, 5.0, 7.0]]));
+    }
+
+    #[test]
+    fn should_diff_pad_reflect_multiple_dims() {
+        let grad = pad_grad([[1.0, 2.0], [3.0, 4.0]], [(1, 0), (1, 0)], PadMode::Reflect);
+
+        assert_eq!(grad, Data::from([[5.0, 10.0], [10.0, 20.0]]));
+    }
+
+    /// Pads the tensor, multiplies the result by increasing weights and returns the gradient.
+    fn pad_grad<const N: usize, const M: usize>(
+        data: [[f32; M]; N],
+        pads: [(usize, usize); 2],
+        mode: PadMode,
+    ) -> Data<f32, 2> {
+        let tensor = TestADTensor::from_data(data).require_grad();
+
+        let padded = tensor.clone().pad(pads, mode);
+        let [rows, cols] = padded.dims();
+        let weights = TestADTensor::from_data(
+            TestTensorInt::arange(1..rows * cols + 1)
+                .reshape([rows, cols])
+                .into_data()
+                .convert(),
+        );
+
+        let grads = padded.mul(weights).backward();
+
+        tensor.grad(&grads).unwrap().into_data()
+    }
+}
@@ -0,0 +1,28 @@
+#[burn_tensor_testgen::testgen(ad_prelu)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data};
+
+    #[test]
+    fn should_diff_prelu() {
+        let tensor_1 = TestADTensor::from_floats([[0.0, 1.0], [-3.0, 4.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[6.0, -0.5], [-3.0, 1.5]]).require_grad();
+        let alpha = TestADTensor::from_floats([0.1, 0.2]).require_grad();
+
+        let x = tensor_1
+            .clone()
+            .matmul(activation::prelu(tensor_2.clone(), alpha.clone()));
+        let x = tensor_1.clone().matmul(x);
+        let grads = x.backward();
+
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+        let grad_alpha = alpha.grad(&grads).unwrap();
+
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[-15.0, -3.0], [1.7, 17.0]]), 3);
+        grad_alpha
+            .to_data()
+            .assert_approx_eq(&Data::from([-51.0, 7.5]), 3);
+    }
+}
@@ -0,0 +1,63 @@
+#[burn_tensor_testgen::testgen(ad_remainder)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_remainder() {
+        let data_1 = Data::<f32, 2>::from([[5.0, -5.0], [4.5, 7.0]]);
+        let data_2 = Data::<f32, 2>::from([[3.0, 3.0], [-2.0, 2.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3 = tensor_1.clone().remainder(tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 1.0], [1.0, 1.0]]), 3);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[-1.0, 2.0], [3.0, -3.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_remainder_broadcast() {
+        let data_1 = Data::<f32, 2>::from([[5.0, -5.0], [4.5, 7.0]]);
+        let data_2 = Data::<f32, 2>::from([[3.0, 2.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3 = tensor_1.clone().remainder(tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 1.0], [1.0, 1.0]]), 3);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[-2.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_remainder_scalar() {
+        let data = Data::<f32, 2>::from([[5.0, -5.0], [4.5, 7.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().remainder_scalar(2.0);
+        let grads = tensor_2.backward();
+
+        let grad = tensor_1.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[1.0, 1.0], [1.0, 1.0]]), 3);
+    }
+}
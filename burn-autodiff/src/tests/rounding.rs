@@ -0,0 +1,36 @@
+#[burn_tensor_testgen::testgen(ad_rounding)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_rounding_ops_to_zero() {
+        let data = Data::<f32, 2>::from([[0.4, -1.6], [2.5, 3.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().floor()
+            + tensor_1.clone().ceil()
+            + tensor_1.clone().round()
+            + tensor_1.clone().trunc()
+            + tensor_1.clone().sign();
+        let grads = tensor_2.backward();
+
+        let grad = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad.to_data(), Data::from([[0.0, 0.0], [0.0, 0.0]]));
+    }
+
+    #[test]
+    fn should_diff_round() {
+        let data = Data::<f32, 2>::from([[0.4, -1.6], [2.5, 3.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().mul(tensor_1.clone().round());
+        let grads = tensor_2.backward();
+
+        let grad = tensor_1.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.0, -2.0], [2.0, 3.0]]), 3);
+    }
+}
@@ -0,0 +1,84 @@
+#[burn_tensor_testgen::testgen(ad_segment)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::segment_reduce;
+    use burn_tensor::ops::SegmentReduction;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn test_segment_reduce_sum_backward() {
+        let test = SegmentTestCase::new();
+
+        let grad = test.grad(SegmentReduction::Sum);
+
+        grad.assert_approx_eq(
+            &Data::from([[1.0, 2.0], [1.0, 2.0], [5.0, 6.0], [5.0, 6.0], [5.0, 6.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_segment_reduce_mean_backward() {
+        let test = SegmentTestCase::new();
+
+        let grad = test.grad(SegmentReduction::Mean);
+
+        grad.assert_approx_eq(
+            &Data::from([
+                [0.5, 1.0],
+                [0.5, 1.0],
+                [1.6667, 2.0],
+                [1.6667, 2.0],
+                [1.6667, 2.0],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_segment_reduce_max_backward() {
+        let test = SegmentTestCase::new();
+
+        let grad = test.grad(SegmentReduction::Max);
+
+        grad.assert_approx_eq(
+            &Data::from([[0.0, 2.0], [1.0, 0.0], [0.0, 6.0], [0.0, 0.0], [5.0, 0.0]]),
+            3,
+        );
+    }
+
+    struct SegmentTestCase {
+        tensor: Tensor<TestADBackend, 2>,
+        offsets: Tensor<TestADBackend, 1, Int>,
+        output_weights: Tensor<TestADBackend, 2>,
+    }
+
+    impl SegmentTestCase {
+        fn new() -> Self {
+            Self {
+                tensor: TestADTensor::from_floats([
+                    [1.0, 2.0],
+                    [3.0, -1.0],
+                    [0.0, 5.0],
+                    [-2.0, 4.0],
+                    [6.0, 0.0],
+                ]),
+                offsets: Tensor::from_ints([0, 2, 2, 5]),
+                output_weights: TestADTensor::from_floats([
+                    [1.0, 2.0],
+                    [3.0, 4.0],
+                    [5.0, 6.0],
+                    [7.0, 8.0],
+                ]),
+            }
+        }
+
+        fn grad(self, reduction: SegmentReduction) -> Data<f32, 2> {
+            let tensor = self.tensor.require_grad();
+            let output = segment_reduce(tensor.clone(), self.offsets, reduction);
+            let grads = output.mul(self.output_weights).sum().backward();
+
+            tensor.grad(&grads).unwrap().into_data()
+        }
+    }
+}
@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(ad_softplus)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data};
+
+    #[test]
+    fn should_diff_softplus() {
+        let tensor_1 = TestADTensor::from_floats([[0.0, 1.0], [-3.0, 4.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[6.0, -0.5], [-3.0, 1.5]]).require_grad();
+
+        let x = tensor_1
+            .clone()
+            .matmul(activation::softplus(tensor_2.clone(), 2.0));
+        let x = tensor_1.clone().matmul(x);
+        let grads = x.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[-16.9444, -16.9444], [32.3087, -4.7401]]), 3);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[-14.9999, -4.0341], [0.042, 16.1938]]), 3);
+    }
+}
@@ -0,0 +1,35 @@
+#[burn_tensor_testgen::testgen(ad_sort)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_sort() {
+        let tensor_1 = TestADTensor::from_floats([[1.0, 7.0], [-2.0, -3.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        let tensor_3 = tensor_1.clone().sort(0).mul(tensor_2);
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[3.0, 4.0], [1.0, 2.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_topk() {
+        let tensor_1 = TestADTensor::from_floats([[1.0, 5.0, 3.0], [4.0, 2.0, 6.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        let tensor_3 = tensor_1.clone().topk(2, 1).mul(tensor_2);
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 1.0, 2.0], [4.0, 0.0, 3.0]]), 5);
+    }
+}
@@ -47,4 +47,23 @@ mod tests {
             Data::from([[[22., 286.], [28., 316.]], [[172., 652.], [190., 694.]]])
         );
     }
+
+    #[test]
+    fn should_diff_permute() {
+        let tensor_1 =
+            TestADTensor::from_floats([[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]])
+                .require_grad();
+        let weights =
+            TestADTensor::from_floats([[[0.0, 1.0], [2.0, 3.0]], [[4.0, 5.0], [6.0, 7.0]]]);
+
+        let tensor_2 = tensor_1.clone().permute([2, 0, 1]);
+        let grads = tensor_2.mul(weights).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.to_data(),
+            Data::from([[[0.0, 4.0], [1.0, 5.0]], [[2.0, 6.0], [3.0, 7.0]]])
+        );
+    }
 }
@@ -34,23 +34,38 @@ mod tests {
     burn_tensor::testgen_softmax!();
     burn_tensor::testgen_sigmoid!();
     burn_tensor::testgen_silu!();
+    burn_tensor::testgen_leaky_relu!();
+    burn_tensor::testgen_prelu!();
+    burn_tensor::testgen_elu!();
+    burn_tensor::testgen_selu!();
+    burn_tensor::testgen_softplus!();
+    burn_tensor::testgen_softsign!();
+    burn_tensor::testgen_hard_sigmoid!();
+    burn_tensor::testgen_hard_swish!();
 
     // test module
     burn_tensor::testgen_module_forward!();
     burn_tensor::testgen_module_conv1d!();
     // burn_tensor::testgen_module_conv2d!();
+    // burn_tensor::testgen_module_conv3d!();
     // burn_tensor::testgen_module_conv_transpose1d!();
     // burn_tensor::testgen_module_conv_transpose2d!();
+    // burn_tensor::testgen_module_conv_transpose3d!();
     // burn_tensor::testgen_module_max_pool1d!();
     // burn_tensor::testgen_module_max_pool2d!();
+    // burn_tensor::testgen_module_max_pool3d!();
     // burn_tensor::testgen_module_avg_pool1d!();
     // burn_tensor::testgen_module_avg_pool2d!();
+    // burn_tensor::testgen_module_avg_pool3d!();
     // burn_tensor::testgen_module_adaptive_avg_pool1d!();
     // burn_tensor::testgen_module_adaptive_avg_pool2d!();
+    // burn_tensor::testgen_module_adaptive_avg_pool3d!();
+    burn_tensor::testgen_module_interpolate!();
 
     // test ops
     burn_tensor::testgen_add!();
     // burn_tensor::testgen_aggregation!();
+    burn_tensor::testgen_any!();
     burn_tensor::testgen_arange!();
     burn_tensor::testgen_arange_step!();
     burn_tensor::testgen_arg!();
@@ -58,13 +73,18 @@ mod tests {
     burn_tensor::testgen_cat!();
     burn_tensor::testgen_clamp!();
     burn_tensor::testgen_cos!();
+    burn_tensor::testgen_cumulative!();
     // burn_tensor::testgen_div!();
+    burn_tensor::testgen_einsum!();
     // burn_tensor::testgen_erf!();
     burn_tensor::testgen_exp!();
+    burn_tensor::testgen_expand!();
     burn_tensor::testgen_flatten!();
+    burn_tensor::testgen_flip!();
     burn_tensor::testgen_full!();
     burn_tensor::testgen_gather_scatter!();
     burn_tensor::testgen_init!();
+    // burn_tensor::testgen_linalg!();
     burn_tensor::testgen_log!();
     burn_tensor::testgen_log1p!();
     burn_tensor::testgen_map_comparison!();
@@ -73,19 +93,27 @@ mod tests {
     burn_tensor::testgen_maxmin!();
     burn_tensor::testgen_mul!();
     burn_tensor::testgen_neg!();
+    // burn_tensor::testgen_pad!();
+    burn_tensor::testgen_permute!();
     burn_tensor::testgen_powf!();
     burn_tensor::testgen_random!();
+    // burn_tensor::testgen_remainder!();
     // burn_tensor::testgen_repeat!();
     burn_tensor::testgen_reshape!();
+    // burn_tensor::testgen_roll!();
+    // burn_tensor::testgen_rounding!();
     burn_tensor::testgen_select!();
+    burn_tensor::testgen_sign!();
     burn_tensor::testgen_sin!();
     // burn_tensor::testgen_slice!();
+    burn_tensor::testgen_sort!();
     burn_tensor::testgen_sqrt!();
     burn_tensor::testgen_abs!();
     burn_tensor::testgen_squeeze!();
     burn_tensor::testgen_sub!();
     burn_tensor::testgen_tanh!();
     burn_tensor::testgen_transpose!();
+    burn_tensor::testgen_triangular!();
 
     // test stats
     burn_tensor::testgen_var!();
@@ -101,14 +129,20 @@ mod tests {
     // Modules
     // burn_autodiff::testgen_ad_conv1d!();
     // burn_autodiff::testgen_ad_conv2d!();
+    // burn_autodiff::testgen_ad_conv3d!();
     // burn_autodiff::testgen_ad_conv_transpose1d!();
     // burn_autodiff::testgen_ad_conv_transpose2d!();
+    // burn_autodiff::testgen_ad_conv_transpose3d!();
     // burn_autodiff::testgen_ad_max_pool1d!();
     // burn_autodiff::testgen_ad_max_pool2d!();
+    // burn_autodiff::testgen_ad_max_pool3d!();
     // burn_autodiff::testgen_ad_avg_pool1d!();
     // burn_autodiff::testgen_ad_avg_pool2d!();
+    // burn_autodiff::testgen_ad_avg_pool3d!();
     // burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
     // burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
+    // burn_autodiff::testgen_ad_adaptive_avg_pool3d!();
+    burn_autodiff::testgen_ad_interpolate!();
     burn_autodiff::testgen_module_backward!();
 
     // Tensor
@@ -120,22 +154,30 @@ mod tests {
     // burn_autodiff::testgen_ad_cat!();
     burn_autodiff::testgen_ad_cos!();
     burn_autodiff::testgen_ad_cross_entropy_loss!();
+    burn_autodiff::testgen_ad_cumulative!();
     burn_autodiff::testgen_ad_div!();
+    burn_autodiff::testgen_ad_einsum!();
     // burn_autodiff::testgen_ad_erf!();
     burn_autodiff::testgen_ad_exp!();
+    burn_autodiff::testgen_ad_expand!();
+    burn_autodiff::testgen_ad_flip!();
     // burn_autodiff::testgen_ad_slice!();
     burn_autodiff::testgen_ad_gather_scatter!();
     burn_autodiff::testgen_ad_select!();
+    // burn_autodiff::testgen_ad_linalg!();
     burn_autodiff::testgen_ad_log!();
     burn_autodiff::testgen_ad_log1p!();
     burn_autodiff::testgen_ad_mask!();
     burn_autodiff::testgen_ad_matmul!();
     burn_autodiff::testgen_ad_mul!();
     burn_autodiff::testgen_ad_neg!();
+    // burn_autodiff::testgen_ad_pad!();
     burn_autodiff::testgen_ad_powf!();
     burn_autodiff::testgen_ad_reshape!();
+    // burn_autodiff::testgen_ad_roll!();
     burn_autodiff::testgen_ad_sin!();
     burn_autodiff::testgen_ad_softmax!();
+    burn_autodiff::testgen_ad_sort!();
     burn_autodiff::testgen_ad_sqrt!();
     burn_autodiff::testgen_ad_abs!();
     burn_autodiff::testgen_ad_sub!();
@@ -17,4 +17,8 @@ impl<F: FloatCandleElement, I: IntCandleElement> ActivationOps<CandleBackend<F,
     fn relu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
         CandleTensor::new(tensor.tensor.relu().unwrap())
     }
+
+    fn gelu_tanh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        CandleTensor::new(tensor.tensor.gelu().unwrap())
+    }
 }
@@ -65,6 +65,20 @@ pub fn swap_dims<E: CandleElement, const D: usize>(
     CandleTensor::new(tensor.tensor.transpose(dim1, dim2).unwrap())
 }
 
+pub fn permute<E: CandleElement, const D: usize>(
+    tensor: CandleTensor<E, D>,
+    axes: [usize; D],
+) -> CandleTensor<E, D> {
+    CandleTensor::new(tensor.tensor.permute(axes.to_vec()).unwrap())
+}
+
+pub fn expand<E: CandleElement, const D1: usize, const D2: usize>(
+    tensor: CandleTensor<E, D1>,
+    shape: Shape<D2>,
+) -> CandleTensor<E, D2> {
+    CandleTensor::new(tensor.tensor.broadcast_as(&shape.dims).unwrap())
+}
+
 pub fn reshape<E: CandleElement, const D1: usize, const D2: usize>(
     tensor: CandleTensor<E, D1>,
     shape: Shape<D2>,
@@ -110,4 +110,18 @@ impl<F: FloatCandleElement, I: IntCandleElement> BoolTensorOps<CandleBackend<F,
     ) -> <CandleBackend<F, I> as burn_tensor::backend::Backend>::BoolTensorPrimitive<D> {
         super::base::swap_dims(tensor, dim1, dim2)
     }
+
+    fn bool_permute<const D: usize>(
+        tensor: <CandleBackend<F, I> as burn_tensor::backend::Backend>::BoolTensorPrimitive<D>,
+        axes: [usize; D],
+    ) -> <CandleBackend<F, I> as burn_tensor::backend::Backend>::BoolTensorPrimitive<D> {
+        super::base::permute(tensor, axes)
+    }
+
+    fn bool_expand<const D1: usize, const D2: usize>(
+        tensor: <CandleBackend<F, I> as burn_tensor::backend::Backend>::BoolTensorPrimitive<D1>,
+        shape: Shape<D2>,
+    ) -> <CandleBackend<F, I> as burn_tensor::backend::Backend>::BoolTensorPrimitive<D2> {
+        super::base::expand(tensor, shape)
+    }
 }
@@ -360,4 +360,18 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<CandleBackend<F, I
     ) -> <CandleBackend<F, I> as burn_tensor::backend::Backend>::IntTensorPrimitive<D> {
         super::base::swap_dims(tensor, dim1, dim2)
     }
+
+    fn int_permute<const D: usize>(
+        tensor: <CandleBackend<F, I> as burn_tensor::backend::Backend>::IntTensorPrimitive<D>,
+        axes: [usize; D],
+    ) -> <CandleBackend<F, I> as burn_tensor::backend::Backend>::IntTensorPrimitive<D> {
+        super::base::permute(tensor, axes)
+    }
+
+    fn int_expand<const D1: usize, const D2: usize>(
+        tensor: <CandleBackend<F, I> as burn_tensor::backend::Backend>::IntTensorPrimitive<D1>,
+        shape: Shape<D2>,
+    ) -> <CandleBackend<F, I> as burn_tensor::backend::Backend>::IntTensorPrimitive<D2> {
+        super::base::expand(tensor, shape)
+    }
 }
@@ -0,0 +1,11 @@
+use burn_tensor::ops::LinalgOps;
+
+use crate::{
+    element::{FloatCandleElement, IntCandleElement},
+    CandleBackend,
+};
+
+impl<F: FloatCandleElement, I: IntCandleElement> LinalgOps<CandleBackend<F, I>>
+    for CandleBackend<F, I>
+{
+}
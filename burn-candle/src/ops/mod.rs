@@ -3,5 +3,6 @@ mod base;
 mod bool_tensor;
 mod candle_utils;
 mod int_tensor;
+mod linalg;
 mod module;
 mod tensor;
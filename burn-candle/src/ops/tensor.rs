@@ -148,6 +148,20 @@ impl<F: FloatCandleElement, I: IntCandleElement> TensorOps<CandleBackend<F, I>>
         super::base::swap_dims(tensor, dim1, dim2)
     }
 
+    fn permute<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        axes: [usize; D],
+    ) -> FloatTensor<Self, D> {
+        super::base::permute(tensor, axes)
+    }
+
+    fn expand<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        super::base::expand(tensor, shape)
+    }
+
     fn reshape<const D1: usize, const D2: usize>(
         tensor: FloatTensor<Self, D1>,
         shape: Shape<D2>,
@@ -397,6 +411,22 @@ impl<F: FloatCandleElement, I: IntCandleElement> TensorOps<CandleBackend<F, I>>
         panic!("erf not supported by Candle")
     }
 
+    fn floor<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        panic!("floor not supported by Candle")
+    }
+
+    fn ceil<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        panic!("ceil not supported by Candle")
+    }
+
+    fn round<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        panic!("round not supported by Candle")
+    }
+
+    fn trunc<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        panic!("trunc not supported by Candle")
+    }
+
     fn cat<const D: usize>(tensors: Vec<FloatTensor<Self, D>>, dim: usize) -> FloatTensor<Self, D> {
         super::base::cat(tensors, dim)
     }
@@ -422,4 +452,7 @@ impl<F: FloatCandleElement, I: IntCandleElement> TensorOps<CandleBackend<F, I>>
                 .unwrap(),
         )
     }
+
+    // The candle revision in use has no sorting kernel, so `sort`, `argsort` and `topk` use the
+    // default implementations, which read the tensor data and sort it on the host.
 }
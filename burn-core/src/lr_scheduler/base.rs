@@ -9,9 +9,43 @@ pub trait LrScheduler: Send + Sync {
     /// learning rate.
     fn step(&mut self) -> LearningRate;
 
+    /// Report the value of the monitored metric, once per epoch, so that the scheduler can react
+    /// to the training progress.
+    ///
+    /// Most schedulers don't depend on any metric, so the value is ignored by default.
+    fn report_metric(&mut self, _value: f64) {}
+
     /// Get the current state of the scheduler as a [record](Record).
     fn to_record(&self) -> Self::Record;
 
     /// Load the state of the scheduler as a [record](Record).
     fn load_record(self, record: Self::Record) -> Self;
 }
+
+/// Checks that the scheduler gives the expected learning rates, step after step.
+#[cfg(test)]
+pub(super) fn check_lr_sequence<S: LrScheduler>(mut scheduler: S, expected: &[LearningRate]) {
+    let lrs: alloc::vec::Vec<_> = expected.iter().map(|_| scheduler.step()).collect();
+
+    for (lr, expected_lr) in lrs.iter().zip(expected) {
+        assert!(
+            (lr - expected_lr).abs() < 1e-4,
+            "Expected learning rates {expected:?}, got {lrs:?}"
+        );
+    }
+}
+
+/// Checks that a scheduler loaded from the record of another one continues its schedule.
+#[cfg(test)]
+pub(super) fn check_save_load<S: LrScheduler + Clone>(scheduler: S, num_steps: usize) {
+    let mut trained = scheduler.clone();
+    for _ in 0..num_steps {
+        trained.step();
+    }
+
+    let mut resumed = scheduler.load_record(trained.to_record());
+
+    for _ in 0..num_steps {
+        assert_eq!(resumed.step(), trained.step());
+    }
+}
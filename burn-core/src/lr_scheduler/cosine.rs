@@ -0,0 +1,207 @@
+use crate as burn;
+
+use super::LrScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create a [cosine annealing](CosineAnnealingLrScheduler) learning rate
+/// scheduler.
+#[derive(Config)]
+pub struct CosineAnnealingLrSchedulerConfig {
+    /// The initial learning rate.
+    init_lr: LearningRate,
+    /// The number of steps to anneal the learning rate down to the minimum learning rate.
+    num_iters: usize,
+    /// The minimum learning rate, reached after `num_iters` steps.
+    #[config(default = 0.0)]
+    min_lr: LearningRate,
+}
+
+/// Cosine annealing learning rate scheduler as described in
+/// [SGDR: Stochastic Gradient Descent with Warm Restarts](https://arxiv.org/abs/1608.03983),
+/// without the restarts.
+///
+/// The learning rate follows half a cosine period from the initial learning rate down to the
+/// minimum learning rate, then stays at the minimum learning rate.
+#[derive(Clone, Debug)]
+pub struct CosineAnnealingLrScheduler {
+    init_lr: LearningRate,
+    min_lr: LearningRate,
+    num_iters: usize,
+    step: usize,
+}
+
+impl CosineAnnealingLrSchedulerConfig {
+    /// Initialize a new [cosine annealing](CosineAnnealingLrScheduler) learning rate scheduler.
+    pub fn init(&self) -> CosineAnnealingLrScheduler {
+        assert!(
+            self.num_iters > 0,
+            "Number of iterations must be at least 1."
+        );
+
+        CosineAnnealingLrScheduler {
+            init_lr: self.init_lr,
+            min_lr: self.min_lr,
+            num_iters: self.num_iters,
+            step: 0,
+        }
+    }
+}
+
+impl LrScheduler for CosineAnnealingLrScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let progress = usize::min(self.step, self.num_iters) as f64 / self.num_iters as f64;
+        self.step += 1;
+
+        cosine_annealing(self.init_lr, self.min_lr, progress)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+/// Configuration to create a [cosine annealing with warm restarts](CosineAnnealingWarmRestartsLrScheduler)
+/// learning rate scheduler.
+#[derive(Config)]
+pub struct CosineAnnealingWarmRestartsLrSchedulerConfig {
+    /// The initial learning rate, used at the start of each cycle.
+    init_lr: LearningRate,
+    /// The number of steps of the first cycle.
+    num_iters: usize,
+    /// The factor by which the number of steps of a cycle grows after each restart.
+    #[config(default = 1)]
+    cycle_mult: usize,
+    /// The minimum learning rate, reached at the end of each cycle.
+    #[config(default = 0.0)]
+    min_lr: LearningRate,
+}
+
+/// Cosine annealing with warm restarts learning rate scheduler as described in
+/// [SGDR: Stochastic Gradient Descent with Warm Restarts](https://arxiv.org/abs/1608.03983).
+///
+/// The learning rate is annealed like with [cosine annealing](CosineAnnealingLrScheduler) and is
+/// reset to the initial learning rate at the end of each cycle.
+#[derive(Clone, Debug)]
+pub struct CosineAnnealingWarmRestartsLrScheduler {
+    init_lr: LearningRate,
+    min_lr: LearningRate,
+    num_iters: usize,
+    cycle_mult: usize,
+    step: usize,
+}
+
+impl CosineAnnealingWarmRestartsLrSchedulerConfig {
+    /// Initialize a new [cosine annealing with warm restarts](CosineAnnealingWarmRestartsLrScheduler)
+    /// learning rate scheduler.
+    pub fn init(&self) -> CosineAnnealingWarmRestartsLrScheduler {
+        assert!(
+            self.num_iters > 0,
+            "Number of iterations must be at least 1."
+        );
+        assert!(self.cycle_mult > 0, "Cycle multiplier must be at least 1.");
+
+        CosineAnnealingWarmRestartsLrScheduler {
+            init_lr: self.init_lr,
+            min_lr: self.min_lr,
+            num_iters: self.num_iters,
+            cycle_mult: self.cycle_mult,
+            step: 0,
+        }
+    }
+}
+
+impl CosineAnnealingWarmRestartsLrScheduler {
+    /// The position in the current cycle along with the length of that cycle.
+    fn position_in_cycle(&self) -> (usize, usize) {
+        let mut position = self.step;
+        let mut cycle_length = self.num_iters;
+
+        if self.cycle_mult == 1 {
+            return (position % cycle_length, cycle_length);
+        }
+
+        while position >= cycle_length {
+            position -= cycle_length;
+            cycle_length *= self.cycle_mult;
+        }
+
+        (position, cycle_length)
+    }
+}
+
+impl LrScheduler for CosineAnnealingWarmRestartsLrScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let (position, cycle_length) = self.position_in_cycle();
+        self.step += 1;
+
+        cosine_annealing(
+            self.init_lr,
+            self.min_lr,
+            position as f64 / cycle_length as f64,
+        )
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+/// Anneals the learning rate from `start` to `end` following half a cosine period, where
+/// `progress` goes from 0 to 1.
+pub(crate) fn cosine_annealing(
+    start: LearningRate,
+    end: LearningRate,
+    progress: f64,
+) -> LearningRate {
+    end + (start - end) * (1.0 + f64::cos(core::f64::consts::PI * progress)) / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{check_lr_sequence, check_save_load};
+    use super::*;
+
+    #[test]
+    fn test_cosine_annealing() {
+        let scheduler = CosineAnnealingLrSchedulerConfig::new(1.0, 4)
+            .with_min_lr(0.2)
+            .init();
+
+        check_lr_sequence(scheduler, &[1.0, 0.8828, 0.6, 0.3172, 0.2, 0.2]);
+    }
+
+    #[test]
+    fn test_cosine_annealing_warm_restarts() {
+        let scheduler = CosineAnnealingWarmRestartsLrSchedulerConfig::new(1.0, 2)
+            .with_cycle_mult(2)
+            .init();
+
+        check_lr_sequence(
+            scheduler,
+            &[1.0, 0.5, 1.0, 0.8536, 0.5, 0.1464, 1.0, 0.9619],
+        );
+    }
+
+    #[test]
+    fn test_cosine_annealing_warm_restarts_save_load() {
+        let scheduler = CosineAnnealingWarmRestartsLrSchedulerConfig::new(1.0, 3)
+            .with_cycle_mult(2)
+            .init();
+
+        check_save_load(scheduler, 5);
+    }
+}
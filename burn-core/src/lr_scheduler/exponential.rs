@@ -0,0 +1,78 @@
+use crate as burn;
+
+use super::LrScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create an [exponential](ExponentialLrScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct ExponentialLrSchedulerConfig {
+    /// The initial learning rate.
+    init_lr: LearningRate,
+    /// The factor by which the learning rate is multiplied at each step.
+    gamma: f64,
+}
+
+/// Exponential learning rate scheduler, which multiplies the learning rate by `gamma` at each
+/// step.
+#[derive(Clone, Debug)]
+pub struct ExponentialLrScheduler {
+    init_lr: LearningRate,
+    gamma: f64,
+    step: usize,
+}
+
+impl ExponentialLrSchedulerConfig {
+    /// Initialize a new [exponential](ExponentialLrScheduler) learning rate scheduler.
+    pub fn init(&self) -> ExponentialLrScheduler {
+        assert!(
+            self.gamma > 0.0 && self.gamma <= 1.0,
+            "Gamma must be in the range (0, 1]."
+        );
+
+        ExponentialLrScheduler {
+            init_lr: self.init_lr,
+            gamma: self.gamma,
+            step: 0,
+        }
+    }
+}
+
+impl LrScheduler for ExponentialLrScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let lr = self.init_lr * self.gamma.powf(self.step as f64);
+        self.step += 1;
+
+        lr
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{check_lr_sequence, check_save_load};
+    use super::*;
+
+    #[test]
+    fn test_exponential_lr() {
+        let scheduler = ExponentialLrSchedulerConfig::new(2.0, 0.5).init();
+
+        check_lr_sequence(scheduler, &[2.0, 1.0, 0.5, 0.25, 0.125]);
+    }
+
+    #[test]
+    fn test_exponential_lr_save_load() {
+        let scheduler = ExponentialLrSchedulerConfig::new(1.0, 0.9).init();
+
+        check_save_load(scheduler, 6);
+    }
+}
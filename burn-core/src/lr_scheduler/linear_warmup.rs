@@ -0,0 +1,166 @@
+use crate as burn;
+
+use super::LrScheduler;
+use crate::record::{PrecisionSettings, Record};
+use crate::{config::Config, LearningRate};
+use serde::{Deserialize, Serialize};
+
+/// Configuration to create a [linear warmup](LinearWarmupLrScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct LinearWarmupLrSchedulerConfig {
+    /// The number of warmup steps, before the wrapped scheduler starts.
+    warmup_steps: usize,
+    /// The fraction of the first learning rate of the wrapped scheduler used at the first step.
+    #[config(default = 0.0)]
+    start_factor: f64,
+}
+
+/// Linear warmup learning rate scheduler, which can be composed with any other scheduler.
+///
+/// During the warmup steps, the learning rate increases linearly from `start_factor` times the
+/// first learning rate of the wrapped scheduler up to that learning rate. The wrapped scheduler
+/// only starts once the warmup is over, so its whole schedule comes after the warmup.
+#[derive(Clone, Debug)]
+pub struct LinearWarmupLrScheduler<S> {
+    scheduler: S,
+    warmup_steps: usize,
+    start_factor: f64,
+    step: usize,
+}
+
+/// [Record](Record) of the [linear warmup](LinearWarmupLrScheduler) learning rate scheduler.
+pub struct LinearWarmupLrSchedulerRecord<R: Record> {
+    step: usize,
+    scheduler: R,
+}
+
+/// The record item type of the [linear warmup record](LinearWarmupLrSchedulerRecord).
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct LinearWarmupLrSchedulerRecordItem<R: Record, S: PrecisionSettings> {
+    step: usize,
+    scheduler: R::Item<S>,
+}
+
+impl<R: Record> Record for LinearWarmupLrSchedulerRecord<R> {
+    type Item<S: PrecisionSettings> = LinearWarmupLrSchedulerRecordItem<R, S>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        LinearWarmupLrSchedulerRecordItem {
+            step: self.step,
+            scheduler: self.scheduler.into_item(),
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
+        Self {
+            step: item.step,
+            scheduler: R::from_item(item.scheduler),
+        }
+    }
+}
+
+impl LinearWarmupLrSchedulerConfig {
+    /// Initialize a new [linear warmup](LinearWarmupLrScheduler) learning rate scheduler that
+    /// wraps the given scheduler.
+    pub fn init<S: LrScheduler + Clone>(&self, scheduler: S) -> LinearWarmupLrScheduler<S> {
+        assert!(
+            self.start_factor >= 0.0 && self.start_factor <= 1.0,
+            "Start factor must be in the range [0, 1]."
+        );
+
+        LinearWarmupLrScheduler {
+            scheduler,
+            warmup_steps: self.warmup_steps,
+            start_factor: self.start_factor,
+            step: 0,
+        }
+    }
+}
+
+impl<S: LrScheduler + Clone> LrScheduler for LinearWarmupLrScheduler<S> {
+    type Record = LinearWarmupLrSchedulerRecord<S::Record>;
+
+    fn step(&mut self) -> LearningRate {
+        if self.step >= self.warmup_steps {
+            return self.scheduler.step();
+        }
+
+        // The wrapped scheduler isn't stepped during the warmup, so its first learning rate is
+        // computed on a copy.
+        let target_lr = self.scheduler.clone().step();
+        let progress = self.step as f64 / self.warmup_steps as f64;
+        self.step += 1;
+
+        target_lr * (self.start_factor + (1.0 - self.start_factor) * progress)
+    }
+
+    fn report_metric(&mut self, value: f64) {
+        self.scheduler.report_metric(value);
+    }
+
+    fn to_record(&self) -> Self::Record {
+        LinearWarmupLrSchedulerRecord {
+            step: self.step,
+            scheduler: self.scheduler.to_record(),
+        }
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record.step;
+        self.scheduler = self.scheduler.load_record(record.scheduler);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::step::StepLrSchedulerConfig;
+    use super::super::{check_lr_sequence, check_save_load};
+    use super::*;
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+
+    #[test]
+    fn test_linear_warmup() {
+        let scheduler = LinearWarmupLrSchedulerConfig::new(4).init(2.0);
+
+        check_lr_sequence(scheduler, &[0.0, 0.5, 1.0, 1.5, 2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_linear_warmup_starts_the_wrapped_scheduler_after_the_warmup() {
+        let scheduler = LinearWarmupLrSchedulerConfig::new(2)
+            .with_start_factor(0.5)
+            .init(StepLrSchedulerConfig::new(1.0, 2).with_gamma(0.5).init());
+
+        check_lr_sequence(scheduler, &[0.5, 0.75, 1.0, 1.0, 0.5, 0.5, 0.25]);
+    }
+
+    #[test]
+    fn test_linear_warmup_save_load() {
+        let scheduler = LinearWarmupLrSchedulerConfig::new(3)
+            .init(StepLrSchedulerConfig::new(1.0, 2).with_gamma(0.5).init());
+
+        // The schedule is resumed both during and after the warmup.
+        check_save_load(scheduler.clone(), 2);
+        check_save_load(scheduler, 5);
+    }
+
+    #[test]
+    fn test_linear_warmup_record_serialization() {
+        let config = LinearWarmupLrSchedulerConfig::new(3);
+        let init = || config.init(StepLrSchedulerConfig::new(1.0, 2).init());
+        let mut scheduler = init();
+        for _ in 0..4 {
+            scheduler.step();
+        }
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+
+        let bytes = recorder.record(scheduler.to_record(), ()).unwrap();
+        let mut resumed = init().load_record(recorder.load(bytes).unwrap());
+
+        for _ in 0..4 {
+            assert_eq!(resumed.step(), scheduler.step());
+        }
+    }
+}
@@ -1,9 +1,30 @@
 /// Constant learning rate scheduler
 pub mod constant;
 
+/// Cosine annealing learning rate schedules
+pub mod cosine;
+
+/// Exponential learning rate schedule
+pub mod exponential;
+
+/// Linear warmup learning rate schedule
+pub mod linear_warmup;
+
 /// Noam Learning rate schedule
 pub mod noam;
 
+/// One-cycle learning rate schedule
+pub mod one_cycle;
+
+/// Polynomial learning rate schedule
+pub mod polynomial;
+
+/// Reduce on plateau learning rate schedule
+pub mod reduce_on_plateau;
+
+/// Step and multi-step learning rate schedules
+pub mod step;
+
 mod base;
 
 pub use base::*;
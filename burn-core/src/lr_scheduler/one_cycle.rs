@@ -0,0 +1,117 @@
+use crate as burn;
+
+use super::cosine::cosine_annealing;
+use super::LrScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create a [one-cycle](OneCycleLrScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct OneCycleLrSchedulerConfig {
+    /// The maximum learning rate, reached at the end of the warmup phase.
+    max_lr: LearningRate,
+    /// The total number of steps of the cycle.
+    num_iters: usize,
+    /// The fraction of the cycle spent increasing the learning rate.
+    #[config(default = 0.3)]
+    pct_start: f64,
+    /// The initial learning rate is given by `max_lr / div_factor`.
+    #[config(default = 25.0)]
+    div_factor: f64,
+    /// The final learning rate is given by `max_lr / (div_factor * final_div_factor)`.
+    #[config(default = 1e4)]
+    final_div_factor: f64,
+}
+
+/// One-cycle learning rate scheduler as described in
+/// [Super-Convergence: Very Fast Training of Neural Networks Using Large Learning Rates](https://arxiv.org/abs/1708.07120).
+///
+/// The learning rate is annealed from the initial learning rate up to the maximum learning rate
+/// during the first part of the cycle, then down to the final learning rate, which is kept after
+/// the end of the cycle. Both phases follow half a cosine period.
+#[derive(Clone, Debug)]
+pub struct OneCycleLrScheduler {
+    init_lr: LearningRate,
+    max_lr: LearningRate,
+    final_lr: LearningRate,
+    warmup_end: f64,
+    cycle_end: f64,
+    step: usize,
+}
+
+impl OneCycleLrSchedulerConfig {
+    /// Initialize a new [one-cycle](OneCycleLrScheduler) learning rate scheduler.
+    pub fn init(&self) -> OneCycleLrScheduler {
+        assert!(
+            self.num_iters > 0,
+            "Number of iterations must be at least 1."
+        );
+        assert!(
+            self.pct_start >= 0.0 && self.pct_start < 1.0,
+            "Percentage of the cycle spent increasing the learning rate must be in the range [0, 1)."
+        );
+
+        let init_lr = self.max_lr / self.div_factor;
+
+        OneCycleLrScheduler {
+            init_lr,
+            max_lr: self.max_lr,
+            final_lr: init_lr / self.final_div_factor,
+            warmup_end: self.pct_start * self.num_iters as f64 - 1.0,
+            cycle_end: self.num_iters as f64 - 1.0,
+            step: 0,
+        }
+    }
+}
+
+impl LrScheduler for OneCycleLrScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let step = f64::min(self.step as f64, self.cycle_end);
+        self.step += 1;
+
+        if step <= self.warmup_end && self.warmup_end > 0.0 {
+            return cosine_annealing(self.init_lr, self.max_lr, step / self.warmup_end);
+        }
+
+        let progress = match self.cycle_end > self.warmup_end {
+            true => (step - self.warmup_end) / (self.cycle_end - self.warmup_end),
+            false => 1.0,
+        };
+
+        cosine_annealing(self.max_lr, self.final_lr, progress)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{check_lr_sequence, check_save_load};
+    use super::*;
+
+    #[test]
+    fn test_one_cycle_lr() {
+        let scheduler = OneCycleLrSchedulerConfig::new(1.0, 5)
+            .with_pct_start(0.4)
+            .with_div_factor(10.0)
+            .with_final_div_factor(10.0)
+            .init();
+
+        check_lr_sequence(scheduler, &[0.1, 1.0, 0.7525, 0.2575, 0.01, 0.01]);
+    }
+
+    #[test]
+    fn test_one_cycle_lr_save_load() {
+        let scheduler = OneCycleLrSchedulerConfig::new(1.0, 10).init();
+
+        check_save_load(scheduler, 5);
+    }
+}
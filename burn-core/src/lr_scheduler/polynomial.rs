@@ -0,0 +1,92 @@
+use crate as burn;
+
+use super::LrScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create a [polynomial](PolynomialLrScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct PolynomialLrSchedulerConfig {
+    /// The initial learning rate.
+    init_lr: LearningRate,
+    /// The number of steps to decay the learning rate down to the final learning rate.
+    num_iters: usize,
+    /// The power of the polynomial, a power of 1 giving a linear decay.
+    #[config(default = 1.0)]
+    power: f64,
+    /// The final learning rate, reached after `num_iters` steps.
+    #[config(default = 0.0)]
+    final_lr: LearningRate,
+}
+
+/// Polynomial learning rate scheduler, which decays the learning rate from the initial learning
+/// rate to the final learning rate following `(1 - step / num_iters) ^ power`, then stays at the
+/// final learning rate.
+#[derive(Clone, Debug)]
+pub struct PolynomialLrScheduler {
+    init_lr: LearningRate,
+    final_lr: LearningRate,
+    power: f64,
+    num_iters: usize,
+    step: usize,
+}
+
+impl PolynomialLrSchedulerConfig {
+    /// Initialize a new [polynomial](PolynomialLrScheduler) learning rate scheduler.
+    pub fn init(&self) -> PolynomialLrScheduler {
+        assert!(
+            self.num_iters > 0,
+            "Number of iterations must be at least 1."
+        );
+
+        PolynomialLrScheduler {
+            init_lr: self.init_lr,
+            final_lr: self.final_lr,
+            power: self.power,
+            num_iters: self.num_iters,
+            step: 0,
+        }
+    }
+}
+
+impl LrScheduler for PolynomialLrScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let progress = usize::min(self.step, self.num_iters) as f64 / self.num_iters as f64;
+        self.step += 1;
+
+        self.final_lr + (self.init_lr - self.final_lr) * (1.0 - progress).powf(self.power)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{check_lr_sequence, check_save_load};
+    use super::*;
+
+    #[test]
+    fn test_polynomial_lr() {
+        let scheduler = PolynomialLrSchedulerConfig::new(1.0, 4)
+            .with_power(2.0)
+            .with_final_lr(0.1)
+            .init();
+
+        check_lr_sequence(scheduler, &[1.0, 0.60625, 0.325, 0.15625, 0.1, 0.1]);
+    }
+
+    #[test]
+    fn test_polynomial_lr_save_load() {
+        let scheduler = PolynomialLrSchedulerConfig::new(1.0, 8).init();
+
+        check_save_load(scheduler, 4);
+    }
+}
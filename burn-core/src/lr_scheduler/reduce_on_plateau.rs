@@ -0,0 +1,202 @@
+use crate as burn;
+
+use super::LrScheduler;
+use crate::record::Record;
+use crate::{config::Config, LearningRate};
+
+/// Whether the monitored metric should be minimized or maximized.
+#[derive(Config, Debug, PartialEq, Eq, Copy)]
+pub enum ReduceOnPlateauMode {
+    /// Lower is better, e.g. for a loss.
+    Min,
+    /// Higher is better, e.g. for an accuracy.
+    Max,
+}
+
+/// Configuration to create a [reduce on plateau](ReduceOnPlateauLrScheduler) learning rate
+/// scheduler.
+#[derive(Config)]
+pub struct ReduceOnPlateauLrSchedulerConfig {
+    /// The initial learning rate.
+    init_lr: LearningRate,
+    /// Whether the monitored metric should be minimized or maximized.
+    #[config(default = "ReduceOnPlateauMode::Min")]
+    mode: ReduceOnPlateauMode,
+    /// The factor by which the learning rate is multiplied when the metric stops improving.
+    #[config(default = 0.1)]
+    factor: f64,
+    /// The number of epochs without improvement after which the learning rate is reduced.
+    #[config(default = 10)]
+    patience: usize,
+    /// The number of epochs to wait after a reduction before counting the epochs without
+    /// improvement again.
+    #[config(default = 0)]
+    cooldown: usize,
+    /// The learning rate is never reduced below this value.
+    #[config(default = 0.0)]
+    min_lr: LearningRate,
+    /// The relative change of the metric needed to be considered as an improvement.
+    #[config(default = 1e-4)]
+    threshold: f64,
+}
+
+/// Reduce on plateau learning rate scheduler, which reduces the learning rate when the monitored
+/// metric stops improving.
+///
+/// The learning rate is constant between the [reports](LrScheduler::report_metric) of the
+/// metric, which are done once per epoch by the learner with the aggregated validation metric.
+#[derive(Clone, Debug)]
+pub struct ReduceOnPlateauLrScheduler {
+    mode: ReduceOnPlateauMode,
+    factor: f64,
+    patience: usize,
+    cooldown: usize,
+    min_lr: LearningRate,
+    threshold: f64,
+    state: ReduceOnPlateauState,
+}
+
+/// State of the [reduce on plateau](ReduceOnPlateauLrScheduler) learning rate scheduler.
+#[derive(Record, Clone, Debug)]
+pub struct ReduceOnPlateauState {
+    lr: LearningRate,
+    best: Option<f64>,
+    num_bad_epochs: usize,
+    cooldown_counter: usize,
+}
+
+impl ReduceOnPlateauLrSchedulerConfig {
+    /// Initialize a new [reduce on plateau](ReduceOnPlateauLrScheduler) learning rate scheduler.
+    pub fn init(&self) -> ReduceOnPlateauLrScheduler {
+        assert!(
+            self.factor > 0.0 && self.factor < 1.0,
+            "Factor must be in the range (0, 1)."
+        );
+
+        ReduceOnPlateauLrScheduler {
+            mode: self.mode,
+            factor: self.factor,
+            patience: self.patience,
+            cooldown: self.cooldown,
+            min_lr: self.min_lr,
+            threshold: self.threshold,
+            state: ReduceOnPlateauState {
+                lr: self.init_lr,
+                best: None,
+                num_bad_epochs: 0,
+                cooldown_counter: 0,
+            },
+        }
+    }
+}
+
+impl ReduceOnPlateauLrScheduler {
+    fn is_improvement(&self, value: f64, best: f64) -> bool {
+        match self.mode {
+            ReduceOnPlateauMode::Min => value < best - best.abs() * self.threshold,
+            ReduceOnPlateauMode::Max => value > best + best.abs() * self.threshold,
+        }
+    }
+}
+
+impl LrScheduler for ReduceOnPlateauLrScheduler {
+    type Record = ReduceOnPlateauState;
+
+    fn step(&mut self) -> LearningRate {
+        self.state.lr
+    }
+
+    fn report_metric(&mut self, value: f64) {
+        let improved = match self.state.best {
+            Some(best) => self.is_improvement(value, best),
+            None => true,
+        };
+        let state = &mut self.state;
+
+        if improved {
+            state.best = Some(value);
+            state.num_bad_epochs = 0;
+        } else {
+            state.num_bad_epochs += 1;
+        }
+
+        if state.cooldown_counter > 0 {
+            state.cooldown_counter -= 1;
+            state.num_bad_epochs = 0;
+        }
+
+        if state.num_bad_epochs > self.patience {
+            state.lr = f64::max(state.lr * self.factor, self.min_lr);
+            state.cooldown_counter = self.cooldown;
+            state.num_bad_epochs = 0;
+        }
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.state.clone()
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.state = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reduce_on_plateau() {
+        let mut scheduler = ReduceOnPlateauLrSchedulerConfig::new(1.0)
+            .with_patience(1)
+            .with_factor(0.5)
+            .init();
+
+        let lrs = report_all(&mut scheduler, &[5.0, 4.0, 4.0, 4.0, 3.0, 3.5, 3.5, 3.2]);
+
+        assert_eq!(lrs, [1.0, 1.0, 1.0, 0.5, 0.5, 0.5, 0.25, 0.25]);
+    }
+
+    #[test]
+    fn test_reduce_on_plateau_with_cooldown_and_min_lr() {
+        let mut scheduler = ReduceOnPlateauLrSchedulerConfig::new(1.0)
+            .with_mode(ReduceOnPlateauMode::Max)
+            .with_patience(0)
+            .with_factor(0.5)
+            .with_cooldown(1)
+            .with_min_lr(0.3)
+            .init();
+
+        let lrs = report_all(&mut scheduler, &[0.5, 0.4, 0.4, 0.4, 0.4, 0.4]);
+
+        assert_eq!(lrs, [1.0, 0.5, 0.5, 0.3, 0.3, 0.3]);
+    }
+
+    #[test]
+    fn test_reduce_on_plateau_save_load() {
+        let mut scheduler = ReduceOnPlateauLrSchedulerConfig::new(1.0)
+            .with_patience(2)
+            .init();
+        report_all(&mut scheduler, &[1.0, 2.0, 2.0]);
+
+        let mut resumed = ReduceOnPlateauLrSchedulerConfig::new(1.0)
+            .with_patience(2)
+            .init()
+            .load_record(scheduler.to_record());
+
+        // The third epoch without improvement reduces the learning rate of both schedulers.
+        assert_eq!(report_all(&mut resumed, &[2.0]), [0.1]);
+        assert_eq!(report_all(&mut scheduler, &[2.0]), [0.1]);
+    }
+
+    fn report_all(scheduler: &mut ReduceOnPlateauLrScheduler, values: &[f64]) -> Vec<f64> {
+        values
+            .iter()
+            .map(|value| {
+                scheduler.report_metric(*value);
+                scheduler.step()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,145 @@
+use crate as burn;
+
+use super::LrScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create a [step](StepLrScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct StepLrSchedulerConfig {
+    /// The initial learning rate.
+    init_lr: LearningRate,
+    /// The number of steps between each decay of the learning rate.
+    step_size: usize,
+    /// The factor by which the learning rate is multiplied at each decay.
+    #[config(default = 0.1)]
+    gamma: f64,
+}
+
+/// Step learning rate scheduler, which multiplies the learning rate by `gamma` every `step_size`
+/// steps.
+#[derive(Clone, Debug)]
+pub struct StepLrScheduler {
+    init_lr: LearningRate,
+    step_size: usize,
+    gamma: f64,
+    step: usize,
+}
+
+impl StepLrSchedulerConfig {
+    /// Initialize a new [step](StepLrScheduler) learning rate scheduler.
+    pub fn init(&self) -> StepLrScheduler {
+        assert!(self.step_size > 0, "Step size must be at least 1.");
+
+        StepLrScheduler {
+            init_lr: self.init_lr,
+            step_size: self.step_size,
+            gamma: self.gamma,
+            step: 0,
+        }
+    }
+}
+
+impl LrScheduler for StepLrScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let num_decays = self.step / self.step_size;
+        self.step += 1;
+
+        self.init_lr * self.gamma.powi(num_decays as i32)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+/// Configuration to create a [multi-step](MultiStepLrScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct MultiStepLrSchedulerConfig {
+    /// The initial learning rate.
+    init_lr: LearningRate,
+    /// The steps at which the learning rate is decayed.
+    milestones: Vec<usize>,
+    /// The factor by which the learning rate is multiplied at each milestone.
+    #[config(default = 0.1)]
+    gamma: f64,
+}
+
+/// Multi-step learning rate scheduler, which multiplies the learning rate by `gamma` each time
+/// one of the milestones is reached.
+#[derive(Clone, Debug)]
+pub struct MultiStepLrScheduler {
+    init_lr: LearningRate,
+    milestones: Vec<usize>,
+    gamma: f64,
+    step: usize,
+}
+
+impl MultiStepLrSchedulerConfig {
+    /// Initialize a new [multi-step](MultiStepLrScheduler) learning rate scheduler.
+    pub fn init(&self) -> MultiStepLrScheduler {
+        MultiStepLrScheduler {
+            init_lr: self.init_lr,
+            milestones: self.milestones.clone(),
+            gamma: self.gamma,
+            step: 0,
+        }
+    }
+}
+
+impl LrScheduler for MultiStepLrScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let num_decays = self
+            .milestones
+            .iter()
+            .filter(|milestone| **milestone <= self.step)
+            .count();
+        self.step += 1;
+
+        self.init_lr * self.gamma.powi(num_decays as i32)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{check_lr_sequence, check_save_load};
+    use super::*;
+
+    #[test]
+    fn test_step_lr() {
+        let scheduler = StepLrSchedulerConfig::new(1.0, 2).with_gamma(0.5).init();
+
+        check_lr_sequence(scheduler, &[1.0, 1.0, 0.5, 0.5, 0.25, 0.25, 0.125]);
+    }
+
+    #[test]
+    fn test_multi_step_lr() {
+        let scheduler = MultiStepLrSchedulerConfig::new(1.0, vec![1, 4]).init();
+
+        check_lr_sequence(scheduler, &[1.0, 0.1, 0.1, 0.1, 0.01, 0.01]);
+    }
+
+    #[test]
+    fn test_multi_step_lr_save_load() {
+        let scheduler = MultiStepLrSchedulerConfig::new(1.0, vec![2, 3, 7]).init();
+
+        check_save_load(scheduler, 5);
+    }
+}
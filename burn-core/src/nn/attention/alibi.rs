@@ -0,0 +1,97 @@
+use alloc::vec::Vec;
+
+use burn_tensor::{backend::Backend, Data, Tensor};
+use libm::powf;
+
+/// Generate the head-specific slopes used by ALiBi.
+///
+/// The slopes form a geometric sequence starting at `2^(-8/n)`, with `n` being the number of
+/// heads. When the number of heads is not a power of two, the slopes of the closest lower power
+/// of two are completed with every other slope of the next power of two, as in the reference
+/// implementation.
+pub fn generate_alibi_slopes(n_heads: usize) -> Vec<f32> {
+    let slopes = |n: usize| {
+        let start = powf(2.0, -8.0 / n as f32);
+        (1..n + 1).map(move |i| powf(start, i as f32))
+    };
+
+    if n_heads.is_power_of_two() {
+        return slopes(n_heads).collect();
+    }
+
+    let closest = 1 << (usize::BITS - n_heads.leading_zeros() - 1);
+
+    slopes(closest)
+        .chain(slopes(2 * closest).step_by(2).take(n_heads - closest))
+        .collect()
+}
+
+/// Generate the attention biases introduced in
+/// [Train Short, Test Long: Attention with Linear Biases Enables Input Length Extrapolation](https://arxiv.org/abs/2108.12409).
+///
+/// The bias of each head is its slope multiplied by the negative distance between the query and
+/// key positions. The queries are aligned with the last keys, so that the biases stay valid when
+/// decoding with a cache.
+///
+/// # Returns
+///
+/// A tensor of shape `[n_heads, seq_length_1, seq_length_2]` to be added to the attention scores.
+pub fn generate_alibi_bias<B: Backend>(
+    n_heads: usize,
+    seq_length_1: usize,
+    seq_length_2: usize,
+    device: &B::Device,
+) -> Tensor<B, 3> {
+    let offset = seq_length_2 as i64 - seq_length_1 as i64;
+    let mut bias = Vec::with_capacity(n_heads * seq_length_1 * seq_length_2);
+
+    for slope in generate_alibi_slopes(n_heads) {
+        for i in 0..seq_length_1 {
+            for j in 0..seq_length_2 {
+                let distance = (i as i64 + offset - j as i64).abs();
+                bias.push(-slope * distance as f32);
+            }
+        }
+    }
+
+    let data = Data::new(bias, [n_heads, seq_length_1, seq_length_2].into());
+
+    Tensor::from_data_device(data.convert(), device)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_alibi_slopes_power_of_two() {
+        let slopes = generate_alibi_slopes(4);
+
+        Data::from(slopes.as_slice())
+            .assert_approx_eq(&Data::from([0.25, 0.0625, 0.015625, 0.00390625]), 6);
+    }
+
+    #[test]
+    fn test_alibi_slopes_not_power_of_two() {
+        let slopes = generate_alibi_slopes(6);
+
+        Data::from(slopes.as_slice()).assert_approx_eq(
+            &Data::from([0.25, 0.0625, 0.015625, 0.00390625, 0.5, 0.125]),
+            6,
+        );
+    }
+
+    #[test]
+    fn test_alibi_bias() {
+        let bias = generate_alibi_bias::<TestBackend>(2, 2, 3, &Default::default());
+
+        bias.into_data().assert_approx_eq(
+            &Data::from([
+                [[-0.0625, 0.0, -0.0625], [-0.125, -0.0625, 0.0]],
+                [[-0.0039, 0.0, -0.0039], [-0.0078, -0.0039, 0.0]],
+            ]),
+            3,
+        );
+    }
+}
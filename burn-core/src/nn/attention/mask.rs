@@ -10,16 +10,7 @@ pub fn generate_autoregressive_mask<B: Backend>(
     seq_length: usize,
     device: &B::Device,
 ) -> Tensor<B, 3, Bool> {
-    let mut mask = Tensor::<B, 3, Int>::zeros([1, seq_length, seq_length]);
-
-    for i in 0..(seq_length - 1) {
-        let values = Tensor::<B, 3, Int>::ones([1, 1, seq_length - (i + 1)]);
-        mask = mask.slice_assign([0..1, i..i + 1, i + 1..seq_length], values);
-    }
-
-    mask = mask.to_device(device).repeat(0, batch_size);
-
-    mask.equal_elem(1_i64.elem::<i64>())
+    Tensor::tril_mask([batch_size, seq_length, seq_length], 0, device)
 }
 
 /// Generate a padding attention mask.
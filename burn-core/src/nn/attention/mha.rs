@@ -1,14 +1,18 @@
 use crate as burn;
 
+use crate::nn::attention::generate_alibi_bias;
 use crate::nn::cache::TensorCache;
-use crate::nn::Initializer;
+use crate::nn::{Initializer, RotaryEncoding, RotaryEncodingConfig};
 use crate::{
     config::Config,
     module::Module,
     nn,
-    tensor::{activation, backend::Backend, Bool, Tensor},
+    tensor::{
+        activation, backend::Backend, module, ops::AttentionOptions, Bool, Distribution,
+        ElementConversion, Tensor,
+    },
 };
-use libm::sqrtf;
+use libm::{sqrt, sqrtf};
 
 /// Configuration to create a [Multi Head Attention](MultiHeadAttention) layer.
 #[derive(Config)]
@@ -17,6 +21,10 @@ pub struct MultiHeadAttentionConfig {
     d_model: usize,
     /// The number of heads.
     n_heads: usize,
+    /// The number of key and value heads, each shared by a group of query heads. When set to 1,
+    /// this gives multi-query attention, and when set to a divisor of `n_heads`, grouped-query
+    /// attention. Uses the same number as query heads when not set.
+    pub n_kv_heads: Option<usize>,
     /// The dropout rate. Default: 0.1
     #[config(default = 0.1)]
     dropout: f64,
@@ -30,6 +38,28 @@ pub struct MultiHeadAttentionConfig {
         default = "Initializer::KaimingUniform{gain:1.0/libm::sqrt(3.0), fan_out_only:false}"
     )]
     pub initializer: Initializer,
+    /// How the positions of the queries and keys are taken into account. Default: Absolute
+    #[config(default = "PositionalStrategy::Absolute")]
+    pub positional: PositionalStrategy,
+}
+
+/// Strategy used by the [multihead attention](MultiHeadAttention) to take the positions of the
+/// queries and keys into account.
+#[derive(Module, Config, Debug, PartialEq)]
+pub enum PositionalStrategy {
+    /// Nothing is done by the attention layer, positional information is expected to be part of
+    /// the inputs, e.g. with a [PositionalEncoding](crate::nn::PositionalEncoding).
+    Absolute,
+    /// The queries and keys of each head are rotated with a [RotaryEncoding](RotaryEncoding).
+    Rotary {
+        /// Maximum sequence length of the queries and keys.
+        max_sequence_length: usize,
+        /// Base used to compute the rotation frequencies.
+        theta: f32,
+    },
+    /// Linear biases are added to the attention scores of each head, see
+    /// [generate_alibi_bias](crate::nn::attention::generate_alibi_bias).
+    Alibi,
 }
 
 /// The multihead attention module as describe in the paper [Attention Is All You Need](https://arxiv.org/abs/1706.03762).
@@ -37,8 +67,10 @@ pub struct MultiHeadAttentionConfig {
 /// # Params
 ///
 /// - query: [Linear](nn::Linear) layer with `d_model` input and output features.
-/// - key: [Linear](nn::Linear) layer with `d_model` input and output features.
-/// - value: [Linear](nn::Linear) layer with `d_model` input and output features.
+/// - key: [Linear](nn::Linear) layer with `d_model` input features and `n_kv_heads * d_k` output
+///   features.
+/// - value: [Linear](nn::Linear) layer with `d_model` input features and `n_kv_heads * d_k` output
+///   features.
 /// - output: [Linear](nn::Linear) layer with `d_model` input and output features.
 #[derive(Module, Debug)]
 pub struct MultiHeadAttention<B: Backend> {
@@ -49,8 +81,11 @@ pub struct MultiHeadAttention<B: Backend> {
     dropout: nn::Dropout,
     activation: nn::GELU,
     n_heads: usize,
+    n_kv_heads: usize,
     d_k: usize,
     min_float: f64,
+    positional: PositionalStrategy,
+    rotary: Option<RotaryEncoding<B>>,
 }
 
 /// [Multihead attention](MultiHeadAttention) forward pass input argument.
@@ -61,27 +96,33 @@ pub struct MhaInput<B: Backend> {
     value: Tensor<B, 3>,
     mask_pad: Option<Tensor<B, 2, Bool>>,
     mask_attn: Option<Tensor<B, 3, Bool>>,
+    need_weights: bool,
 }
 
 impl MultiHeadAttentionConfig {
     /// Initialize a new [multihead attention](MultiHeadAttention) module.
     pub fn init<B: Backend>(&self) -> MultiHeadAttention<B> {
-        let linear = |config: &Self| {
-            nn::LinearConfig::new(config.d_model, config.d_model)
+        let n_kv_heads = self.n_kv_heads();
+        let d_kv = n_kv_heads * (self.d_model / self.n_heads);
+        let linear = |d_input, d_output| {
+            nn::LinearConfig::new(d_input, d_output)
                 .with_initializer(self.initializer.clone())
                 .init()
         };
 
         MultiHeadAttention {
-            query: linear(self),
-            key: linear(self),
-            value: linear(self),
-            output: linear(self),
+            query: linear(self.d_model, self.d_model),
+            key: linear(self.d_model, d_kv),
+            value: linear(self.d_model, d_kv),
+            output: linear(self.d_model, self.d_model),
             dropout: nn::DropoutConfig::new(self.dropout).init(),
             activation: nn::GELU::new(),
             n_heads: self.n_heads,
+            n_kv_heads,
             d_k: self.d_model / self.n_heads,
             min_float: self.min_float,
+            positional: self.positional.clone(),
+            rotary: self.init_rotary(),
         }
     }
 
@@ -91,20 +132,53 @@ impl MultiHeadAttentionConfig {
         &self,
         record: MultiHeadAttentionRecord<B>,
     ) -> MultiHeadAttention<B> {
-        let linear = |config: &Self, record| {
-            nn::LinearConfig::new(config.d_model, config.d_model).init_with(record)
-        };
+        let n_kv_heads = self.n_kv_heads();
+        let d_kv = n_kv_heads * (self.d_model / self.n_heads);
+        let linear =
+            |d_input, d_output, record| nn::LinearConfig::new(d_input, d_output).init_with(record);
 
         MultiHeadAttention {
-            query: linear(self, record.query),
-            key: linear(self, record.key),
-            value: linear(self, record.value),
-            output: linear(self, record.output),
+            query: linear(self.d_model, self.d_model, record.query),
+            key: linear(self.d_model, d_kv, record.key),
+            value: linear(self.d_model, d_kv, record.value),
+            output: linear(self.d_model, self.d_model, record.output),
             dropout: nn::DropoutConfig::new(self.dropout).init(),
             activation: nn::GELU::new(),
             n_heads: self.n_heads,
+            n_kv_heads,
             d_k: self.d_model / self.n_heads,
             min_float: self.min_float,
+            positional: self.positional.clone(),
+            rotary: self.init_rotary(),
+        }
+    }
+}
+
+impl MultiHeadAttentionConfig {
+    fn n_kv_heads(&self) -> usize {
+        let n_kv_heads = self.n_kv_heads.unwrap_or(self.n_heads);
+
+        if n_kv_heads == 0 || self.n_heads % n_kv_heads != 0 {
+            panic!(
+                "The number of heads {} must be divisible by the number of key and value heads {}",
+                self.n_heads, n_kv_heads
+            );
+        }
+
+        n_kv_heads
+    }
+
+    fn init_rotary<B: Backend>(&self) -> Option<RotaryEncoding<B>> {
+        match self.positional {
+            PositionalStrategy::Rotary {
+                max_sequence_length,
+                theta,
+            } => Some(
+                RotaryEncodingConfig::new(max_sequence_length, self.d_model / self.n_heads)
+                    .with_theta(theta)
+                    .init(),
+            ),
+            _ => None,
         }
     }
 }
@@ -119,6 +193,7 @@ impl<B: Backend> MhaInput<B> {
             value: tensor,
             mask_pad: None,
             mask_attn: None,
+            need_weights: false,
         }
     }
 
@@ -130,6 +205,7 @@ impl<B: Backend> MhaInput<B> {
             value,
             mask_pad: None,
             mask_attn: None,
+            need_weights: false,
         }
     }
 
@@ -144,12 +220,25 @@ impl<B: Backend> MhaInput<B> {
         self.mask_attn = Some(mask_attn);
         self
     }
+
+    /// Whether the attention weights should be returned. Default: false
+    ///
+    /// When they are not needed, the attention is computed with the
+    /// [scaled dot-product attention](crate::tensor::module::scaled_dot_product_attention)
+    /// operation, which doesn't materialize the attention weights.
+    pub fn need_weights(mut self, need_weights: bool) -> Self {
+        self.need_weights = need_weights;
+        self
+    }
 }
 
 /// [Multihead attention](MultiHeadAttention) outputs.
 #[derive(Debug, Clone)]
 pub struct MhaOutput<B: Backend> {
-    /// The attention weights [batch_size, seq_length_1, seq_length_2].
+    /// The attention weights [batch_size, n_heads, seq_length_1, seq_length_2].
+    ///
+    /// They are only computed when [needed](MhaInput::need_weights), otherwise the tensor is
+    /// empty, with the shape [batch_size, n_heads, seq_length_1, 0].
     pub weights: Tensor<B, 4>,
     /// The context tensor [batch_size, seq_length_1, d_model].
     pub context: Tensor<B, 3>,
@@ -167,14 +256,21 @@ impl<B: Backend> MultiHeadAttention<B> {
     pub fn forward(&self, input: MhaInput<B>) -> MhaOutput<B> {
         let [batch_size, seq_length_1, d_model] = input.query.dims();
 
-        let query = self.attention_linear(input.query, &self.query);
-        let key = self.attention_linear(input.key, &self.key);
-        let value = self.attention_linear(input.value, &self.value);
+        let query = self.attention_linear(input.query, &self.query, self.n_heads);
+        let key = self.attention_linear(input.key, &self.key, self.n_kv_heads);
+        let value = self.attention_linear(input.value, &self.value, self.n_kv_heads);
 
-        let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
+        let query = self.rotate(query, 0);
+        let key = self.rotate(key, 0);
 
-        let context = weights.clone().matmul(value);
+        let (context, weights) = self.attention(
+            query,
+            key,
+            value,
+            input.mask_pad,
+            input.mask_attn,
+            input.need_weights,
+        );
         let context = context
             .swap_dims(1, 2)
             .reshape([batch_size, seq_length_1, d_model]);
@@ -185,6 +281,9 @@ impl<B: Backend> MultiHeadAttention<B> {
 
     /// Applies the forward pass using a cache.
     ///
+    /// The keys and values are cached before being shared across the query heads, so the cache
+    /// holds `n_kv_heads` heads.
+    ///
     /// # Shapes
     ///
     /// - query: `[batch_size, seq_length_1, d_model]`
@@ -193,21 +292,30 @@ impl<B: Backend> MultiHeadAttention<B> {
     /// - output: `[batch_size, seq_length_1, d_model]`
     pub fn forward_cache(&self, input: MhaInput<B>, cache: &mut MhaCache<B>) -> MhaOutput<B> {
         let [batch_size, seq_length_1, d_model] = input.query.dims();
-
-        let query = cache
-            .query
-            .forward(input.query, |t| self.attention_linear(t, &self.query));
-        let key = cache
-            .key
-            .forward(input.key, |t| self.attention_linear(t, &self.key));
-        let value = cache
-            .value
-            .forward(input.value, |t| self.attention_linear(t, &self.value));
-
-        let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
-
-        let context = weights.clone().matmul(value);
+        let [_, seq_length_2, _] = input.key.dims();
+
+        // Only the last tokens may be projected when using a cache, so their positions are
+        // offset by the number of tokens already in the cache.
+        let query = cache.query.forward(input.query, |t| {
+            let offset = seq_length_1 - t.dims()[1];
+            self.rotate(self.attention_linear(t, &self.query, self.n_heads), offset)
+        });
+        let key = cache.key.forward(input.key, |t| {
+            let offset = seq_length_2 - t.dims()[1];
+            self.rotate(self.attention_linear(t, &self.key, self.n_kv_heads), offset)
+        });
+        let value = cache.value.forward(input.value, |t| {
+            self.attention_linear(t, &self.value, self.n_kv_heads)
+        });
+
+        let (context, weights) = self.attention(
+            query,
+            key,
+            value,
+            input.mask_pad,
+            input.mask_attn,
+            input.need_weights,
+        );
         let context = context
             .swap_dims(1, 2)
             .reshape([batch_size, seq_length_1, d_model]);
@@ -217,14 +325,118 @@ impl<B: Backend> MultiHeadAttention<B> {
         MhaOutput { weights, context }
     }
 
-    fn attn_scores(&self, query: Tensor<B, 4>, key: Tensor<B, 4>) -> Tensor<B, 4> {
+    /// Computes the attention of each head, returning the context and the attention weights.
+    ///
+    /// The query heads sharing the same key and value head are stacked along the sequence
+    /// dimension, so the keys and values are never repeated across the query heads.
+    fn attention(
+        &self,
+        query: Tensor<B, 4>,
+        key: Tensor<B, 4>,
+        value: Tensor<B, 4>,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+        need_weights: bool,
+    ) -> (Tensor<B, 4>, Tensor<B, 4>) {
+        let [batch_size, n_heads, seq_length_1, d_k] = query.dims();
+        let [_, n_kv_heads, seq_length_2, _] = key.dims();
+        let n_groups = n_heads / n_kv_heads;
+        let device = query.device();
+        let bias = self.alibi_bias(seq_length_1, seq_length_2, &device);
+        let query = query.reshape([batch_size, n_kv_heads, n_groups * seq_length_1, d_k]);
+
+        if need_weights {
+            let attn_scores = self.attn_scores(
+                query,
+                key,
+                bias,
+                [batch_size, n_heads, seq_length_1, seq_length_2],
+            );
+            let weights = self.attn_weights(attn_scores, mask_pad, mask_attn);
+            let context = weights
+                .clone()
+                .reshape([
+                    batch_size,
+                    n_kv_heads,
+                    n_groups * seq_length_1,
+                    seq_length_2,
+                ])
+                .matmul(value)
+                .reshape([batch_size, n_heads, seq_length_1, d_k]);
+
+            return (context, weights);
+        }
+
+        let bias =
+            bias.map(|bias| bias.reshape([1, n_kv_heads, n_groups * seq_length_1, seq_length_2]));
+        let mask = self
+            .attn_mask(
+                mask_pad,
+                mask_attn,
+                [batch_size, seq_length_1, seq_length_2],
+            )
+            .map(|mask| Self::group_mask(mask, n_groups));
+        let options = self.attn_options(&device);
+        let context = module::scaled_dot_product_attention(query, key, value, bias, mask, options)
+            .reshape([batch_size, n_heads, seq_length_1, d_k]);
+        let weights = Tensor::empty_device([batch_size, n_heads, seq_length_1, 0], &device);
+
+        (context, weights)
+    }
+
+    fn attn_options(&self, device: &B::Device) -> AttentionOptions {
+        let options = AttentionOptions::new(1.0 / sqrt(self.d_k as f64), self.min_float);
+
+        if !self.dropout.is_active::<B>() {
+            return options;
+        }
+
+        // The fused operation derives the dropped scores from a seed, which is sampled with the
+        // random generator of the backend like the masks of the dropout module.
+        let seed = Tensor::<B, 1>::random_device([1], Distribution::Default, device)
+            .into_scalar()
+            .elem::<f64>();
+
+        options.with_dropout(self.dropout.prob(), (seed * u32::MAX as f64) as u32)
+    }
+
+    fn attn_scores(
+        &self,
+        query: Tensor<B, 4>,
+        key: Tensor<B, 4>,
+        bias: Option<Tensor<B, 4>>,
+        shape: [usize; 4],
+    ) -> Tensor<B, 4> {
         let attn_scores = query
             .matmul(key.transpose())
-            .div_scalar(sqrtf(self.d_k as f32));
+            .div_scalar(sqrtf(self.d_k as f32))
+            .reshape(shape);
+
+        let attn_scores = match bias {
+            Some(bias) => attn_scores.add(bias),
+            None => attn_scores,
+        };
 
         self.dropout.forward(attn_scores)
     }
 
+    fn alibi_bias(
+        &self,
+        seq_length_1: usize,
+        seq_length_2: usize,
+        device: &B::Device,
+    ) -> Option<Tensor<B, 4>> {
+        match self.positional {
+            PositionalStrategy::Alibi => {
+                let bias =
+                    generate_alibi_bias::<B>(self.n_heads, seq_length_1, seq_length_2, device);
+
+                Some(bias.unsqueeze())
+            }
+            _ => None,
+        }
+    }
+
     fn attn_weights(
         &self,
         mut attn_scores: Tensor<B, 4>,
@@ -252,11 +464,58 @@ impl<B: Backend> MultiHeadAttention<B> {
         activation::softmax(attn_scores, 3)
     }
 
-    fn attention_linear(&self, x: Tensor<B, 3>, linear: &nn::Linear<B>) -> Tensor<B, 4> {
+    /// Combines the padding and attention masks into a single mask broadcastable to the attention
+    /// scores.
+    fn attn_mask(
+        &self,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+        [batch_size, seq_length_1, seq_length_2]: [usize; 3],
+    ) -> Option<Tensor<B, 4, Bool>> {
+        let mask_pad = mask_pad.map(|mask| mask.reshape([batch_size, 1, 1, seq_length_2]));
+        let mask_attn =
+            mask_attn.map(|mask| mask.reshape([batch_size, 1, seq_length_1, seq_length_2]));
+
+        match (mask_pad, mask_attn) {
+            (Some(mask_pad), Some(mask_attn)) => {
+                Some(mask_attn.int().add(mask_pad.int()).greater_elem(0))
+            }
+            (mask_pad, mask_attn) => mask_pad.or(mask_attn),
+        }
+    }
+
+    fn rotate(&self, x: Tensor<B, 4>, offset: usize) -> Tensor<B, 4> {
+        match &self.rotary {
+            Some(rotary) => rotary.forward_with_offset(x, offset),
+            None => x,
+        }
+    }
+
+    /// Repeats the rows of the mask for each query head stacked along the sequence dimension.
+    ///
+    /// A padding mask, which has a single row, is broadcast to every row as is.
+    fn group_mask(mask: Tensor<B, 4, Bool>, n_groups: usize) -> Tensor<B, 4, Bool> {
+        let [batch_size, _, seq_length_1, seq_length_2] = mask.dims();
+
+        if n_groups == 1 || seq_length_1 == 1 {
+            return mask;
+        }
+
+        mask.reshape([batch_size, 1, 1, seq_length_1, seq_length_2])
+            .expand([batch_size, 1, n_groups, seq_length_1, seq_length_2])
+            .reshape([batch_size, 1, n_groups * seq_length_1, seq_length_2])
+    }
+
+    fn attention_linear(
+        &self,
+        x: Tensor<B, 3>,
+        linear: &nn::Linear<B>,
+        n_heads: usize,
+    ) -> Tensor<B, 4> {
         let [batch_size, seq_length, _d_model] = x.dims();
         linear
             .forward(x)
-            .reshape([batch_size, seq_length, self.n_heads, self.d_k])
+            .reshape([batch_size, seq_length, n_heads, self.d_k])
             .swap_dims(1, 2)
     }
 }
@@ -317,7 +576,7 @@ impl<B: Backend, const D: usize> MhaLinearCache<B, D> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{nn::attention::generate_autoregressive_mask, TestBackend};
+    use crate::{nn::attention::generate_autoregressive_mask, TestADBackend, TestBackend};
     use alloc::vec::Vec;
     use burn::tensor::{Distribution, Shape};
     use burn_tensor::Int;
@@ -331,7 +590,7 @@ mod tests {
             Distribution::Default,
         ));
 
-        let output = mha.forward(input);
+        let output = mha.forward(input.need_weights(true));
 
         assert_eq!(
             output.context.shape(),
@@ -355,7 +614,7 @@ mod tests {
             Tensor::random([batch_size, seq_length_2, d_model], Distribution::Default),
         );
 
-        let output = mha.forward(input);
+        let output = mha.forward(input.need_weights(true));
 
         assert_eq!(
             output.context.shape(),
@@ -416,10 +675,206 @@ mod tests {
             );
     }
 
+    #[test]
+    fn test_attention_without_weights_should_have_same_output() {
+        let [batch_size, seq_length, d_model, n_heads] = [3, 6, 12, 2];
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_positional(PositionalStrategy::Alibi)
+            .init::<TestBackend>();
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+        );
+        let mask_pad =
+            Tensor::<TestBackend, 2>::random([batch_size, seq_length], Distribution::Default)
+                .lower_elem(0.3);
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &tensor.device());
+        let input = MhaInput::self_attn(tensor)
+            .mask_pad(mask_pad)
+            .mask_attn(mask_attn);
+
+        let output_1 = mha.forward(input.clone().need_weights(true));
+        let output_2 = mha.forward(input);
+
+        assert_eq!(
+            output_2.weights.dims(),
+            [batch_size, n_heads, seq_length, 0],
+            "Weights should be empty when they are not needed",
+        );
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.context.into_data(), 3);
+    }
+
+    #[test]
+    fn test_dropout_should_be_applied_by_the_fused_attention_during_training() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 5, 8, 2];
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_dropout(0.5)
+            .init::<TestADBackend>();
+        let tensor = Tensor::<TestADBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+        )
+        .require_grad();
+
+        let output_1 = mha.forward(MhaInput::self_attn(tensor.clone()));
+        let output_2 = mha.forward(MhaInput::self_attn(tensor.clone()));
+
+        assert_ne!(output_1.context.to_data(), output_2.context.to_data());
+        let grads = output_1.context.sum().backward();
+        assert!(tensor.grad(&grads).is_some());
+    }
+
     #[test]
     fn test_autoregressive_mask_should_have_same_output_as_autoregressive_decoding() {
-        let [batch_size, seq_length, d_model, n_heads] = [3, 4, 12, 2];
-        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>();
+        let [d_model, n_heads] = [12, 2];
+
+        test_autoregressive(MultiHeadAttentionConfig::new(d_model, n_heads));
+    }
+
+    #[test]
+    fn test_autoregressive_rotary_should_have_same_output_as_autoregressive_decoding() {
+        let [d_model, n_heads] = [12, 2];
+
+        test_autoregressive(
+            MultiHeadAttentionConfig::new(d_model, n_heads).with_positional(
+                PositionalStrategy::Rotary {
+                    max_sequence_length: 8,
+                    theta: 10000.0,
+                },
+            ),
+        );
+    }
+
+    #[test]
+    fn test_autoregressive_alibi_should_have_same_output_as_autoregressive_decoding() {
+        let [d_model, n_heads] = [12, 2];
+
+        test_autoregressive(
+            MultiHeadAttentionConfig::new(d_model, n_heads)
+                .with_positional(PositionalStrategy::Alibi),
+        );
+    }
+
+    #[test]
+    fn test_rotary_attention_is_relative() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 3, 8, 2];
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_positional(PositionalStrategy::Rotary {
+                max_sequence_length: 8,
+                theta: 10000.0,
+            })
+            .init::<TestBackend>();
+        let token =
+            Tensor::<TestBackend, 3>::random([batch_size, 1, d_model], Distribution::Default);
+        let tensor = Tensor::cat(vec![token; seq_length], 1);
+
+        let output = mha.forward(MhaInput::self_attn(tensor).need_weights(true));
+
+        // All the tokens are identical, so the attention scores only depend on the distance
+        // between the query and the key.
+        let weights = output.weights.into_data().value;
+        let weight = |i: usize, j: usize| weights[i * seq_length + j];
+        let ratio_1 = weight(0, 0) / weight(0, 1);
+        let ratio_2 = weight(1, 1) / weight(1, 2);
+        assert!((ratio_1 - ratio_2).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_autoregressive_grouped_query_should_have_same_output_as_autoregressive_decoding() {
+        let [d_model, n_heads] = [16, 4];
+
+        test_autoregressive(
+            MultiHeadAttentionConfig::new(d_model, n_heads).with_n_kv_heads(Some(2)),
+        );
+        test_autoregressive(
+            MultiHeadAttentionConfig::new(d_model, n_heads).with_n_kv_heads(Some(1)),
+        );
+    }
+
+    #[test]
+    fn test_grouped_query_should_match_mha_with_shared_key_value_weights() {
+        let [batch_size, seq_length, d_model, n_heads, n_kv_heads] = [2, 3, 16, 4, 2];
+        let config = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_positional(PositionalStrategy::Alibi);
+        let gqa = config
+            .clone()
+            .with_n_kv_heads(Some(n_kv_heads))
+            .init::<TestBackend>();
+
+        assert_eq!(
+            gqa.key.weight.shape().dims,
+            [d_model, n_kv_heads * d_model / n_heads]
+        );
+
+        let repeat_heads = |record: nn::LinearRecord<TestBackend>| {
+            let d_k = d_model / n_heads;
+            let weight = record
+                .weight
+                .val()
+                .reshape([d_model, n_kv_heads, 1, d_k])
+                .repeat(2, n_heads / n_kv_heads)
+                .reshape([d_model, d_model]);
+            let bias = record
+                .bias
+                .unwrap()
+                .val()
+                .reshape([n_kv_heads, 1, d_k])
+                .repeat(1, n_heads / n_kv_heads)
+                .reshape([d_model]);
+
+            nn::LinearRecord {
+                weight: weight.into(),
+                bias: Some(bias.into()),
+            }
+        };
+        let mut record = gqa.clone().into_record();
+        record.key = repeat_heads(record.key);
+        record.value = repeat_heads(record.value);
+        let mha = config.init_with(record);
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+        );
+        let mask_pad =
+            Tensor::<TestBackend, 2>::random([batch_size, seq_length], Distribution::Default)
+                .lower_elem(0.3);
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &tensor.device());
+        let input = MhaInput::self_attn(tensor)
+            .mask_pad(mask_pad)
+            .mask_attn(mask_attn);
+
+        for need_weights in [false, true] {
+            let input = input.clone().need_weights(need_weights);
+            let output_1 = gqa.forward(input.clone());
+            let output_2 = mha.forward(input);
+
+            output_1
+                .context
+                .into_data()
+                .assert_approx_eq(&output_2.context.into_data(), 3);
+            output_1
+                .weights
+                .into_data()
+                .assert_approx_eq(&output_2.weights.into_data(), 3);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_n_heads_not_divisible_by_n_kv_heads() {
+        MultiHeadAttentionConfig::new(12, 4)
+            .with_n_kv_heads(Some(3))
+            .init::<TestBackend>();
+    }
+
+    fn test_autoregressive(config: MultiHeadAttentionConfig) {
+        let [batch_size, seq_length, d_model] = [3, 4, config.d_model];
+        let mha = config.init::<TestBackend>();
 
         let tensor = Tensor::<TestBackend, 3>::random(
             [batch_size, seq_length, d_model],
@@ -0,0 +1,168 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::nn::Initializer;
+use crate::nn::PaddingConfig3d;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::module::conv3d;
+use burn_tensor::ops::ConvOptions;
+use libm::sqrt;
+
+use super::checks;
+
+/// Configuration to create an [3D convolution](Conv3d) layer.
+#[derive(Config, Debug)]
+pub struct Conv3dConfig {
+    /// The number of channels.
+    pub channels: [usize; 2],
+    /// The size of the kernel.
+    pub kernel_size: [usize; 3],
+    /// The stride of the convolution.
+    #[config(default = "[1, 1, 1]")]
+    pub stride: [usize; 3],
+    /// Spacing between kernel elements.
+    #[config(default = "[1, 1, 1]")]
+    pub dilation: [usize; 3],
+    /// Controls the connections between input and output channels.
+    #[config(default = "1")]
+    pub groups: usize,
+    /// The padding configuration.
+    #[config(default = "PaddingConfig3d::Valid")]
+    pub padding: PaddingConfig3d,
+    /// If bias should be added to the output.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::KaimingUniform{gain:1.0/sqrt(3.0),fan_out_only:false}")]
+    pub initializer: Initializer,
+}
+
+/// Applies a 3D convolution over input tensors.
+///
+/// # Params
+///
+/// - weight: Tensor of shape `[channels_out, channels_in / groups, kernel_size_1, kernel_size_2, kernel_size_3]`
+///
+/// - bias:   Tensor of shape `[channels_out]`
+#[derive(Module, Debug)]
+pub struct Conv3d<B: Backend> {
+    weight: Param<Tensor<B, 5>>,
+    bias: Option<Param<Tensor<B, 1>>>,
+    stride: [usize; 3],
+    kernel_size: [usize; 3],
+    dilation: [usize; 3],
+    groups: usize,
+    padding: PaddingConfig3d,
+}
+
+impl Conv3dConfig {
+    /// Initialize a new [conv3d](Conv3d) module.
+    pub fn init<B: Backend>(&self) -> Conv3d<B> {
+        checks::checks_channels_div_groups(self.channels[0], self.channels[1], self.groups);
+
+        let shape = [
+            self.channels[1],
+            self.channels[0] / self.groups,
+            self.kernel_size[0],
+            self.kernel_size[1],
+            self.kernel_size[2],
+        ];
+
+        let fan_in = self.channels[0] / self.groups * self.kernel_size.iter().product::<usize>();
+        let weight = self.initializer.init_with(shape, Some(fan_in), None);
+        let mut bias = None;
+
+        if self.bias {
+            bias = Some(
+                self.initializer
+                    .init_with([self.channels[1]], Some(fan_in), None),
+            );
+        }
+
+        Conv3d {
+            weight: Param::from(weight),
+            bias: bias.map(Param::from),
+            stride: self.stride,
+            kernel_size: self.kernel_size,
+            dilation: self.dilation,
+            padding: self.padding.clone(),
+            groups: self.groups,
+        }
+    }
+
+    /// Initialize a new [conv3d](Conv3d) module with a [record](Conv3dRecord).
+    pub fn init_with<B: Backend>(&self, record: Conv3dRecord<B>) -> Conv3d<B> {
+        Conv3d {
+            weight: record.weight,
+            bias: record.bias,
+            stride: self.stride,
+            dilation: self.dilation,
+            kernel_size: self.kernel_size,
+            padding: self.padding.clone(),
+            groups: self.groups,
+        }
+    }
+}
+
+impl<B: Backend> Conv3d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels_in, depth_in, height_in, width_in],
+    /// - output: [batch_size, channels_out, depth_out, height_out, width_out],
+    pub fn forward(&self, input: Tensor<B, 5>) -> Tensor<B, 5> {
+        let [_batch_size, _channels_in, depth_in, height_in, width_in] = input.dims();
+        let padding = self.padding.calculate_padding_3d(
+            depth_in,
+            height_in,
+            width_in,
+            &self.kernel_size,
+            &self.stride,
+        );
+        conv3d(
+            input,
+            self.weight.val(),
+            self.bias.as_ref().map(|bias| bias.val()),
+            ConvOptions::new(self.stride, padding, self.dilation, self.groups),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn initializer_default() {
+        TestBackend::seed(0);
+
+        let config = Conv3dConfig::new([5, 1], [5, 5, 5]);
+        let k = (config.channels[0]
+            * config.kernel_size[0]
+            * config.kernel_size[1]
+            * config.kernel_size[2]) as f64;
+        let k = sqrt(config.groups as f64 / k) as f32;
+        let conv = config.init::<TestBackend>();
+
+        conv.weight.to_data().assert_within_range(-k..k);
+    }
+
+    #[test]
+    fn initializer_zeros() {
+        TestBackend::seed(0);
+
+        let config = Conv3dConfig::new([5, 2], [5, 5, 5]).with_initializer(Initializer::Zeros);
+        let conv = config.init::<TestBackend>();
+
+        assert_eq!(config.initializer, Initializer::Zeros);
+        conv.weight
+            .to_data()
+            .assert_approx_eq(&Data::zeros(conv.weight.shape()), 3);
+    }
+}
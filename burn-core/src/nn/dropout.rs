@@ -38,7 +38,7 @@ impl Dropout {
     /// - input: `[..., any]`
     /// - output: `[..., any]`
     pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
-        if !B::ad_enabled() || self.prob == 0.0 {
+        if !self.is_active::<B>() {
             return input;
         }
 
@@ -48,6 +48,17 @@ impl Dropout {
 
         x * (1.0 / prob_keep)
     }
+
+    /// The probability of zeroing each element.
+    pub(crate) fn prob(&self) -> f64 {
+        self.prob
+    }
+
+    /// Whether the dropout modifies its input, which is only the case during training with a
+    /// non-zero probability.
+    pub(crate) fn is_active<B: Backend>(&self) -> bool {
+        B::ad_enabled() && self.prob != 0.0
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,41 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [ELU](ELU) layer.
+#[derive(Config, Debug)]
+pub struct ELUConfig {
+    /// The saturation value for negative inputs. Default: 1.0
+    #[config(default = 1.0)]
+    pub alpha: f64,
+}
+
+/// Applies the exponential linear unit function element-wise:
+///
+/// `y = x if x > 0, alpha * (exp(x) - 1) otherwise`
+#[derive(Module, Clone, Debug)]
+pub struct ELU {
+    alpha: f64,
+}
+
+impl ELUConfig {
+    /// Initialize a new [elu](ELU) module.
+    pub fn init(&self) -> ELU {
+        ELU { alpha: self.alpha }
+    }
+}
+
+impl ELU {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any]`
+    /// - output: `[..., any]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        crate::tensor::activation::elu(input, self.alpha)
+    }
+}
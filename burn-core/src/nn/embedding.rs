@@ -18,6 +18,14 @@ pub struct EmbeddingConfig {
     /// The type of function used to initialize neural network parameters
     #[config(default = "Initializer::Normal{mean:0.0, std:1.0}")]
     pub initializer: Initializer,
+    /// The index of the padding vector, which is always zero and doesn't receive any gradient.
+    pub padding_idx: Option<usize>,
+    /// If set, the looked up vectors with a norm larger than this value are rescaled to this
+    /// norm.
+    pub max_norm: Option<f64>,
+    /// The p of the p-norm used with `max_norm`. Default: 2.0
+    #[config(default = 2.0)]
+    pub norm_type: f64,
 }
 
 /// Lookup table to store a fix number of vectors.
@@ -26,31 +34,117 @@ pub struct EmbeddingConfig {
 ///
 /// - weight: Matrix of shape `[n_embedding, d_model]` initialized from a normal distribution:
 ///     `N(0, 1)`
+///
+/// Unlike PyTorch, the stored vectors aren't renormalized in place when `max_norm` is set: only
+/// the looked up vectors are rescaled.
 #[derive(Module, Debug)]
 pub struct Embedding<B: Backend> {
     weight: Param<Tensor<B, 2>>,
+    padding_idx: Option<usize>,
+    max_norm: Option<f64>,
+    norm_type: f64,
 }
 
 impl EmbeddingConfig {
     /// Initialize a new [embedding](Embedding) module.
     pub fn init<B: Backend>(&self) -> Embedding<B> {
-        let weight = self
-            .initializer
-            .init([self.n_embedding, self.d_model])
-            .require_grad();
+        let weight = init_weight(
+            &self.initializer,
+            self.n_embedding,
+            self.d_model,
+            self.padding_idx,
+        );
 
         Embedding {
             weight: Param::from(weight),
+            padding_idx: self.padding_idx,
+            max_norm: self.max_norm,
+            norm_type: self.norm_type,
         }
     }
     /// Initialize a new [embedding](Embedding) module with a [record](EmbeddingRecord).
     pub fn init_with<B: Backend>(&self, record: EmbeddingRecord<B>) -> Embedding<B> {
         Embedding {
             weight: record.weight,
+            padding_idx: self.padding_idx,
+            max_norm: self.max_norm,
+            norm_type: self.norm_type,
         }
     }
 }
 
+/// Initializes the embedding vectors, with a zero padding vector.
+pub(crate) fn init_weight<B: Backend>(
+    initializer: &Initializer,
+    n_embedding: usize,
+    d_model: usize,
+    padding_idx: Option<usize>,
+) -> Tensor<B, 2> {
+    let weight = initializer.init([n_embedding, d_model]);
+    let weight = match padding_idx {
+        Some(index) => {
+            assert!(
+                index < n_embedding,
+                "The padding index {index} should be lower than the number of embeddings {n_embedding}."
+            );
+            weight.slice_assign([index..index + 1, 0..d_model], Tensor::zeros([1, d_model]))
+        }
+        None => weight,
+    };
+
+    weight.require_grad()
+}
+
+/// Zeroes the vectors looked up at the padding index, so that the padding vector doesn't receive
+/// any gradient even if its stored value was changed.
+///
+/// The embeddings have the shape of the indices with an additional `d_model` dimension.
+pub(crate) fn mask_padding<B: Backend, const D: usize, const D2: usize>(
+    embeddings: Tensor<B, D2>,
+    indices: Tensor<B, D, Int>,
+    padding_idx: Option<usize>,
+) -> Tensor<B, D2> {
+    let index = match padding_idx {
+        Some(index) => index,
+        None => return embeddings,
+    };
+
+    let dims = embeddings.dims();
+    let mut mask_dims = dims;
+    mask_dims[D2 - 1] = 1;
+    let padding = indices
+        .equal_elem(index as i64)
+        .reshape(mask_dims)
+        .expand(dims);
+
+    embeddings.mask_fill(padding, 0.0)
+}
+
+/// Rescales the vectors, along the last dimension, with a p-norm larger than `max_norm`.
+///
+/// The scale is detached, so the gradient flows through the vectors as if the rescaled vectors
+/// were the stored ones.
+pub(crate) fn renorm<B: Backend, const D: usize>(
+    embeddings: Tensor<B, D>,
+    max_norm: f64,
+    norm_type: f64,
+) -> Tensor<B, D> {
+    let norm = embeddings
+        .clone()
+        .abs()
+        .powf(norm_type as f32)
+        .sum_dim(D - 1)
+        .powf(1.0 / norm_type as f32);
+    let scale = norm
+        .add_scalar(1e-7)
+        .powf(-1.0)
+        .mul_scalar(max_norm)
+        .clamp_max(1.0)
+        .detach();
+
+    embeddings.mul(scale)
+}
+
 impl<B: Backend> Embedding<B> {
     /// Applies the forward pass on the input tensor.
     ///
@@ -59,14 +153,20 @@ impl<B: Backend> Embedding<B> {
     /// - input: [batch_size, seq_length]
     /// - output: [batch_size, d_model]
     pub fn forward(&self, input: Tensor<B, 2, Int>) -> Tensor<B, 3> {
-        burn_tensor::module::embedding(self.weight.val(), input)
+        let output = burn_tensor::module::embedding(self.weight.val(), input.clone());
+        let output = mask_padding(output, input, self.padding_idx);
+
+        match self.max_norm {
+            Some(max_norm) => renorm(output, max_norm, self.norm_type),
+            None => output,
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::TestBackend;
+    use crate::{TestADBackend, TestBackend};
     use burn_tensor::Data;
 
     #[test]
@@ -104,4 +204,44 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::zeros(embed.weight.shape()), 3);
     }
+
+    #[test]
+    fn padding_idx_should_be_zero_without_gradient() {
+        TestBackend::seed(0);
+
+        let config = EmbeddingConfig::new(4, 3).with_padding_idx(Some(1));
+        let embed = config.init::<TestADBackend>();
+        let input = Tensor::from_ints([[0, 1], [1, 3]]);
+
+        let output = embed.forward(input);
+        let grads = output.clone().sum().backward();
+        let weight_grad = embed.weight.grad(&grads).unwrap();
+
+        output
+            .slice([0..1, 1..2])
+            .into_data()
+            .assert_approx_eq(&Data::zeros([1, 1, 3]), 3);
+        weight_grad.into_data().assert_approx_eq(
+            &Data::from([
+                [1.0, 1.0, 1.0],
+                [0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0],
+                [1.0, 1.0, 1.0],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn max_norm_should_rescale_large_vectors() {
+        let config = EmbeddingConfig::new(2, 2).with_max_norm(Some(1.0));
+        let mut embed = config.init::<TestBackend>();
+        embed.weight = Param::from(Tensor::from_floats([[3.0, 4.0], [0.3, 0.4]]));
+
+        let output = embed.forward(Tensor::from_ints([[0, 1]]));
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[0.6, 0.8], [0.3, 0.4]]]), 3);
+    }
 }
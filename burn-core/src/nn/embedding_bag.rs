@@ -0,0 +1,227 @@
+use crate as burn;
+
+use super::embedding::{init_weight, mask_padding, renorm};
+use super::Initializer;
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::module::segment_reduce;
+use burn_tensor::ops::SegmentReduction;
+use burn_tensor::Int;
+
+/// Reduction applied to the vectors of each bag of an [embedding bag](EmbeddingBag).
+#[derive(Module, Config, Debug, PartialEq)]
+pub enum EmbeddingBagMode {
+    /// Sum of the vectors.
+    Sum,
+    /// Mean of the vectors.
+    Mean,
+    /// Maximum of the vectors, for each feature.
+    Max,
+}
+
+impl From<EmbeddingBagMode> for SegmentReduction {
+    fn from(mode: EmbeddingBagMode) -> Self {
+        match mode {
+            EmbeddingBagMode::Sum => SegmentReduction::Sum,
+            EmbeddingBagMode::Mean => SegmentReduction::Mean,
+            EmbeddingBagMode::Max => SegmentReduction::Max,
+        }
+    }
+}
+
+/// Configuration to create an [EmbeddingBag](EmbeddingBag) layer.
+#[derive(Config)]
+pub struct EmbeddingBagConfig {
+    /// The number of embedding vectors.
+    n_embedding: usize,
+    /// The size of each vector.
+    d_model: usize,
+    /// The reduction applied to the vectors of each bag. Default: Mean
+    #[config(default = "EmbeddingBagMode::Mean")]
+    pub mode: EmbeddingBagMode,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::Normal{mean:0.0, std:1.0}")]
+    pub initializer: Initializer,
+    /// The index of the padding vector, which is always zero, doesn't receive any gradient and is
+    /// excluded from the bags.
+    pub padding_idx: Option<usize>,
+    /// If set, the looked up vectors with a norm larger than this value are rescaled to this
+    /// norm.
+    pub max_norm: Option<f64>,
+    /// The p of the p-norm used with `max_norm`. Default: 2.0
+    #[config(default = 2.0)]
+    pub norm_type: f64,
+}
+
+/// Computes the sums, means or maximums of bags of embedding vectors, without materializing the
+/// vectors of each bag as a padded tensor.
+///
+/// The bags are given as a flat list of indices, where each bag starts at its offset and ends at
+/// the next one. Empty bags, including the ones only made of padding, give zero vectors.
+///
+/// # Params
+///
+/// - weight: Matrix of shape `[n_embedding, d_model]` initialized from a normal distribution
+///   `N(0, 1)`
+#[derive(Module, Debug)]
+pub struct EmbeddingBag<B: Backend> {
+    weight: Param<Tensor<B, 2>>,
+    mode: EmbeddingBagMode,
+    padding_idx: Option<usize>,
+    max_norm: Option<f64>,
+    norm_type: f64,
+}
+
+impl EmbeddingBagConfig {
+    /// Initialize a new [embedding bag](EmbeddingBag) module.
+    pub fn init<B: Backend>(&self) -> EmbeddingBag<B> {
+        let weight = init_weight(
+            &self.initializer,
+            self.n_embedding,
+            self.d_model,
+            self.padding_idx,
+        );
+
+        EmbeddingBag {
+            weight: Param::from(weight),
+            mode: self.mode.clone(),
+            padding_idx: self.padding_idx,
+            max_norm: self.max_norm,
+            norm_type: self.norm_type,
+        }
+    }
+    /// Initialize a new [embedding bag](EmbeddingBag) module with a [record](EmbeddingBagRecord).
+    pub fn init_with<B: Backend>(&self, record: EmbeddingBagRecord<B>) -> EmbeddingBag<B> {
+        EmbeddingBag {
+            weight: record.weight,
+            mode: self.mode.clone(),
+            padding_idx: self.padding_idx,
+            max_norm: self.max_norm,
+            norm_type: self.norm_type,
+        }
+    }
+}
+
+impl<B: Backend> EmbeddingBag<B> {
+    /// Applies the forward pass on the input tensors.
+    ///
+    /// The offsets should be sorted, starting with zero.
+    ///
+    /// # Shapes
+    ///
+    /// - indices: [num_indices]
+    /// - offsets: [num_bags]
+    /// - output: [num_bags, d_model]
+    pub fn forward(&self, indices: Tensor<B, 1, Int>, offsets: Tensor<B, 1, Int>) -> Tensor<B, 2> {
+        let embeddings = self.weight.val().select(0, indices.clone());
+        let embeddings = mask_padding(embeddings, indices.clone(), self.padding_idx);
+        let embeddings = match self.max_norm {
+            Some(max_norm) => renorm(embeddings, max_norm, self.norm_type),
+            None => embeddings,
+        };
+
+        let index = match self.padding_idx {
+            Some(index) => index,
+            None => return segment_reduce(embeddings, offsets, self.mode.clone().into()),
+        };
+
+        // The padding vectors are zero, so they only need to be excluded from the counts of the
+        // mean and from the maximums.
+        let [num_indices, d_model] = embeddings.dims();
+        let padding = indices.equal_elem(index as i64).reshape([num_indices, 1]);
+        let counts = segment_reduce(
+            padding.clone().bool_not().float(),
+            offsets.clone(),
+            SegmentReduction::Sum,
+        );
+
+        match self.mode {
+            EmbeddingBagMode::Sum => segment_reduce(embeddings, offsets, SegmentReduction::Sum),
+            EmbeddingBagMode::Mean => segment_reduce(embeddings, offsets, SegmentReduction::Sum)
+                .div(counts.clamp_min(1.0)),
+            EmbeddingBagMode::Max => {
+                let embeddings =
+                    embeddings.mask_fill(padding.expand([num_indices, d_model]), f32::MIN);
+                let [num_bags, _] = counts.dims();
+
+                segment_reduce(embeddings, offsets, SegmentReduction::Max)
+                    .mask_fill(counts.equal_elem(0.0).expand([num_bags, d_model]), 0.0)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestADBackend, TestBackend};
+    use burn_tensor::Data;
+
+    #[test]
+    fn forward_should_reduce_bags() {
+        let indices = Tensor::from_ints([0, 1, 2, 1, 3]);
+        let offsets = Tensor::from_ints([0, 2, 2]);
+
+        for (mode, expected) in [
+            (EmbeddingBagMode::Sum, [[1.0, 5.0], [0.0, 0.0], [7.0, 2.0]]),
+            (
+                EmbeddingBagMode::Mean,
+                [[0.5, 2.5], [0.0, 0.0], [2.3333, 0.6667]],
+            ),
+            (EmbeddingBagMode::Max, [[2.0, 3.0], [0.0, 0.0], [4.0, 3.0]]),
+        ] {
+            let embed = embedding_bag::<TestBackend>(EmbeddingBagConfig::new(4, 2).with_mode(mode));
+
+            let output = embed.forward(indices.clone(), offsets.clone());
+
+            output
+                .into_data()
+                .assert_approx_eq(&Data::from(expected), 3);
+        }
+    }
+
+    #[test]
+    fn padding_idx_should_be_excluded_from_bags() {
+        let indices = Tensor::from_ints([0, 1, 1, 3, 1]);
+        let offsets = Tensor::from_ints([0, 2, 4]);
+
+        for (mode, expected) in [
+            (
+                EmbeddingBagMode::Mean,
+                [[-1.0, 2.0], [4.0, -1.0], [0.0, 0.0]],
+            ),
+            (
+                EmbeddingBagMode::Max,
+                [[-1.0, 2.0], [4.0, -1.0], [0.0, 0.0]],
+            ),
+        ] {
+            let config = EmbeddingBagConfig::new(4, 2)
+                .with_mode(mode)
+                .with_padding_idx(Some(1));
+            let embed = embedding_bag::<TestADBackend>(config);
+
+            let output = embed.forward(indices.clone(), offsets.clone());
+            let grads = output.clone().sum().backward();
+            let weight_grad = embed.weight.grad(&grads).unwrap();
+
+            output
+                .into_data()
+                .assert_approx_eq(&Data::from(expected), 3);
+            weight_grad.into_data().assert_approx_eq(
+                &Data::from([[1.0, 1.0], [0.0, 0.0], [0.0, 0.0], [1.0, 1.0]]),
+                3,
+            );
+        }
+    }
+
+    fn embedding_bag<B: Backend>(config: EmbeddingBagConfig) -> EmbeddingBag<B> {
+        let mut embed = config.init::<B>();
+        embed.weight = Param::from(
+            Tensor::from_floats([[-1.0, 2.0], [2.0, 3.0], [1.0, 0.0], [4.0, -1.0]]).require_grad(),
+        );
+        embed
+    }
+}
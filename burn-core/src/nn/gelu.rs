@@ -24,3 +24,26 @@ impl GELU {
         crate::tensor::activation::gelu(input)
     }
 }
+
+/// Applies the tanh approximation of the Gaussian Error Linear Units function element-wise.
+///
+/// `y = 0.5 * x * (1 + tanh(sqrt(2 / π) * (x + 0.044715 * x^3)))`
+#[derive(Module, Clone, Debug, Default)]
+pub struct GELUTanh {}
+
+impl GELUTanh {
+    /// Create the module.
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any]`
+    /// - output: `[..., any]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        crate::tensor::activation::gelu_tanh(input)
+    }
+}
@@ -0,0 +1,50 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [HardSigmoid](HardSigmoid) layer.
+#[derive(Config, Debug)]
+pub struct HardSigmoidConfig {
+    /// The slope of the linear region. Default: 0.2
+    #[config(default = 0.2)]
+    pub alpha: f64,
+    /// The offset of the linear region. Default: 0.5
+    #[config(default = 0.5)]
+    pub beta: f64,
+}
+
+/// Applies the hard sigmoid function element-wise:
+///
+/// `y = max(0, min(1, alpha * x + beta))`
+///
+/// The default values match the ONNX operator, use `alpha = 1 / 6` to match PyTorch.
+#[derive(Module, Clone, Debug)]
+pub struct HardSigmoid {
+    alpha: f64,
+    beta: f64,
+}
+
+impl HardSigmoidConfig {
+    /// Initialize a new [hard sigmoid](HardSigmoid) module.
+    pub fn init(&self) -> HardSigmoid {
+        HardSigmoid {
+            alpha: self.alpha,
+            beta: self.beta,
+        }
+    }
+}
+
+impl HardSigmoid {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any]`
+    /// - output: `[..., any]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        crate::tensor::activation::hard_sigmoid(input, self.alpha, self.beta)
+    }
+}
@@ -0,0 +1,132 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::{backend::Backend, Int, Tensor};
+use core::marker::PhantomData;
+
+/// Configuration to create a [cosine embedding loss](CosineEmbeddingLoss).
+#[derive(Config, Debug)]
+pub struct CosineEmbeddingLossConfig {
+    /// The cosine similarity under which dissimilar pairs don't contribute to the loss, between
+    /// -1 and 1.
+    #[config(default = 0.0)]
+    pub margin: f32,
+}
+
+impl CosineEmbeddingLossConfig {
+    /// Initialize [cosine embedding loss](CosineEmbeddingLoss).
+    pub fn init<B: Backend>(&self) -> CosineEmbeddingLoss<B> {
+        assert!(
+            (-1.0..=1.).contains(&self.margin),
+            "Margin of cosine embedding loss should be in interval [-1, 1]. Got {}",
+            self.margin
+        );
+
+        CosineEmbeddingLoss {
+            margin: self.margin,
+            backend: PhantomData,
+        }
+    }
+}
+
+/// Calculate the cosine embedding loss from pairs of embeddings and targets telling if the pairs
+/// are similar (1) or dissimilar (-1).
+///
+/// - `1 - cos(x_1, x_2)` for similar pairs
+/// - `max(0, cos(x_1, x_2) - margin)` for dissimilar pairs
+#[derive(Module, Debug)]
+pub struct CosineEmbeddingLoss<B: Backend> {
+    margin: f32,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for CosineEmbeddingLoss<B> {
+    fn default() -> Self {
+        CosineEmbeddingLossConfig::new().init()
+    }
+}
+
+impl<B: Backend> CosineEmbeddingLoss<B> {
+    /// Compute the criterion on the input tensors.
+    ///
+    /// # Shapes
+    ///
+    /// - inputs_1: [batch_size, d_model]
+    /// - inputs_2: [batch_size, d_model]
+    /// - targets: [batch_size]
+    pub fn forward(
+        &self,
+        inputs_1: Tensor<B, 2>,
+        inputs_2: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        reduction.apply(self.forward_no_reduction(inputs_1, inputs_2, targets))
+    }
+
+    /// Compute the criterion on the input tensors without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - inputs_1: [batch_size, d_model]
+    /// - inputs_2: [batch_size, d_model]
+    /// - targets: [batch_size]
+    /// - output: [batch_size]
+    pub fn forward_no_reduction(
+        &self,
+        inputs_1: Tensor<B, 2>,
+        inputs_2: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, _] = inputs_1.dims();
+        let [targets_size] = targets.dims();
+        assert!(
+            batch_size == targets_size,
+            "Shape of targets ({}) should correspond to outer shape of inputs ({}).",
+            targets_size,
+            batch_size
+        );
+
+        let squared_norm = |tensor: Tensor<B, 2>| tensor.powf(2.0).sum_dim(1).add_scalar(1e-12);
+        let dot = inputs_1.clone().mul(inputs_2.clone()).sum_dim(1);
+        let cosine = dot
+            .div(squared_norm(inputs_1).mul(squared_norm(inputs_2)).sqrt())
+            .reshape([batch_size]);
+
+        let similar = cosine.clone().neg().add_scalar(1.0);
+        let dissimilar = cosine.sub_scalar(self.margin).clamp_min(0.0);
+
+        dissimilar.mask_where(targets.equal_elem(1), similar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_cosine_embedding_loss() {
+        let inputs_1 =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 0.0], [1.0, 1.0], [0.0, 2.0]]));
+        let inputs_2 =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 1.0], [-1.0, -1.0], [1.0, 1.0]]));
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1, -1, -1]));
+
+        let cosine_embedding = CosineEmbeddingLossConfig::new().with_margin(0.5).init();
+        let loss_no_reduction = cosine_embedding.forward_no_reduction(
+            inputs_1.clone(),
+            inputs_2.clone(),
+            targets.clone(),
+        );
+        let loss = cosine_embedding.forward(inputs_1, inputs_2, targets, Reduction::Auto);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([0.292893, 0.0, 0.207107]), 3);
+        loss.into_data()
+            .assert_approx_eq(&Data::from([0.166667]), 3);
+    }
+}
@@ -1,5 +1,6 @@
 use crate as burn;
 
+use crate::nn::loss::reduction::Reduction;
 use crate::{config::Config, module::Module};
 use alloc::vec;
 use alloc::vec::Vec;
@@ -93,71 +94,198 @@ impl<B: Backend> CrossEntropyLoss<B> {
 
     /// Compute the criterion on the input tensor.
     ///
+    /// The classes are on the second dimension of the logits, like with PyTorch, so the logits
+    /// of sequences or images have to be given as `[batch_size, num_targets, d_1, ..., d_k]`.
+    ///
     /// # Shapes
     ///
-    /// - logits: `[batch_size, num_targets]`
-    /// - targets: `[batch_size]`
-    pub fn forward(&self, logits: Tensor<B, 2>, targets: Tensor<B, 1, Int>) -> Tensor<B, 1> {
-        Self::assertions(logits.clone(), targets.clone());
-        match self.smoothing {
-            Some(alpha) => self.forward_smoothed(logits, targets, alpha),
-            _ => self.forward_default(logits, targets),
+    /// - logits: `[batch_size, num_targets, d_1, ..., d_k]`
+    /// - targets: `[batch_size, d_1, ..., d_k]`
+    pub fn forward<const D: usize, const D2: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D2, Int>,
+    ) -> Tensor<B, 1> {
+        self.forward_with_reduction(logits, targets, Reduction::Mean)
+    }
+
+    /// Compute the criterion on the input tensor with the given reduction.
+    ///
+    /// With weights, the mean is the sum of the losses divided by the sum of the weights of the
+    /// targets.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_targets, d_1, ..., d_k]`
+    /// - targets: `[batch_size, d_1, ..., d_k]`
+    pub fn forward_with_reduction<const D: usize, const D2: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D2, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let (logits, targets) = Self::flatten(logits, targets);
+        let losses = self.losses(logits, targets.clone());
+
+        match (reduction, &self.weights) {
+            (Reduction::Mean | Reduction::Auto, Some(weights)) => {
+                losses.sum() / weights.clone().gather(0, targets).sum()
+            }
+            (reduction, _) => reduction.apply(losses),
         }
     }
 
-    fn forward_smoothed(
+    /// Compute the criterion on the input tensor without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_targets, d_1, ..., d_k]`
+    /// - targets: `[batch_size, d_1, ..., d_k]`
+    /// - output: `[batch_size, d_1, ..., d_k]`
+    pub fn forward_no_reduction<const D: usize, const D2: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D2, Int>,
+    ) -> Tensor<B, D2> {
+        let shape = targets.shape();
+        let (logits, targets) = Self::flatten(logits, targets);
+
+        self.losses(logits, targets).reshape(shape)
+    }
+
+    /// Compute the criterion on the input tensor with target distributions instead of classes,
+    /// as used by mixup, cutmix or distillation.
+    ///
+    /// The label smoothing and the weights are applied to the target distributions, while the
+    /// pad tokens are ignored.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_targets]`
+    /// - targets: `[batch_size, num_targets]`
+    pub fn forward_probs(&self, logits: Tensor<B, 2>, targets: Tensor<B, 2>) -> Tensor<B, 1> {
+        self.forward_probs_with_reduction(logits, targets, Reduction::Mean)
+    }
+
+    /// Compute the criterion on the input tensor with target distributions and the given
+    /// reduction.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_targets]`
+    /// - targets: `[batch_size, num_targets]`
+    pub fn forward_probs_with_reduction(
+        &self,
+        logits: Tensor<B, 2>,
+        targets: Tensor<B, 2>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        reduction.apply(self.forward_probs_no_reduction(logits, targets))
+    }
+
+    /// Compute the criterion on the input tensor with target distributions, without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_targets]`
+    /// - targets: `[batch_size, num_targets]`
+    /// - output: `[batch_size]`
+    pub fn forward_probs_no_reduction(
         &self,
         logits: Tensor<B, 2>,
-        targets: Tensor<B, 1, Int>,
-        alpha: f32,
+        targets: Tensor<B, 2>,
     ) -> Tensor<B, 1> {
-        let mask = self.padding_mask(&targets);
-        let tensor = if self.logits {
-            log_softmax(logits, 1)
-        } else {
-            logits.log()
+        assert!(
+            logits.dims() == targets.dims(),
+            "Shape of targets ({:?}) should correspond to the shape of logits ({:?}).",
+            targets.dims(),
+            logits.dims()
+        );
+        let [_, nr_classes] = targets.dims();
+        let targets = match self.smoothing {
+            Some(alpha) => targets * (1. - alpha) + alpha / nr_classes as f32,
+            None => targets,
         };
-        let [batch_size, nr_classes] = tensor.dims();
-        let tensor = tensor
-            * Self::compute_smoothed_targets([batch_size, nr_classes], targets.clone(), alpha);
 
-        match &self.weights {
-            Some(weights) => {
-                let tensor = tensor
-                    * weights
-                        .clone()
-                        .reshape([1, nr_classes])
-                        .repeat(0, batch_size);
-                let weights = weights.clone().gather(0, targets);
-                let tensor = Self::apply_mask_2d(tensor, mask);
-                tensor.sum().neg() / weights.sum()
-            }
-            None => {
-                let tensor = Self::apply_mask_2d(tensor, mask);
-                tensor.sum_dim(1).mean().neg()
-            }
-        }
+        self.weighted_sum(self.log_probs(logits) * targets).neg()
     }
 
-    fn forward_default(&self, logits: Tensor<B, 2>, targets: Tensor<B, 1, Int>) -> Tensor<B, 1> {
+    /// Computes the loss of each sample.
+    fn losses(&self, logits: Tensor<B, 2>, targets: Tensor<B, 1, Int>) -> Tensor<B, 1> {
         let [batch_size] = targets.dims();
 
         let mask = self.padding_mask(&targets);
-        let tensor = log_softmax(logits, 1);
-        let tensor = tensor.gather(1, targets.clone().reshape([batch_size, 1]));
-
-        match &self.weights {
-            Some(weights) => {
-                let weights = weights.clone().gather(0, targets);
-                let tensor = tensor.reshape([batch_size]) * weights.clone();
-                let tensor = Self::apply_mask_1d(tensor, mask);
-                tensor.sum().neg() / weights.sum()
+        let tensor = self.log_probs(logits);
+        let tensor = match self.smoothing {
+            Some(alpha) => {
+                let smoothed_targets =
+                    Self::compute_smoothed_targets(tensor.dims(), targets, alpha);
+                self.weighted_sum(tensor * smoothed_targets)
             }
             None => {
-                let tensor = Self::apply_mask_1d(tensor.reshape([batch_size]), mask);
-                tensor.mean().neg()
+                let tensor = tensor
+                    .gather(1, targets.clone().reshape([batch_size, 1]))
+                    .reshape([batch_size]);
+                match &self.weights {
+                    Some(weights) => tensor * weights.clone().gather(0, targets),
+                    None => tensor,
+                }
             }
+        };
+
+        Self::apply_mask_1d(tensor.neg(), mask)
+    }
+
+    fn log_probs(&self, logits: Tensor<B, 2>) -> Tensor<B, 2> {
+        if self.logits {
+            log_softmax(logits, 1)
+        } else {
+            logits.log()
+        }
+    }
+
+    /// Sums the classes, weighted by the class weights.
+    fn weighted_sum(&self, tensor: Tensor<B, 2>) -> Tensor<B, 1> {
+        let [batch_size, nr_classes] = tensor.dims();
+        let tensor = match &self.weights {
+            Some(weights) => tensor * weights.clone().reshape([1, nr_classes]),
+            None => tensor,
+        };
+
+        tensor.sum_dim(1).reshape([batch_size])
+    }
+
+    /// Moves the classes to the last dimension and flattens the other dimensions.
+    fn flatten<const D: usize, const D2: usize>(
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D2, Int>,
+    ) -> (Tensor<B, 2>, Tensor<B, 1, Int>) {
+        assert!(
+            D >= 2 && D2 + 1 == D,
+            "Targets should have one dimension less than logits, got {} and {}.",
+            D2,
+            D
+        );
+        let logits_dims = logits.dims();
+        let targets_dims = targets.dims();
+        assert!(
+            logits_dims[0] == targets_dims[0] && logits_dims[2..] == targets_dims[1..],
+            "Shape of targets ({:?}) should correspond to the shape of logits ({:?}) without the class dimension.",
+            targets_dims,
+            logits_dims
+        );
+
+        let nr_classes = logits_dims[1];
+        let num_elements = targets_dims.iter().product();
+        let mut axes = [0; D];
+        for (i, axis) in axes.iter_mut().enumerate().skip(1) {
+            *axis = if i == D - 1 { 1 } else { i + 1 };
         }
+
+        (
+            logits.permute(axes).reshape([num_elements, nr_classes]),
+            targets.reshape([num_elements]),
+        )
     }
 
     fn compute_smoothed_targets(
@@ -195,26 +323,6 @@ impl<B: Backend> CrossEntropyLoss<B> {
 
         tensor
     }
-
-    fn apply_mask_2d(mut tensor: Tensor<B, 2>, mask: Option<Tensor<B, 1, Bool>>) -> Tensor<B, 2> {
-        if let Some(mask) = mask {
-            let [batch_size, nr_classes] = tensor.dims();
-            tensor = tensor.mask_fill(mask.reshape([batch_size, 1]).repeat(1, nr_classes), 0);
-        }
-
-        tensor
-    }
-
-    fn assertions(logits: Tensor<B, 2>, targets: Tensor<B, 1, Int>) {
-        let [logits_height, _] = logits.dims();
-        let [targets_height] = targets.dims();
-        assert!(
-            logits_height == targets_height,
-            "Shape of targets ({}) should correspond to outer shape of logits ({}).",
-            targets_height,
-            logits_height
-        );
-    }
 }
 
 #[cfg(test)]
@@ -387,4 +495,79 @@ mod tests {
 
         loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
     }
+
+    #[test]
+    fn test_cross_entropy_loss_with_probs_should_match_classes() {
+        let (logits, targets, targets_logits) = setup!();
+        let loss = CrossEntropyLossConfig::new()
+            .with_weights(Some(vec![1.0, 2., 3., 4., 5.]))
+            .with_smoothing(Some(0.1));
+
+        let loss_1 = loss.init().forward(logits.clone(), targets);
+        let loss_2 = loss.init().forward_probs(logits, targets_logits);
+
+        // With target distributions, the mean isn't divided by the weights of the targets.
+        loss_1
+            .into_data()
+            .assert_approx_eq(&(loss_2 * 4. / (3. + 1. + 5. + 2.)).into_data(), 3);
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_with_soft_targets() {
+        let (logits, _, _) = setup!();
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([
+            [0.0, 0.3, 0.7, 0.0, 0.0],
+            [0.5, 0.0, 0.0, 0.0, 0.5],
+            [0.0, 0.0, 0.0, 0.2, 0.8],
+            [0.1, 0.6, 0.1, 0.1, 0.1],
+        ]));
+
+        let loss_1 = CrossEntropyLossConfig::new()
+            .init()
+            .forward_probs(logits.clone(), targets.clone());
+        let loss_2 = cross_entropy_with_logits(logits, targets);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_with_sequences() {
+        let [batch_size, num_targets, seq_length] = [2, 5, 3];
+        let logits = Tensor::<TestBackend, 3>::random(
+            [batch_size, num_targets, seq_length],
+            Distribution::Normal(0., 1.0),
+        );
+        let targets = Tensor::<TestBackend, 2, Int>::from_data(Data::from([[2, 0, 4], [1, 1, 3]]));
+        let loss = CrossEntropyLossConfig::new()
+            .with_pad_tokens(Some(vec![1]))
+            .with_weights(Some(vec![1.0, 2., 3., 4., 5.]))
+            .init();
+
+        let loss_1 = loss.forward(logits.clone(), targets.clone());
+        let loss_2 = loss.forward(
+            logits
+                .swap_dims(1, 2)
+                .reshape([batch_size * seq_length, num_targets]),
+            targets.reshape([batch_size * seq_length]),
+        );
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_without_reduction() {
+        let (logits, targets, targets_logits) = setup!();
+        let loss = CrossEntropyLossConfig::new().init();
+
+        let loss_no_reduction = loss.forward_no_reduction(logits.clone(), targets.clone());
+        let loss_sum = loss.forward_with_reduction(logits.clone(), targets, Reduction::Sum);
+
+        let expected = (log_softmax(logits, 1) * targets_logits).sum_dim(1).neg();
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&expected.clone().reshape([4]).into_data(), 3);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&expected.sum().into_data(), 3);
+    }
 }
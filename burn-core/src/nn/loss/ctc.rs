@@ -0,0 +1,389 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_tensor::{backend::Backend, Bool, Int, Tensor};
+use core::marker::PhantomData;
+
+/// The log-probability given to the impossible alignments.
+///
+/// A finite value is used instead of negative infinity, so that no `inf - inf` or `0 * inf` can
+/// give NaN values in the forward or the backward pass.
+const NEG_INF: f32 = -1e30;
+
+/// Configuration to create a [connectionist temporal classification loss](CTCLoss).
+#[derive(Config, Debug)]
+pub struct CTCLossConfig {
+    /// The index of the blank label.
+    #[config(default = 0)]
+    pub blank: usize,
+    /// If the losses of the impossible alignments, for instance when an input is shorter than
+    /// its target, are replaced by zero along with their gradients.
+    #[config(default = false)]
+    pub zero_infinity: bool,
+}
+
+impl CTCLossConfig {
+    /// Initialize [connectionist temporal classification loss](CTCLoss).
+    pub fn init<B: Backend>(&self) -> CTCLoss<B> {
+        CTCLoss {
+            blank: self.blank,
+            zero_infinity: self.zero_infinity,
+            backend: PhantomData,
+        }
+    }
+}
+
+/// Calculate the connectionist temporal classification loss, as described in
+/// [Connectionist Temporal Classification: Labelling Unsegmented Sequence Data with Recurrent Neural Networks](https://www.cs.toronto.edu/~graves/icml_2006.pdf).
+///
+/// The loss is the negative log-likelihood of the targets, summed over all the alignments of the
+/// targets with the inputs. It's computed with the forward recursion in log space, only using
+/// tensor operations, so the backward recursion is given by autodiff on any backend.
+///
+/// The mean reduction divides the loss of each sample by its target length before averaging
+/// over the batch.
+#[derive(Module, Debug)]
+pub struct CTCLoss<B: Backend> {
+    blank: usize,
+    zero_infinity: bool,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for CTCLoss<B> {
+    fn default() -> Self {
+        CTCLossConfig::new().init()
+    }
+}
+
+impl<B: Backend> CTCLoss<B> {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// The targets are padded to the longest target, the padding values being ignored.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[input_length, batch_size, num_classes]`
+    /// - targets: `[batch_size, target_length]`
+    /// - input_lengths: `[batch_size]`
+    /// - target_lengths: `[batch_size]`
+    pub fn forward(
+        &self,
+        log_probs: Tensor<B, 3>,
+        targets: Tensor<B, 2, Int>,
+        input_lengths: Tensor<B, 1, Int>,
+        target_lengths: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let losses =
+            self.forward_no_reduction(log_probs, targets, input_lengths, target_lengths.clone());
+
+        match reduction {
+            Reduction::Mean | Reduction::Auto => {
+                losses.div(target_lengths.float().clamp_min(1.0)).mean()
+            }
+            Reduction::Sum => losses.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[input_length, batch_size, num_classes]`
+    /// - targets: `[batch_size, target_length]`
+    /// - input_lengths: `[batch_size]`
+    /// - target_lengths: `[batch_size]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction(
+        &self,
+        log_probs: Tensor<B, 3>,
+        targets: Tensor<B, 2, Int>,
+        input_lengths: Tensor<B, 1, Int>,
+        target_lengths: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let [max_input_length, batch_size, _] = log_probs.dims();
+        let [targets_size, max_target_length] = targets.dims();
+        assert!(
+            batch_size == targets_size,
+            "Shape of targets ({}) should correspond to the batch size of log_probs ({}).",
+            targets_size,
+            batch_size
+        );
+        assert!(
+            max_target_length > 0,
+            "Targets should have at least one column, even if all the target lengths are zero."
+        );
+
+        let labels = self.extended_labels(targets, target_lengths.clone());
+        let [_, num_labels] = labels.dims();
+        let shape = [batch_size, num_labels];
+        let device = log_probs.device();
+
+        // Log-probabilities of the extended labels at each time step.
+        let emissions = log_probs.gather(
+            2,
+            labels.clone().reshape([1, batch_size, num_labels]).expand([
+                max_input_length,
+                batch_size,
+                num_labels,
+            ]),
+        );
+        let emission = |t: usize| -> Tensor<B, 2> {
+            emissions
+                .clone()
+                .slice([t..t + 1, 0..batch_size])
+                .reshape(shape)
+        };
+        let skip_allowed = self.skip_allowed(labels);
+
+        // The alignments start with a blank or with the first label.
+        let mut alpha = Tensor::full_device(shape, NEG_INF, &device).slice_assign(
+            [0..batch_size, 0..2],
+            emission(0).slice([0..batch_size, 0..2]),
+        );
+
+        for t in 1..max_input_length {
+            let stay = alpha.clone();
+            let next = shift(alpha.clone(), 1);
+            let skip = shift(alpha.clone(), 2).mask_fill(skip_allowed.clone().bool_not(), NEG_INF);
+            let alpha_t = log_sum_exp(vec![stay, next, skip]).add(emission(t));
+
+            // The inputs that already ended keep their last alpha.
+            let active = input_lengths
+                .clone()
+                .greater_elem(t as i64)
+                .reshape([batch_size, 1])
+                .expand(shape);
+            alpha = alpha.mask_where(active, alpha_t);
+        }
+
+        // The alignments end with the last label or with the blank after it.
+        let end = target_lengths.mul_scalar(2).reshape([batch_size, 1]);
+        let ends = Tensor::cat(vec![end.clone(), end.sub_scalar(1)], 1);
+        let no_label = ends.clone().lower_elem(0);
+        let alpha_end = alpha
+            .gather(1, ends.clamp_min(0))
+            .mask_fill(no_label, NEG_INF);
+        let [alpha_last, alpha_blank] =
+            [0, 1].map(|i| alpha_end.clone().slice([0..batch_size, i..i + 1]));
+        let losses = log_sum_exp(vec![alpha_last, alpha_blank])
+            .neg()
+            .reshape([batch_size]);
+
+        if self.zero_infinity {
+            let impossible = losses.clone().greater_elem(-NEG_INF / 2.0);
+            return losses.mask_fill(impossible, 0.0);
+        }
+
+        losses
+    }
+
+    /// Inserts a blank before each label and after the last one, the padding being replaced by
+    /// blanks.
+    fn extended_labels(
+        &self,
+        targets: Tensor<B, 2, Int>,
+        target_lengths: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 2, Int> {
+        let [batch_size, max_target_length] = targets.dims();
+        let device = targets.device();
+        let shape = [batch_size, max_target_length];
+
+        let padding = Tensor::<B, 1, Int>::arange_device(0..max_target_length, &device)
+            .reshape([1, max_target_length])
+            .expand(shape)
+            .greater_equal(target_lengths.reshape([batch_size, 1]).expand(shape));
+        let targets = targets.mask_fill(padding, self.blank as i64);
+        let blanks = Tensor::<B, 2, Int>::full_device(shape, self.blank as i64, &device);
+
+        let labels = Tensor::cat(
+            vec![
+                blanks.reshape([batch_size, max_target_length, 1]),
+                targets.reshape([batch_size, max_target_length, 1]),
+            ],
+            2,
+        )
+        .reshape([batch_size, 2 * max_target_length]);
+
+        Tensor::cat(
+            vec![
+                labels,
+                Tensor::full_device([batch_size, 1], self.blank as i64, &device),
+            ],
+            1,
+        )
+    }
+
+    /// A label can be reached from two labels before, skipping a blank, when it isn't a blank
+    /// and differs from that label.
+    fn skip_allowed(&self, labels: Tensor<B, 2, Int>) -> Tensor<B, 2, Bool> {
+        let [batch_size, num_labels] = labels.dims();
+        let previous = Tensor::cat(
+            vec![
+                Tensor::full_device([batch_size, 2], self.blank as i64, &labels.device()),
+                labels.clone().slice([0..batch_size, 0..num_labels - 2]),
+            ],
+            1,
+        );
+
+        labels
+            .clone()
+            .equal(previous)
+            .int()
+            .add(labels.equal_elem(self.blank as i64).int())
+            .equal_elem(0)
+    }
+}
+
+/// Shifts the tensor to the right along the second dimension, the first values being impossible.
+fn shift<B: Backend>(tensor: Tensor<B, 2>, offset: usize) -> Tensor<B, 2> {
+    let [batch_size, num_labels] = tensor.dims();
+    let device = tensor.device();
+
+    Tensor::cat(
+        vec![
+            Tensor::full_device([batch_size, offset], NEG_INF, &device),
+            tensor.slice([0..batch_size, 0..num_labels - offset]),
+        ],
+        1,
+    )
+}
+
+/// Computes `log(sum(exp(x)))` element-wise over the tensors, without overflow.
+fn log_sum_exp<B: Backend>(tensors: Vec<Tensor<B, 2>>) -> Tensor<B, 2> {
+    // The maximum doesn't change the result, so it's detached to simplify the backward pass.
+    let max = tensors
+        .iter()
+        .cloned()
+        .reduce(|max, tensor| max.clone().mask_where(tensor.clone().greater(max), tensor))
+        .expect("At least one tensor is needed")
+        .detach();
+
+    let sum = tensors
+        .into_iter()
+        .map(|tensor| tensor.sub(max.clone()).exp())
+        .reduce(|sum, tensor| sum.add(tensor))
+        .expect("At least one tensor is needed");
+
+    sum.log().add(max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestADBackend, TestBackend};
+    use burn_tensor::Data;
+
+    fn log_probs<B: Backend>() -> Tensor<B, 3> {
+        Tensor::<B, 3>::from_floats([
+            [
+                [-1.353287, -0.853287, -1.153287],
+                [-0.407606, -1.407606, -2.407606],
+            ],
+            [
+                [-1.250424, -1.250424, -0.850424],
+                [-1.45802, -0.95802, -0.95802],
+            ],
+            [
+                [-0.945911, -1.345911, -1.045911],
+                [-1.964369, -0.464369, -1.464369],
+            ],
+            [
+                [-1.133069, -1.133069, -1.033069],
+                [-1.26795, -1.36795, -0.76795],
+            ],
+        ])
+    }
+
+    fn targets<B: Backend>() -> (Tensor<B, 2, Int>, Tensor<B, 1, Int>, Tensor<B, 1, Int>) {
+        // The second target is padded and its input is shorter.
+        (
+            Tensor::from_ints([[1, 1], [2, 0]]),
+            Tensor::from_ints([4, 3]),
+            Tensor::from_ints([2, 1]),
+        )
+    }
+
+    #[test]
+    fn test_ctc_loss() {
+        let (targets, input_lengths, target_lengths) = targets::<TestBackend>();
+        let ctc = CTCLossConfig::new().init();
+
+        let loss_no_reduction = ctc.forward_no_reduction(
+            log_probs(),
+            targets.clone(),
+            input_lengths.clone(),
+            target_lengths.clone(),
+        );
+        let loss = ctc.forward(
+            log_probs(),
+            targets.clone(),
+            input_lengths.clone(),
+            target_lengths.clone(),
+            Reduction::Auto,
+        );
+        let loss_sum = ctc.forward(
+            log_probs(),
+            targets,
+            input_lengths,
+            target_lengths,
+            Reduction::Sum,
+        );
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([2.809691, 1.921628]), 3);
+        loss.into_data()
+            .assert_approx_eq(&Data::from([1.663237]), 3);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&Data::from([4.731319]), 3);
+    }
+
+    #[test]
+    fn test_ctc_loss_backward() {
+        let (targets, input_lengths, target_lengths) = targets::<TestADBackend>();
+        let log_probs = log_probs::<TestADBackend>().require_grad();
+
+        let loss = CTCLossConfig::new().init().forward(
+            log_probs.clone(),
+            targets,
+            input_lengths,
+            target_lengths,
+            Reduction::Sum,
+        );
+        let grads = loss.backward();
+        let log_probs_grad = log_probs.grad(&grads).unwrap();
+
+        log_probs_grad.into_data().assert_approx_eq(
+            &Data::from([
+                [[-0.1537, -0.8463, 0.0], [-0.8923, 0.0, -0.1077]],
+                [[-0.593, -0.407, 0.0], [-0.2646, 0.0, -0.7354]],
+                [[-0.6604, -0.3396, 0.0], [-0.2977, 0.0, -0.7023]],
+                [[-0.1698, -0.8302, 0.0], [0.0, 0.0, 0.0]],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_ctc_loss_with_zero_infinity() {
+        let log_probs = log_probs::<TestBackend>();
+        // The repeated labels need a blank between them, which doesn't fit in two steps.
+        let targets = Tensor::from_ints([[1, 1], [2, 2]]);
+        let input_lengths = Tensor::from_ints([2, 4]);
+        let target_lengths = Tensor::from_ints([2, 1]);
+
+        let loss = CTCLossConfig::new()
+            .with_zero_infinity(true)
+            .init()
+            .forward_no_reduction(log_probs, targets, input_lengths, target_lengths);
+
+        let loss = loss.into_data();
+        assert_eq!(loss.value[0], 0.0);
+        assert!(loss.value[1] > 0.0 && loss.value[1] < 10.0);
+    }
+}
@@ -0,0 +1,161 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::{backend::Backend, Tensor};
+use core::marker::PhantomData;
+
+/// Configuration to create a [focal loss](FocalLoss).
+#[derive(Config, Debug)]
+pub struct FocalLossConfig {
+    /// The focusing parameter, which reduces the loss of the well classified elements.
+    #[config(default = 2.0)]
+    pub gamma: f32,
+
+    /// The weight of the positive targets, the negative ones being weighted by `1 - alpha`.
+    ///
+    /// The original paper uses 0.25, while no weighting is applied if not set.
+    pub alpha: Option<f32>,
+}
+
+impl FocalLossConfig {
+    /// Initialize [focal loss](FocalLoss).
+    pub fn init<B: Backend>(&self) -> FocalLoss<B> {
+        assert!(
+            self.gamma >= 0.0,
+            "Gamma of focal loss should be positive or zero. Got {}",
+            self.gamma
+        );
+        if let Some(alpha) = self.alpha {
+            assert!(
+                (0.0..=1.).contains(&alpha),
+                "Alpha of focal loss should be in interval [0, 1]. Got {}",
+                alpha
+            );
+        }
+
+        FocalLoss {
+            gamma: self.gamma,
+            alpha: self.alpha,
+            backend: PhantomData,
+        }
+    }
+}
+
+/// Calculate the binary focal loss from the input logits and the targets, as described in
+/// [Focal Loss for Dense Object Detection](https://arxiv.org/abs/1708.02002).
+///
+/// The binary cross-entropy of each element is multiplied by `(1 - p_t)^gamma`, where `p_t` is
+/// the predicted probability of the target. The targets are probabilities, so several labels can
+/// be positive for the same sample.
+#[derive(Module, Debug)]
+pub struct FocalLoss<B: Backend> {
+    gamma: f32,
+    alpha: Option<f32>,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for FocalLoss<B> {
+    fn default() -> Self {
+        FocalLossConfig::new().init()
+    }
+}
+
+impl<B: Backend> FocalLoss<B> {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: [batch_size, num_targets]
+    /// - targets: [batch_size, num_targets]
+    pub fn forward<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        reduction.apply(self.forward_no_reduction(logits, targets))
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        // Binary cross-entropy computed from the logits, which is stable for large logits.
+        let cross_entropy = logits.clone().clamp_min(0.0) - logits.clone().mul(targets.clone())
+            + logits.clone().abs().neg().exp().add_scalar(1.0).log();
+
+        let probs = burn_tensor::activation::sigmoid(logits);
+        let probs_targets = probs.clone().mul(targets.clone())
+            + probs
+                .neg()
+                .add_scalar(1.0)
+                .mul(targets.clone().neg().add_scalar(1.0));
+        let loss = cross_entropy.mul(probs_targets.neg().add_scalar(1.0).powf(self.gamma));
+
+        match self.alpha {
+            Some(alpha) => {
+                let weights = targets.clone().mul_scalar(alpha)
+                    + targets.neg().add_scalar(1.0).mul_scalar(1.0 - alpha);
+                loss.mul(weights)
+            }
+            None => loss,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_focal_loss() {
+        let logits = Tensor::<TestBackend, 2>::from_data(Data::from([[0.0, 2.0], [-1.0, 1.0]]));
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 0.0], [0.0, 1.0]]));
+
+        let focal = FocalLossConfig::new().init();
+        let loss_no_reduction = focal.forward_no_reduction(logits.clone(), targets.clone());
+        let loss = focal.forward(logits, targets, Reduction::Auto);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.173287, 1.650078], [0.022658, 0.022658]]), 3);
+        loss.into_data()
+            .assert_approx_eq(&Data::from([0.467170]), 3);
+    }
+
+    #[test]
+    fn test_focal_loss_with_alpha() {
+        let logits = Tensor::<TestBackend, 2>::from_data(Data::from([[0.0, 2.0], [-1.0, 1.0]]));
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 0.0], [0.0, 1.0]]));
+
+        let loss = FocalLossConfig::new()
+            .with_alpha(Some(0.25))
+            .init()
+            .forward(logits, targets, Reduction::Sum);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([1.303538]), 3);
+    }
+
+    #[test]
+    fn test_focal_loss_with_zero_gamma_should_be_binary_cross_entropy() {
+        let logits = Tensor::<TestBackend, 1>::from_data(Data::from([0.5, -2.0, 3.0]));
+        let targets = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 0.0, 0.0]));
+
+        let loss = FocalLossConfig::new()
+            .with_gamma(0.0)
+            .init()
+            .forward_no_reduction(logits.clone(), targets.clone());
+
+        let probs = burn_tensor::activation::sigmoid(logits);
+        let expected = (targets.clone() * probs.clone().log()
+            + (targets.neg() + 1.0) * (probs.neg() + 1.0).log())
+        .neg();
+        loss.into_data().assert_approx_eq(&expected.into_data(), 3);
+    }
+}
@@ -0,0 +1,109 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::{backend::Backend, Tensor};
+use core::marker::PhantomData;
+
+/// Configuration to create a [Huber loss](HuberLoss).
+#[derive(Config, Debug)]
+pub struct HuberLossConfig {
+    /// The absolute error from which the loss becomes linear instead of quadratic.
+    #[config(default = 1.0)]
+    pub delta: f32,
+}
+
+impl HuberLossConfig {
+    /// Initialize [Huber loss](HuberLoss).
+    pub fn init<B: Backend>(&self) -> HuberLoss<B> {
+        assert!(
+            self.delta > 0.0,
+            "Delta of Huber loss should be positive. Got {}",
+            self.delta
+        );
+
+        HuberLoss {
+            delta: self.delta,
+            backend: PhantomData,
+        }
+    }
+}
+
+/// Calculate the Huber loss from the input logits and the targets.
+///
+/// The loss is quadratic for absolute errors smaller than delta and linear otherwise:
+///
+/// - `0.5 * x^2` if `|x| <= delta`
+/// - `delta * (|x| - 0.5 * delta)` otherwise
+#[derive(Module, Debug)]
+pub struct HuberLoss<B: Backend> {
+    delta: f32,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for HuberLoss<B> {
+    fn default() -> Self {
+        HuberLossConfig::new().init()
+    }
+}
+
+impl<B: Backend> HuberLoss<B> {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: [batch_size, num_targets]
+    /// - targets: [batch_size, num_targets]
+    pub fn forward<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        reduction.apply(self.forward_no_reduction(logits, targets))
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        huber(logits.sub(targets).abs(), self.delta)
+    }
+}
+
+/// Computes the Huber loss from the absolute errors.
+///
+/// The quadratic part is clamped to delta, which gives both cases without masking.
+pub(crate) fn huber<B: Backend, const D: usize>(error: Tensor<B, D>, delta: f32) -> Tensor<B, D> {
+    let quadratic = error.clone().clamp_max(delta);
+
+    quadratic.clone().mul(error.sub(quadratic.mul_scalar(0.5)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_huber_loss() {
+        let logits = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.5]]));
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[1.5, 0.0], [3.0, 1.0]]));
+
+        let huber = HuberLossConfig::new().with_delta(2.0).init();
+        let loss_no_reduction = huber.forward_no_reduction(logits.clone(), targets.clone());
+        let loss = huber.forward(logits.clone(), targets.clone(), Reduction::Auto);
+        let loss_sum = huber.forward(logits, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.125, 2.0], [0.0, 5.0]]), 3);
+        loss.into_data().assert_approx_eq(&Data::from([1.78125]), 3);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&Data::from([7.125]), 3);
+    }
+}
@@ -0,0 +1,124 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::{backend::Backend, Tensor};
+use core::marker::PhantomData;
+
+/// Configuration to create a [Kullback-Leibler divergence loss](KLDivLoss).
+#[derive(Config, Debug)]
+pub struct KLDivLossConfig {
+    /// If the targets are given as log-probabilities instead of probabilities.
+    #[config(default = false)]
+    pub log_target: bool,
+}
+
+impl KLDivLossConfig {
+    /// Initialize [Kullback-Leibler divergence loss](KLDivLoss).
+    pub fn init<B: Backend>(&self) -> KLDivLoss<B> {
+        KLDivLoss {
+            log_target: self.log_target,
+            backend: PhantomData,
+        }
+    }
+}
+
+/// Calculate the Kullback-Leibler divergence loss from the input log-probabilities and the target
+/// distributions.
+///
+/// The loss of each element is `target * (log(target) - input)`, where zero targets give a zero
+/// loss. Note that the mean reduction averages over all the elements: the divergence between the
+/// distributions, averaged over the batch, is given by the sum reduction divided by the batch
+/// size.
+#[derive(Module, Debug)]
+pub struct KLDivLoss<B: Backend> {
+    log_target: bool,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for KLDivLoss<B> {
+    fn default() -> Self {
+        KLDivLossConfig::new().init()
+    }
+}
+
+impl<B: Backend> KLDivLoss<B> {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - inputs: [batch_size, num_classes]
+    /// - targets: [batch_size, num_classes]
+    pub fn forward<const D: usize>(
+        &self,
+        inputs: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        reduction.apply(self.forward_no_reduction(inputs, targets))
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        inputs: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        if self.log_target {
+            return targets.clone().exp().mul(targets.sub(inputs));
+        }
+
+        // The targets are clamped before the log, so that zero targets give `0 * finite` instead
+        // of `0 * -inf`.
+        let log_targets = targets.clone().clamp_min(f32::MIN_POSITIVE).log();
+
+        targets.mul(log_targets.sub(inputs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_kl_div_loss() {
+        let inputs =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[0.2, 0.8], [0.5, 0.5]])).log();
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[0.5, 0.5], [1.0, 0.0]]));
+
+        let kl_div = KLDivLossConfig::new().init();
+        let loss_no_reduction = kl_div.forward_no_reduction(inputs.clone(), targets.clone());
+        let loss = kl_div.forward(inputs.clone(), targets.clone(), Reduction::Auto);
+        let loss_sum = kl_div.forward(inputs, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.458145, -0.235002], [0.693147, 0.0]]), 3);
+        loss.into_data()
+            .assert_approx_eq(&Data::from([0.229073]), 3);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&Data::from([0.916291]), 3);
+    }
+
+    #[test]
+    fn test_kl_div_loss_with_log_target() {
+        let inputs =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[0.2, 0.8], [0.5, 0.5]])).log();
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[0.5, 0.5], [0.9, 0.1]]));
+
+        let loss_1 =
+            KLDivLossConfig::new()
+                .init()
+                .forward(inputs.clone(), targets.clone(), Reduction::Sum);
+        let loss_2 = KLDivLossConfig::new().with_log_target(true).init().forward(
+            inputs,
+            targets.log(),
+            Reduction::Sum,
+        );
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+}
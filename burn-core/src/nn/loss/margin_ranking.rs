@@ -0,0 +1,101 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::{backend::Backend, Int, Tensor};
+use core::marker::PhantomData;
+
+/// Configuration to create a [margin ranking loss](MarginRankingLoss).
+#[derive(Config, Debug)]
+pub struct MarginRankingLossConfig {
+    /// The margin by which the inputs should be ranked.
+    #[config(default = 0.0)]
+    pub margin: f32,
+}
+
+impl MarginRankingLossConfig {
+    /// Initialize [margin ranking loss](MarginRankingLoss).
+    pub fn init<B: Backend>(&self) -> MarginRankingLoss<B> {
+        MarginRankingLoss {
+            margin: self.margin,
+            backend: PhantomData,
+        }
+    }
+}
+
+/// Calculate the margin ranking loss from pairs of inputs and targets telling if the first input
+/// should be ranked higher (1) or lower (-1) than the second one.
+///
+/// `max(0, -target * (input_1 - input_2) + margin)`
+#[derive(Module, Debug)]
+pub struct MarginRankingLoss<B: Backend> {
+    margin: f32,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for MarginRankingLoss<B> {
+    fn default() -> Self {
+        MarginRankingLossConfig::new().init()
+    }
+}
+
+impl<B: Backend> MarginRankingLoss<B> {
+    /// Compute the criterion on the input tensors.
+    ///
+    /// # Shapes
+    ///
+    /// - inputs_1: [batch_size]
+    /// - inputs_2: [batch_size]
+    /// - targets: [batch_size]
+    pub fn forward<const D: usize>(
+        &self,
+        inputs_1: Tensor<B, D>,
+        inputs_2: Tensor<B, D>,
+        targets: Tensor<B, D, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        reduction.apply(self.forward_no_reduction(inputs_1, inputs_2, targets))
+    }
+
+    /// Compute the criterion on the input tensors without reducing.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        inputs_1: Tensor<B, D>,
+        inputs_2: Tensor<B, D>,
+        targets: Tensor<B, D, Int>,
+    ) -> Tensor<B, D> {
+        inputs_1
+            .sub(inputs_2)
+            .mul(targets.float())
+            .neg()
+            .add_scalar(self.margin)
+            .clamp_min(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_margin_ranking_loss() {
+        let inputs_1 = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 2.0, 3.0]));
+        let inputs_2 = Tensor::<TestBackend, 1>::from_data(Data::from([2.0, 1.0, 3.0]));
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1, 1, -1]));
+
+        let margin_ranking = MarginRankingLossConfig::new().with_margin(0.5).init();
+        let loss_no_reduction = margin_ranking.forward_no_reduction(
+            inputs_1.clone(),
+            inputs_2.clone(),
+            targets.clone(),
+        );
+        let loss = margin_ranking.forward(inputs_1, inputs_2, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([1.5, 0.0, 0.5]), 3);
+        loss.into_data().assert_approx_eq(&Data::from([2.0]), 3);
+    }
+}
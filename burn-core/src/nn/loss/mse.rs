@@ -35,11 +35,7 @@ impl<B: Backend> MSELoss<B> {
         targets: Tensor<B, D>,
         reduction: Reduction,
     ) -> Tensor<B, 1> {
-        let tensor = self.forward_no_reduction(logits, targets);
-        match reduction {
-            Reduction::Mean | Reduction::Auto => tensor.mean(),
-            Reduction::Sum => tensor.sum(),
-        }
+        reduction.apply(self.forward_no_reduction(logits, targets))
     }
 
     /// Compute the criterion on the input tensor without reducing.
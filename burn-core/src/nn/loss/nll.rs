@@ -0,0 +1,196 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use alloc::vec::Vec;
+use burn_tensor::{backend::Backend, Int, Tensor};
+
+/// Configuration to create a [negative log-likelihood loss](NLLLoss).
+#[derive(Config, Debug)]
+pub struct NLLLossConfig {
+    /// Create weighted negative log-likelihood.
+    ///
+    /// The order of the weight vector should correspond to the label integer assignment.
+    pub weights: Option<Vec<f32>>,
+
+    /// The target that is ignored, which doesn't contribute to the loss nor to the mean.
+    pub ignore_index: Option<usize>,
+}
+
+impl NLLLossConfig {
+    /// Initialize [negative log-likelihood loss](NLLLoss).
+    pub fn init<B: Backend>(&self) -> NLLLoss<B> {
+        if let Some(weights) = self.weights.as_ref() {
+            assert!(
+                weights.iter().all(|e| e > &0.),
+                "Weights of negative log-likelihood have to be positive."
+            );
+        }
+
+        NLLLoss {
+            weights: self
+                .weights
+                .as_ref()
+                .map(|e| Tensor::<B, 1>::from_floats(e.as_slice())),
+            ignore_index: self.ignore_index,
+        }
+    }
+}
+
+/// Calculate the negative log-likelihood loss from the input log-probabilities and the targets.
+///
+/// The mean reduction is weighted: the sum of the losses is divided by the sum of the weights of
+/// the targets that aren't ignored.
+#[derive(Module, Debug)]
+pub struct NLLLoss<B: Backend> {
+    /// Weights for negative log-likelihood.
+    pub weights: Option<Tensor<B, 1>>,
+    ignore_index: Option<usize>,
+}
+
+impl<B: Backend> Default for NLLLoss<B> {
+    fn default() -> Self {
+        NLLLossConfig::new().init()
+    }
+}
+
+impl<B: Backend> NLLLoss<B> {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - inputs: [batch_size, num_classes]
+    /// - targets: [batch_size]
+    pub fn forward(
+        &self,
+        inputs: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let (losses, weights) = self.losses_and_weights(inputs, targets);
+
+        match reduction {
+            Reduction::Mean | Reduction::Auto => losses.sum().div(weights.sum()),
+            Reduction::Sum => losses.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - inputs: [batch_size, num_classes]
+    /// - targets: [batch_size]
+    /// - output: [batch_size]
+    pub fn forward_no_reduction(
+        &self,
+        inputs: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let (losses, _) = self.losses_and_weights(inputs, targets);
+        losses
+    }
+
+    fn losses_and_weights(
+        &self,
+        inputs: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> (Tensor<B, 1>, Tensor<B, 1>) {
+        let [batch_size, _] = inputs.dims();
+        let [targets_size] = targets.dims();
+        assert!(
+            batch_size == targets_size,
+            "Shape of targets ({}) should correspond to outer shape of inputs ({}).",
+            targets_size,
+            batch_size
+        );
+
+        let ignored = self
+            .ignore_index
+            .map(|index| targets.clone().equal_elem(index as i64));
+        // The ignored targets may not be valid classes, so they're replaced before any gather.
+        let targets = match &ignored {
+            Some(ignored) => targets.mask_fill(ignored.clone(), 0),
+            None => targets,
+        };
+
+        let weights = match &self.weights {
+            Some(weights) => weights.clone().gather(0, targets.clone()),
+            None => Tensor::ones_device([batch_size], &inputs.device()),
+        };
+        let weights = match ignored {
+            Some(ignored) => weights.mask_fill(ignored, 0.0),
+            None => weights,
+        };
+
+        let losses = inputs
+            .gather(1, targets.reshape([batch_size, 1]))
+            .reshape([batch_size])
+            .neg()
+            .mul(weights.clone());
+
+        (losses, weights)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use alloc::vec;
+    use burn_tensor::Data;
+
+    fn inputs() -> Tensor<TestBackend, 2> {
+        Tensor::from_data(Data::from([
+            [0.1, 0.2, 0.7],
+            [0.5, 0.25, 0.25],
+            [0.3, 0.3, 0.4],
+        ]))
+        .log()
+    }
+
+    #[test]
+    fn test_nll_loss() {
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([2, 0, 1]));
+
+        let nll = NLLLossConfig::new().init();
+        let loss_no_reduction = nll.forward_no_reduction(inputs(), targets.clone());
+        let loss = nll.forward(inputs(), targets.clone(), Reduction::Auto);
+        let loss_sum = nll.forward(inputs(), targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([0.356675, 0.693147, 1.203973]), 3);
+        loss.into_data()
+            .assert_approx_eq(&Data::from([0.751265]), 3);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&Data::from([2.253795]), 3);
+    }
+
+    #[test]
+    fn test_nll_loss_with_weights() {
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([2, 0, 1]));
+
+        let loss = NLLLossConfig::new()
+            .with_weights(Some(vec![1.0, 2.0, 3.0]))
+            .init()
+            .forward(inputs(), targets, Reduction::Mean);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([0.695186]), 3);
+    }
+
+    #[test]
+    fn test_nll_loss_with_ignore_index() {
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([2, 0, 1]));
+
+        let loss = NLLLossConfig::new()
+            .with_ignore_index(Some(0))
+            .init()
+            .forward(inputs(), targets, Reduction::Mean);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([0.780324]), 3);
+    }
+}
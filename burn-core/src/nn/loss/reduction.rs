@@ -1,4 +1,7 @@
+use burn_tensor::{backend::Backend, Tensor};
+
 /// The reduction type for the loss.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Reduction {
     /// The mean of the losses will be returned.
     Mean,
@@ -9,3 +12,13 @@ pub enum Reduction {
     /// The mean of the losses will be returned.
     Auto,
 }
+
+impl Reduction {
+    /// Reduces the losses of each element to a single value.
+    pub(crate) fn apply<B: Backend, const D: usize>(self, tensor: Tensor<B, D>) -> Tensor<B, 1> {
+        match self {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+}
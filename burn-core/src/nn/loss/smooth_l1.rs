@@ -0,0 +1,119 @@
+use crate as burn;
+
+use super::huber::huber;
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::{backend::Backend, Tensor};
+use core::marker::PhantomData;
+
+/// Configuration to create a [smooth L1 loss](SmoothL1Loss).
+#[derive(Config, Debug)]
+pub struct SmoothL1LossConfig {
+    /// The absolute error from which the loss becomes linear instead of quadratic.
+    ///
+    /// A beta of zero gives the L1 loss.
+    #[config(default = 1.0)]
+    pub beta: f32,
+}
+
+impl SmoothL1LossConfig {
+    /// Initialize [smooth L1 loss](SmoothL1Loss).
+    pub fn init<B: Backend>(&self) -> SmoothL1Loss<B> {
+        assert!(
+            self.beta >= 0.0,
+            "Beta of smooth L1 loss should be positive or zero. Got {}",
+            self.beta
+        );
+
+        SmoothL1Loss {
+            beta: self.beta,
+            backend: PhantomData,
+        }
+    }
+}
+
+/// Calculate the smooth L1 loss from the input logits and the targets.
+///
+/// This is the [Huber loss](super::HuberLoss) divided by beta, so that the linear part has a slope
+/// of one:
+///
+/// - `0.5 * x^2 / beta` if `|x| < beta`
+/// - `|x| - 0.5 * beta` otherwise
+#[derive(Module, Debug)]
+pub struct SmoothL1Loss<B: Backend> {
+    beta: f32,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for SmoothL1Loss<B> {
+    fn default() -> Self {
+        SmoothL1LossConfig::new().init()
+    }
+}
+
+impl<B: Backend> SmoothL1Loss<B> {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: [batch_size, num_targets]
+    /// - targets: [batch_size, num_targets]
+    pub fn forward<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        reduction.apply(self.forward_no_reduction(logits, targets))
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        let error = logits.sub(targets).abs();
+
+        if self.beta == 0.0 {
+            return error;
+        }
+
+        huber(error, self.beta).div_scalar(self.beta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_smooth_l1_loss() {
+        let logits = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.5]]));
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[1.5, 0.0], [3.0, 1.0]]));
+
+        let smooth_l1 = SmoothL1LossConfig::new().init();
+        let loss_no_reduction = smooth_l1.forward_no_reduction(logits.clone(), targets.clone());
+        let loss = smooth_l1.forward(logits, targets, Reduction::Auto);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.125, 1.5], [0.0, 3.0]]), 3);
+        loss.into_data().assert_approx_eq(&Data::from([1.15625]), 3);
+    }
+
+    #[test]
+    fn test_smooth_l1_loss_with_zero_beta_should_be_l1_loss() {
+        let logits = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.5]]));
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[1.5, 0.0], [3.0, 1.0]]));
+
+        let smooth_l1 = SmoothL1LossConfig::new().with_beta(0.0).init();
+        let loss_no_reduction = smooth_l1.forward_no_reduction(logits, targets);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.5, 2.0], [0.0, 3.5]]), 3);
+    }
+}
@@ -0,0 +1,172 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::{backend::Backend, Tensor};
+use core::marker::PhantomData;
+
+/// Configuration to create a [triplet margin loss](TripletMarginLoss).
+#[derive(Config, Debug)]
+pub struct TripletMarginLossConfig {
+    /// The margin by which the negative should be farther from the anchor than the positive.
+    #[config(default = 1.0)]
+    pub margin: f32,
+    /// The p of the p-norm used as distance.
+    #[config(default = 2.0)]
+    pub p: f32,
+    /// A small value added to the differences, to avoid a zero distance.
+    #[config(default = 1e-6)]
+    pub epsilon: f32,
+    /// If the distance between the positive and the negative is used as distance of the negative
+    /// when it's smaller than the distance between the anchor and the negative, as described in
+    /// [Learning shallow convolutional feature descriptors with triplet losses](http://www.bmva.org/bmvc/2016/papers/paper119/index.html).
+    #[config(default = false)]
+    pub swap: bool,
+}
+
+impl TripletMarginLossConfig {
+    /// Initialize [triplet margin loss](TripletMarginLoss).
+    pub fn init<B: Backend>(&self) -> TripletMarginLoss<B> {
+        assert!(
+            self.p > 0.0,
+            "P of triplet margin loss should be positive. Got {}",
+            self.p
+        );
+
+        TripletMarginLoss {
+            margin: self.margin,
+            p: self.p,
+            epsilon: self.epsilon,
+            swap: self.swap,
+            backend: PhantomData,
+        }
+    }
+}
+
+/// Calculate the triplet margin loss from anchors, positives and negatives embeddings.
+///
+/// `max(0, d(anchor, positive) - d(anchor, negative) + margin)`, where `d` is the p-norm of the
+/// difference.
+#[derive(Module, Debug)]
+pub struct TripletMarginLoss<B: Backend> {
+    margin: f32,
+    p: f32,
+    epsilon: f32,
+    swap: bool,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for TripletMarginLoss<B> {
+    fn default() -> Self {
+        TripletMarginLossConfig::new().init()
+    }
+}
+
+impl<B: Backend> TripletMarginLoss<B> {
+    /// Compute the criterion on the input tensors.
+    ///
+    /// # Shapes
+    ///
+    /// - anchors: [batch_size, d_model]
+    /// - positives: [batch_size, d_model]
+    /// - negatives: [batch_size, d_model]
+    pub fn forward(
+        &self,
+        anchors: Tensor<B, 2>,
+        positives: Tensor<B, 2>,
+        negatives: Tensor<B, 2>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        reduction.apply(self.forward_no_reduction(anchors, positives, negatives))
+    }
+
+    /// Compute the criterion on the input tensors without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - anchors: [batch_size, d_model]
+    /// - positives: [batch_size, d_model]
+    /// - negatives: [batch_size, d_model]
+    /// - output: [batch_size]
+    pub fn forward_no_reduction(
+        &self,
+        anchors: Tensor<B, 2>,
+        positives: Tensor<B, 2>,
+        negatives: Tensor<B, 2>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, _] = anchors.dims();
+
+        let distance_positive = self.distance(anchors.clone(), positives.clone());
+        let distance_negative = self.distance(anchors, negatives.clone());
+        let distance_negative = match self.swap {
+            true => {
+                let distance_swap = self.distance(positives, negatives);
+                let swap = distance_swap.clone().lower(distance_negative.clone());
+                distance_negative.mask_where(swap, distance_swap)
+            }
+            false => distance_negative,
+        };
+
+        distance_positive
+            .sub(distance_negative)
+            .add_scalar(self.margin)
+            .clamp_min(0.0)
+            .reshape([batch_size])
+    }
+
+    fn distance(&self, tensor_1: Tensor<B, 2>, tensor_2: Tensor<B, 2>) -> Tensor<B, 2> {
+        tensor_1
+            .sub(tensor_2)
+            .add_scalar(self.epsilon)
+            .abs()
+            .powf(self.p)
+            .sum_dim(1)
+            .powf(1.0 / self.p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    fn triplets() -> [Tensor<TestBackend, 2>; 3] {
+        [
+            Tensor::from_data(Data::from([[0.0, 0.0], [1.0, 1.0]])),
+            Tensor::from_data(Data::from([[1.0, 0.0], [1.0, 2.0]])),
+            Tensor::from_data(Data::from([[0.0, 0.5], [1.0, 2.5]])),
+        ]
+    }
+
+    #[test]
+    fn test_triplet_margin_loss() {
+        let [anchors, positives, negatives] = triplets();
+
+        let triplet_margin = TripletMarginLossConfig::new().init();
+        let loss_no_reduction = triplet_margin.forward_no_reduction(
+            anchors.clone(),
+            positives.clone(),
+            negatives.clone(),
+        );
+        let loss = triplet_margin.forward(anchors, positives, negatives, Reduction::Auto);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([1.5, 0.5]), 3);
+        loss.into_data().assert_approx_eq(&Data::from([1.0]), 3);
+    }
+
+    #[test]
+    fn test_triplet_margin_loss_with_swap() {
+        let [anchors, positives, negatives] = triplets();
+
+        let loss = TripletMarginLossConfig::new()
+            .with_swap(true)
+            .init()
+            .forward_no_reduction(anchors, positives, negatives);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([1.5, 1.5]), 3);
+    }
+}
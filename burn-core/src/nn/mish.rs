@@ -0,0 +1,28 @@
+use crate as burn;
+
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Applies the mish function element-wise:
+///
+/// `y = x * tanh(softplus(x))`
+#[derive(Module, Clone, Debug, Default)]
+pub struct Mish {}
+
+impl Mish {
+    /// Create the module.
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any]`
+    /// - output: `[..., any]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        crate::tensor::activation::mish(input)
+    }
+}
@@ -17,23 +17,47 @@ pub mod pool;
 pub mod transformer;
 
 mod dropout;
+mod elu;
 mod embedding;
+mod embedding_bag;
 mod gelu;
+mod hard_sigmoid;
+mod hard_swish;
 mod initializer;
+mod leaky_relu;
 mod linear;
+mod mish;
 mod norm;
 mod padding;
 mod pos_encoding;
+mod prelu;
 mod relu;
 mod rnn;
+mod rope_encoding;
+mod selu;
+mod softplus;
+mod softsign;
+mod upsample;
 
 pub use dropout::*;
+pub use elu::*;
 pub use embedding::*;
+pub use embedding_bag::*;
 pub use gelu::*;
+pub use hard_sigmoid::*;
+pub use hard_swish::*;
 pub use initializer::*;
+pub use leaky_relu::*;
 pub use linear::*;
+pub use mish::*;
 pub use norm::*;
 pub use padding::*;
 pub use pos_encoding::*;
+pub use prelu::*;
 pub use relu::*;
 pub use rnn::*;
+pub use rope_encoding::*;
+pub use selu::*;
+pub use softplus::*;
+pub use softsign::*;
+pub use upsample::*;
@@ -0,0 +1,231 @@
+use crate as burn;
+
+use crate::{
+    config::Config,
+    module::{Module, Param},
+    tensor::{backend::Backend, Tensor},
+};
+
+/// Configuration to create a [GroupNorm](GroupNorm) layer.
+#[derive(Config, Debug)]
+pub struct GroupNormConfig {
+    /// The number of groups to separate the channels into.
+    pub num_groups: usize,
+    /// The number of channels expected in the input.
+    pub num_channels: usize,
+    /// A value required for numerical stability. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub epsilon: f64,
+    /// If a learnable per-channel affine transformation is applied. Default: true
+    #[config(default = true)]
+    pub affine: bool,
+}
+
+/// Applies Group Normalization over a tensor as described in the paper [Group Normalization](https://arxiv.org/abs/1803.08494).
+///
+/// `Y = groupnorm(X) * γ + β`
+#[derive(Module, Debug)]
+pub struct GroupNorm<B: Backend, const D: usize> {
+    gamma: Option<Param<Tensor<B, 1>>>,
+    beta: Option<Param<Tensor<B, 1>>>,
+    num_groups: usize,
+    num_channels: usize,
+    epsilon: f64,
+}
+
+impl GroupNormConfig {
+    /// Initialize a new [group norm](GroupNorm) module.
+    pub fn init<B: Backend, const D: usize>(&self) -> GroupNorm<B, D> {
+        self.check_groups();
+
+        let (gamma, beta) = match self.affine {
+            true => (
+                Some(Param::from(Tensor::ones([self.num_channels]))),
+                Some(Param::from(Tensor::zeros([self.num_channels]))),
+            ),
+            false => (None, None),
+        };
+
+        GroupNorm {
+            gamma,
+            beta,
+            num_groups: self.num_groups,
+            num_channels: self.num_channels,
+            epsilon: self.epsilon,
+        }
+    }
+
+    /// Initialize a new [group norm](GroupNorm) module with a [record](GroupNormRecord).
+    pub fn init_with<B: Backend, const D: usize>(
+        &self,
+        record: GroupNormRecord<B, D>,
+    ) -> GroupNorm<B, D> {
+        self.check_groups();
+
+        GroupNorm {
+            gamma: record.gamma,
+            beta: record.beta,
+            num_groups: self.num_groups,
+            num_channels: self.num_channels,
+            epsilon: self.epsilon,
+        }
+    }
+
+    fn check_groups(&self) {
+        if self.num_channels % self.num_groups != 0 {
+            panic!(
+                "The number of channels {} must be divisible by the number of groups {}",
+                self.num_channels, self.num_groups
+            );
+        }
+    }
+}
+
+impl<const D: usize, B: Backend> GroupNorm<B, D> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, ...]`
+    /// - output: `[batch_size, channels, ...]`
+    pub fn forward<const DI: usize>(&self, input: Tensor<B, DI>) -> Tensor<B, DI> {
+        // Should be move to a compilation error when const generic support that kind of
+        // validation. https://github.com/rust-lang/rust/issues/76560
+        if D + 2 != DI {
+            panic!("GroupNorm{}D can only be applied on tensors of size {} with the following shape [batch_size, channels, ...], received {}D tensor", D, D+2, DI);
+        }
+
+        let shape = input.shape();
+        let batch_size = shape.dims[0];
+        let channels = shape.dims[1];
+
+        if channels != self.num_channels {
+            panic!(
+                "Expected {} channels in the input tensor, received {}",
+                self.num_channels, channels
+            );
+        }
+
+        let hidden_size = shape.num_elements() / (batch_size * self.num_groups);
+        let input = input.reshape([batch_size, self.num_groups, hidden_size]);
+
+        let (var, mean) = input.clone().var_mean_bias(2);
+        let std = var.add_scalar(self.epsilon).sqrt();
+        let input = input.sub(mean).div(std).reshape(shape);
+
+        match (&self.gamma, &self.beta) {
+            (Some(gamma), Some(beta)) => {
+                let mut affine_shape = [1; DI];
+                affine_shape[1] = channels;
+
+                input
+                    .mul(gamma.val().reshape(affine_shape))
+                    .add(beta.val().reshape(affine_shape))
+            }
+            _ => input,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn group_norm_forward_1d() {
+        let module = GroupNormConfig::new(3, 6).init::<TestBackend, 1>();
+        let input = Tensor::<TestBackend, 3>::from_floats([
+            [
+                [0.4524, 0.5598],
+                [0.9242, 0.4657],
+                [0.5078, 0.5874],
+                [0.1847, 0.5119],
+                [0.6299, 0.7930],
+                [0.0941, 0.3034],
+            ],
+            [
+                [0.0907, 0.8096],
+                [0.6934, 0.0419],
+                [0.9822, 0.9648],
+                [0.6539, 0.6156],
+                [0.1575, 0.0150],
+                [0.5284, 0.0596],
+            ],
+        ]);
+
+        let output = module.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [
+                    [-0.7738, -0.2127],
+                    [1.6908, -0.7043],
+                    [0.3854, 0.8980],
+                    [-1.6952, 0.4118],
+                    [0.6403, 1.2378],
+                    [-1.3224, -0.5557],
+                ],
+                [
+                    [-0.9210, 1.1598],
+                    [0.8234, -1.0622],
+                    [1.0471, 0.9448],
+                    [-0.8834, -1.1086],
+                    [-0.1615, -0.8669],
+                    [1.6745, -0.6461],
+                ],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn group_norm_forward_2d_without_affine() {
+        let module = GroupNormConfig::new(2, 4)
+            .with_affine(false)
+            .init::<TestBackend, 2>();
+        let input = Tensor::<TestBackend, 4>::from_floats([
+            [
+                [[0.1902, 0.2419], [0.0301, 0.4639]],
+                [[0.4405, 0.8424], [0.5191, 0.6403]],
+                [[0.4998, 0.6624], [0.4573, 0.2782]],
+                [[0.9977, 0.9957], [0.8402, 0.7078]],
+            ],
+            [
+                [[0.3153, 0.2297], [0.2890, 0.0702]],
+                [[0.7663, 0.4004], [0.8466, 0.3865]],
+                [[0.9580, 0.8473], [0.0005, 0.2097]],
+                [[0.9103, 0.4700], [0.9804, 0.3974]],
+            ],
+        ]);
+
+        let output = module.forward(input);
+
+        assert!(module.gamma.is_none());
+        assert!(module.beta.is_none());
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [
+                    [[-0.9466, -0.7346], [-1.6032, 0.1757]],
+                    [[0.0798, 1.7278], [0.4021, 0.8991]],
+                    [[-0.7422, -0.0721], [-0.9174, -1.6556]],
+                    [[1.3099, 1.3016], [0.6607, 0.1150]],
+                ],
+                [
+                    [[-0.3948, -0.7408], [-0.5011, -1.3854]],
+                    [[1.4278, -0.0509], [1.7524, -0.1071]],
+                    [[1.0219, 0.7088], [-1.6863, -1.0946]],
+                    [[0.8870, -0.3584], [1.0853, -0.5637]],
+                ],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn group_norm_channels_not_divisible_by_groups() {
+        GroupNormConfig::new(4, 6).init::<TestBackend, 1>();
+    }
+}
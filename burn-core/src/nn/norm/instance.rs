@@ -0,0 +1,302 @@
+use crate as burn;
+
+use crate::{
+    config::Config,
+    module::{Module, Param, RunningState},
+    tensor::{backend::Backend, Tensor},
+};
+
+/// Configuration to create an [InstanceNorm](InstanceNorm) layer.
+#[derive(Config, Debug)]
+pub struct InstanceNormConfig {
+    /// The number of channels expected in the input.
+    pub num_channels: usize,
+    /// A value required for numerical stability. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub epsilon: f64,
+    /// Momentum used to update the running statistics. Default: 0.1
+    #[config(default = 0.1)]
+    pub momentum: f64,
+    /// If a learnable per-channel affine transformation is applied. Default: false
+    #[config(default = false)]
+    pub affine: bool,
+    /// If running statistics are tracked during training and used during inference.
+    /// Default: false
+    #[config(default = false)]
+    pub track_running_stats: bool,
+}
+
+/// Applies Instance Normalization over a tensor as described in the paper [Instance Normalization](https://arxiv.org/abs/1607.08022).
+///
+/// Each channel of each sample is normalized with its own statistics computed over the
+/// spatial dimensions. When running statistics are tracked, they are used instead during
+/// inference.
+///
+/// `Y = instancenorm(X) * γ + β`
+#[derive(Module, Debug)]
+pub struct InstanceNorm<B: Backend, const D: usize> {
+    gamma: Option<Param<Tensor<B, 1>>>,
+    beta: Option<Param<Tensor<B, 1>>>,
+    running_mean: Option<RunningState<Tensor<B, 1>>>,
+    running_var: Option<RunningState<Tensor<B, 1>>>,
+    momentum: f64,
+    epsilon: f64,
+}
+
+impl InstanceNormConfig {
+    /// Initialize a new [instance norm](InstanceNorm) module.
+    pub fn init<B: Backend, const D: usize>(&self) -> InstanceNorm<B, D> {
+        let (gamma, beta) = match self.affine {
+            true => (
+                Some(Param::from(Tensor::ones([self.num_channels]))),
+                Some(Param::from(Tensor::zeros([self.num_channels]))),
+            ),
+            false => (None, None),
+        };
+
+        let (running_mean, running_var) = match self.track_running_stats {
+            true => (
+                Some(RunningState::new(Tensor::zeros([self.num_channels]))),
+                Some(RunningState::new(Tensor::ones([self.num_channels]))),
+            ),
+            false => (None, None),
+        };
+
+        InstanceNorm {
+            gamma,
+            beta,
+            running_mean,
+            running_var,
+            momentum: self.momentum,
+            epsilon: self.epsilon,
+        }
+    }
+
+    /// Initialize a new [instance norm](InstanceNorm) module with a [record](InstanceNormRecord).
+    pub fn init_with<B: Backend, const D: usize>(
+        &self,
+        record: InstanceNormRecord<B, D>,
+    ) -> InstanceNorm<B, D> {
+        InstanceNorm {
+            gamma: record.gamma,
+            beta: record.beta,
+            running_mean: record.running_mean.map(RunningState::from_record),
+            running_var: record.running_var.map(RunningState::from_record),
+            momentum: self.momentum,
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+impl<const D: usize, B: Backend> InstanceNorm<B, D> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, ...]`
+    /// - output: `[batch_size, channels, ...]`
+    pub fn forward<const DI: usize>(&self, input: Tensor<B, DI>) -> Tensor<B, DI> {
+        // Should be move to a compilation error when const generic support that kind of
+        // validation. https://github.com/rust-lang/rust/issues/76560
+        if D + 2 != DI {
+            panic!("InstanceNorm{}D can only be applied on tensors of size {} with the following shape [batch_size, channels, ...], received {}D tensor", D, D+2, DI);
+        }
+
+        match (B::ad_enabled(), &self.running_mean, &self.running_var) {
+            (false, Some(running_mean), Some(running_var)) => {
+                self.forward_inference(input, running_mean, running_var)
+            }
+            _ => self.forward_train(input),
+        }
+    }
+
+    fn forward_inference<const DI: usize>(
+        &self,
+        input: Tensor<B, DI>,
+        running_mean: &RunningState<Tensor<B, 1>>,
+        running_var: &RunningState<Tensor<B, 1>>,
+    ) -> Tensor<B, DI> {
+        let channels = input.dims()[1];
+        let mut shape = [1; DI];
+        shape[1] = channels;
+
+        let mean = running_mean.value().reshape(shape);
+        let var = running_var.value().reshape(shape);
+
+        self.forward_shared(input, mean, var)
+    }
+
+    fn forward_train<const DI: usize>(&self, input: Tensor<B, DI>) -> Tensor<B, DI> {
+        let shape = input.shape();
+        let batch_size = shape.dims[0];
+        let channels = shape.dims[1];
+        let spatial_size = shape.num_elements() / (batch_size * channels);
+
+        let input = input.reshape([batch_size, channels, spatial_size]);
+        let (var, mean) = input.clone().var_mean_bias(2);
+
+        if let (Some(running_mean), Some(running_var)) = (&self.running_mean, &self.running_var) {
+            let batch_mean = mean
+                .clone()
+                .detach()
+                .reshape([batch_size, channels])
+                .mean_dim(0)
+                .reshape([channels]);
+            let batch_var = var
+                .clone()
+                .detach()
+                .reshape([batch_size, channels])
+                .mean_dim(0)
+                .reshape([channels]);
+
+            let running_mean_value = running_mean
+                .value_sync()
+                .mul_scalar(1.0 - self.momentum)
+                .add(batch_mean.mul_scalar(self.momentum));
+            let running_var_value = running_var
+                .value_sync()
+                .mul_scalar(1.0 - self.momentum)
+                .add(batch_var.mul_scalar(self.momentum));
+
+            running_mean.update(running_mean_value.detach());
+            running_var.update(running_var_value.detach());
+        }
+
+        self.forward_shared(input, mean, var).reshape(shape)
+    }
+
+    fn forward_shared<const DI: usize>(
+        &self,
+        x: Tensor<B, DI>,
+        mean: Tensor<B, DI>,
+        var: Tensor<B, DI>,
+    ) -> Tensor<B, DI> {
+        let std = var.add_scalar(self.epsilon).sqrt();
+        let x = x.sub(mean).div(std);
+
+        match (&self.gamma, &self.beta) {
+            (Some(gamma), Some(beta)) => {
+                let channels = x.dims()[1];
+                let mut shape = [1; DI];
+                shape[1] = channels;
+
+                x.mul(gamma.val().reshape(shape))
+                    .add(beta.val().reshape(shape))
+            }
+            _ => x,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn instance_norm_forward_1d() {
+        let module = InstanceNormConfig::new(3).init::<TestBackend, 1>();
+
+        let output = module.forward(input_tensor());
+
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [
+                    [-1.3298, -0.2944, 0.1707, 1.4535],
+                    [0.5965, 1.1386, -1.5164, -0.2188],
+                    [0.8825, -0.0079, 0.7540, -1.6286],
+                ],
+                [
+                    [0.0839, -1.6529, 0.6670, 0.9020],
+                    [-1.0159, -0.4134, -0.2276, 1.6568],
+                    [0.9494, -0.9662, 1.0487, -1.0319],
+                ],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn instance_norm_forward_2d_affine() {
+        let module = InstanceNormConfig::new(2)
+            .with_affine(true)
+            .init::<TestBackend, 2>();
+        let input = Tensor::<TestBackend, 4>::from_floats([[
+            [[0.6175, 0.1267, 0.0018], [0.8714, 0.2095, 0.2155]],
+            [[0.9824, 0.8724, 0.2893], [0.9615, 0.5392, 0.6778]],
+        ]]);
+
+        let output = module.forward(input);
+
+        assert!(module.gamma.is_some());
+        output.to_data().assert_approx_eq(
+            &Data::from([[
+                [[0.9137, -0.7046, -1.1165], [1.7509, -0.4316, -0.4118]],
+                [[1.0543, 0.6116, -1.7351], [0.9702, -0.7294, -0.1716]],
+            ]]),
+            3,
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn instance_norm_running_stats() {
+        use crate::{module::ADModule, TestADBackend};
+
+        let module = InstanceNormConfig::new(3)
+            .with_track_running_stats(true)
+            .init::<TestADBackend, 1>();
+
+        module.forward(input_tensor());
+
+        module
+            .running_mean
+            .as_ref()
+            .unwrap()
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0617, 0.0448, 0.0558]), 3);
+        module
+            .running_var
+            .as_ref()
+            .unwrap()
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.9015, 0.9114, 0.9105]), 3);
+
+        let module = module.valid();
+        let output = module.forward(input_tensor());
+
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [
+                    [0.5911, 0.7163, 0.7725, 0.9277],
+                    [0.7281, 0.9192, -0.0165, 0.4408],
+                    [0.9302, 0.6216, 0.8856, 0.0601],
+                ],
+                [
+                    [0.4291, 0.1947, 0.5078, 0.5395],
+                    [-0.0332, 0.1801, 0.2459, 0.9129],
+                    [0.7439, 0.1087, 0.7768, 0.0869],
+                ],
+            ]),
+            3,
+        );
+    }
+
+    fn input_tensor<B: Backend>() -> Tensor<B, 3> {
+        Tensor::<B, 3>::from_floats([
+            [
+                [0.6229, 0.7418, 0.7952, 0.9425],
+                [0.7399, 0.9223, 0.0290, 0.4656],
+                [0.9434, 0.6490, 0.9009, 0.1132],
+            ],
+            [
+                [0.4691, 0.2466, 0.5438, 0.5739],
+                [0.0131, 0.2167, 0.2795, 0.9163],
+                [0.7657, 0.1596, 0.7971, 0.1388],
+            ],
+        ])
+    }
+}
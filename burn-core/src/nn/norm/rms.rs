@@ -0,0 +1,137 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [RMSNorm](RMSNorm) layer.
+#[derive(Config, Debug)]
+pub struct RMSNormConfig {
+    /// The size of the input features.
+    pub d_model: usize,
+    /// A value required for numerical stability. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub epsilon: f64,
+    /// If a learnable scale is applied to the normalized output. Default: true
+    #[config(default = true)]
+    pub affine: bool,
+}
+
+/// Applies Root Mean Square Layer Normalization over an input tensor as described in the paper
+/// [Root Mean Square Layer Normalization](https://arxiv.org/abs/1910.07467).
+///
+/// `Y = X / sqrt(mean(X^2) + ε) * γ`
+#[derive(Module, Debug)]
+pub struct RMSNorm<B: Backend> {
+    gamma: Option<Param<Tensor<B, 1>>>,
+    epsilon: f64,
+}
+
+impl RMSNormConfig {
+    /// Initialize a new [RMS norm](RMSNorm) module.
+    pub fn init<B: Backend>(&self) -> RMSNorm<B> {
+        let gamma = match self.affine {
+            true => Some(Param::from(Tensor::ones([self.d_model]))),
+            false => None,
+        };
+
+        RMSNorm {
+            gamma,
+            epsilon: self.epsilon,
+        }
+    }
+
+    /// Initialize a new [RMS norm](RMSNorm) module with a [record](RMSNormRecord).
+    pub fn init_with<B: Backend>(&self, record: RMSNormRecord<B>) -> RMSNorm<B> {
+        RMSNorm {
+            gamma: record.gamma,
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+impl<B: Backend> RMSNorm<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_model]`
+    /// - output: `[..., any, d_model]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let rms = input
+            .clone()
+            .powf(2.0)
+            .mean_dim(D - 1)
+            .add_scalar(self.epsilon)
+            .sqrt();
+        let input_normalized = input.div(rms);
+
+        match &self.gamma {
+            Some(gamma) => input_normalized.mul(gamma.val().unsqueeze()),
+            None => input_normalized,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[cfg(feature = "std")]
+    use crate::{TestADBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[test]
+    fn rms_norm_forward() {
+        let module = RMSNormConfig::new(5).init::<TestBackend>();
+        let input = Tensor::from_data(Data::from([
+            [0.2048, 0.9410, 0.6906, 0.9666, 0.8937],
+            [0.2988, 0.3612, 0.1660, 0.1457, 0.0651],
+        ]));
+
+        let output = module.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [0.2585, 1.1879, 0.8718, 1.2202, 1.1282],
+                [1.2792, 1.5463, 0.7106, 0.6237, 0.2787],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn rms_norm_without_affine() {
+        let module = RMSNormConfig::new(5)
+            .with_affine(false)
+            .init::<TestBackend>();
+
+        assert!(module.gamma.is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn rms_norm_backward() {
+        let module = RMSNormConfig::new(2).init::<TestADBackend>();
+        let input = Tensor::<TestADBackend, 2>::from_data(Data::from([[3.0, 4.0], [1.0, 1.0]]))
+            .require_grad();
+
+        let output = module.forward(input.clone());
+        let grads = output.backward();
+
+        let gamma_grad = module.gamma.as_ref().unwrap().grad(&grads).unwrap();
+        let input_grad = input.grad(&grads).unwrap();
+
+        gamma_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([1.8485, 2.1314]), 3);
+        input_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0452, -0.0339], [0.0, 0.0]]), 3);
+    }
+}
@@ -0,0 +1,66 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::nn::PaddingConfig3d;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::module::max_pool3d;
+
+/// Configuration to create an [3D max pooling](MaxPool3d) layer.
+#[derive(Debug, Config)]
+pub struct MaxPool3dConfig {
+    /// The size of the kernel.
+    pub kernel_size: [usize; 3],
+    /// The strides.
+    #[config(default = "[1, 1, 1]")]
+    pub strides: [usize; 3],
+    /// The padding configuration.
+    #[config(default = "PaddingConfig3d::Valid")]
+    pub padding: PaddingConfig3d,
+    /// The dilation.
+    #[config(default = "[1, 1, 1]")]
+    pub dilation: [usize; 3],
+}
+
+/// Applies a 3D max pooling over input tensors.
+#[derive(Module, Debug, Clone)]
+pub struct MaxPool3d {
+    stride: [usize; 3],
+    kernel_size: [usize; 3],
+    padding: PaddingConfig3d,
+    dilation: [usize; 3],
+}
+
+impl MaxPool3dConfig {
+    /// Initialize a new [max pool 3d](MaxPool3d) module.
+    pub fn init(&self) -> MaxPool3d {
+        MaxPool3d {
+            stride: self.strides,
+            kernel_size: self.kernel_size,
+            padding: self.padding.clone(),
+            dilation: self.dilation,
+        }
+    }
+}
+
+impl MaxPool3d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels, depth_in, height_in, width_in],
+    /// - output: [batch_size, channels, depth_out, height_out, width_out],
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 5>) -> Tensor<B, 5> {
+        let [_batch_size, _channels_in, depth_in, height_in, width_in] = input.dims();
+        let padding = self.padding.calculate_padding_3d(
+            depth_in,
+            height_in,
+            width_in,
+            &self.kernel_size,
+            &self.stride,
+        );
+
+        max_pool3d(input, self.kernel_size, self.stride, padding, self.dilation)
+    }
+}
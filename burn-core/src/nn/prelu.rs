@@ -0,0 +1,81 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [PReLU](PReLU) layer.
+#[derive(Config, Debug)]
+pub struct PReLUConfig {
+    /// The number of learnable parameters, either 1 or the number of input channels. Default: 1
+    #[config(default = 1)]
+    pub num_parameters: usize,
+    /// The initial value of the learnable parameters. Default: 0.25
+    #[config(default = 0.25)]
+    pub alpha: f64,
+}
+
+/// Applies the parametric rectified linear unit function element-wise:
+///
+/// `y = max(0, x) + alpha * min(0, x)`
+///
+/// # Params
+///
+/// - alpha: Tensor of shape `[num_parameters]`
+#[derive(Module, Debug)]
+pub struct PReLU<B: Backend> {
+    alpha: Param<Tensor<B, 1>>,
+}
+
+impl PReLUConfig {
+    /// Initialize a new [prelu](PReLU) module.
+    pub fn init<B: Backend>(&self) -> PReLU<B> {
+        let alpha = Tensor::ones([self.num_parameters]).mul_scalar(self.alpha);
+
+        PReLU {
+            alpha: Param::from(alpha),
+        }
+    }
+
+    /// Initialize a new [prelu](PReLU) module with a [record](PReLURecord).
+    pub fn init_with<B: Backend>(&self, record: PReLURecord<B>) -> PReLU<B> {
+        PReLU {
+            alpha: record.alpha,
+        }
+    }
+}
+
+impl<B: Backend> PReLU<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, ...]`
+    /// - output: `[batch_size, channels, ...]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        crate::tensor::activation::prelu(input, self.alpha.val())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn prelu_forward_per_channel() {
+        let module = PReLUConfig::new()
+            .with_num_parameters(2)
+            .init::<TestBackend>();
+        let input = Tensor::<TestBackend, 3>::from_floats([[[-1.0, 2.0], [-4.0, 0.5]]]);
+
+        let output = module.forward(input);
+
+        output
+            .to_data()
+            .assert_approx_eq(&Data::from([[[-0.25, 2.0], [-1.0, 0.5]]]), 3);
+    }
+}
@@ -5,6 +5,7 @@ use crate::nn::Initializer;
 use crate::nn::Linear;
 use crate::nn::LinearConfig;
 use burn_tensor::backend::Backend;
+use burn_tensor::Tensor;
 
 /// A GateController represents a gate in an LSTM cell. An
 /// LSTM cell generally contains three gates: an input gate,
@@ -53,6 +54,40 @@ impl<B: Backend> GateController<B> {
         }
     }
 
+    /// Helper function for performing weighted matrix product for a gate and adds
+    /// bias, if any.
+    ///
+    ///  Mathematically, performs `Wx*X + Wh*H + b`, where:
+    ///     Wx = weight matrix for the connection to input vector X
+    ///     Wh = weight matrix for the connection to hidden state H
+    ///     X = input vector
+    ///     H = hidden state
+    ///     b = bias terms
+    pub(crate) fn gate_product(&self, input: &Tensor<B, 2>, hidden: &Tensor<B, 2>) -> Tensor<B, 2> {
+        let input_product = input.clone().matmul(self.input_transform.weight.val());
+        let hidden_product = hidden.clone().matmul(self.hidden_transform.weight.val());
+
+        let input_bias = self
+            .input_transform
+            .bias
+            .as_ref()
+            .map(|bias_param| bias_param.val());
+        let hidden_bias = self
+            .hidden_transform
+            .bias
+            .as_ref()
+            .map(|bias_param| bias_param.val());
+
+        match (input_bias, hidden_bias) {
+            (Some(input_bias), Some(hidden_bias)) => {
+                input_product + input_bias.unsqueeze() + hidden_product + hidden_bias.unsqueeze()
+            }
+            (Some(input_bias), None) => input_product + input_bias.unsqueeze() + hidden_product,
+            (None, Some(hidden_bias)) => input_product + hidden_product + hidden_bias.unsqueeze(),
+            (None, None) => input_product + hidden_product,
+        }
+    }
+
     /// Used to initialize a gate controller with known weight layers,
     /// allowing for predictable behavior. Used only for testing in
     /// lstm.
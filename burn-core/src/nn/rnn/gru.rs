@@ -1,12 +1,18 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
 use crate as burn;
 
 use crate::config::Config;
-use crate::module::Module;
+use crate::module::{ADModule, Module, ModuleMapper, ModuleVisitor};
 use crate::nn::rnn::gate_controller;
+use crate::nn::rnn::sequence::{masked_update, sequence_masks};
 use crate::nn::Initializer;
 use crate::nn::LinearConfig;
-use crate::tensor::backend::Backend;
-use crate::tensor::Tensor;
+use crate::nn::{Dropout, DropoutConfig};
+use crate::record::Record;
+use crate::tensor::backend::{ADBackend, Backend};
+use crate::tensor::{Int, Tensor};
 use burn_tensor::activation;
 
 use super::gate_controller::GateController;
@@ -23,69 +29,184 @@ pub struct GruConfig {
     /// Gru initializer
     #[config(default = "Initializer::XavierNormal{gain:1.0}")]
     pub initializer: Initializer,
+    /// The number of stacked layers, each layer taking the hidden states of the previous one as
+    /// input. Default: 1
+    #[config(default = 1)]
+    pub num_layers: usize,
+    /// If each layer should also process the sequence in reverse order, the hidden states of both
+    /// directions being concatenated. Default: false
+    #[config(default = false)]
+    pub bidirectional: bool,
+    /// The dropout rate applied to the hidden states of each layer except the last one.
+    /// Default: 0.0
+    #[config(default = 0.0)]
+    pub dropout: f64,
 }
 
-/// The Gru module. This implementation is for a unidirectional, stateless, Gru.
-#[derive(Module, Debug)]
+/// The Gru module, made of `num_layers` stacked layers, each one processing the sequence in one or
+/// both directions.
+#[derive(Debug, Clone)]
 pub struct Gru<B: Backend> {
+    first_layer: GruCell<B>,
+    // The other layers are only present when configured.
+    stacked_layers: Option<Vec<GruCell<B>>>,
+    reverse_layers: Option<Vec<GruCell<B>>>,
+    dropout: Dropout,
+}
+
+/// The record type for the [gru](Gru) module.
+///
+/// The gates of the first layer in the forward direction are top-level fields, so the records of
+/// single layer grus keep the layout they had before multiple layers were supported.
+#[derive(Record, Debug, Clone)]
+pub struct GruRecord<B: Backend> {
+    /// The update gate of the first layer.
+    pub update_gate: <GateController<B> as Module<B>>::Record,
+    /// The reset gate of the first layer.
+    pub reset_gate: <GateController<B> as Module<B>>::Record,
+    /// The new gate of the first layer.
+    pub new_gate: <GateController<B> as Module<B>>::Record,
+    /// The size of the hidden state.
+    pub d_hidden: <usize as Module<B>>::Record,
+    /// The other layers in the forward direction.
+    pub stacked_layers: <Option<Vec<GruCell<B>>> as Module<B>>::Record,
+    /// The layers in the reverse direction.
+    pub reverse_layers: <Option<Vec<GruCell<B>>> as Module<B>>::Record,
+    /// The dropout between the layers.
+    pub dropout: <Dropout as Module<B>>::Record,
+}
+
+/// A single direction of a [gru](Gru) layer.
+#[derive(Module, Debug)]
+pub struct GruCell<B: Backend> {
     update_gate: GateController<B>,
     reset_gate: GateController<B>,
     new_gate: GateController<B>,
     d_hidden: usize,
 }
 
+/// [Gru](Gru) outputs.
+#[derive(Debug, Clone)]
+pub struct GruOutput<B: Backend> {
+    /// The hidden states of the last layer for each timestep
+    /// `[batch_size, seq_length, num_directions * d_hidden]`.
+    pub hidden_states: Tensor<B, 3>,
+    /// The final hidden state of each layer and direction
+    /// `[num_layers * num_directions, batch_size, d_hidden]`, ordered by layer, the forward
+    /// direction of a layer coming before its reverse direction.
+    pub state: Tensor<B, 3>,
+}
+
 impl GruConfig {
     /// Initialize a new [gru](Gru) module.
     pub fn init<B: Backend>(&self) -> Gru<B> {
-        let d_output = self.d_hidden;
+        let init_cell = |layer| {
+            let d_input = self.d_input_layer(layer);
+            let gate = || {
+                gate_controller::GateController::new(
+                    d_input,
+                    self.d_hidden,
+                    self.bias,
+                    self.initializer.clone(),
+                )
+            };
 
-        let update_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-        );
-        let reset_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-        );
-        let new_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-        );
+            GruCell {
+                update_gate: gate(),
+                reset_gate: gate(),
+                new_gate: gate(),
+                d_hidden: self.d_hidden,
+            }
+        };
+
+        let stacked_layers =
+            (self.num_layers > 1).then(|| (1..self.num_layers).map(init_cell).collect());
+        let reverse_layers = self
+            .bidirectional
+            .then(|| (0..self.num_layers).map(init_cell).collect());
 
         Gru {
-            update_gate,
-            reset_gate,
-            new_gate,
-            d_hidden: self.d_hidden,
+            first_layer: init_cell(0),
+            stacked_layers,
+            reverse_layers,
+            dropout: DropoutConfig::new(self.dropout).init(),
         }
     }
 
     /// Initialize a new [gru](Gru) module.
+    ///
+    /// # Panics
+    ///
+    /// If the record doesn't have the number of layers and directions of the config.
     pub fn init_with<B: Backend>(self, record: GruRecord<B>) -> Gru<B> {
-        let linear_config = LinearConfig {
-            d_input: self.d_input,
+        let num_layers = 1 + record.stacked_layers.as_ref().map_or(0, Vec::len);
+        let num_reverse_layers = record.reverse_layers.as_ref().map_or(0, Vec::len);
+        let num_reverse_layers_config = usize::from(self.bidirectional) * self.num_layers;
+        assert_eq!(
+            num_layers, self.num_layers,
+            "The gru record should have {} layers as configured, but has {num_layers}",
+            self.num_layers
+        );
+        assert_eq!(
+            num_reverse_layers, num_reverse_layers_config,
+            "The gru record should have {num_reverse_layers_config} reverse layers as configured, \
+             but has {num_reverse_layers}"
+        );
+
+        let linear_config = |layer| LinearConfig {
+            d_input: self.d_input_layer(layer),
             d_output: self.d_hidden,
             bias: self.bias,
             initializer: self.initializer.clone(),
         };
+        let init_cell = |(layer, record): (usize, GruCellRecord<B>)| {
+            let linear_config = linear_config(layer);
+
+            GruCell {
+                update_gate: gate_controller::GateController::new_with(
+                    &linear_config,
+                    record.update_gate,
+                ),
+                reset_gate: gate_controller::GateController::new_with(
+                    &linear_config,
+                    record.reset_gate,
+                ),
+                new_gate: gate_controller::GateController::new_with(
+                    &linear_config,
+                    record.new_gate,
+                ),
+                d_hidden: self.d_hidden,
+            }
+        };
+
+        let first_layer = GruCellRecord {
+            update_gate: record.update_gate,
+            reset_gate: record.reset_gate,
+            new_gate: record.new_gate,
+            d_hidden: record.d_hidden,
+        };
 
         Gru {
-            update_gate: gate_controller::GateController::new_with(
-                &linear_config,
-                record.update_gate,
-            ),
-            reset_gate: gate_controller::GateController::new_with(
-                &linear_config,
-                record.reset_gate,
-            ),
-            new_gate: gate_controller::GateController::new_with(&linear_config, record.new_gate),
-            d_hidden: self.d_hidden,
+            first_layer: init_cell((0, first_layer)),
+            stacked_layers: record.stacked_layers.map(|layers| {
+                layers
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, record)| init_cell((index + 1, record)))
+                    .collect()
+            }),
+            reverse_layers: record
+                .reverse_layers
+                .map(|layers| layers.into_iter().enumerate().map(init_cell).collect()),
+            dropout: DropoutConfig::new(self.dropout).init(),
+        }
+    }
+
+    fn d_input_layer(&self, layer: usize) -> usize {
+        match (layer, self.bidirectional) {
+            (0, _) => self.d_input,
+            (_, true) => 2 * self.d_hidden,
+            (_, false) => self.d_hidden,
         }
     }
 }
@@ -96,105 +217,258 @@ impl<B: Backend> Gru<B> {
     ///
     /// Parameters:
     ///     batched_input: The input tensor of shape [batch_size, sequence_length, input_size].
-    ///     state: An optional tensor representing an initial cell state with the same dimensions
-    ///            as batched_input. If none is provided, one will be generated.
+    ///     state: An optional tensor representing the initial hidden state, with shape
+    ///            [batch_size, hidden_size], used as the initial state of every layer and
+    ///            direction. If none is provided, one will be generated.
     ///
     /// Returns:
-    ///     The resulting state tensor, with shape [batch_size, sequence_length, hidden_size].
+    ///     The hidden states of the last layer, with shape [batch_size, sequence_length, hidden_size],
+    ///     the hidden size being doubled when bidirectional.
     pub fn forward(
         &self,
         batched_input: Tensor<B, 3>,
-        state: Option<Tensor<B, 3>>,
+        state: Option<Tensor<B, 2>>,
     ) -> Tensor<B, 3> {
-        let [batch_size, seq_length, _] = batched_input.shape().dims;
+        let num_states = self.layers().len() + self.reverse_layers().len();
+        let state = state.map(|state| state.unsqueeze().repeat(0, num_states));
 
-        let mut hidden_state = match state {
-            Some(state) => state,
-            None => Tensor::zeros([batch_size, seq_length, self.d_hidden]),
-        };
+        self.forward_masked(batched_input, None, state)
+            .hidden_states
+    }
 
-        for (t, (input_t, hidden_t)) in batched_input
-            .iter_dim(1)
-            .zip(hidden_state.clone().iter_dim(1))
-            .enumerate()
-        {
-            let input_t = input_t.squeeze(1);
-            let hidden_t = hidden_t.squeeze(1);
-            // u(pdate)g(ate) tensors
-            let biased_ug_input_sum = self.gate_product(&input_t, &hidden_t, &self.update_gate);
-            let update_values = activation::sigmoid(biased_ug_input_sum); // Colloquially referred to as z(t)
-
-            // r(eset)g(ate) tensors
-            let biased_rg_input_sum = self.gate_product(&input_t, &hidden_t, &self.reset_gate);
-            let reset_values = activation::sigmoid(biased_rg_input_sum); // Colloquially referred to as r(t)
-            let reset_t = hidden_t.clone().mul(reset_values); // Passed as input to new_gate
-
-            // n(ew)g(ate) tensor
-            let biased_ng_input_sum = self.gate_product(&input_t, &reset_t, &self.new_gate);
-            let candidate_state = biased_ng_input_sum.tanh(); // Colloquially referred to as g(t)
-
-            // calculate linear interpolation between previous hidden state and candidate state:
-            // g(t) * (1 - z(t)) + z(t) * hidden_t
-            let state_vector = candidate_state
+    /// Applies the forward pass on a batch of padded sequences of different lengths.
+    ///
+    /// The state of each sequence stops being updated past its length, so the final state is the
+    /// one of its last element, and the reverse direction starts from its last element. The
+    /// hidden states of the padded elements are set to zero.
+    ///
+    /// # Shapes
+    ///
+    /// - batched_input: `[batch_size, seq_length, d_input]`
+    /// - lengths: `[batch_size]`
+    /// - state: `[num_layers * num_directions, batch_size, d_hidden]`
+    pub fn forward_with_lengths(
+        &self,
+        batched_input: Tensor<B, 3>,
+        lengths: Tensor<B, 1, Int>,
+        state: Option<Tensor<B, 3>>,
+    ) -> GruOutput<B> {
+        self.forward_masked(batched_input, Some(lengths), state)
+    }
+
+    fn forward_masked(
+        &self,
+        batched_input: Tensor<B, 3>,
+        lengths: Option<Tensor<B, 1, Int>>,
+        state: Option<Tensor<B, 3>>,
+    ) -> GruOutput<B> {
+        let [batch_size, seq_length, _] = batched_input.dims();
+        let masks = lengths.map(|lengths| sequence_masks(lengths, seq_length));
+        let layers = self.layers();
+        let reverse_layers = self.reverse_layers();
+        let num_directions = 1 + usize::from(!reverse_layers.is_empty());
+        let d_hidden = self.first_layer.d_hidden;
+
+        let initial_state = |index: usize| match &state {
+            Some(state) => state
                 .clone()
-                .mul(update_values.clone().sub_scalar(1).mul_scalar(-1)) // (1 - z(t)) = -(z(t) - 1)
-                + update_values.clone().mul(hidden_t);
-
-            let current_shape = state_vector.shape().dims;
-            let unsqueezed_shape = [current_shape[0], 1, current_shape[1]];
-            let reshaped_state_vector = state_vector.reshape(unsqueezed_shape);
-            hidden_state = hidden_state.slice_assign(
-                [0..batch_size, t..(t + 1), 0..self.d_hidden],
-                reshaped_state_vector,
+                .slice([index..index + 1, 0..batch_size, 0..d_hidden])
+                .squeeze(0),
+            None => Tensor::zeros([batch_size, d_hidden]),
+        };
+
+        let mut final_states = Vec::with_capacity(layers.len() * num_directions);
+        let mut layer_input = batched_input;
+
+        for (layer, cell) in layers.iter().enumerate() {
+            if layer > 0 {
+                layer_input = self.dropout.forward(layer_input);
+            }
+
+            let index = layer * num_directions;
+            let (mut hidden_states, state) = cell.forward(
+                layer_input.clone(),
+                masks.as_deref(),
+                initial_state(index),
+                false,
             );
+            final_states.push(state.unsqueeze());
+
+            if let Some(reverse_cell) = reverse_layers.get(layer) {
+                let (reverse_hidden_states, state) = reverse_cell.forward(
+                    layer_input,
+                    masks.as_deref(),
+                    initial_state(index + 1),
+                    true,
+                );
+                final_states.push(state.unsqueeze());
+
+                hidden_states = Tensor::cat(vec![hidden_states, reverse_hidden_states], 2);
+            }
+
+            layer_input = hidden_states;
         }
 
-        hidden_state
+        GruOutput {
+            hidden_states: layer_input,
+            state: Tensor::cat(final_states, 0),
+        }
     }
 
-    /// Helper function for performing weighted matrix product for a gate and adds
-    /// bias, if any.
-    ///
-    ///  Mathematically, performs `Wx*X + Wh*H + b`, where:
-    ///     Wx = weight matrix for the connection to input vector X
-    ///     Wh = weight matrix for the connection to hidden state H
-    ///     X = input vector
-    ///     H = hidden state
-    ///     b = bias terms
-    fn gate_product(
+    /// The layers in the forward direction.
+    fn layers(&self) -> Vec<&GruCell<B>> {
+        core::iter::once(&self.first_layer)
+            .chain(self.stacked_layers.iter().flatten())
+            .collect()
+    }
+
+    /// The layers in the reverse direction, empty when the gru isn't bidirectional.
+    fn reverse_layers(&self) -> Vec<&GruCell<B>> {
+        self.reverse_layers.iter().flatten().collect()
+    }
+}
+
+impl<B: Backend> Module<B> for Gru<B> {
+    type Record = GruRecord<B>;
+
+    fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
+        self.first_layer.visit(visitor);
+        self.stacked_layers.visit(visitor);
+        self.reverse_layers.visit(visitor);
+    }
+
+    fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
+        Self {
+            first_layer: self.first_layer.map(mapper),
+            stacked_layers: Module::<B>::map(self.stacked_layers, mapper),
+            reverse_layers: Module::<B>::map(self.reverse_layers, mapper),
+            dropout: self.dropout,
+        }
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        let first_layer = GruCellRecord {
+            update_gate: record.update_gate,
+            reset_gate: record.reset_gate,
+            new_gate: record.new_gate,
+            d_hidden: record.d_hidden,
+        };
+
+        Self {
+            first_layer: self.first_layer.load_record(first_layer),
+            stacked_layers: self.stacked_layers.load_record(record.stacked_layers),
+            reverse_layers: self.reverse_layers.load_record(record.reverse_layers),
+            dropout: Module::<B>::load_record(self.dropout, record.dropout),
+        }
+    }
+
+    fn into_record(self) -> Self::Record {
+        let first_layer = self.first_layer.into_record();
+
+        GruRecord {
+            update_gate: first_layer.update_gate,
+            reset_gate: first_layer.reset_gate,
+            new_gate: first_layer.new_gate,
+            d_hidden: first_layer.d_hidden,
+            stacked_layers: self.stacked_layers.into_record(),
+            reverse_layers: self.reverse_layers.into_record(),
+            dropout: Module::<B>::into_record(self.dropout),
+        }
+    }
+}
+
+impl<B: ADBackend> ADModule<B> for Gru<B> {
+    type InnerModule = Gru<B::InnerBackend>;
+
+    fn valid(&self) -> Self::InnerModule {
+        Gru {
+            first_layer: self.first_layer.valid(),
+            stacked_layers: self.stacked_layers.valid(),
+            reverse_layers: self.reverse_layers.valid(),
+            dropout: self.dropout.clone(),
+        }
+    }
+}
+
+impl<B: Backend> core::fmt::Display for Gru<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Gru[num_params={}]", self.num_params())
+    }
+}
+
+impl<B: Backend> GruCell<B> {
+    /// Processes the sequence one element at a time, in reverse order if `reverse` is set,
+    /// returning the hidden state of each element along with the final state.
+    fn forward(
         &self,
-        input: &Tensor<B, 2>,
-        hidden: &Tensor<B, 2>,
-        gate: &GateController<B>,
-    ) -> Tensor<B, 2> {
-        let input_product = input.clone().matmul(gate.input_transform.weight.val());
-        let hidden_product = hidden.clone().matmul(gate.hidden_transform.weight.val());
-
-        let input_bias = gate
-            .input_transform
-            .bias
-            .as_ref()
-            .map(|bias_param| bias_param.val());
-        let hidden_bias = gate
-            .hidden_transform
-            .bias
-            .as_ref()
-            .map(|bias_param| bias_param.val());
-
-        match (input_bias, hidden_bias) {
-            (Some(input_bias), Some(hidden_bias)) => {
-                input_product + input_bias.unsqueeze() + hidden_product + hidden_bias.unsqueeze()
-            }
-            (Some(input_bias), None) => input_product + input_bias.unsqueeze() + hidden_product,
-            (None, Some(hidden_bias)) => input_product + hidden_product + hidden_bias.unsqueeze(),
-            (None, None) => input_product + hidden_product,
+        batched_input: Tensor<B, 3>,
+        masks: Option<&[Tensor<B, 2>]>,
+        state: Tensor<B, 2>,
+        reverse: bool,
+    ) -> (Tensor<B, 3>, Tensor<B, 2>) {
+        let [batch_size, seq_length, _] = batched_input.dims();
+        let inputs: Vec<_> = batched_input.iter_dim(1).collect();
+        let mut hidden_state = state;
+
+        let mut batched_hidden_state = Vec::with_capacity(seq_length);
+        let timesteps: Vec<usize> = match reverse {
+            true => (0..seq_length).rev().collect(),
+            false => (0..seq_length).collect(),
+        };
+
+        for t in timesteps {
+            let input_t = inputs[t].clone().squeeze(1);
+            let next_hidden_state = self.step(input_t, hidden_state.clone());
+
+            let hidden_t = match masks {
+                Some(masks) => {
+                    hidden_state = masked_update(hidden_state, next_hidden_state, &masks[t]);
+                    hidden_state.clone().mul(masks[t].clone())
+                }
+                None => {
+                    hidden_state = next_hidden_state;
+                    hidden_state.clone()
+                }
+            };
+
+            batched_hidden_state.push(hidden_t.reshape([batch_size, 1, self.d_hidden]));
         }
+
+        if reverse {
+            batched_hidden_state.reverse();
+        }
+
+        (Tensor::cat(batched_hidden_state, 1), hidden_state)
+    }
+
+    /// Computes the hidden state of the next element of the sequence.
+    fn step(&self, input_t: Tensor<B, 2>, hidden_t: Tensor<B, 2>) -> Tensor<B, 2> {
+        // u(pdate)g(ate) tensors
+        let biased_ug_input_sum = self.update_gate.gate_product(&input_t, &hidden_t);
+        let update_values = activation::sigmoid(biased_ug_input_sum); // Colloquially referred to as z(t)
+
+        // r(eset)g(ate) tensors
+        let biased_rg_input_sum = self.reset_gate.gate_product(&input_t, &hidden_t);
+        let reset_values = activation::sigmoid(biased_rg_input_sum); // Colloquially referred to as r(t)
+        let reset_t = hidden_t.clone().mul(reset_values); // Passed as input to new_gate
+
+        // n(ew)g(ate) tensor
+        let biased_ng_input_sum = self.new_gate.gate_product(&input_t, &reset_t);
+        let candidate_state = biased_ng_input_sum.tanh(); // Colloquially referred to as g(t)
+
+        // calculate linear interpolation between previous hidden state and candidate state:
+        // g(t) * (1 - z(t)) + z(t) * hidden_t
+        candidate_state
+            .clone()
+            .mul(update_values.clone().sub_scalar(1).mul_scalar(-1)) // (1 - z(t)) = -(z(t) - 1)
+            + update_values.clone().mul(hidden_t)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::record::{FullPrecisionSettings, NamedMpkBytesRecorder, Recorder};
     use crate::{module::Param, nn::LinearRecord, TestBackend};
     use burn_tensor::{Data, Distribution};
 
@@ -233,7 +507,7 @@ mod tests {
             )
         }
 
-        gru.update_gate = create_gate_controller(
+        gru.first_layer.update_gate = create_gate_controller(
             0.5,
             0.0,
             1,
@@ -241,7 +515,7 @@ mod tests {
             false,
             Initializer::XavierNormal { gain: 1.0 },
         );
-        gru.reset_gate = create_gate_controller(
+        gru.first_layer.reset_gate = create_gate_controller(
             0.6,
             0.0,
             1,
@@ -249,7 +523,7 @@ mod tests {
             false,
             Initializer::XavierNormal { gain: 1.0 },
         );
-        gru.new_gate = create_gate_controller(
+        gru.first_layer.new_gate = create_gate_controller(
             0.7,
             0.0,
             1,
@@ -276,4 +550,159 @@ mod tests {
 
         assert_eq!(hidden_state.shape().dims, [8, 10, 1024]);
     }
+
+    #[test]
+    fn test_forward_should_use_the_previous_hidden_state() {
+        let mut gru = GruConfig::new(1, 1, false).init::<TestBackend>();
+        let gate = |weight: f32| {
+            let record = LinearRecord {
+                weight: Param::from(Tensor::from_data(Data::from([[weight]]))),
+                bias: None,
+            };
+            gate_controller::GateController::create_with_weights(
+                1,
+                1,
+                false,
+                Initializer::XavierNormal { gain: 1.0 },
+                record.clone(),
+                record,
+            )
+        };
+        gru.first_layer.update_gate = gate(0.5);
+        gru.first_layer.reset_gate = gate(0.6);
+        gru.first_layer.new_gate = gate(0.7);
+
+        let input = Tensor::<TestBackend, 3>::from_data(Data::from([[[0.1], [0.2]]]));
+
+        let state = gru.forward(input, None);
+
+        state
+            .to_data()
+            .assert_approx_eq(&Data::from([[[0.0341], [0.0894]]]), 3);
+    }
+
+    #[test]
+    fn test_init_with_single_layer_record() {
+        // The module of a single layer gru before multiple layers and directions were supported.
+        #[derive(Module, Debug)]
+        struct LegacyGru<B: Backend> {
+            update_gate: GateController<B>,
+            reset_gate: GateController<B>,
+            new_gate: GateController<B>,
+            d_hidden: usize,
+        }
+
+        let config = GruConfig::new(2, 3, true);
+        let gru = config.init::<TestBackend>();
+        let legacy = LegacyGru {
+            update_gate: gru.first_layer.update_gate.clone(),
+            reset_gate: gru.first_layer.reset_gate.clone(),
+            new_gate: gru.first_layer.new_gate.clone(),
+            d_hidden: 3,
+        };
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::default();
+
+        let bytes = recorder.record(legacy.into_record(), ()).unwrap();
+        let gru_loaded = config.init_with(recorder.load(bytes).unwrap());
+
+        let batched_input = Tensor::<TestBackend, 3>::random([2, 4, 2], Distribution::Default);
+        gru.forward(batched_input.clone(), None)
+            .into_data()
+            .assert_approx_eq(&gru_loaded.forward(batched_input, None).into_data(), 3);
+    }
+
+    #[test]
+    fn test_init_with_multi_layer_record() {
+        let config = GruConfig::new(2, 3, true)
+            .with_num_layers(2)
+            .with_bidirectional(true);
+        let gru = config.init::<TestBackend>();
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::default();
+
+        let bytes = recorder.record(gru.clone().into_record(), ()).unwrap();
+        let gru_loaded = config.init_with(recorder.load(bytes).unwrap());
+
+        assert_eq!(gru.num_params(), gru_loaded.num_params());
+        let batched_input = Tensor::<TestBackend, 3>::random([2, 4, 2], Distribution::Default);
+        gru.forward(batched_input.clone(), None)
+            .into_data()
+            .assert_approx_eq(&gru_loaded.forward(batched_input, None).into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic = "The gru record should have 2 layers as configured, but has 1"]
+    fn test_init_with_record_of_fewer_layers() {
+        let record = GruConfig::new(2, 3, true)
+            .init::<TestBackend>()
+            .into_record();
+
+        GruConfig::new(2, 3, true)
+            .with_num_layers(2)
+            .init_with(record);
+    }
+
+    #[test]
+    #[should_panic = "The gru record should have 1 reverse layers as configured, but has 0"]
+    fn test_init_with_unidirectional_record() {
+        let record = GruConfig::new(2, 3, true)
+            .init::<TestBackend>()
+            .into_record();
+
+        GruConfig::new(2, 3, true)
+            .with_bidirectional(true)
+            .init_with(record);
+    }
+
+    #[test]
+    fn test_bidirectional_multi_layer_forward_pass() {
+        let gru = GruConfig::new(8, 16, true)
+            .with_num_layers(3)
+            .with_bidirectional(true)
+            .init::<TestBackend>();
+        let batched_input = Tensor::<TestBackend, 3>::random([4, 6, 8], Distribution::Default);
+
+        let output = gru.forward_with_lengths(batched_input, Tensor::from_ints([6, 2, 4, 1]), None);
+
+        assert_eq!(output.hidden_states.shape().dims, [4, 6, 32]);
+        assert_eq!(output.state.shape().dims, [6, 4, 16]);
+    }
+
+    #[test]
+    fn test_forward_with_lengths_should_match_unpadded_sequences() {
+        let [seq_length, length, d_input, d_hidden] = [5, 3, 4, 6];
+        let gru = GruConfig::new(d_input, d_hidden, true)
+            .with_num_layers(2)
+            .with_bidirectional(true)
+            .init::<TestBackend>();
+        let batched_input =
+            Tensor::<TestBackend, 3>::random([2, seq_length, d_input], Distribution::Default);
+        let lengths = Tensor::from_ints([seq_length as i32, length as i32]);
+
+        let output = gru.forward_with_lengths(batched_input.clone(), lengths, None);
+        let output_unpadded = gru.forward_with_lengths(
+            batched_input.slice([1..2, 0..length, 0..d_input]),
+            Tensor::from_ints([length as i32]),
+            None,
+        );
+
+        output
+            .hidden_states
+            .clone()
+            .slice([1..2, 0..length, 0..2 * d_hidden])
+            .into_data()
+            .assert_approx_eq(&output_unpadded.hidden_states.into_data(), 3);
+        output
+            .hidden_states
+            .slice([1..2, length..seq_length, 0..2 * d_hidden])
+            .into_data()
+            .assert_approx_eq(
+                &Tensor::<TestBackend, 3>::zeros([1, 2, 2 * d_hidden]).into_data(),
+                3,
+            );
+        output
+            .state
+            .slice([0..4, 1..2, 0..d_hidden])
+            .into_data()
+            .assert_approx_eq(&output_unpadded.state.into_data(), 3);
+    }
 }
@@ -1,12 +1,18 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
 use crate as burn;
 
 use crate::config::Config;
-use crate::module::Module;
+use crate::module::{ADModule, Module, ModuleMapper, ModuleVisitor};
 use crate::nn::rnn::gate_controller;
+use crate::nn::rnn::sequence::{masked_update, sequence_masks};
 use crate::nn::Initializer;
 use crate::nn::LinearConfig;
-use crate::tensor::backend::Backend;
-use crate::tensor::Tensor;
+use crate::nn::{Dropout, DropoutConfig};
+use crate::record::Record;
+use crate::tensor::backend::{ADBackend, Backend};
+use crate::tensor::{Int, Tensor};
 use burn_tensor::activation;
 
 use super::gate_controller::GateController;
@@ -23,11 +29,58 @@ pub struct LstmConfig {
     /// Lstm initializer
     #[config(default = "Initializer::XavierNormal{gain:1.0}")]
     pub initializer: Initializer,
+    /// The number of stacked layers, each layer taking the hidden states of the previous one as
+    /// input. Default: 1
+    #[config(default = 1)]
+    pub num_layers: usize,
+    /// If each layer should also process the sequence in reverse order, the hidden states of both
+    /// directions being concatenated. Default: false
+    #[config(default = false)]
+    pub bidirectional: bool,
+    /// The dropout rate applied to the hidden states of each layer except the last one.
+    /// Default: 0.0
+    #[config(default = 0.0)]
+    pub dropout: f64,
 }
 
-/// The Lstm module. This implementation is for a unidirectional, stateless, Lstm.
-#[derive(Module, Debug)]
+/// The Lstm module, made of `num_layers` stacked layers, each one processing the sequence in one
+/// or both directions.
+#[derive(Debug, Clone)]
 pub struct Lstm<B: Backend> {
+    first_layer: LstmCell<B>,
+    // The other layers are only present when configured.
+    stacked_layers: Option<Vec<LstmCell<B>>>,
+    reverse_layers: Option<Vec<LstmCell<B>>>,
+    dropout: Dropout,
+}
+
+/// The record type for the [lstm](Lstm) module.
+///
+/// The gates of the first layer in the forward direction are top-level fields, so the records of
+/// single layer lstms keep the layout they had before multiple layers were supported.
+#[derive(Record, Debug, Clone)]
+pub struct LstmRecord<B: Backend> {
+    /// The input gate of the first layer.
+    pub input_gate: <GateController<B> as Module<B>>::Record,
+    /// The forget gate of the first layer.
+    pub forget_gate: <GateController<B> as Module<B>>::Record,
+    /// The output gate of the first layer.
+    pub output_gate: <GateController<B> as Module<B>>::Record,
+    /// The cell gate of the first layer.
+    pub cell_gate: <GateController<B> as Module<B>>::Record,
+    /// The size of the hidden state.
+    pub d_hidden: <usize as Module<B>>::Record,
+    /// The other layers in the forward direction.
+    pub stacked_layers: <Option<Vec<LstmCell<B>>> as Module<B>>::Record,
+    /// The layers in the reverse direction.
+    pub reverse_layers: <Option<Vec<LstmCell<B>>> as Module<B>>::Record,
+    /// The dropout between the layers.
+    pub dropout: <Dropout as Module<B>>::Record,
+}
+
+/// A single direction of a [lstm](Lstm) layer.
+#[derive(Module, Debug)]
+pub struct LstmCell<B: Backend> {
     input_gate: GateController<B>,
     forget_gate: GateController<B>,
     output_gate: GateController<B>,
@@ -35,69 +88,146 @@ pub struct Lstm<B: Backend> {
     d_hidden: usize,
 }
 
+/// The state of each layer and direction of a [lstm](Lstm) module.
+///
+/// The states are ordered by layer, the forward direction of a layer coming before its reverse
+/// direction.
+#[derive(Debug, Clone)]
+pub struct LstmState<B: Backend> {
+    /// The cell states `[num_layers * num_directions, batch_size, d_hidden]`.
+    pub cell: Tensor<B, 3>,
+    /// The hidden states `[num_layers * num_directions, batch_size, d_hidden]`.
+    pub hidden: Tensor<B, 3>,
+}
+
+/// [Lstm](Lstm) outputs.
+#[derive(Debug, Clone)]
+pub struct LstmOutput<B: Backend> {
+    /// The cell states of the last layer for each timestep
+    /// `[batch_size, seq_length, num_directions * d_hidden]`.
+    pub cell_states: Tensor<B, 3>,
+    /// The hidden states of the last layer for each timestep
+    /// `[batch_size, seq_length, num_directions * d_hidden]`.
+    pub hidden_states: Tensor<B, 3>,
+    /// The final state of each layer and direction.
+    pub state: LstmState<B>,
+}
+
 impl LstmConfig {
     /// Initialize a new [lstm](Lstm) module.
     pub fn init<B: Backend>(&self) -> Lstm<B> {
-        let d_output = self.d_hidden;
+        let init_cell = |layer| {
+            let d_input = self.d_input_layer(layer);
+            let gate = || {
+                gate_controller::GateController::new(
+                    d_input,
+                    self.d_hidden,
+                    self.bias,
+                    self.initializer.clone(),
+                )
+            };
 
-        let input_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-        );
-        let forget_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-        );
-        let output_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-        );
-        let cell_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-        );
+            LstmCell {
+                input_gate: gate(),
+                forget_gate: gate(),
+                output_gate: gate(),
+                cell_gate: gate(),
+                d_hidden: self.d_hidden,
+            }
+        };
+
+        let stacked_layers =
+            (self.num_layers > 1).then(|| (1..self.num_layers).map(init_cell).collect());
+        let reverse_layers = self
+            .bidirectional
+            .then(|| (0..self.num_layers).map(init_cell).collect());
 
         Lstm {
-            input_gate,
-            forget_gate,
-            output_gate,
-            cell_gate,
-            d_hidden: self.d_hidden,
+            first_layer: init_cell(0),
+            stacked_layers,
+            reverse_layers,
+            dropout: DropoutConfig::new(self.dropout).init(),
         }
     }
 
     /// Initialize a new [lstm](Lstm) module with a [record](LstmRecord).
+    ///
+    /// # Panics
+    ///
+    /// If the record doesn't have the number of layers and directions of the config.
     pub fn init_with<B: Backend>(&self, record: LstmRecord<B>) -> Lstm<B> {
-        let linear_config = LinearConfig {
-            d_input: self.d_input,
+        let num_layers = 1 + record.stacked_layers.as_ref().map_or(0, Vec::len);
+        let num_reverse_layers = record.reverse_layers.as_ref().map_or(0, Vec::len);
+        let num_reverse_layers_config = usize::from(self.bidirectional) * self.num_layers;
+        assert_eq!(
+            num_layers, self.num_layers,
+            "The lstm record should have {} layers as configured, but has {num_layers}",
+            self.num_layers
+        );
+        assert_eq!(
+            num_reverse_layers, num_reverse_layers_config,
+            "The lstm record should have {num_reverse_layers_config} reverse layers as configured, \
+             but has {num_reverse_layers}"
+        );
+
+        let linear_config = |layer| LinearConfig {
+            d_input: self.d_input_layer(layer),
             d_output: self.d_hidden,
             bias: self.bias,
             initializer: self.initializer.clone(),
         };
+        let init_cell = |(layer, record): (usize, LstmCellRecord<B>)| {
+            let linear_config = linear_config(layer);
+
+            LstmCell {
+                input_gate: gate_controller::GateController::new_with(
+                    &linear_config,
+                    record.input_gate,
+                ),
+                forget_gate: gate_controller::GateController::new_with(
+                    &linear_config,
+                    record.forget_gate,
+                ),
+                output_gate: gate_controller::GateController::new_with(
+                    &linear_config,
+                    record.output_gate,
+                ),
+                cell_gate: gate_controller::GateController::new_with(
+                    &linear_config,
+                    record.cell_gate,
+                ),
+                d_hidden: self.d_hidden,
+            }
+        };
+        let first_layer = LstmCellRecord {
+            input_gate: record.input_gate,
+            forget_gate: record.forget_gate,
+            output_gate: record.output_gate,
+            cell_gate: record.cell_gate,
+            d_hidden: record.d_hidden,
+        };
 
         Lstm {
-            input_gate: gate_controller::GateController::new_with(
-                &linear_config,
-                record.input_gate,
-            ),
-            forget_gate: gate_controller::GateController::new_with(
-                &linear_config,
-                record.forget_gate,
-            ),
-            output_gate: gate_controller::GateController::new_with(
-                &linear_config,
-                record.output_gate,
-            ),
-            cell_gate: gate_controller::GateController::new_with(&linear_config, record.cell_gate),
-            d_hidden: self.d_hidden,
+            first_layer: init_cell((0, first_layer)),
+            stacked_layers: record.stacked_layers.map(|layers| {
+                layers
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, record)| init_cell((index + 1, record)))
+                    .collect()
+            }),
+            reverse_layers: record
+                .reverse_layers
+                .map(|layers| layers.into_iter().enumerate().map(init_cell).collect()),
+            dropout: DropoutConfig::new(self.dropout).init(),
+        }
+    }
+
+    fn d_input_layer(&self, layer: usize) -> usize {
+        match (layer, self.bidirectional) {
+            (0, _) => self.d_input,
+            (_, true) => 2 * self.d_hidden,
+            (_, false) => self.d_hidden,
         }
     }
 }
@@ -110,113 +240,311 @@ impl<B: Backend> Lstm<B> {
     /// Parameters:
     ///     batched_input: The input tensor of shape [batch_size, sequence_length, input_size].
     ///     state: An optional tuple of tensors representing the initial cell state and hidden state.
-    ///            Each state tensor has shape [batch_size, hidden_size].
+    ///            Each state tensor has shape [batch_size, hidden_size], and is used as the initial
+    ///            state of every layer and direction.
     ///            If no initial state is provided, these tensors are initialized to zeros.
     ///
     /// Returns:
     ///     A tuple of tensors, where the first tensor represents the cell states and
-    ///     the second tensor represents the hidden states for each sequence element.
-    ///     Both output tensors have the shape [batch_size, sequence_length, hidden_size].
+    ///     the second tensor represents the hidden states of the last layer for each sequence element.
+    ///     Both output tensors have the shape [batch_size, sequence_length, hidden_size], the
+    ///     hidden size being doubled when bidirectional.
     pub fn forward(
         &self,
         batched_input: Tensor<B, 3>,
         state: Option<(Tensor<B, 2>, Tensor<B, 2>)>,
     ) -> (Tensor<B, 3>, Tensor<B, 3>) {
-        let [batch_size, seq_length, _] = batched_input.shape().dims;
-        let mut batched_cell_state = Tensor::zeros([batch_size, seq_length, self.d_hidden]);
-        let mut batched_hidden_state = Tensor::zeros([batch_size, seq_length, self.d_hidden]);
+        let num_states = self.layers().len() + self.reverse_layers().len();
+        let state = state.map(|(cell_state, hidden_state)| LstmState {
+            cell: cell_state.unsqueeze().repeat(0, num_states),
+            hidden: hidden_state.unsqueeze().repeat(0, num_states),
+        });
+
+        let output = self.forward_masked(batched_input, None, state);
+
+        (output.cell_states, output.hidden_states)
+    }
+
+    /// Applies the forward pass on a batch of padded sequences of different lengths.
+    ///
+    /// The state of each sequence stops being updated past its length, so the final state is the
+    /// one of its last element, and the reverse direction starts from its last element. The cell
+    /// and hidden states of the padded elements are set to zero.
+    ///
+    /// # Shapes
+    ///
+    /// - batched_input: `[batch_size, seq_length, d_input]`
+    /// - lengths: `[batch_size]`
+    /// - state: `[num_layers * num_directions, batch_size, d_hidden]`
+    pub fn forward_with_lengths(
+        &self,
+        batched_input: Tensor<B, 3>,
+        lengths: Tensor<B, 1, Int>,
+        state: Option<LstmState<B>>,
+    ) -> LstmOutput<B> {
+        self.forward_masked(batched_input, Some(lengths), state)
+    }
 
-        let (mut cell_state, mut hidden_state) = match state {
-            Some((cell_state, hidden_state)) => (cell_state, hidden_state),
+    fn forward_masked(
+        &self,
+        batched_input: Tensor<B, 3>,
+        lengths: Option<Tensor<B, 1, Int>>,
+        state: Option<LstmState<B>>,
+    ) -> LstmOutput<B> {
+        let [batch_size, seq_length, _] = batched_input.dims();
+        let masks = lengths.map(|lengths| sequence_masks(lengths, seq_length));
+        let layers = self.layers();
+        let reverse_layers = self.reverse_layers();
+        let num_directions = 1 + usize::from(!reverse_layers.is_empty());
+        let d_hidden = self.first_layer.d_hidden;
+
+        let initial_state = |index: usize| match &state {
+            Some(state) => {
+                let range = [index..index + 1, 0..batch_size, 0..d_hidden];
+
+                (
+                    state.cell.clone().slice(range.clone()).squeeze(0),
+                    state.hidden.clone().slice(range).squeeze(0),
+                )
+            }
             None => (
-                Tensor::zeros([batch_size, self.d_hidden]),
-                Tensor::zeros([batch_size, self.d_hidden]),
+                Tensor::zeros([batch_size, d_hidden]),
+                Tensor::zeros([batch_size, d_hidden]),
             ),
         };
 
-        for (t, input_t) in batched_input.iter_dim(1).enumerate() {
-            let input_t = input_t.squeeze(1);
-            // f(orget)g(ate) tensors
-            let biased_fg_input_sum = self.gate_product(&input_t, &hidden_state, &self.forget_gate);
-            let forget_values = activation::sigmoid(biased_fg_input_sum); // to multiply with cell state
+        let mut final_cell_states = Vec::with_capacity(layers.len() * num_directions);
+        let mut final_hidden_states = Vec::with_capacity(layers.len() * num_directions);
+        let mut layer_input = batched_input;
+        let mut cell_states = None;
 
-            // i(nput)g(ate) tensors
-            let biased_ig_input_sum = self.gate_product(&input_t, &hidden_state, &self.input_gate);
-            let add_values = activation::sigmoid(biased_ig_input_sum);
+        for (layer, cell) in layers.iter().enumerate() {
+            if layer > 0 {
+                layer_input = self.dropout.forward(layer_input);
+            }
 
-            // o(output)g(ate) tensors
-            let biased_og_input_sum = self.gate_product(&input_t, &hidden_state, &self.output_gate);
-            let output_values = activation::sigmoid(biased_og_input_sum);
+            let index = layer * num_directions;
+            let mut output = cell.forward(
+                layer_input.clone(),
+                masks.as_deref(),
+                initial_state(index),
+                false,
+            );
+            final_cell_states.push(output.state.cell);
+            final_hidden_states.push(output.state.hidden);
+
+            if let Some(reverse_cell) = reverse_layers.get(layer) {
+                let reverse_output = reverse_cell.forward(
+                    layer_input,
+                    masks.as_deref(),
+                    initial_state(index + 1),
+                    true,
+                );
+                final_cell_states.push(reverse_output.state.cell);
+                final_hidden_states.push(reverse_output.state.hidden);
+
+                output.cell_states =
+                    Tensor::cat(vec![output.cell_states, reverse_output.cell_states], 2);
+                output.hidden_states =
+                    Tensor::cat(vec![output.hidden_states, reverse_output.hidden_states], 2);
+            }
 
-            // c(ell)g(ate) tensors
-            let biased_cg_input_sum = self.gate_product(&input_t, &hidden_state, &self.cell_gate);
-            let candidate_cell_values = biased_cg_input_sum.tanh();
+            layer_input = output.hidden_states;
+            cell_states = Some(output.cell_states);
+        }
 
-            cell_state = forget_values * cell_state.clone() + add_values * candidate_cell_values;
-            hidden_state = output_values * cell_state.clone().tanh();
+        LstmOutput {
+            cell_states: cell_states.expect("The lstm should have at least one layer"),
+            hidden_states: layer_input,
+            state: LstmState {
+                cell: Tensor::cat(final_cell_states, 0),
+                hidden: Tensor::cat(final_hidden_states, 0),
+            },
+        }
+    }
 
-            let unsqueezed_shape = [cell_state.shape().dims[0], 1, cell_state.shape().dims[1]];
+    /// The layers in the forward direction.
+    fn layers(&self) -> Vec<&LstmCell<B>> {
+        core::iter::once(&self.first_layer)
+            .chain(self.stacked_layers.iter().flatten())
+            .collect()
+    }
 
-            let unsqueezed_cell_state = cell_state.clone().reshape(unsqueezed_shape);
-            let unsqueezed_hidden_state = hidden_state.clone().reshape(unsqueezed_shape);
+    /// The layers in the reverse direction, empty when the lstm isn't bidirectional.
+    fn reverse_layers(&self) -> Vec<&LstmCell<B>> {
+        self.reverse_layers.iter().flatten().collect()
+    }
+}
 
-            // store the state for this timestep
-            batched_cell_state = batched_cell_state.slice_assign(
-                [0..batch_size, t..(t + 1), 0..self.d_hidden],
-                unsqueezed_cell_state.clone(),
-            );
-            batched_hidden_state = batched_hidden_state.slice_assign(
-                [0..batch_size, t..(t + 1), 0..self.d_hidden],
-                unsqueezed_hidden_state.clone(),
-            );
+impl<B: Backend> Module<B> for Lstm<B> {
+    type Record = LstmRecord<B>;
+
+    fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
+        self.first_layer.visit(visitor);
+        self.stacked_layers.visit(visitor);
+        self.reverse_layers.visit(visitor);
+    }
+
+    fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
+        Self {
+            first_layer: self.first_layer.map(mapper),
+            stacked_layers: Module::<B>::map(self.stacked_layers, mapper),
+            reverse_layers: Module::<B>::map(self.reverse_layers, mapper),
+            dropout: self.dropout,
+        }
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        let first_layer = LstmCellRecord {
+            input_gate: record.input_gate,
+            forget_gate: record.forget_gate,
+            output_gate: record.output_gate,
+            cell_gate: record.cell_gate,
+            d_hidden: record.d_hidden,
+        };
+
+        Self {
+            first_layer: self.first_layer.load_record(first_layer),
+            stacked_layers: self.stacked_layers.load_record(record.stacked_layers),
+            reverse_layers: self.reverse_layers.load_record(record.reverse_layers),
+            dropout: Module::<B>::load_record(self.dropout, record.dropout),
         }
+    }
 
-        (batched_cell_state, batched_hidden_state)
+    fn into_record(self) -> Self::Record {
+        let first_layer = self.first_layer.into_record();
+
+        LstmRecord {
+            input_gate: first_layer.input_gate,
+            forget_gate: first_layer.forget_gate,
+            output_gate: first_layer.output_gate,
+            cell_gate: first_layer.cell_gate,
+            d_hidden: first_layer.d_hidden,
+            stacked_layers: self.stacked_layers.into_record(),
+            reverse_layers: self.reverse_layers.into_record(),
+            dropout: Module::<B>::into_record(self.dropout),
+        }
     }
+}
 
-    /// Helper function for performing weighted matrix product for a gate and adds
-    /// bias, if any.
-    ///
-    ///  Mathematically, performs `Wx*X + Wh*H + b`, where:
-    ///     Wx = weight matrix for the connection to input vector X
-    ///     Wh = weight matrix for the connection to hidden state H
-    ///     X = input vector
-    ///     H = hidden state
-    ///     b = bias terms
-    fn gate_product(
+impl<B: ADBackend> ADModule<B> for Lstm<B> {
+    type InnerModule = Lstm<B::InnerBackend>;
+
+    fn valid(&self) -> Self::InnerModule {
+        Lstm {
+            first_layer: self.first_layer.valid(),
+            stacked_layers: self.stacked_layers.valid(),
+            reverse_layers: self.reverse_layers.valid(),
+            dropout: self.dropout.clone(),
+        }
+    }
+}
+
+impl<B: Backend> core::fmt::Display for Lstm<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Lstm[num_params={}]", self.num_params())
+    }
+}
+
+impl<B: Backend> LstmCell<B> {
+    /// Processes the sequence one element at a time, in reverse order if `reverse` is set,
+    /// returning the cell and hidden states of each element along with the final state.
+    fn forward(
         &self,
-        input: &Tensor<B, 2>,
-        hidden: &Tensor<B, 2>,
-        gate: &GateController<B>,
-    ) -> Tensor<B, 2> {
-        let input_product = input.clone().matmul(gate.input_transform.weight.val());
-        let hidden_product = hidden.clone().matmul(gate.hidden_transform.weight.val());
-
-        let input_bias = gate
-            .input_transform
-            .bias
-            .as_ref()
-            .map(|bias_param| bias_param.val());
-        let hidden_bias = gate
-            .hidden_transform
-            .bias
-            .as_ref()
-            .map(|bias_param| bias_param.val());
-
-        match (input_bias, hidden_bias) {
-            (Some(input_bias), Some(hidden_bias)) => {
-                input_product + input_bias.unsqueeze() + hidden_product + hidden_bias.unsqueeze()
-            }
-            (Some(input_bias), None) => input_product + input_bias.unsqueeze() + hidden_product,
-            (None, Some(hidden_bias)) => input_product + hidden_product + hidden_bias.unsqueeze(),
-            (None, None) => input_product + hidden_product,
+        batched_input: Tensor<B, 3>,
+        masks: Option<&[Tensor<B, 2>]>,
+        state: (Tensor<B, 2>, Tensor<B, 2>),
+        reverse: bool,
+    ) -> LstmOutput<B> {
+        let [batch_size, seq_length, _] = batched_input.dims();
+        let inputs: Vec<_> = batched_input.iter_dim(1).collect();
+        let (mut cell_state, mut hidden_state) = state;
+
+        let mut batched_cell_state = Vec::with_capacity(seq_length);
+        let mut batched_hidden_state = Vec::with_capacity(seq_length);
+        let timesteps: Vec<usize> = match reverse {
+            true => (0..seq_length).rev().collect(),
+            false => (0..seq_length).collect(),
+        };
+
+        for t in timesteps {
+            let input_t = inputs[t].clone().squeeze(1);
+            let (next_cell_state, next_hidden_state) =
+                self.step(input_t, cell_state.clone(), hidden_state.clone());
+
+            let (cell_t, hidden_t) = match masks {
+                Some(masks) => {
+                    cell_state = masked_update(cell_state, next_cell_state, &masks[t]);
+                    hidden_state = masked_update(hidden_state, next_hidden_state, &masks[t]);
+
+                    (
+                        cell_state.clone().mul(masks[t].clone()),
+                        hidden_state.clone().mul(masks[t].clone()),
+                    )
+                }
+                None => {
+                    cell_state = next_cell_state;
+                    hidden_state = next_hidden_state;
+
+                    (cell_state.clone(), hidden_state.clone())
+                }
+            };
+
+            // store the state for this timestep
+            let unsqueezed_shape = [batch_size, 1, self.d_hidden];
+            batched_cell_state.push(cell_t.reshape(unsqueezed_shape));
+            batched_hidden_state.push(hidden_t.reshape(unsqueezed_shape));
+        }
+
+        if reverse {
+            batched_cell_state.reverse();
+            batched_hidden_state.reverse();
+        }
+
+        LstmOutput {
+            cell_states: Tensor::cat(batched_cell_state, 1),
+            hidden_states: Tensor::cat(batched_hidden_state, 1),
+            state: LstmState {
+                cell: cell_state.unsqueeze(),
+                hidden: hidden_state.unsqueeze(),
+            },
         }
     }
+
+    /// Computes the cell state and hidden state of the next element of the sequence.
+    fn step(
+        &self,
+        input_t: Tensor<B, 2>,
+        cell_state: Tensor<B, 2>,
+        hidden_state: Tensor<B, 2>,
+    ) -> (Tensor<B, 2>, Tensor<B, 2>) {
+        // f(orget)g(ate) tensors
+        let biased_fg_input_sum = self.forget_gate.gate_product(&input_t, &hidden_state);
+        let forget_values = activation::sigmoid(biased_fg_input_sum); // to multiply with cell state
+
+        // i(nput)g(ate) tensors
+        let biased_ig_input_sum = self.input_gate.gate_product(&input_t, &hidden_state);
+        let add_values = activation::sigmoid(biased_ig_input_sum);
+
+        // o(output)g(ate) tensors
+        let biased_og_input_sum = self.output_gate.gate_product(&input_t, &hidden_state);
+        let output_values = activation::sigmoid(biased_og_input_sum);
+
+        // c(ell)g(ate) tensors
+        let biased_cg_input_sum = self.cell_gate.gate_product(&input_t, &hidden_state);
+        let candidate_cell_values = biased_cg_input_sum.tanh();
+
+        let cell_state = forget_values * cell_state + add_values * candidate_cell_values;
+        let hidden_state = output_values * cell_state.clone().tanh();
+
+        (cell_state, hidden_state)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::record::{FullPrecisionSettings, NamedMpkBytesRecorder, Recorder};
     use crate::{module::Param, nn::LinearRecord, TestBackend};
     use burn_tensor::{Data, Distribution};
 
@@ -231,10 +559,10 @@ mod tests {
         let gate_to_data =
             |gate: GateController<TestBackend>| gate.input_transform.weight.val().to_data();
 
-        gate_to_data(lstm.input_gate).assert_within_range(0..1);
-        gate_to_data(lstm.forget_gate).assert_within_range(0..1);
-        gate_to_data(lstm.output_gate).assert_within_range(0..1);
-        gate_to_data(lstm.cell_gate).assert_within_range(0..1);
+        gate_to_data(lstm.first_layer.input_gate).assert_within_range(0..1);
+        gate_to_data(lstm.first_layer.forget_gate).assert_within_range(0..1);
+        gate_to_data(lstm.first_layer.output_gate).assert_within_range(0..1);
+        gate_to_data(lstm.first_layer.cell_gate).assert_within_range(0..1);
     }
 
     /// Test forward pass with simple input vector.
@@ -274,7 +602,7 @@ mod tests {
             )
         }
 
-        lstm.input_gate = create_gate_controller(
+        lstm.first_layer.input_gate = create_gate_controller(
             0.5,
             0.0,
             1,
@@ -282,7 +610,7 @@ mod tests {
             false,
             Initializer::XavierUniform { gain: 1.0 },
         );
-        lstm.forget_gate = create_gate_controller(
+        lstm.first_layer.forget_gate = create_gate_controller(
             0.7,
             0.0,
             1,
@@ -290,7 +618,7 @@ mod tests {
             false,
             Initializer::XavierUniform { gain: 1.0 },
         );
-        lstm.cell_gate = create_gate_controller(
+        lstm.first_layer.cell_gate = create_gate_controller(
             0.9,
             0.0,
             1,
@@ -298,7 +626,7 @@ mod tests {
             false,
             Initializer::XavierUniform { gain: 1.0 },
         );
-        lstm.output_gate = create_gate_controller(
+        lstm.first_layer.output_gate = create_gate_controller(
             1.1,
             0.0,
             1,
@@ -333,4 +661,145 @@ mod tests {
         assert_eq!(cell_state.shape().dims, [8, 10, 1024]);
         assert_eq!(hidden_state.shape().dims, [8, 10, 1024]);
     }
+
+    #[test]
+    fn test_init_with_single_layer_record() {
+        // The module of a single layer lstm before multiple layers and directions were supported.
+        #[derive(Module, Debug)]
+        struct LegacyLstm<B: Backend> {
+            input_gate: GateController<B>,
+            forget_gate: GateController<B>,
+            output_gate: GateController<B>,
+            cell_gate: GateController<B>,
+            d_hidden: usize,
+        }
+
+        let config = LstmConfig::new(2, 3, true);
+        let lstm = config.init::<TestBackend>();
+        let legacy = LegacyLstm {
+            input_gate: lstm.first_layer.input_gate.clone(),
+            forget_gate: lstm.first_layer.forget_gate.clone(),
+            output_gate: lstm.first_layer.output_gate.clone(),
+            cell_gate: lstm.first_layer.cell_gate.clone(),
+            d_hidden: 3,
+        };
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::default();
+
+        let bytes = recorder.record(legacy.into_record(), ()).unwrap();
+        let lstm_loaded = config.init_with(recorder.load(bytes).unwrap());
+
+        let batched_input = Tensor::<TestBackend, 3>::random([2, 4, 2], Distribution::Default);
+        let (_, hidden_state) = lstm.forward(batched_input.clone(), None);
+        let (_, hidden_state_loaded) = lstm_loaded.forward(batched_input, None);
+        hidden_state
+            .into_data()
+            .assert_approx_eq(&hidden_state_loaded.into_data(), 3);
+    }
+
+    #[test]
+    fn test_init_with_multi_layer_record() {
+        let config = LstmConfig::new(2, 3, true)
+            .with_num_layers(2)
+            .with_bidirectional(true);
+        let lstm = config.init::<TestBackend>();
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::default();
+
+        let bytes = recorder.record(lstm.clone().into_record(), ()).unwrap();
+        let lstm_loaded = config.init_with(recorder.load(bytes).unwrap());
+
+        assert_eq!(lstm.num_params(), lstm_loaded.num_params());
+        let batched_input = Tensor::<TestBackend, 3>::random([2, 4, 2], Distribution::Default);
+        let (_, hidden_state) = lstm.forward(batched_input.clone(), None);
+        let (_, hidden_state_loaded) = lstm_loaded.forward(batched_input, None);
+        hidden_state
+            .into_data()
+            .assert_approx_eq(&hidden_state_loaded.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic = "The lstm record should have 2 layers as configured, but has 1"]
+    fn test_init_with_record_of_fewer_layers() {
+        let record = LstmConfig::new(2, 3, true)
+            .init::<TestBackend>()
+            .into_record();
+
+        LstmConfig::new(2, 3, true)
+            .with_num_layers(2)
+            .init_with(record);
+    }
+
+    #[test]
+    #[should_panic = "The lstm record should have 1 reverse layers as configured, but has 0"]
+    fn test_init_with_unidirectional_record() {
+        let record = LstmConfig::new(2, 3, true)
+            .init::<TestBackend>()
+            .into_record();
+
+        LstmConfig::new(2, 3, true)
+            .with_bidirectional(true)
+            .init_with(record);
+    }
+
+    #[test]
+    fn test_bidirectional_multi_layer_forward_pass() {
+        let lstm = LstmConfig::new(8, 16, true)
+            .with_num_layers(3)
+            .with_bidirectional(true)
+            .init::<TestBackend>();
+        let batched_input = Tensor::<TestBackend, 3>::random([4, 6, 8], Distribution::Default);
+        let lengths = Tensor::from_ints([6, 6, 6, 6]);
+
+        let output = lstm.forward_with_lengths(batched_input, lengths, None);
+
+        assert_eq!(output.cell_states.shape().dims, [4, 6, 32]);
+        assert_eq!(output.hidden_states.shape().dims, [4, 6, 32]);
+        assert_eq!(output.state.cell.shape().dims, [6, 4, 16]);
+        assert_eq!(output.state.hidden.shape().dims, [6, 4, 16]);
+    }
+
+    #[test]
+    fn test_forward_with_lengths_should_match_unpadded_sequences() {
+        let [seq_length, length, d_input, d_hidden] = [5, 3, 4, 6];
+        let lstm = LstmConfig::new(d_input, d_hidden, true)
+            .with_num_layers(2)
+            .with_bidirectional(true)
+            .init::<TestBackend>();
+        let batched_input =
+            Tensor::<TestBackend, 3>::random([2, seq_length, d_input], Distribution::Default);
+        let lengths = Tensor::from_ints([seq_length as i32, length as i32]);
+
+        let output = lstm.forward_with_lengths(batched_input.clone(), lengths, None);
+        let output_unpadded = lstm.forward_with_lengths(
+            batched_input.slice([1..2, 0..length, 0..d_input]),
+            Tensor::from_ints([length as i32]),
+            None,
+        );
+
+        output
+            .hidden_states
+            .clone()
+            .slice([1..2, 0..length, 0..2 * d_hidden])
+            .into_data()
+            .assert_approx_eq(&output_unpadded.hidden_states.into_data(), 3);
+        output
+            .hidden_states
+            .slice([1..2, length..seq_length, 0..2 * d_hidden])
+            .into_data()
+            .assert_approx_eq(
+                &Tensor::<TestBackend, 3>::zeros([1, 2, 2 * d_hidden]).into_data(),
+                3,
+            );
+        output
+            .state
+            .hidden
+            .slice([0..4, 1..2, 0..d_hidden])
+            .into_data()
+            .assert_approx_eq(&output_unpadded.state.hidden.into_data(), 3);
+        output
+            .state
+            .cell
+            .slice([0..4, 1..2, 0..d_hidden])
+            .into_data()
+            .assert_approx_eq(&output_unpadded.state.cell.into_data(), 3);
+    }
 }
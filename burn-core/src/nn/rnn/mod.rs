@@ -1,4 +1,5 @@
 mod gate_controller;
+mod sequence;
 
 /// Gated Recurrent Unit module.
 pub mod gru;
@@ -0,0 +1,38 @@
+use alloc::vec::Vec;
+
+use crate::tensor::backend::Backend;
+use crate::tensor::{Int, Tensor};
+
+/// Creates a mask for each timestep of a batch of padded sequences, set to 1 for the sequences
+/// still running at that timestep and to 0 for the ones already finished.
+///
+/// # Shapes
+///
+/// - lengths: `[batch_size]`
+/// - output: `seq_length` masks of shape `[batch_size, 1]`
+pub(crate) fn sequence_masks<B: Backend>(
+    lengths: Tensor<B, 1, Int>,
+    seq_length: usize,
+) -> Vec<Tensor<B, 2>> {
+    let [batch_size] = lengths.dims();
+
+    (0..seq_length)
+        .map(|t| {
+            lengths
+                .clone()
+                .greater_elem(t as i64)
+                .float()
+                .reshape([batch_size, 1])
+        })
+        .collect()
+}
+
+/// Only updates the state of the sequences still running according to the mask, the state of the
+/// finished ones being kept as is.
+pub(crate) fn masked_update<B: Backend>(
+    state: Tensor<B, 2>,
+    new_state: Tensor<B, 2>,
+    mask: &Tensor<B, 2>,
+) -> Tensor<B, 2> {
+    state.clone() + (new_state - state).mul(mask.clone())
+}
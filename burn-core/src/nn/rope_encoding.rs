@@ -0,0 +1,212 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate as burn;
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::Data;
+
+use libm::{cosf, powf, sinf};
+
+/// Configuration to create a [RotaryEncoding](RotaryEncoding) layer.
+#[derive(Config, Debug)]
+pub struct RotaryEncodingConfig {
+    /// Maximum sequence length of the input.
+    pub max_sequence_length: usize,
+
+    /// The size of each vector to rotate, usually the size of an attention head.
+    pub d_model: usize,
+
+    /// Base used to compute the rotation frequencies. Default: 10000.0
+    #[config(default = "10000.0")]
+    pub theta: f32,
+}
+
+/// Rotary positional encoding layer for transformer models.
+///
+/// Instead of being added to the input embeddings, the positional information is encoded by
+/// rotating each pair of consecutive features of the queries and keys by an angle proportional to
+/// their position, as introduced in
+/// [RoFormer: Enhanced Transformer with Rotary Position Embedding](https://arxiv.org/abs/2104.09864).
+#[derive(Module, Debug)]
+pub struct RotaryEncoding<B: Backend> {
+    cos: Tensor<B, 2>,
+    sin: Tensor<B, 2>,
+}
+
+impl RotaryEncodingConfig {
+    /// Initialize a new [RotaryEncoding](RotaryEncoding) module.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `d_model` is not even.
+    pub fn init<B: Backend>(&self) -> RotaryEncoding<B> {
+        assert!(
+            self.d_model % 2 == 0,
+            "d_model({}) must be even to apply rotary encoding",
+            self.d_model
+        );
+
+        let d_half = self.d_model / 2;
+        let mut cos = Vec::with_capacity(self.max_sequence_length * d_half);
+        let mut sin = Vec::with_capacity(self.max_sequence_length * d_half);
+
+        for position in 0..self.max_sequence_length {
+            for i in 0..d_half {
+                let frequency = 1.0 / powf(self.theta, (2 * i) as f32 / self.d_model as f32);
+                let angle = position as f32 * frequency;
+
+                cos.push(cosf(angle));
+                sin.push(sinf(angle));
+            }
+        }
+
+        let shape = [self.max_sequence_length, d_half];
+        let cos = Data::new(cos, shape.into());
+        let sin = Data::new(sin, shape.into());
+
+        RotaryEncoding {
+            cos: Tensor::from_data(cos.convert()),
+            sin: Tensor::from_data(sin.convert()),
+        }
+    }
+}
+
+impl<B: Backend> RotaryEncoding<B> {
+    /// Applies the rotary encoding to the input tensor, the first position being 0.
+    ///
+    /// # Shapes
+    ///
+    /// * input: `[..., seq_length, d_model]`
+    /// * output: `[..., seq_length, d_model]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        self.forward_with_offset(input, 0)
+    }
+
+    /// Applies the rotary encoding to the input tensor, the first position being `offset`.
+    ///
+    /// This is useful during autoregressive decoding, where only the last tokens are encoded.
+    ///
+    /// # Shapes
+    ///
+    /// * input: `[..., seq_length, d_model]`
+    /// * output: `[..., seq_length, d_model]`
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `offset + seq_length` is greater than the maximum sequence length.
+    /// * Panics if the input d_model is not equal to the d_model of the encoding.
+    pub fn forward_with_offset<const D: usize>(
+        &self,
+        input: Tensor<B, D>,
+        offset: usize,
+    ) -> Tensor<B, D> {
+        let shape = input.shape();
+        let [seq_length, d_model] = [shape.dims[D - 2], shape.dims[D - 1]];
+        let [max_sequence_length, d_half] = self.cos.dims();
+
+        assert!(
+            offset + seq_length <= max_sequence_length,
+            "max_sequence_length({}) must be greater or equal than offset + length({})",
+            max_sequence_length,
+            offset + seq_length,
+        );
+        assert!(
+            d_model == 2 * d_half,
+            "d_model({}) of the input must be equal to d_model of encoding({})",
+            d_model,
+            2 * d_half,
+        );
+
+        let batch_size = shape.num_elements() / (seq_length * d_model);
+        let input = input.reshape([batch_size, seq_length, d_half, 2]);
+
+        let x_even = input
+            .clone()
+            .slice([0..batch_size, 0..seq_length, 0..d_half, 0..1]);
+        let x_odd = input.slice([0..batch_size, 0..seq_length, 0..d_half, 1..2]);
+
+        let positions = [offset..offset + seq_length, 0..d_half];
+        let cos = self
+            .cos
+            .clone()
+            .slice(positions.clone())
+            .reshape([1, seq_length, d_half, 1]);
+        let sin = self
+            .sin
+            .clone()
+            .slice(positions)
+            .reshape([1, seq_length, d_half, 1]);
+
+        let out_even = x_even.clone().mul(cos.clone()) - x_odd.clone().mul(sin.clone());
+        let out_odd = x_even.mul(sin) + x_odd.mul(cos);
+
+        Tensor::cat(vec![out_even, out_odd], 3).reshape(shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_rotary_encoding_forward() {
+        let rope = RotaryEncodingConfig::new(4, 4).init::<TestBackend>();
+        let input = Tensor::<TestBackend, 3>::from_floats([[
+            [1.0, 2.0, 3.0, 4.0],
+            [1.0, 2.0, 3.0, 4.0],
+            [1.0, 0.0, 1.0, 0.0],
+        ]]);
+
+        let output = rope.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &Data::from([[
+                [1.0, 2.0, 3.0, 4.0],
+                [-1.1426, 1.9221, 2.9598, 4.0298],
+                [-0.4161, 0.9093, 0.9998, 0.0200],
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_rotary_encoding_offset() {
+        let rope = RotaryEncodingConfig::new(8, 6)
+            .with_theta(500.0)
+            .init::<TestBackend>();
+        let input =
+            Tensor::<TestBackend, 4>::random([2, 3, 5, 6], burn_tensor::Distribution::Default);
+
+        let output = rope.forward(input.clone());
+        let output_last = rope.forward_with_offset(input.slice([0..2, 0..3, 4..5, 0..6]), 4);
+
+        output
+            .slice([0..2, 0..3, 4..5, 0..6])
+            .into_data()
+            .assert_approx_eq(&output_last.into_data(), 3);
+    }
+
+    #[test]
+    fn test_rotary_encoding_preserves_norm() {
+        let rope = RotaryEncodingConfig::new(16, 8).init::<TestBackend>();
+        let input = Tensor::<TestBackend, 2>::random([16, 8], burn_tensor::Distribution::Default);
+
+        let output = rope.forward(input.clone());
+
+        output
+            .powf(2.0)
+            .sum_dim(1)
+            .into_data()
+            .assert_approx_eq(&input.powf(2.0).sum_dim(1).into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotary_encoding_odd_d_model() {
+        RotaryEncodingConfig::new(4, 5).init::<TestBackend>();
+    }
+}
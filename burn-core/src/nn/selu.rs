@@ -0,0 +1,28 @@
+use crate as burn;
+
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Applies the scaled exponential linear unit function element-wise:
+///
+/// `y = scale * elu(x, alpha)`, with `alpha ≈ 1.6733` and `scale ≈ 1.0507`
+#[derive(Module, Clone, Debug, Default)]
+pub struct SELU {}
+
+impl SELU {
+    /// Create the module.
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any]`
+    /// - output: `[..., any]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        crate::tensor::activation::selu(input)
+    }
+}
@@ -0,0 +1,41 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [Softplus](Softplus) layer.
+#[derive(Config, Debug)]
+pub struct SoftplusConfig {
+    /// The sharpness of the transition. Default: 1.0
+    #[config(default = 1.0)]
+    pub beta: f64,
+}
+
+/// Applies the softplus function element-wise:
+///
+/// `y = log(1 + exp(beta * x)) / beta`
+#[derive(Module, Clone, Debug)]
+pub struct Softplus {
+    beta: f64,
+}
+
+impl SoftplusConfig {
+    /// Initialize a new [softplus](Softplus) module.
+    pub fn init(&self) -> Softplus {
+        Softplus { beta: self.beta }
+    }
+}
+
+impl Softplus {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any]`
+    /// - output: `[..., any]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        crate::tensor::activation::softplus(input, self.beta)
+    }
+}
@@ -6,12 +6,12 @@ use crate::{
     nn::{attention::MhaCache, cache::TensorCache, Initializer},
 };
 
-use super::{PositionWiseFeedForward, PositionWiseFeedForwardConfig};
+use super::{FeedForwardActivation, PositionWiseFeedForward, PositionWiseFeedForwardConfig};
 use crate::{
     config::Config,
     module::Module,
     nn::{
-        attention::{MhaInput, MultiHeadAttention, MultiHeadAttentionConfig},
+        attention::{MhaInput, MultiHeadAttention, MultiHeadAttentionConfig, PositionalStrategy},
         Dropout, DropoutConfig, LayerNorm, LayerNormConfig,
     },
     tensor::{backend::Backend, Tensor},
@@ -26,6 +26,9 @@ pub struct TransformerDecoderConfig {
     pub d_ff: usize,
     /// The number of attention heads.
     pub n_heads: usize,
+    /// The number of key and value heads of the attention layers, for grouped-query attention.
+    /// Uses the same number as attention heads when not set.
+    pub n_kv_heads: Option<usize>,
     /// The number of layers.
     pub n_layers: usize,
     /// The dropout rate. Default: 0.1
@@ -34,6 +37,16 @@ pub struct TransformerDecoderConfig {
     /// Layer norm will be applied first instead of after the other modules.
     #[config(default = false)]
     pub norm_first: bool,
+    /// The activation function of the position-wise feed-forward network. Default: Gelu
+    #[config(default = "FeedForwardActivation::Gelu")]
+    pub ff_activation: FeedForwardActivation,
+    /// If the position-wise feed-forward network is gated (GeGLU, ReGLU or SwiGLU).
+    /// Default: false
+    #[config(default = false)]
+    pub ff_gated: bool,
+    /// How the positions are taken into account by the self-attention layers. Default: Absolute
+    #[config(default = "PositionalStrategy::Absolute")]
+    pub positional: PositionalStrategy,
     /// The type of function used to initialize neural network parameters
     #[config(
         default = "Initializer::KaimingUniform{gain:1.0/libm::sqrt(3.0), fan_out_only:false}"
@@ -184,11 +197,14 @@ impl<B: Backend> TransformerDecoderAutoregressiveCache<B> {
 impl<B: Backend> TransformerDecoderLayer<B> {
     fn new(config: &TransformerDecoderConfig) -> Self {
         let self_attn = MultiHeadAttentionConfig::new(config.d_model, config.n_heads)
+            .with_n_kv_heads(config.n_kv_heads)
             .with_initializer(config.initializer.clone())
             .with_dropout(config.dropout)
+            .with_positional(config.positional.clone())
             .init();
 
         let cross_attn = MultiHeadAttentionConfig::new(config.d_model, config.n_heads)
+            .with_n_kv_heads(config.n_kv_heads)
             .with_initializer(config.initializer.clone())
             .with_dropout(config.dropout)
             .init();
@@ -198,6 +214,8 @@ impl<B: Backend> TransformerDecoderLayer<B> {
         let dropout = DropoutConfig::new(config.dropout).init();
         let pwff = PositionWiseFeedForwardConfig::new(config.d_model, config.d_ff)
             .with_dropout(config.dropout)
+            .with_activation(config.ff_activation.clone())
+            .with_gated(config.ff_gated)
             .init();
 
         Self {
@@ -217,10 +235,13 @@ impl<B: Backend> TransformerDecoderLayer<B> {
         record: TransformerDecoderLayerRecord<B>,
     ) -> Self {
         let self_attn = MultiHeadAttentionConfig::new(config.d_model, config.n_heads)
+            .with_n_kv_heads(config.n_kv_heads)
             .with_initializer(config.initializer.clone())
             .with_dropout(config.dropout)
+            .with_positional(config.positional.clone())
             .init_with(record.self_attn);
         let cross_attn = MultiHeadAttentionConfig::new(config.d_model, config.n_heads)
+            .with_n_kv_heads(config.n_kv_heads)
             .with_initializer(config.initializer.clone())
             .with_dropout(config.dropout)
             .init_with(record.cross_attn);
@@ -230,6 +251,8 @@ impl<B: Backend> TransformerDecoderLayer<B> {
         let dropout = DropoutConfig::new(config.dropout).init();
         let pwff = PositionWiseFeedForwardConfig::new(config.d_model, config.d_ff)
             .with_dropout(config.dropout)
+            .with_activation(config.ff_activation.clone())
+            .with_gated(config.ff_gated)
             .init_with(record.pwff);
 
         Self {
@@ -406,6 +429,44 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_autoregressive_gated_feed_forward() {
+        let [d_model, d_ff, n_heads, num_layers] = [12, 24, 2, 3];
+        TestBackend::seed(0);
+
+        test_autoregressive(
+            TransformerDecoderConfig::new(d_model, d_ff, n_heads, num_layers)
+                .with_ff_activation(FeedForwardActivation::Silu)
+                .with_ff_gated(true),
+        )
+    }
+
+    #[test]
+    fn test_autoregressive_grouped_query() {
+        let [d_model, d_ff, n_heads, num_layers] = [12, 24, 4, 3];
+        TestBackend::seed(0);
+
+        test_autoregressive(
+            TransformerDecoderConfig::new(d_model, d_ff, n_heads, num_layers)
+                .with_n_kv_heads(Some(2)),
+        )
+    }
+
+    #[test]
+    fn test_autoregressive_rotary() {
+        let [d_model, d_ff, n_heads, num_layers] = [12, 24, 2, 3];
+        TestBackend::seed(0);
+
+        test_autoregressive(
+            TransformerDecoderConfig::new(d_model, d_ff, n_heads, num_layers).with_positional(
+                PositionalStrategy::Rotary {
+                    max_sequence_length: 8,
+                    theta: 10000.0,
+                },
+            ),
+        )
+    }
+
     fn test_autoregressive(config: TransformerDecoderConfig) {
         let [batch_size, seq_length, d_model] = [3, 4, config.d_model];
         let transformer = config.init();
@@ -4,10 +4,32 @@ use crate::nn::Initializer;
 use crate::{
     config::Config,
     module::Module,
-    nn::{Dropout, DropoutConfig, Linear, LinearConfig, GELU},
-    tensor::{backend::Backend, Tensor},
+    nn::{Dropout, DropoutConfig, Linear, LinearConfig},
+    tensor::{activation, backend::Backend, Tensor},
 };
 
+/// Activation function applied to the hidden features of a
+/// [position-wise feed-forward](PositionWiseFeedForward) layer.
+#[derive(Module, Config, Debug, PartialEq)]
+pub enum FeedForwardActivation {
+    /// Gaussian Error Linear Units function.
+    Gelu,
+    /// Rectified Linear Unit function.
+    Relu,
+    /// Sigmoid Linear Unit function, also known as swish.
+    Silu,
+}
+
+impl FeedForwardActivation {
+    fn forward<B: Backend, const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        match self {
+            Self::Gelu => activation::gelu(tensor),
+            Self::Relu => activation::relu(tensor),
+            Self::Silu => activation::silu(tensor),
+        }
+    }
+}
+
 /// Configuration to create a [position-wise feed-forward](PositionWiseFeedForward) layer.
 #[derive(Config)]
 pub struct PositionWiseFeedForwardConfig {
@@ -18,6 +40,14 @@ pub struct PositionWiseFeedForwardConfig {
     /// The dropout rate. Default: 0.1
     #[config(default = 0.1)]
     pub dropout: f64,
+    /// The activation function applied to the hidden features. Default: Gelu
+    #[config(default = "FeedForwardActivation::Gelu")]
+    pub activation: FeedForwardActivation,
+    /// If the hidden features are gated by a second linear projection of the input, as in
+    /// [GLU Variants Improve Transformer](https://arxiv.org/abs/2002.05202). Combined with the
+    /// activation, this gives GeGLU, ReGLU or SwiGLU. Default: false
+    #[config(default = false)]
+    pub gated: bool,
     /// The type of function used to initialize neural network parameters
     #[config(
         default = "Initializer::KaimingUniform{gain:1.0/libm::sqrt(3.0), fan_out_only:false}"
@@ -27,30 +57,44 @@ pub struct PositionWiseFeedForwardConfig {
 
 /// Applies the position-wise feed-forward network to the input tensor.
 ///
+/// When gated, the hidden features are computed as `activation(linear_inner(x)) * linear_gate(x)`.
+///
 /// # Params
 ///
 /// - linear inner: Linear layer with `d_model` input features and `d_ff` output features.
+/// - linear gate: Optional linear layer with `d_model` input features and `d_ff` output features.
 /// - linear outer: Linear layer with `d_ff` input features and `d_model` output features.
 #[derive(Module, Debug)]
 pub struct PositionWiseFeedForward<B: Backend> {
     linear_inner: Linear<B>,
+    linear_gate: Option<Linear<B>>,
     linear_outer: Linear<B>,
     dropout: Dropout,
-    gelu: GELU,
+    activation: FeedForwardActivation,
 }
 
 impl PositionWiseFeedForwardConfig {
     /// Initialize a new [position-wise feed-forward](PositionWiseFeedForward) module.
     pub fn init<B: Backend>(&self) -> PositionWiseFeedForward<B> {
+        let linear_gate = match self.gated {
+            true => Some(
+                LinearConfig::new(self.d_model, self.d_ff)
+                    .with_initializer(self.initializer.clone())
+                    .init(),
+            ),
+            false => None,
+        };
+
         PositionWiseFeedForward {
             linear_inner: LinearConfig::new(self.d_model, self.d_ff)
                 .with_initializer(self.initializer.clone())
                 .init(),
+            linear_gate,
             linear_outer: LinearConfig::new(self.d_ff, self.d_model)
                 .with_initializer(self.initializer.clone())
                 .init(),
             dropout: DropoutConfig::new(self.dropout).init(),
-            gelu: GELU::new(),
+            activation: self.activation.clone(),
         }
     }
     /// Initialize a new [position-wise feed-forward](PositionWiseFeedForward) module with a
@@ -59,11 +103,20 @@ impl PositionWiseFeedForwardConfig {
         &self,
         record: PositionWiseFeedForwardRecord<B>,
     ) -> PositionWiseFeedForward<B> {
+        let linear_gate = match (self.gated, record.linear_gate) {
+            (true, Some(record)) => {
+                Some(LinearConfig::new(self.d_model, self.d_ff).init_with(record))
+            }
+            (true, None) => panic!("A gated feed-forward layer requires a record for its gate"),
+            (false, _) => None,
+        };
+
         PositionWiseFeedForward {
             linear_inner: LinearConfig::new(self.d_model, self.d_ff).init_with(record.linear_inner),
+            linear_gate,
             linear_outer: LinearConfig::new(self.d_ff, self.d_model).init_with(record.linear_outer),
             dropout: DropoutConfig::new(self.dropout).init(),
-            gelu: GELU::new(),
+            activation: self.activation.clone(),
         }
     }
 }
@@ -76,10 +129,87 @@ impl<B: Backend> PositionWiseFeedForward<B> {
     /// - tensor: `[batch_size, seq_length, d_model]`
     /// - output: `[batch_size, seq_length, d_model]`
     pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
-        let x = self.linear_inner.forward(input);
-        let x = self.gelu.forward(x);
+        let x = match &self.linear_gate {
+            Some(linear_gate) => {
+                let gate = linear_gate.forward(input.clone());
+                let x = self.linear_inner.forward(input);
+
+                self.activation.forward(x).mul(gate)
+            }
+            None => {
+                let x = self.linear_inner.forward(input);
+                self.activation.forward(x)
+            }
+        };
         let x = self.dropout.forward(x);
 
         self.linear_outer.forward(x)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::LinearRecord;
+    use crate::{
+        module::{ConstantRecord, Param},
+        TestBackend,
+    };
+    use burn_tensor::Data;
+
+    #[test]
+    fn gated_feed_forward_has_gate() {
+        let config = PositionWiseFeedForwardConfig::new(4, 8).with_gated(true);
+        let pwff = config.init::<TestBackend>();
+
+        assert!(pwff.linear_gate.is_some());
+        assert_eq!(pwff.num_params(), 2 * (4 * 8 + 8) + (8 * 4 + 4));
+        assert!(PositionWiseFeedForwardConfig::new(4, 8)
+            .init::<TestBackend>()
+            .linear_gate
+            .is_none());
+    }
+
+    #[test]
+    fn swiglu_forward() {
+        let config = PositionWiseFeedForwardConfig::new(2, 2)
+            .with_dropout(0.0)
+            .with_activation(FeedForwardActivation::Silu)
+            .with_gated(true);
+        let record = PositionWiseFeedForwardRecord {
+            linear_inner: linear_record([[1.0, -1.0], [0.5, 2.0]]),
+            linear_gate: Some(linear_record([[2.0, 0.0], [1.0, 1.0]])),
+            linear_outer: linear_record([[1.0, 0.0], [0.0, 1.0]]),
+            dropout: ConstantRecord::new(),
+            activation: ConstantRecord::new(),
+        };
+        let pwff = config.init_with::<TestBackend>(record);
+        let input = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [-1.0, 0.5]]);
+
+        let output = pwff.forward(input);
+
+        // inner = [[2, 3], [-0.75, 2]], gate = [[4, 2], [-1.5, 0.5]]
+        output
+            .to_data()
+            .assert_approx_eq(&Data::from([[7.0464, 5.7154], [0.3609, 0.8808]]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn gated_feed_forward_requires_gate_record() {
+        let record = PositionWiseFeedForwardConfig::new(4, 8)
+            .init::<TestBackend>()
+            .into_record();
+
+        PositionWiseFeedForwardConfig::new(4, 8)
+            .with_gated(true)
+            .init_with::<TestBackend>(record);
+    }
+
+    fn linear_record<B: Backend>(weight: [[f32; 2]; 2]) -> LinearRecord<B> {
+        LinearRecord {
+            weight: Param::from(Tensor::from_floats(weight)),
+            bias: Some(Param::from(Tensor::zeros([2]))),
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::module::interpolate;
+use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+
+/// Algorithm used for upsampling.
+#[derive(Module, Config, Debug, PartialEq)]
+pub enum UpsampleMode {
+    /// Nearest neighbor interpolation.
+    Nearest,
+    /// Bilinear interpolation.
+    Bilinear,
+    /// Bicubic interpolation.
+    Bicubic,
+}
+
+impl From<UpsampleMode> for InterpolateMode {
+    fn from(mode: UpsampleMode) -> Self {
+        match mode {
+            UpsampleMode::Nearest => InterpolateMode::Nearest,
+            UpsampleMode::Bilinear => InterpolateMode::Bilinear,
+            UpsampleMode::Bicubic => InterpolateMode::Bicubic,
+        }
+    }
+}
+
+/// Configuration to create an [upsample](Upsample) layer.
+///
+/// Exactly one of `output_size` and `scale_factor` must be set.
+#[derive(Config)]
+pub struct UpsampleConfig {
+    /// The size of the output.
+    pub output_size: Option<[usize; 2]>,
+    /// The multiplier applied to the height and the width of the input.
+    pub scale_factor: Option<[f32; 2]>,
+    /// The interpolation algorithm.
+    #[config(default = "UpsampleMode::Nearest")]
+    pub mode: UpsampleMode,
+    /// If true, the corner pixels of the input and the output are aligned, preserving their
+    /// values. Ignored by the nearest mode.
+    #[config(default = false)]
+    pub align_corners: bool,
+}
+
+/// Upsamples the spatial dimensions of input tensors with interpolation.
+#[derive(Module, Debug, Clone)]
+pub struct Upsample {
+    output_size: Option<[usize; 2]>,
+    scale_factor: Option<[f32; 2]>,
+    mode: UpsampleMode,
+    align_corners: bool,
+}
+
+impl UpsampleConfig {
+    /// Initialize a new [upsample](Upsample) module.
+    pub fn init(&self) -> Upsample {
+        assert!(
+            self.output_size.is_some() != self.scale_factor.is_some(),
+            "Exactly one of output_size and scale_factor must be set."
+        );
+
+        Upsample {
+            output_size: self.output_size,
+            scale_factor: self.scale_factor,
+            mode: self.mode.clone(),
+            align_corners: self.align_corners,
+        }
+    }
+}
+
+impl Upsample {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// When a scale factor is used, the output size is the input size multiplied by the scale
+    /// factor, rounded down.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels, height_in, width_in],
+    /// - output: [batch_size, channels, height_out, width_out],
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let output_size = self.output_size(input.dims());
+        let options = InterpolateOptions::new(self.mode.clone().into(), self.align_corners);
+
+        interpolate(input, output_size, options)
+    }
+
+    fn output_size(&self, [_, _, height, width]: [usize; 4]) -> [usize; 2] {
+        match (self.output_size, self.scale_factor) {
+            (Some(output_size), _) => output_size,
+            (None, Some([scale_height, scale_width])) => [
+                (height as f32 * scale_height) as usize,
+                (width as f32 * scale_width) as usize,
+            ],
+            (None, None) => unreachable!("Checked when the module is initialized."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn forward_with_scale_factor() {
+        let upsample = UpsampleConfig::new()
+            .with_scale_factor(Some([2.0, 1.5]))
+            .init();
+        let input = Tensor::<TestBackend, 4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]]);
+
+        let output = upsample.forward(input);
+
+        let expected = Data::from([[[
+            [1.0, 1.0, 2.0],
+            [1.0, 1.0, 2.0],
+            [3.0, 3.0, 4.0],
+            [3.0, 3.0, 4.0],
+        ]]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn forward_with_output_size() {
+        let upsample = UpsampleConfig::new()
+            .with_output_size(Some([3, 3]))
+            .with_mode(UpsampleMode::Bilinear)
+            .with_align_corners(true)
+            .init();
+        let input = Tensor::<TestBackend, 4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]]);
+
+        let output = upsample.forward(input);
+
+        let expected = Data::from([[[[1.0, 1.5, 2.0], [2.0, 2.5, 3.0], [3.0, 3.5, 4.0]]]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    #[should_panic = "Exactly one of output_size and scale_factor must be set."]
+    fn init_without_size_should_panic() {
+        UpsampleConfig::new().init();
+    }
+}
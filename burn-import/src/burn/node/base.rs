@@ -1,8 +1,9 @@
 use super::{
     avg_pool2d::AvgPool2dNode, batch_norm::BatchNormNode, binary::BinaryNode, clip::ClipNode,
     concat::ConcatNode, constant::ConstantNode, conv1d::Conv1dNode, conv2d::Conv2dNode,
-    dropout::DropoutNode, global_avg_pool::GlobalAvgPoolNode, linear::LinearNode,
-    matmul::MatmulNode, max_pool2d::MaxPool2dNode, reshape::ReshapeNode, unary::UnaryNode,
+    dropout::DropoutNode, global_avg_pool::GlobalAvgPoolNode, group_norm::GroupNormNode,
+    instance_norm::InstanceNormNode, linear::LinearNode, matmul::MatmulNode,
+    max_pool2d::MaxPool2dNode, reshape::ReshapeNode, unary::UnaryNode,
 };
 use crate::burn::{BurnImports, Scope, Type};
 use burn::record::PrecisionSettings;
@@ -82,6 +83,8 @@ pub enum Node<PS: PrecisionSettings> {
     Conv2d(Conv2dNode<PS>),
     Dropout(DropoutNode),
     GlobalAvgPool(GlobalAvgPoolNode),
+    GroupNorm(GroupNormNode<PS>),
+    InstanceNorm(InstanceNormNode<PS>),
     Linear(LinearNode<PS>),
     Matmul(MatmulNode),
     MaxPool2d(MaxPool2dNode),
@@ -103,6 +106,8 @@ macro_rules! match_all {
             Node::Conv2d(node) => $func(node),
             Node::Dropout(node) => $func(node),
             Node::GlobalAvgPool(node) => $func(node),
+            Node::GroupNorm(node) => $func(node),
+            Node::InstanceNorm(node) => $func(node),
             Node::Linear(node) => $func(node),
             Node::Matmul(node) => $func(node),
             Node::MaxPool2d(node) => $func(node),
@@ -134,6 +139,8 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Conv2d(_) => "conv2d",
             Node::Dropout(_) => "dropout",
             Node::GlobalAvgPool(_) => "global_avg_pool",
+            Node::GroupNorm(_) => "group_norm",
+            Node::InstanceNorm(_) => "instance_norm",
             Node::Linear(_) => "linear",
             Node::Matmul(_) => "matmul",
             Node::MaxPool2d(_) => "max_pool2d",
@@ -0,0 +1,209 @@
+use super::{Node, NodeCodegen, SerializationBackend};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::{ConstantRecord, Param, ParamId},
+    nn::{InstanceNormConfig, InstanceNormRecord},
+    record::{PrecisionSettings, Record},
+    tensor::{DataSerialize, Tensor},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+#[derive(Debug, Clone)]
+pub struct InstanceNormNode<PS: PrecisionSettings> {
+    pub dim: usize,
+    pub field: OtherType,
+    pub input: TensorType,
+    pub output: TensorType,
+    pub gamma: DataSerialize<PS::FloatElem>,
+    pub beta: DataSerialize<PS::FloatElem>,
+    pub config: InstanceNormConfig,
+}
+
+impl<PS: PrecisionSettings> InstanceNormNode<PS> {
+    pub fn new<S: AsRef<str>>(
+        dim: usize,
+        name: S,
+        input: TensorType,
+        output: TensorType,
+        gamma: DataSerialize<PS::FloatElem>,
+        beta: DataSerialize<PS::FloatElem>,
+        config: InstanceNormConfig,
+    ) -> Self {
+        let dim_tokens = dim.to_tokens();
+
+        Self {
+            dim,
+            field: OtherType::new(
+                name,
+                quote! {
+                    InstanceNorm<B, #dim_tokens>
+                },
+            ),
+            input,
+            output,
+            gamma,
+            beta,
+            config,
+        }
+    }
+}
+
+macro_rules! instance_norm_serialize {
+    ($self:expr, $serializer:expr) => {{
+        match $self.dim {
+            0 => instance_norm_serialize!($self, $serializer, 0),
+            1 => instance_norm_serialize!($self, $serializer, 1),
+            2 => instance_norm_serialize!($self, $serializer, 2),
+            3 => instance_norm_serialize!($self, $serializer, 3),
+            4 => instance_norm_serialize!($self, $serializer, 4),
+            _ => panic!("Unsupported dim {}", $self.dim),
+        }
+    }};
+
+    ($self:expr, $serializer:expr, $dim:expr) => {{
+        let record: InstanceNormRecord<SerializationBackend, $dim> = instance_norm_serialize!(record $self);
+        let item = Record::into_item::<PS>(record);
+
+        item.serialize($serializer)
+    }};
+
+    (record $self:expr) => {{
+        InstanceNormRecord {
+            gamma: Some(Param::new(
+                ParamId::new(),
+                Tensor::from_data($self.gamma.clone().convert()),
+            )),
+            beta: Some(Param::new(
+                ParamId::new(),
+                Tensor::from_data($self.beta.clone().convert()),
+            )),
+            running_mean: None,
+            running_var: None,
+            momentum: ConstantRecord::new(),
+            epsilon: ConstantRecord::new(),
+        }
+    }};
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for InstanceNormNode<PS> {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self, with_record: bool) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let num_channels = self.config.num_channels.to_tokens();
+        let epsilon = self.config.epsilon;
+        let affine = self.config.affine;
+
+        let init_line = match with_record {
+            true => quote! {
+                init_with(record.#name);
+            },
+            false => quote! {
+                init();
+            },
+        };
+
+        let tokens = quote! {
+            let #name = InstanceNormConfig::new(#num_channels)
+                .with_epsilon(#epsilon)
+                .with_affine(#affine)
+                .#init_line
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        instance_norm_serialize!(self, serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let field = &self.field.name;
+
+        quote! {
+            let #output = self.#field.forward(#input);
+        }
+    }
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::InstanceNorm");
+        imports.register("burn::nn::InstanceNormConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::InstanceNorm(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+    use burn::{record::FullPrecisionSettings, tensor::Data};
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(InstanceNormNode::new(
+            2, // Instance norm 2d
+            "norm",
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+            Data::from([2.]).serialize(),
+            Data::from([2.]).serialize(),
+            InstanceNormConfig::new(128).with_affine(true),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::InstanceNorm;
+            use burn::nn::InstanceNormConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                norm: InstanceNorm<B, 2>,
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    let norm = InstanceNormConfig::new(128)
+                        .with_epsilon(0.00001f64)
+                        .with_affine(true)
+                        .init_with(record.norm);
+
+                    Self {
+                        norm,
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = self.norm.forward(input);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -10,6 +10,8 @@ pub(crate) mod conv1d;
 pub(crate) mod conv2d;
 pub(crate) mod dropout;
 pub(crate) mod global_avg_pool;
+pub(crate) mod group_norm;
+pub(crate) mod instance_norm;
 pub(crate) mod linear;
 pub(crate) mod matmul;
 pub(crate) mod max_pool2d;
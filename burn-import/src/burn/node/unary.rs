@@ -21,11 +21,19 @@ pub struct UnaryNode {
 #[derive(Clone)]
 pub enum UnaryNodeKind {
     Cast,
+    Elu,
     Erf,
     Flatten,
+    HardSigmoid,
+    HardSwish,
+    LeakyRelu,
     LogSoftmax,
+    Mish,
     Softmax,
+    Softplus,
+    Softsign,
     Relu,
+    Selu,
     Sigmoid,
     Tanh,
     Transpose,
@@ -35,11 +43,19 @@ impl UnaryNodeKind {
     pub fn as_str(&self) -> &str {
         match self {
             Self::Cast => "cast",
+            Self::Elu => "elu",
             Self::Erf => "erf",
             Self::Flatten => "flatten",
+            Self::HardSigmoid => "hard_sigmoid",
+            Self::HardSwish => "hard_swish",
+            Self::LeakyRelu => "leaky_relu",
             Self::LogSoftmax => "log_softmax",
+            Self::Mish => "mish",
             Self::Softmax => "softmax",
+            Self::Softplus => "softplus",
+            Self::Softsign => "softsign",
             Self::Relu => "relu",
+            Self::Selu => "selu",
             Self::Sigmoid => "sigmoid",
             Self::Tanh => "tanh",
             Self::Transpose => "transpose",
@@ -114,6 +130,47 @@ impl UnaryNode {
         Self::new(input, output, UnaryNodeKind::Relu, Rc::new(function))
     }
 
+    pub(crate) fn leaky_relu(input: Type, output: Type, alpha: f64) -> Self {
+        let function = move |input| quote! { burn::tensor::activation::leaky_relu(#input, #alpha) };
+        Self::new(input, output, UnaryNodeKind::LeakyRelu, Rc::new(function))
+    }
+
+    pub(crate) fn elu(input: Type, output: Type, alpha: f64) -> Self {
+        let function = move |input| quote! { burn::tensor::activation::elu(#input, #alpha) };
+        Self::new(input, output, UnaryNodeKind::Elu, Rc::new(function))
+    }
+
+    pub(crate) fn selu(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { burn::tensor::activation::selu(#input) };
+        Self::new(input, output, UnaryNodeKind::Selu, Rc::new(function))
+    }
+
+    pub(crate) fn hard_sigmoid(input: Type, output: Type, alpha: f64, beta: f64) -> Self {
+        let function =
+            move |input| quote! { burn::tensor::activation::hard_sigmoid(#input, #alpha, #beta) };
+        Self::new(input, output, UnaryNodeKind::HardSigmoid, Rc::new(function))
+    }
+
+    pub(crate) fn hard_swish(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { burn::tensor::activation::hard_swish(#input) };
+        Self::new(input, output, UnaryNodeKind::HardSwish, Rc::new(function))
+    }
+
+    pub(crate) fn mish(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { burn::tensor::activation::mish(#input) };
+        Self::new(input, output, UnaryNodeKind::Mish, Rc::new(function))
+    }
+
+    pub(crate) fn softplus(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { burn::tensor::activation::softplus(#input, 1.0) };
+        Self::new(input, output, UnaryNodeKind::Softplus, Rc::new(function))
+    }
+
+    pub(crate) fn softsign(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { burn::tensor::activation::softsign(#input) };
+        Self::new(input, output, UnaryNodeKind::Softsign, Rc::new(function))
+    }
+
     pub(crate) fn sigmoid(input: Type, output: Type) -> Self {
         let function = move |input| quote! { burn::tensor::activation::sigmoid(#input) };
         Self::new(input, output, UnaryNodeKind::Sigmoid, Rc::new(function))
@@ -237,6 +294,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_leaky_relu() {
+        one_node_graph(
+            UnaryNode::leaky_relu(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                0.1,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::activation::leaky_relu(tensor1, 0.1f64);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_hard_sigmoid() {
+        one_node_graph(
+            UnaryNode::hard_sigmoid(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                0.2,
+                0.5,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::activation::hard_sigmoid(tensor1, 0.2f64, 0.5f64);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_mish() {
+        one_node_graph(
+            UnaryNode::mish(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::activation::mish(tensor1);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_sigmoid() {
         one_node_graph(
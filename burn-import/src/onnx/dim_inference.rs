@@ -68,8 +68,18 @@ pub fn dim_inference(
             NodeType::Linear => linear_update_outputs(node),
             NodeType::Flatten => flatten_update_outputs(node),
             NodeType::Relu => same_as_input(node),
+            NodeType::LeakyRelu => same_as_input(node),
+            NodeType::Elu => same_as_input(node),
+            NodeType::Selu => same_as_input(node),
+            NodeType::HardSigmoid => same_as_input(node),
+            NodeType::HardSwish => same_as_input(node),
+            NodeType::Mish => same_as_input(node),
+            NodeType::Softplus => same_as_input(node),
+            NodeType::Softsign => same_as_input(node),
             NodeType::LogSoftmax => same_as_input(node),
             NodeType::BatchNormalization => same_as_input(node),
+            NodeType::GroupNormalization => same_as_input(node),
+            NodeType::InstanceNormalization => same_as_input(node),
             NodeType::Add => same_as_input(node),
             NodeType::Sub => same_as_input(node),
             NodeType::Mul => same_as_input(node),
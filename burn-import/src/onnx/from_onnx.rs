@@ -15,12 +15,14 @@ use super::protos::{ModelProto, TensorProto};
 
 use protobuf::Message;
 
-const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 6] = [
+const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 8] = [
     NodeType::BatchNormalization,
     NodeType::Clip,
     NodeType::Conv1d,
     NodeType::Conv2d,
     NodeType::Dropout,
+    NodeType::GroupNormalization,
+    NodeType::InstanceNormalization,
     NodeType::Reshape,
 ];
 
@@ -2,7 +2,8 @@ use burn::nn::{
     conv::Conv1dConfig,
     conv::Conv2dConfig,
     pool::{AvgPool2dConfig, MaxPool2dConfig},
-    BatchNormConfig, DropoutConfig, LinearConfig, PaddingConfig1d, PaddingConfig2d,
+    BatchNormConfig, DropoutConfig, GroupNormConfig, InstanceNormConfig, LinearConfig,
+    PaddingConfig1d, PaddingConfig2d,
 };
 
 use crate::onnx::ir::Data;
@@ -379,6 +380,62 @@ pub fn batch_norm_config(node: &Node) -> BatchNormConfig {
         .with_momentum(momentum as f64)
 }
 
+/// Create a GroupNormConfig from the attributes of the node
+pub fn group_norm_config(node: &Node) -> GroupNormConfig {
+    // extract the number of channels from the input shape, falling back to the scale tensor
+    let input_channels = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.shape.as_ref().map(|shape| shape[1]),
+        _ => panic!("GroupNorm: only tensor input is valid"),
+    };
+    let num_channels = match (input_channels, &node.inputs[1].ty) {
+        (Some(channels), _) => channels,
+        (None, ArgType::Tensor(tensor)) => tensor.shape.clone().unwrap()[0],
+        _ => panic!("GroupNorm: scale tensor must be present"),
+    };
+
+    let mut num_groups = 0;
+    let mut epsilon = 1e-5;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "num_groups" => num_groups = value.clone().into_i64() as usize,
+            "epsilon" => epsilon = value.clone().into_f32(),
+            _ => {}
+        }
+    }
+
+    if num_groups == 0 {
+        panic!("GroupNorm: num_groups attribute must be present");
+    }
+
+    GroupNormConfig::new(num_groups, num_channels).with_epsilon(epsilon as f64)
+}
+
+/// Create a InstanceNormConfig from the attributes of the node
+pub fn instance_norm_config(node: &Node) -> InstanceNormConfig {
+    // extract the shape of the scale tensor
+    let tensor_type = if let ArgType::Tensor(ref tensor_type) = node.inputs[1].ty {
+        tensor_type
+    } else {
+        panic!("InstanceNorm: scale tensor must be present");
+    };
+
+    let num_channels: usize = tensor_type.shape.clone().unwrap()[0];
+
+    let mut epsilon = 1e-5;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "epsilon" => epsilon = value.clone().into_f32(),
+            _ => {}
+        }
+    }
+
+    InstanceNormConfig::new(num_channels)
+        .with_epsilon(epsilon as f64)
+        .with_affine(true)
+}
+
 /// Calculate the padding configuration for a 2D operations such as Convolution and Pooling.
 ///
 /// # Arguments
@@ -417,6 +474,36 @@ fn padding_config(pads: &[i64]) -> PaddingConfig2d {
     }
 }
 
+/// Extract the alpha attribute of LeakyRelu and Elu nodes, using the given default when absent.
+pub fn activation_alpha_config(node: &Node, default: f32) -> f64 {
+    let mut alpha = default;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "alpha" => alpha = value.clone().into_f32(),
+            _ => {}
+        }
+    }
+
+    alpha as f64
+}
+
+/// Extract the alpha and beta attributes of a HardSigmoid node.
+pub fn hard_sigmoid_config(node: &Node) -> (f64, f64) {
+    let mut alpha = 0.2;
+    let mut beta = 0.5;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "alpha" => alpha = value.clone().into_f32(),
+            "beta" => beta = value.clone().into_f32(),
+            _ => {}
+        }
+    }
+
+    (alpha as f64, beta as f64)
+}
+
 pub fn reshape_config(node: &Node) -> Vec<i64> {
     let mut allowzero = 0;
 
@@ -23,6 +23,8 @@ use crate::{
             conv2d::Conv2dNode,
             dropout::DropoutNode,
             global_avg_pool::GlobalAvgPoolNode,
+            group_norm::GroupNormNode,
+            instance_norm::InstanceNormNode,
             linear::LinearNode,
             matmul::MatmulNode,
             max_pool2d::MaxPool2dNode,
@@ -37,8 +39,8 @@ use crate::{
         from_onnx::convert_constant_value,
         ir::{Node, NodeType},
         op_configuration::{
-            batch_norm_config, conv1d_config, conv2d_config, flatten_config, linear_config,
-            log_softmax_config, max_pool2d_config,
+            batch_norm_config, conv1d_config, conv2d_config, flatten_config, group_norm_config,
+            instance_norm_config, linear_config, log_softmax_config, max_pool2d_config,
         },
     },
 };
@@ -47,8 +49,8 @@ use super::{
     from_onnx::parse_onnx,
     ir::{ArgType, Argument, Data, ElementType, ONNXGraph},
     op_configuration::{
-        avg_pool2d_config, clip_config, concat_config, dropout_config, reshape_config,
-        softmax_config,
+        activation_alpha_config, avg_pool2d_config, clip_config, concat_config, dropout_config,
+        hard_sigmoid_config, reshape_config, softmax_config,
     },
 };
 
@@ -241,7 +243,21 @@ impl ONNXGraph {
                 NodeType::BatchNormalization => {
                     graph.register(Self::batch_norm_conversion::<PS>(node))
                 }
+                NodeType::GroupNormalization => {
+                    graph.register(Self::group_norm_conversion::<PS>(node))
+                }
+                NodeType::InstanceNormalization => {
+                    graph.register(Self::instance_norm_conversion::<PS>(node))
+                }
                 NodeType::Relu => graph.register(Self::relu_conversion(node)),
+                NodeType::LeakyRelu => graph.register(Self::leaky_relu_conversion(node)),
+                NodeType::Elu => graph.register(Self::elu_conversion(node)),
+                NodeType::Selu => graph.register(Self::selu_conversion(node)),
+                NodeType::HardSigmoid => graph.register(Self::hard_sigmoid_conversion(node)),
+                NodeType::HardSwish => graph.register(Self::hard_swish_conversion(node)),
+                NodeType::Mish => graph.register(Self::mish_conversion(node)),
+                NodeType::Softplus => graph.register(Self::softplus_conversion(node)),
+                NodeType::Softsign => graph.register(Self::softsign_conversion(node)),
                 NodeType::Flatten => graph.register(Self::flatten_conversion(node)),
                 NodeType::LogSoftmax => graph.register(Self::log_softmax_conversion(node)),
                 NodeType::Softmax => graph.register(Self::softmax_conversion(node)),
@@ -391,6 +407,65 @@ impl ONNXGraph {
         UnaryNode::relu(input, output)
     }
 
+    fn leaky_relu_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.get(0).unwrap().to_type();
+        let output = node.outputs.get(0).unwrap().to_type();
+        let alpha = activation_alpha_config(&node, 0.01);
+
+        UnaryNode::leaky_relu(input, output, alpha)
+    }
+
+    fn elu_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.get(0).unwrap().to_type();
+        let output = node.outputs.get(0).unwrap().to_type();
+        let alpha = activation_alpha_config(&node, 1.0);
+
+        UnaryNode::elu(input, output, alpha)
+    }
+
+    fn selu_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.get(0).unwrap().to_type();
+        let output = node.outputs.get(0).unwrap().to_type();
+
+        UnaryNode::selu(input, output)
+    }
+
+    fn hard_sigmoid_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.get(0).unwrap().to_type();
+        let output = node.outputs.get(0).unwrap().to_type();
+        let (alpha, beta) = hard_sigmoid_config(&node);
+
+        UnaryNode::hard_sigmoid(input, output, alpha, beta)
+    }
+
+    fn hard_swish_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.get(0).unwrap().to_type();
+        let output = node.outputs.get(0).unwrap().to_type();
+
+        UnaryNode::hard_swish(input, output)
+    }
+
+    fn mish_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.get(0).unwrap().to_type();
+        let output = node.outputs.get(0).unwrap().to_type();
+
+        UnaryNode::mish(input, output)
+    }
+
+    fn softplus_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.get(0).unwrap().to_type();
+        let output = node.outputs.get(0).unwrap().to_type();
+
+        UnaryNode::softplus(input, output)
+    }
+
+    fn softsign_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.get(0).unwrap().to_type();
+        let output = node.outputs.get(0).unwrap().to_type();
+
+        UnaryNode::softsign(input, output)
+    }
+
     fn flatten_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.get(0).unwrap().to_type();
         let output = node.outputs.get(0).unwrap().to_type();
@@ -522,6 +597,38 @@ impl ONNXGraph {
         )
     }
 
+    fn group_norm_conversion<PS: PrecisionSettings>(node: Node) -> GroupNormNode<PS> {
+        let config = group_norm_config(&node);
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let dim = input.dim - 2;
+
+        let gamma = extract_data_serialize::<PS::FloatElem>(1, &node).expect("Scale is required");
+        let beta = extract_data_serialize::<PS::FloatElem>(2, &node).expect("Bias is required");
+
+        // Before opset 21, the scale and bias are specified per group instead of per channel.
+        let gamma = expand_groups_to_channels(gamma, config.num_groups, config.num_channels);
+        let beta = expand_groups_to_channels(beta, config.num_groups, config.num_channels);
+
+        let name = &node.name;
+
+        GroupNormNode::new(dim, name, input, output, gamma, beta, config)
+    }
+
+    fn instance_norm_conversion<PS: PrecisionSettings>(node: Node) -> InstanceNormNode<PS> {
+        let config = instance_norm_config(&node);
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let dim = input.dim - 2;
+
+        let gamma = extract_data_serialize::<PS::FloatElem>(1, &node).expect("Scale is required");
+        let beta = extract_data_serialize::<PS::FloatElem>(2, &node).expect("Bias is required");
+
+        let name = &node.name;
+
+        InstanceNormNode::new(dim, name, input, output, gamma, beta, config)
+    }
+
     fn conv1d_conversion<PS: PrecisionSettings>(node: Node) -> Conv1dNode<PS> {
         let input = node.inputs.get(0).unwrap().to_tensor_type();
         let output = node.outputs.get(0).unwrap().to_tensor_type();
@@ -582,6 +689,28 @@ impl ONNXGraph {
     }
 }
 
+/// Repeat each value of a per-group parameter for every channel of its group.
+///
+/// Parameters that are already specified per channel are returned unchanged.
+fn expand_groups_to_channels<E: Element>(
+    data: DataSerialize<E>,
+    num_groups: usize,
+    num_channels: usize,
+) -> DataSerialize<E> {
+    if data.value.len() != num_groups || num_groups == num_channels {
+        return data;
+    }
+
+    let channels_per_group = num_channels / num_groups;
+    let value = data
+        .value
+        .into_iter()
+        .flat_map(|elem| std::iter::repeat(elem).take(channels_per_group))
+        .collect();
+
+    DataSerialize::new(value, vec![num_channels])
+}
+
 /// Extract data from node states and convert it to `DataSerialize`.
 ///
 /// # Arguments
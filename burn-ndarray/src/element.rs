@@ -1,6 +1,6 @@
 use burn_tensor::Element;
-use libm::{exp, fabs, log, log1p, pow, sqrt};
-use libm::{expf, fabsf, log1pf, logf, powf, sqrtf};
+use libm::{ceil, exp, fabs, floor, log, log1p, pow, rint, sqrt, trunc};
+use libm::{ceilf, expf, fabsf, floorf, log1pf, logf, powf, rintf, sqrtf, truncf};
 use ndarray::LinalgScalar;
 
 /// A float element for ndarray backend.
@@ -18,6 +18,7 @@ pub trait NdArrayElement:
     + ExpElement
     + num_traits::FromPrimitive
     + core::ops::AddAssign
+    + core::ops::Rem<Output = Self>
     + core::cmp::PartialEq
     + core::cmp::PartialOrd<Self>
 {
@@ -33,6 +34,10 @@ pub trait ExpElement {
     fn sqrt_elem(self) -> Self;
     fn abs_elem(self) -> Self;
     fn int_abs_elem(self) -> Self;
+    fn floor_elem(self) -> Self;
+    fn ceil_elem(self) -> Self;
+    fn round_elem(self) -> Self;
+    fn trunc_elem(self) -> Self;
 }
 
 impl FloatNdArrayElement for f64 {}
@@ -91,6 +96,26 @@ macro_rules! make_elem {
             fn int_abs_elem(self) -> Self {
                 (self as i64).abs() as $ty
             }
+
+            #[inline(always)]
+            fn floor_elem(self) -> Self {
+                floor(self as f64) as $ty
+            }
+
+            #[inline(always)]
+            fn ceil_elem(self) -> Self {
+                ceil(self as f64) as $ty
+            }
+
+            #[inline(always)]
+            fn round_elem(self) -> Self {
+                rint(self as f64) as $ty
+            }
+
+            #[inline(always)]
+            fn trunc_elem(self) -> Self {
+                trunc(self as f64) as $ty
+            }
         }
     };
     (
@@ -145,6 +170,26 @@ macro_rules! make_elem {
             fn int_abs_elem(self) -> Self {
                 (self as i32).abs() as $ty
             }
+
+            #[inline(always)]
+            fn floor_elem(self) -> Self {
+                floorf(self as f32) as $ty
+            }
+
+            #[inline(always)]
+            fn ceil_elem(self) -> Self {
+                ceilf(self as f32) as $ty
+            }
+
+            #[inline(always)]
+            fn round_elem(self) -> Self {
+                rintf(self as f32) as $ty
+            }
+
+            #[inline(always)]
+            fn trunc_elem(self) -> Self {
+                truncf(self as f32) as $ty
+            }
         }
     };
 }
@@ -14,4 +14,34 @@ impl<E: FloatNdArrayElement> ActivationOps<NdArrayBackend<E>> for NdArrayBackend
 
         NdArrayTensor::new(array)
     }
+
+    fn leaky_relu<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        negative_slope: E,
+    ) -> NdArrayTensor<E, D> {
+        let zero = 0.elem();
+        let array = tensor
+            .array
+            .mapv_into(|elem| match elem < zero {
+                true => elem * negative_slope,
+                false => elem,
+            })
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn elu<const D: usize>(tensor: NdArrayTensor<E, D>, alpha: E) -> NdArrayTensor<E, D> {
+        let zero = 0.elem();
+        let one: E = 1.elem();
+        let array = tensor
+            .array
+            .mapv_into(|elem| match elem > zero {
+                true => elem,
+                false => alpha * (elem.exp_elem() - one),
+            })
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
 }
@@ -0,0 +1,350 @@
+use core::ops::Range;
+
+use crate::{
+    element::FloatNdArrayElement, iter_range_par, run_par, sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
+};
+use burn_tensor::ops::AttentionOptions;
+use burn_tensor::ElementConversion;
+use ndarray::{
+    linalg::general_mat_mul, s, ArcArray, Array1, Array2, Array4, ArrayView2, ArrayView4,
+    ArrayViewMut2, Axis, Ix4, IxDyn, Zip,
+};
+
+/// Number of queries and keys processed at once, so that only blocks of
+/// `BLOCK_SIZE x BLOCK_SIZE` attention scores are kept in memory.
+const BLOCK_SIZE: usize = 64;
+
+/// Inputs of the attention, with the bias and the mask broadcasted to the shape of the scores.
+struct Attention<'a, E> {
+    query: ArrayView4<'a, E>,
+    key: ArrayView4<'a, E>,
+    value: ArrayView4<'a, E>,
+    bias: Option<ArrayView4<'a, E>>,
+    mask: Option<ArrayView4<'a, bool>>,
+    options: &'a AttentionOptions,
+}
+
+/// Inputs of the attention of a single batch and head, which is computed block by block.
+struct AttentionHead<'a, E> {
+    query: ArrayView2<'a, E>,
+    key: ArrayView2<'a, E>,
+    value: ArrayView2<'a, E>,
+    bias: Option<ArrayView2<'a, E>>,
+    mask: Option<ArrayView2<'a, bool>>,
+    /// Index of the first score of the head in the scores of all heads, used by the dropout.
+    offset: usize,
+    options: &'a AttentionOptions,
+}
+
+impl<'a, E: FloatNdArrayElement> Attention<'a, E> {
+    fn head(&self, b: usize, h: usize) -> AttentionHead<'_, E> {
+        let [_, n_heads, seq_length_1, _] = self.query.shape().try_into().unwrap();
+        let seq_length_2 = self.key.shape()[2];
+
+        AttentionHead {
+            query: self.query.slice(s![b, h, .., ..]),
+            key: self.key.slice(s![b, h, .., ..]),
+            value: self.value.slice(s![b, h, .., ..]),
+            bias: self.bias.as_ref().map(|bias| bias.slice(s![b, h, .., ..])),
+            mask: self.mask.as_ref().map(|mask| mask.slice(s![b, h, .., ..])),
+            offset: (b * n_heads + h) * seq_length_1 * seq_length_2,
+            options: self.options,
+        }
+    }
+}
+
+impl<'a, E: FloatNdArrayElement> AttentionHead<'a, E> {
+    /// Computes the attention scores of the given queries and keys.
+    fn scores(&self, queries: Range<usize>, keys: Range<usize>, scores: &mut ArrayViewMut2<E>) {
+        general_mat_mul(
+            self.options.scale.elem(),
+            &self.query.slice(s![queries.clone(), ..]),
+            &self.key.slice(s![keys.clone(), ..]).t(),
+            0.elem(),
+            scores,
+        );
+
+        if let Some(bias) = &self.bias {
+            *scores += &bias.slice(s![queries.clone(), keys.clone()]);
+        }
+
+        if self.options.dropout != 0.0 {
+            let seq_length_2 = self.key.nrows();
+
+            for ((i, j), score) in scores.indexed_iter_mut() {
+                let index = self.offset + (queries.start + i) * seq_length_2 + keys.start + j;
+                *score = *score * self.options.dropout_factor(index).elem();
+            }
+        }
+
+        if let Some(mask) = &self.mask {
+            let mask_value: E = self.options.mask_value.elem();
+
+            Zip::from(scores)
+                .and(&mask.slice(s![queries, keys]))
+                .for_each(|score, &masked| {
+                    if masked {
+                        *score = mask_value;
+                    }
+                });
+        }
+    }
+
+    /// Computes the output of the given queries with an online softmax, where the accumulated
+    /// values are rescaled each time the maximum score changes, and returns it along with the log
+    /// of the softmax denominator of each query.
+    fn forward(&self, queries: Range<usize>, buffer: &mut Array2<E>) -> (Array2<E>, Array1<E>) {
+        let num_queries = queries.len();
+        let mut output = Array2::zeros((num_queries, self.value.ncols()));
+        let mut max = Array1::from_elem(num_queries, f64::NEG_INFINITY.elem::<E>());
+        let mut sum = Array1::<E>::zeros(num_queries);
+
+        for keys in blocks(self.key.nrows()) {
+            let mut weights = buffer.slice_mut(s![..num_queries, ..keys.len()]);
+            self.scores(queries.clone(), keys.clone(), &mut weights);
+
+            for (i, mut row) in weights.rows_mut().into_iter().enumerate() {
+                let max_new = row.fold(max[i], |acc, &score| match score > acc {
+                    true => score,
+                    false => acc,
+                });
+                let correction = (max[i] - max_new).exp_elem();
+
+                row.mapv_inplace(|score| (score - max_new).exp_elem());
+                sum[i] = sum[i] * correction + row.sum();
+                output.row_mut(i).mapv_inplace(|x| x * correction);
+                max[i] = max_new;
+            }
+
+            general_mat_mul(
+                1.elem(),
+                &weights,
+                &self.value.slice(s![keys, ..]),
+                1.elem(),
+                &mut output,
+            );
+        }
+
+        for (i, mut row) in output.rows_mut().into_iter().enumerate() {
+            row.mapv_inplace(|x| x / sum[i]);
+        }
+        let log_sum_exp = Zip::from(&max)
+            .and(&sum)
+            .map_collect(|&max, &sum| max + sum.log_elem());
+
+        (output, log_sum_exp)
+    }
+
+    /// Accumulates the gradients of the query, the key and the value of the head.
+    ///
+    /// The output and the log of the softmax denominator of each block of queries are recomputed,
+    /// so that the attention weights are only recomputed block by block.
+    fn backward(
+        &self,
+        output_grad: ArrayView2<E>,
+        mut query_grad: ArrayViewMut2<E>,
+        mut key_grad: ArrayViewMut2<E>,
+        mut value_grad: ArrayViewMut2<E>,
+    ) {
+        let mut buffer = Array2::zeros((BLOCK_SIZE, BLOCK_SIZE));
+        let mut buffer_grad = Array2::zeros((BLOCK_SIZE, BLOCK_SIZE));
+
+        for queries in blocks(self.query.nrows()) {
+            let num_queries = queries.len();
+            let (output, log_sum_exp) = self.forward(queries.clone(), &mut buffer);
+            let output_grad = output_grad.slice(s![queries.clone(), ..]);
+
+            // Sum of the gradients of the weights multiplied by the weights of each query, which
+            // is also the sum of its output gradient multiplied by its output.
+            let weighted_sum = (&output * &output_grad).sum_axis(Axis(1));
+
+            for keys in blocks(self.key.nrows()) {
+                let mut weights = buffer.slice_mut(s![..num_queries, ..keys.len()]);
+                self.scores(queries.clone(), keys.clone(), &mut weights);
+
+                for (i, mut row) in weights.rows_mut().into_iter().enumerate() {
+                    row.mapv_inplace(|score| (score - log_sum_exp[i]).exp_elem());
+                }
+
+                general_mat_mul(
+                    1.elem(),
+                    &weights.t(),
+                    &output_grad,
+                    1.elem(),
+                    &mut value_grad.slice_mut(s![keys.clone(), ..]),
+                );
+
+                let mut scores_grad = buffer_grad.slice_mut(s![..num_queries, ..keys.len()]);
+                general_mat_mul(
+                    1.elem(),
+                    &output_grad,
+                    &self.value.slice(s![keys.clone(), ..]).t(),
+                    0.elem(),
+                    &mut scores_grad,
+                );
+
+                // Backward of the softmax: dS = P * (dP - sum(dP * P)).
+                Zip::indexed(&mut scores_grad).and(&weights).for_each(
+                    |(i, j), grad, &weight| {
+                        let factor = self.score_grad_factor(queries.start + i, keys.start + j);
+                        *grad = weight * (*grad - weighted_sum[i]) * factor;
+                    },
+                );
+
+                general_mat_mul(
+                    1.elem(),
+                    &scores_grad,
+                    &self.key.slice(s![keys.clone(), ..]),
+                    1.elem(),
+                    &mut query_grad.slice_mut(s![queries.clone(), ..]),
+                );
+                general_mat_mul(
+                    1.elem(),
+                    &scores_grad.t(),
+                    &self.query.slice(s![queries.clone(), ..]),
+                    1.elem(),
+                    &mut key_grad.slice_mut(s![keys, ..]),
+                );
+            }
+        }
+    }
+
+    /// The factor applied to the gradient of a score to get the gradient of the dot product of
+    /// its query and key.
+    fn score_grad_factor(&self, i: usize, j: usize) -> E {
+        if let Some(mask) = &self.mask {
+            if mask[[i, j]] {
+                return 0.elem();
+            }
+        }
+
+        let index = self.offset + i * self.key.nrows() + j;
+        (self.options.scale * self.options.dropout_factor(index)).elem()
+    }
+}
+
+/// Splits the given length into blocks of at most [BLOCK_SIZE](BLOCK_SIZE) elements.
+fn blocks(length: usize) -> impl Iterator<Item = Range<usize>> {
+    (0..length)
+        .step_by(BLOCK_SIZE)
+        .map(move |start| start..usize::min(start + BLOCK_SIZE, length))
+}
+
+fn dim4<E>(array: ArcArray<E, IxDyn>) -> ArcArray<E, Ix4> {
+    array.into_dimensionality::<Ix4>().unwrap()
+}
+
+/// Broadcasts the bias or the mask to the shape of the attention scores.
+fn broadcast<E>(array: &ArcArray<E, Ix4>, shape: [usize; 4]) -> ArrayView4<'_, E> {
+    array
+        .broadcast(shape)
+        .expect("The bias and the mask should be broadcastable to the attention scores")
+}
+
+pub(crate) fn scaled_dot_product_attention<E: FloatNdArrayElement>(
+    query: NdArrayTensor<E, 4>,
+    key: NdArrayTensor<E, 4>,
+    value: NdArrayTensor<E, 4>,
+    bias: Option<NdArrayTensor<E, 4>>,
+    mask: Option<NdArrayTensor<bool, 4>>,
+    options: AttentionOptions,
+) -> NdArrayTensor<E, 4> {
+    let [batch_size, n_heads, seq_length_1, _] = query.shape().dims;
+    let [_, _, seq_length_2, d_v] = value.shape().dims;
+    let shape_scores = [batch_size, n_heads, seq_length_1, seq_length_2];
+
+    let query = dim4(query.array);
+    let key = dim4(key.array);
+    let value = dim4(value.array);
+    let bias = bias.map(|bias| dim4(bias.array));
+    let mask = mask.map(|mask| dim4(mask.array));
+    let attention = Attention {
+        query: query.view(),
+        key: key.view(),
+        value: value.view(),
+        bias: bias.as_ref().map(|bias| broadcast(bias, shape_scores)),
+        mask: mask.as_ref().map(|mask| broadcast(mask, shape_scores)),
+        options: &options,
+    };
+
+    let mut output = Array4::zeros((batch_size, n_heads, seq_length_1, d_v));
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * n_heads).for_each(|k| unsafe {
+            let b = k / n_heads;
+            let h = k % n_heads;
+
+            let head = attention.head(b, h);
+            let mut buffer = Array2::zeros((BLOCK_SIZE, BLOCK_SIZE));
+
+            for queries in blocks(seq_length_1) {
+                let (output, _) = head.forward(queries.clone(), &mut buffer);
+
+                unsafe_shared_out
+                    .get()
+                    .slice_mut(s![b, h, queries, ..])
+                    .assign(&output);
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+pub(crate) fn scaled_dot_product_attention_backward<E: FloatNdArrayElement>(
+    query: NdArrayTensor<E, 4>,
+    key: NdArrayTensor<E, 4>,
+    value: NdArrayTensor<E, 4>,
+    bias: Option<NdArrayTensor<E, 4>>,
+    mask: Option<NdArrayTensor<bool, 4>>,
+    output_grad: NdArrayTensor<E, 4>,
+    options: AttentionOptions,
+) -> [NdArrayTensor<E, 4>; 3] {
+    let [batch_size, n_heads, seq_length_1, d_k] = query.shape().dims;
+    let [_, _, seq_length_2, d_v] = value.shape().dims;
+    let shape_scores = [batch_size, n_heads, seq_length_1, seq_length_2];
+
+    let query = dim4(query.array);
+    let key = dim4(key.array);
+    let value = dim4(value.array);
+    let output_grad = dim4(output_grad.array);
+    let bias = bias.map(|bias| dim4(bias.array));
+    let mask = mask.map(|mask| dim4(mask.array));
+    let attention = Attention {
+        query: query.view(),
+        key: key.view(),
+        value: value.view(),
+        bias: bias.as_ref().map(|bias| broadcast(bias, shape_scores)),
+        mask: mask.as_ref().map(|mask| broadcast(mask, shape_scores)),
+        options: &options,
+    };
+
+    let mut query_grad = Array4::zeros((batch_size, n_heads, seq_length_1, d_k));
+    let mut key_grad = Array4::zeros((batch_size, n_heads, seq_length_2, d_k));
+    let mut value_grad = Array4::zeros((batch_size, n_heads, seq_length_2, d_v));
+    let unsafe_shared_query_grad = UnsafeSharedRef::new(&mut query_grad);
+    let unsafe_shared_key_grad = UnsafeSharedRef::new(&mut key_grad);
+    let unsafe_shared_value_grad = UnsafeSharedRef::new(&mut value_grad);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * n_heads).for_each(|k| unsafe {
+            let b = k / n_heads;
+            let h = k % n_heads;
+
+            attention.head(b, h).backward(
+                output_grad.slice(s![b, h, .., ..]),
+                unsafe_shared_query_grad.get().slice_mut(s![b, h, .., ..]),
+                unsafe_shared_key_grad.get().slice_mut(s![b, h, .., ..]),
+                unsafe_shared_value_grad.get().slice_mut(s![b, h, .., ..]),
+            );
+        })
+    });
+
+    [
+        NdArrayTensor::new(query_grad.into_dyn().into_shared()),
+        NdArrayTensor::new(key_grad.into_dyn().into_shared()),
+        NdArrayTensor::new(value_grad.into_dyn().into_shared()),
+    ]
+}
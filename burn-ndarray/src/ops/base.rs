@@ -1,7 +1,7 @@
 use alloc::vec::Vec;
 use burn_tensor::Data;
 use burn_tensor::ElementConversion;
-use core::{marker::PhantomData, ops::Range};
+use core::{cmp::Ordering, marker::PhantomData, ops::Range};
 use ndarray::s;
 use ndarray::Array2;
 
@@ -10,6 +10,7 @@ use ndarray::Axis;
 use ndarray::Dim;
 use ndarray::IxDyn;
 use ndarray::SliceInfoElem;
+use ndarray::Zip;
 
 use crate::element::NdArrayElement;
 use crate::ops::macros::{keepdim, mean_dim, sum_dim};
@@ -107,6 +108,70 @@ where
 
         NdArrayTensor::new(array)
     }
+
+    pub fn permute<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        axes: [usize; D],
+    ) -> NdArrayTensor<E, D> {
+        let array = tensor.array.permuted_axes(IxDyn(&axes));
+
+        NdArrayTensor::new(array)
+    }
+
+    pub fn expand<const D1: usize, const D2: usize>(
+        tensor: NdArrayTensor<E, D1>,
+        shape: Shape<D2>,
+    ) -> NdArrayTensor<E, D2> {
+        // Only views can have zero strides with ndarray, so the broadcasted view is copied into a
+        // shared array.
+        let array = tensor
+            .array
+            .broadcast(IxDyn(&shape.dims))
+            .expect("The tensor should be broadcastable to the given shape")
+            .to_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    pub fn flip<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        axes: &[usize],
+    ) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array;
+
+        for &axis in axes {
+            array.invert_axis(Axis(axis));
+        }
+
+        NdArrayTensor::new(array)
+    }
+
+    pub fn roll<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> NdArrayTensor<E, D> {
+        let mut offsets = [0; D];
+
+        for (&shift, &dim) in shifts.iter().zip(dims.iter()) {
+            offsets[dim] += shift;
+        }
+
+        let array = &tensor.array;
+        let shape = array.shape();
+        let output = ndarray::Array::from_shape_fn(IxDyn(shape), |index| {
+            let mut index = index;
+
+            for (dim, offset) in offsets.iter().enumerate() {
+                let size = shape[dim] as i64;
+                index[dim] = (index[dim] as i64 - offset).rem_euclid(size) as usize;
+            }
+
+            array[index]
+        });
+
+        NdArrayTensor::new(output.into_shared())
+    }
 }
 
 impl<E> NdArrayMathOps<E>
@@ -181,6 +246,28 @@ where
         NdArrayTensor { array }
     }
 
+    pub fn remainder<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        // The `%` operator keeps the sign of the dividend, shifting by the divisor gives the
+        // remainder with the sign of the divisor.
+        let array = &(&(&lhs.array % &rhs.array) + &rhs.array) % &rhs.array;
+        let array = array.into_shared();
+
+        NdArrayTensor { array }
+    }
+
+    pub fn remainder_scalar<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: E,
+    ) -> NdArrayTensor<E, D> {
+        let array = lhs.array.mapv_into(|a| ((a % rhs) + rhs) % rhs);
+        let array = array.into_shared();
+
+        NdArrayTensor { array }
+    }
+
     pub fn mean<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
         let data = Data::from([tensor.array.mean().unwrap()]);
         NdArrayTensor::from_data(data)
@@ -218,6 +305,23 @@ where
         }
     }
 
+    pub fn prod<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
+        let data = Data::from([tensor.array.product()]);
+        NdArrayTensor::from_data(data)
+    }
+
+    pub fn prod_dim<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .fold_axis(Axis(dim), E::one(), |&acc, &x| acc * x)
+            .insert_axis(Axis(dim));
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
     pub fn gather<const D: usize>(
         dim: usize,
         mut tensor: NdArrayTensor<E, D>,
@@ -403,6 +507,112 @@ where
         arg(tensor, dim, CmpType::Min)
     }
 
+    pub fn cumsum<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&prev, curr| *curr += prev);
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn cumprod<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&prev, curr| *curr = *curr * prev);
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn cummax<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&prev, curr| {
+            if prev > *curr {
+                *curr = prev;
+            }
+        });
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn cummax_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        let mut values = tensor.array.into_owned();
+        let mut indices = values.map(|_| 0i64);
+
+        Zip::from(values.lanes_mut(Axis(dim)))
+            .and(indices.lanes_mut(Axis(dim)))
+            .for_each(|mut lane_values, mut lane_indices| {
+                let mut index = 0;
+
+                for i in 0..lane_values.len() {
+                    // The last index is used when the maximum is reached multiple times.
+                    if lane_values[i] >= lane_values[index] {
+                        index = i;
+                    } else {
+                        lane_values[i] = lane_values[index];
+                    }
+                    lane_indices[i] = index as i64;
+                }
+            });
+
+        (
+            NdArrayTensor::new(values.into_shared()),
+            NdArrayTensor::new(indices.into_shared()),
+        )
+    }
+
+    pub fn logcumsumexp<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&prev, curr| {
+            // log(exp(a) + exp(b)) = max(a, b) + log(1 + exp(min(a, b) - max(a, b)))
+            let (max, diff) = match prev == *curr {
+                true => (prev, 0.elem()),
+                false if prev > *curr => (prev, *curr - prev),
+                false => (*curr, prev - *curr),
+            };
+
+            *curr = max + diff.exp_elem().log1p_elem();
+        });
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn sort_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        let mut values = tensor.array.to_owned();
+        let mut indices = values.map(|_| 0i64);
+        let mut lane_indices: Vec<usize> = Vec::with_capacity(values.len_of(Axis(dim)));
+
+        Zip::from(tensor.array.lanes(Axis(dim)))
+            .and(values.lanes_mut(Axis(dim)))
+            .and(indices.lanes_mut(Axis(dim)))
+            .for_each(|lane, mut lane_values, mut lane_output| {
+                lane_indices.clear();
+                lane_indices.extend(0..lane.len());
+                // The sort is stable, so equal elements keep their original order.
+                lane_indices.sort_by(|a, b| match descending {
+                    true => cmp_nan_last(&lane[*b], &lane[*a]),
+                    false => cmp_nan_last(&lane[*a], &lane[*b]),
+                });
+
+                for (i, index) in lane_indices.iter().enumerate() {
+                    lane_values[i] = lane[*index];
+                    lane_output[i] = *index as i64;
+                }
+            });
+
+        (
+            NdArrayTensor::new(values.into_shared()),
+            NdArrayTensor::new(indices.into_shared()),
+        )
+    }
+
     pub fn clamp_min<const D: usize>(
         mut tensor: NdArrayTensor<E, D>,
         min: E,
@@ -444,6 +654,15 @@ where
     }
 }
 
+/// Compares two elements, considering `NaN` values greater than any other value.
+fn cmp_nan_last<E: NdArrayElement>(a: &E, b: &E) -> Ordering {
+    #[allow(clippy::eq_op)]
+    let is_nan = |x: &E| x != x;
+
+    a.partial_cmp(b)
+        .unwrap_or_else(|| is_nan(a).cmp(&is_nan(b)))
+}
+
 enum CmpType {
     Min,
     Max,
@@ -4,6 +4,7 @@ use alloc::vec::Vec;
 use burn_tensor::ops::{BoolTensorOps, IntTensorOps};
 use burn_tensor::{ElementConversion, Reader};
 use core::ops::Range;
+use ndarray::Axis;
 
 // Current crate
 use crate::element::FloatNdArrayElement;
@@ -128,4 +129,56 @@ impl<E: FloatNdArrayElement> BoolTensorOps<NdArrayBackend<E>> for NdArrayBackend
     ) -> <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D> {
         NdArrayOps::swap_dims(tensor, dim1, dim2)
     }
+
+    fn bool_permute<const D: usize>(
+        tensor: NdArrayTensor<bool, D>,
+        axes: [usize; D],
+    ) -> NdArrayTensor<bool, D> {
+        NdArrayOps::permute(tensor, axes)
+    }
+
+    fn bool_expand<const D1: usize, const D2: usize>(
+        tensor: NdArrayTensor<bool, D1>,
+        shape: Shape<D2>,
+    ) -> NdArrayTensor<bool, D2> {
+        NdArrayOps::expand(tensor, shape)
+    }
+
+    fn bool_any<const D: usize>(
+        tensor: <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
+    ) -> <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<1> {
+        let any = tensor.array.iter().any(|&a| a);
+        NdArrayTensor::from_data(Data::from([any]))
+    }
+
+    fn bool_any_dim<const D: usize>(
+        tensor: <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
+        dim: usize,
+    ) -> <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D> {
+        let array = tensor
+            .array
+            .map_axis(Axis(dim), |lane| lane.iter().any(|&a| a))
+            .insert_axis(Axis(dim));
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    fn bool_all<const D: usize>(
+        tensor: <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
+    ) -> <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<1> {
+        let all = tensor.array.iter().all(|&a| a);
+        NdArrayTensor::from_data(Data::from([all]))
+    }
+
+    fn bool_all_dim<const D: usize>(
+        tensor: <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
+        dim: usize,
+    ) -> <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D> {
+        let array = tensor
+            .array
+            .map_axis(Axis(dim), |lane| lane.iter().all(|&a| a))
+            .insert_axis(Axis(dim));
+
+        NdArrayTensor::new(array.into_shared())
+    }
 }
@@ -1,7 +1,7 @@
 // Language
 use alloc::vec;
 use alloc::vec::Vec;
-use burn_tensor::ops::IntTensorOps;
+use burn_tensor::ops::{IntTensorOps, PadMode};
 use burn_tensor::Reader;
 
 use burn_tensor::ElementConversion;
@@ -16,7 +16,7 @@ use crate::{tensor::NdArrayTensor, NdArrayBackend};
 // Workspace crates
 use burn_tensor::{backend::Backend, Data, Shape};
 
-use super::{NdArrayMathOps, NdArrayOps};
+use super::{padding, NdArrayMathOps, NdArrayOps};
 
 impl<E: FloatNdArrayElement> IntTensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
     fn int_from_data<const D: usize>(
@@ -240,6 +240,20 @@ impl<E: FloatNdArrayElement> IntTensorOps<NdArrayBackend<E>> for NdArrayBackend<
         NdArrayMathOps::div_scalar(lhs, rhs)
     }
 
+    fn int_remainder<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::remainder(lhs, rhs)
+    }
+
+    fn int_remainder_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::remainder_scalar(lhs, rhs)
+    }
+
     fn int_neg<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, D> {
         Self::int_mul_scalar(tensor, -1)
     }
@@ -277,6 +291,17 @@ impl<E: FloatNdArrayElement> IntTensorOps<NdArrayBackend<E>> for NdArrayBackend<
         NdArrayMathOps::sum_dim(tensor, dim)
     }
 
+    fn int_prod<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, 1> {
+        NdArrayMathOps::prod(tensor)
+    }
+
+    fn int_prod_dim<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::prod_dim(tensor, dim)
+    }
+
     fn int_mean<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, 1> {
         NdArrayMathOps::mean(tensor)
     }
@@ -335,6 +360,42 @@ impl<E: FloatNdArrayElement> IntTensorOps<NdArrayBackend<E>> for NdArrayBackend<
         NdArrayMathOps::argmin(tensor, dim)
     }
 
+    fn int_cumsum<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::cumsum(tensor, dim)
+    }
+
+    fn int_cumprod<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::cumprod(tensor, dim)
+    }
+
+    fn int_cummax<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::cummax(tensor, dim)
+    }
+
+    fn int_cummax_with_indices<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<i64, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::cummax_with_indices(tensor, dim)
+    }
+
+    fn int_sort_with_indices<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (NdArrayTensor<i64, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::sort_with_indices(tensor, dim, descending)
+    }
+
     fn int_clamp_min<const D: usize>(
         tensor: NdArrayTensor<i64, D>,
         min: i64,
@@ -377,4 +438,41 @@ impl<E: FloatNdArrayElement> IntTensorOps<NdArrayBackend<E>> for NdArrayBackend<
     ) -> <NdArrayBackend<E> as Backend>::IntTensorPrimitive<D> {
         NdArrayOps::swap_dims(tensor, dim1, dim2)
     }
+
+    fn int_permute<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        axes: [usize; D],
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayOps::permute(tensor, axes)
+    }
+
+    fn int_expand<const D1: usize, const D2: usize>(
+        tensor: NdArrayTensor<i64, D1>,
+        shape: Shape<D2>,
+    ) -> NdArrayTensor<i64, D2> {
+        NdArrayOps::expand(tensor, shape)
+    }
+
+    fn int_flip<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        axes: &[usize],
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayOps::flip(tensor, axes)
+    }
+
+    fn int_roll<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayOps::roll(tensor, shifts, dims)
+    }
+
+    fn int_pad<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> NdArrayTensor<i64, D> {
+        padding::pad(tensor, pads, mode)
+    }
 }
@@ -0,0 +1,90 @@
+use crate::{
+    element::FloatNdArrayElement, iter_range_par, run_par, sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
+};
+use burn_tensor::ops::{interpolate::interpolate_weights, InterpolateOptions};
+use burn_tensor::ElementConversion;
+use ndarray::Array4;
+
+pub(crate) fn interpolate<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 4>,
+    output_size: [usize; 2],
+    options: InterpolateOptions,
+) -> NdArrayTensor<E, 4> {
+    let [batch_size, channels, input_height, input_width] = x.shape().dims;
+    let [output_height, output_width] = output_size;
+
+    let weights_height = interpolate_weights(input_height, output_height, &options);
+    let weights_width = interpolate_weights(input_width, output_width, &options);
+
+    let x = x.array;
+    let mut output = Array4::from_elem(
+        (batch_size, channels, output_height, output_width),
+        0.elem(),
+    );
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+            for (oh, taps_height) in weights_height.iter().enumerate() {
+                for (ow, taps_width) in weights_width.iter().enumerate() {
+                    let mut sum = 0.0;
+
+                    for (ih, weight_height) in taps_height.iter() {
+                        for (iw, weight_width) in taps_width.iter() {
+                            let value: f64 = x[[b, c, *ih, *iw]].elem();
+                            sum += value * weight_height * weight_width;
+                        }
+                    }
+
+                    output[[b, c, oh, ow]] = sum.elem();
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+pub(crate) fn interpolate_backward<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 4>,
+    grad: NdArrayTensor<E, 4>,
+    options: InterpolateOptions,
+) -> NdArrayTensor<E, 4> {
+    let [_, _, input_height, input_width] = x.shape().dims;
+    let [batch_size, channels, output_height, output_width] = grad.shape().dims;
+
+    let weights_height = interpolate_weights(input_height, output_height, &options);
+    let weights_width = interpolate_weights(input_width, output_width, &options);
+
+    let mut output_grad =
+        Array4::from_elem((batch_size, channels, input_height, input_width), 0.elem());
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output_grad);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output_grad = unsafe_shared_out.get();
+            for (oh, taps_height) in weights_height.iter().enumerate() {
+                for (ow, taps_width) in weights_width.iter().enumerate() {
+                    let value: f64 = grad.array[[b, c, oh, ow]].elem();
+
+                    for (ih, weight_height) in taps_height.iter() {
+                        for (iw, weight_width) in taps_width.iter() {
+                            let contribution: E = (value * weight_height * weight_width).elem();
+                            output_grad[[b, c, *ih, *iw]] += contribution;
+                        }
+                    }
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output_grad.into_dyn().into_shared())
+}
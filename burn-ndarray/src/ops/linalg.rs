@@ -0,0 +1,4 @@
+use crate::{element::FloatNdArrayElement, NdArrayBackend};
+use burn_tensor::ops::LinalgOps;
+
+impl<E: FloatNdArrayElement> LinalgOps<NdArrayBackend<E>> for NdArrayBackend<E> {}
@@ -2,16 +2,20 @@ mod activations;
 mod base;
 mod bool_tensor;
 mod int_tensor;
+mod linalg;
 mod module;
 mod tensor;
 
 pub(crate) mod adaptive_avgpool;
+pub(crate) mod attention;
 pub(crate) mod avgpool;
 pub(crate) mod conv;
+pub(crate) mod interpolate;
 pub(crate) mod macros;
 pub(crate) mod matmul;
 pub(crate) mod maxpool;
 pub(crate) mod padding;
+pub(crate) mod segment;
 pub(crate) mod unfold;
 
 pub(crate) use base::*;
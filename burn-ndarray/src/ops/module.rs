@@ -1,8 +1,11 @@
 use super::{
     adaptive_avgpool::{adaptive_avg_pool2d, adaptive_avg_pool2d_backward},
+    attention::{scaled_dot_product_attention, scaled_dot_product_attention_backward},
     avgpool::{avg_pool2d, avg_pool2d_backward},
     conv::{conv2d, conv_transpose2d},
+    interpolate::{interpolate, interpolate_backward},
     maxpool::{max_pool2d, max_pool2d_backward, max_pool2d_with_indices},
+    segment::{segment_reduce, segment_reduce_backward},
     unfold::unfold4d,
 };
 use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArrayBackend};
@@ -108,4 +111,70 @@ impl<E: FloatNdArrayElement> ModuleOps<NdArrayBackend<E>> for NdArrayBackend<E>
     ) -> NdArrayTensor<E, 4> {
         adaptive_avg_pool2d_backward(x, grad)
     }
+
+    fn interpolate(
+        x: NdArrayTensor<E, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> NdArrayTensor<E, 4> {
+        interpolate(x, output_size, options)
+    }
+
+    fn interpolate_backward(
+        x: NdArrayTensor<E, 4>,
+        grad: NdArrayTensor<E, 4>,
+        options: InterpolateOptions,
+    ) -> NdArrayTensor<E, 4> {
+        interpolate_backward(x, grad, options)
+    }
+
+    fn scaled_dot_product_attention(
+        query: NdArrayTensor<E, 4>,
+        key: NdArrayTensor<E, 4>,
+        value: NdArrayTensor<E, 4>,
+        bias: Option<NdArrayTensor<E, 4>>,
+        mask: Option<NdArrayTensor<bool, 4>>,
+        options: AttentionOptions,
+    ) -> NdArrayTensor<E, 4> {
+        scaled_dot_product_attention(query, key, value, bias, mask, options)
+    }
+
+    fn scaled_dot_product_attention_backward(
+        query: NdArrayTensor<E, 4>,
+        key: NdArrayTensor<E, 4>,
+        value: NdArrayTensor<E, 4>,
+        bias: Option<NdArrayTensor<E, 4>>,
+        mask: Option<NdArrayTensor<bool, 4>>,
+        output_grad: NdArrayTensor<E, 4>,
+        options: AttentionOptions,
+    ) -> AttentionBackward<NdArrayBackend<E>> {
+        let [query_grad, key_grad, value_grad] = scaled_dot_product_attention_backward(
+            query,
+            key,
+            value,
+            bias,
+            mask,
+            output_grad,
+            options,
+        );
+
+        AttentionBackward::new(query_grad, key_grad, value_grad)
+    }
+
+    fn segment_reduce(
+        tensor: NdArrayTensor<E, 2>,
+        offsets: NdArrayTensor<i64, 1>,
+        reduction: SegmentReduction,
+    ) -> NdArrayTensor<E, 2> {
+        segment_reduce(tensor, offsets, reduction)
+    }
+
+    fn segment_reduce_backward(
+        tensor: NdArrayTensor<E, 2>,
+        offsets: NdArrayTensor<i64, 1>,
+        output_grad: NdArrayTensor<E, 2>,
+        reduction: SegmentReduction,
+    ) -> NdArrayTensor<E, 2> {
+        segment_reduce_backward(tensor, offsets, output_grad, reduction)
+    }
 }
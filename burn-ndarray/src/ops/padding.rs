@@ -1,33 +1,75 @@
-use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArrayBackend};
-use burn_tensor::ops::TensorOps;
-use ndarray::Array4;
+use super::NdArrayOps;
+use crate::{element::NdArrayElement, tensor::NdArrayTensor};
+use burn_tensor::{ops::PadMode, ElementConversion};
+use ndarray::{ArrayD, IxDyn};
 
-pub(crate) fn apply_padding_4d<E: FloatNdArrayElement>(
+pub(crate) fn apply_padding_4d<E: NdArrayElement>(
     x: NdArrayTensor<E, 4>,
     padding: [usize; 2],
     elem: E,
 ) -> NdArrayTensor<E, 4> {
-    let [batch_size, input_channels, height, width] = x.shape().dims;
     let [padding_height, padding_width] = padding;
-    let padded_height = height + 2 * padding_height;
-    let padded_width = width + 2 * padding_width;
 
-    let x_new = Array4::from_elem(
-        (batch_size, input_channels, padded_height, padded_width),
-        elem,
-    );
-    let mut x_new = NdArrayTensor::new(x_new.into_shared().into_dyn());
-
-    x_new = NdArrayBackend::slice_assign(
-        x_new,
+    pad_constant(
+        x,
         [
-            0..batch_size,
-            0..input_channels,
-            padding_height..(height + padding_height),
-            padding_width..width + padding_width,
+            (0, 0),
+            (0, 0),
+            (padding_height, padding_height),
+            (padding_width, padding_width),
         ],
-        x,
-    );
+        elem,
+    )
+}
+
+pub(crate) fn pad<E: NdArrayElement, const D: usize>(
+    x: NdArrayTensor<E, D>,
+    pads: [(usize, usize); D],
+    mode: PadMode,
+) -> NdArrayTensor<E, D> {
+    let source: fn(i64, i64) -> i64 = match mode {
+        PadMode::Constant(value) => return pad_constant(x, pads, value.elem()),
+        PadMode::Reflect => |index: i64, size: i64| {
+            let index = index.abs();
+            if index < size {
+                index
+            } else {
+                2 * (size - 1) - index
+            }
+        },
+        PadMode::Replicate => |index: i64, size: i64| index.clamp(0, size - 1),
+        PadMode::Circular => |index: i64, size: i64| index.rem_euclid(size),
+    };
+
+    let array = &x.array;
+    let shape = array.shape();
+    let padded_shape: [usize; D] =
+        core::array::from_fn(|dim| shape[dim] + pads[dim].0 + pads[dim].1);
+
+    let output = ArrayD::from_shape_fn(IxDyn(&padded_shape), |mut index| {
+        for (dim, &(before, _)) in pads.iter().enumerate() {
+            let position = index[dim] as i64 - before as i64;
+            index[dim] = source(position, shape[dim] as i64) as usize;
+        }
+
+        array[index]
+    });
+
+    NdArrayTensor::new(output.into_shared())
+}
+
+fn pad_constant<E: NdArrayElement, const D: usize>(
+    x: NdArrayTensor<E, D>,
+    pads: [(usize, usize); D],
+    elem: E,
+) -> NdArrayTensor<E, D> {
+    let shape = x.shape().dims;
+    let padded_shape: [usize; D] =
+        core::array::from_fn(|dim| shape[dim] + pads[dim].0 + pads[dim].1);
+    let ranges = core::array::from_fn(|dim| pads[dim].0..pads[dim].0 + shape[dim]);
+
+    let x_new = ArrayD::from_elem(IxDyn(&padded_shape), elem);
+    let x_new = NdArrayTensor::new(x_new.into_shared());
 
-    x_new
+    NdArrayOps::slice_assign::<D, D>(x_new, ranges, x)
 }
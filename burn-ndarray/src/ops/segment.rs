@@ -0,0 +1,131 @@
+use crate::{
+    element::FloatNdArrayElement, iter_range_par, run_par, sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
+};
+use alloc::vec::Vec;
+use burn_tensor::ops::SegmentReduction;
+use burn_tensor::ElementConversion;
+use ndarray::{Array2, ArrayD};
+
+/// Returns the range of rows of each segment.
+fn segment_ranges(offsets: NdArrayTensor<i64, 1>, num_rows: usize) -> Vec<(usize, usize)> {
+    let starts: Vec<usize> = offsets
+        .array
+        .iter()
+        .map(|offset| *offset as usize)
+        .collect();
+
+    starts
+        .iter()
+        .enumerate()
+        .map(|(segment, start)| {
+            let end = starts.get(segment + 1).copied().unwrap_or(num_rows);
+            (*start, end)
+        })
+        .collect()
+}
+
+/// Returns the row holding the maximum of the given column in the segment, the last one in
+/// case of ties.
+fn argmax<E: FloatNdArrayElement>(tensor: &ArrayD<E>, start: usize, end: usize, c: usize) -> usize {
+    let mut index = start;
+    for i in start + 1..end {
+        if tensor[[i, c]] >= tensor[[index, c]] {
+            index = i;
+        }
+    }
+    index
+}
+
+pub(crate) fn segment_reduce<E: FloatNdArrayElement>(
+    tensor: NdArrayTensor<E, 2>,
+    offsets: NdArrayTensor<i64, 1>,
+    reduction: SegmentReduction,
+) -> NdArrayTensor<E, 2> {
+    let [num_rows, num_columns] = tensor.shape().dims;
+    let segments = segment_ranges(offsets, num_rows);
+    let tensor = tensor.array.into_owned();
+
+    let mut output = Array2::from_elem((segments.len(), num_columns), 0.elem());
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, segments.len()).for_each(|segment| unsafe {
+            let output = unsafe_shared_out.get();
+            let (start, end) = segments[segment];
+
+            if start == end {
+                return;
+            }
+
+            let sum = |c: usize| {
+                let mut sum = 0.0;
+                for i in start..end {
+                    let elem: f64 = tensor[[i, c]].elem();
+                    sum += elem;
+                }
+                sum
+            };
+
+            for c in 0..num_columns {
+                output[[segment, c]] = match reduction {
+                    SegmentReduction::Sum => sum(c).elem(),
+                    SegmentReduction::Mean => (sum(c) / (end - start) as f64).elem(),
+                    SegmentReduction::Max => tensor[[argmax(&tensor, start, end, c), c]],
+                };
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+pub(crate) fn segment_reduce_backward<E: FloatNdArrayElement>(
+    tensor: NdArrayTensor<E, 2>,
+    offsets: NdArrayTensor<i64, 1>,
+    output_grad: NdArrayTensor<E, 2>,
+    reduction: SegmentReduction,
+) -> NdArrayTensor<E, 2> {
+    let [num_rows, num_columns] = tensor.shape().dims;
+    let segments = segment_ranges(offsets, num_rows);
+    let tensor = tensor.array.into_owned();
+    let output_grad = output_grad.array.into_owned();
+
+    let mut tensor_grad = Array2::from_elem((num_rows, num_columns), 0.elem());
+    let unsafe_shared_grad = UnsafeSharedRef::new(&mut tensor_grad);
+
+    run_par!(|| {
+        iter_range_par!(0, segments.len()).for_each(|segment| unsafe {
+            let tensor_grad = unsafe_shared_grad.get();
+            let (start, end) = segments[segment];
+
+            if start == end {
+                return;
+            }
+
+            for c in 0..num_columns {
+                let grad = output_grad[[segment, c]];
+
+                match reduction {
+                    SegmentReduction::Sum => {
+                        for i in start..end {
+                            tensor_grad[[i, c]] = grad;
+                        }
+                    }
+                    SegmentReduction::Mean => {
+                        let grad: f64 = grad.elem();
+                        let grad: E = (grad / (end - start) as f64).elem();
+                        for i in start..end {
+                            tensor_grad[[i, c]] = grad;
+                        }
+                    }
+                    SegmentReduction::Max => {
+                        tensor_grad[[argmax(&tensor, start, end, c), c]] = grad;
+                    }
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(tensor_grad.into_dyn().into_shared())
+}
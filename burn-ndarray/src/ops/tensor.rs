@@ -3,14 +3,18 @@ use alloc::vec::Vec;
 use core::ops::Range;
 
 // Current crate
-use super::{matmul::matmul, NdArrayMathOps, NdArrayOps};
+use super::{matmul::matmul, padding, NdArrayMathOps, NdArrayOps};
 use crate::element::FloatNdArrayElement;
 use crate::{tensor::NdArrayTensor, NdArrayBackend};
 use crate::{NdArrayDevice, SEED};
 
 // Workspace crates
 use burn_common::rand::get_seeded_rng;
-use burn_tensor::{backend::Backend, ops::TensorOps, Data, ElementConversion, Shape};
+use burn_tensor::{
+    backend::Backend,
+    ops::{PadMode, TensorOps},
+    Data, ElementConversion, Shape,
+};
 use burn_tensor::{Distribution, Reader};
 
 // External crates
@@ -116,6 +120,17 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayMathOps::div_scalar(lhs, rhs)
     }
 
+    fn remainder<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::remainder(lhs, rhs)
+    }
+
+    fn remainder_scalar<const D: usize>(lhs: NdArrayTensor<E, D>, rhs: E) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::remainder_scalar(lhs, rhs)
+    }
+
     fn matmul<const D: usize>(
         lhs: NdArrayTensor<E, D>,
         rhs: NdArrayTensor<E, D>,
@@ -135,6 +150,40 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayOps::swap_dims(tensor, dim1, dim2)
     }
 
+    fn permute<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        axes: [usize; D],
+    ) -> NdArrayTensor<E, D> {
+        NdArrayOps::permute(tensor, axes)
+    }
+
+    fn expand<const D1: usize, const D2: usize>(
+        tensor: NdArrayTensor<E, D1>,
+        shape: Shape<D2>,
+    ) -> NdArrayTensor<E, D2> {
+        NdArrayOps::expand(tensor, shape)
+    }
+
+    fn flip<const D: usize>(tensor: NdArrayTensor<E, D>, axes: &[usize]) -> NdArrayTensor<E, D> {
+        NdArrayOps::flip(tensor, axes)
+    }
+
+    fn roll<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> NdArrayTensor<E, D> {
+        NdArrayOps::roll(tensor, shifts, dims)
+    }
+
+    fn pad<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> NdArrayTensor<E, D> {
+        padding::pad(tensor, pads, mode)
+    }
+
     fn reshape<const D1: usize, const D2: usize>(
         tensor: NdArrayTensor<E, D1>,
         shape: Shape<D2>,
@@ -309,6 +358,14 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayMathOps::sum_dim(tensor, dim)
     }
 
+    fn prod<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
+        NdArrayMathOps::prod(tensor)
+    }
+
+    fn prod_dim<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::prod_dim(tensor, dim)
+    }
+
     fn to_full_precision<const D: usize>(tensor: &NdArrayTensor<E, D>) -> NdArrayTensor<f32, D> {
         let array = tensor.array.mapv(|a| a.elem()).into_shared();
 
@@ -329,6 +386,40 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayMathOps::argmin(tensor, dim)
     }
 
+    fn cumsum<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::cumsum(tensor, dim)
+    }
+
+    fn cumprod<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::cumprod(tensor, dim)
+    }
+
+    fn cummax<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::cummax(tensor, dim)
+    }
+
+    fn cummax_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::cummax_with_indices(tensor, dim)
+    }
+
+    fn logcumsumexp<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::logcumsumexp(tensor, dim)
+    }
+
+    fn sort_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::sort_with_indices(tensor, dim, descending)
+    }
+
     fn exp<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
         let array = tensor.array.mapv_into(|a| a.exp_elem()).into_shared();
 
@@ -413,6 +504,50 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayTensor::new(array)
     }
 
+    fn floor<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor.array.mapv_into(|a| a.floor_elem()).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn ceil<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor.array.mapv_into(|a| a.ceil_elem()).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn round<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor.array.mapv_into(|a| a.round_elem()).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn trunc<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor.array.mapv_into(|a| a.trunc_elem()).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn sign<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let zero = 0.elem::<E>();
+        let one = 1.elem::<E>();
+        let minus_one = (-1).elem::<E>();
+        let array = tensor
+            .array
+            .mapv_into(|a| {
+                if a > zero {
+                    one
+                } else if a < zero {
+                    minus_one
+                } else {
+                    a
+                }
+            })
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
     fn cat<const D: usize>(tensors: Vec<NdArrayTensor<E, D>>, dim: usize) -> NdArrayTensor<E, D> {
         NdArrayOps::cat(tensors, dim)
     }
@@ -61,12 +61,9 @@ macro_rules! reshape {
         array $array:expr
     ) => {{
         let dim = $crate::to_typed_dims!($n, $shape.dims, justdim);
-        let safe_into_shape =
-            $array.is_standard_layout() ||
-            (
-                $array.ndim() > 1 &&
-                $array.raw_view().reversed_axes().is_standard_layout()
-            );
+        // Arrays with a column major layout can't be reshaped without relayout, `into_shape`
+        // would read their elements in memory order instead of the logical order.
+        let safe_into_shape = $array.is_standard_layout();
 
         let array: ndarray::ArcArray<$ty, Dim<[usize; $n]>> = match safe_into_shape {
             true => $array
@@ -22,4 +22,21 @@ impl<E: TchElement> ActivationOps<TchBackend<E>> for TchBackend<E> {
 
         TchTensor::from_existing(tensor, storage)
     }
+
+    fn gelu_tanh<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(
+            |mut tensor| tensor.gelu_("tanh"),
+            |tensor| tensor.gelu("tanh"),
+        )
+    }
+
+    fn gelu_tanh_backward<const D: usize>(
+        tensor: TchTensor<E, D>,
+        grad: TchTensor<E, D>,
+    ) -> TchTensor<E, D> {
+        let storage = tensor.storage.clone();
+        let tensor = tensor.tensor.gelu_backward(&grad.tensor, "tanh");
+
+        TchTensor::from_existing(tensor, storage)
+    }
 }
@@ -288,6 +288,19 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         )
     }
 
+    pub fn remainder<const D: usize>(
+        lhs: TchTensor<E, D>,
+        rhs: TchTensor<E, D>,
+    ) -> TchTensor<E, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_remainder_tensor_(rhs).unwrap(),
+            |lhs, rhs| lhs.f_remainder_tensor(rhs).unwrap(),
+            |lhs, rhs| lhs.f_remainder_tensor(rhs).unwrap(),
+        )
+    }
+
     pub fn mean<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, 1> {
         let tensor = tensor.tensor.mean(E::KIND);
         TchTensor::new(tensor)
@@ -370,6 +383,75 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         (tensor, indices)
     }
 
+    pub fn cumsum<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.cumsum(dim as i64, E::KIND))
+    }
+
+    pub fn prod<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, 1> {
+        let tensor = tensor.tensor.prod(E::KIND);
+        TchTensor::new(tensor)
+    }
+
+    pub fn prod_dim<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchTensor::from_existing(
+            tensor.tensor.prod_dim_int(dim as i64, true, E::KIND),
+            tensor.storage,
+        )
+    }
+
+    pub fn cumprod<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.cumprod(dim as i64, E::KIND))
+    }
+
+    pub fn cummax_with_indices<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        let (tensor, indices) = tensor.tensor.cummax(dim as i64);
+
+        (TchTensor::new(tensor), TchTensor::new(indices))
+    }
+
+    pub fn sort<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<E, D> {
+        let (tensor, _indices) = tensor.tensor.sort_stable(true, dim as i64, descending);
+
+        TchTensor::new(tensor)
+    }
+
+    pub fn sort_with_indices<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        let (tensor, indices) = tensor.tensor.sort_stable(true, dim as i64, descending);
+
+        (TchTensor::new(tensor), TchTensor::new(indices))
+    }
+
+    pub fn argsort<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<i64, D> {
+        let indices = tensor.tensor.argsort_stable(true, dim as i64, descending);
+
+        TchTensor::new(indices)
+    }
+
+    pub fn topk_with_indices<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        k: usize,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        let (tensor, indices) = tensor.tensor.topk(k as i64, dim as i64, true, true);
+
+        (TchTensor::new(tensor), TchTensor::new(indices))
+    }
+
     pub fn clamp_min<const D: usize, S: Into<tch::Scalar> + Clone + Copy>(
         tensor: TchTensor<E, D>,
         min: S,
@@ -409,4 +491,35 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         let tensor = tensor.tensor.transpose(dim1 as i64, dim2 as i64);
         TchTensor::new(tensor)
     }
+
+    pub fn permute<const D: usize>(tensor: TchTensor<E, D>, axes: [usize; D]) -> TchTensor<E, D> {
+        let axes: Vec<i64> = axes.iter().map(|&axis| axis as i64).collect();
+        let tensor = tensor.tensor.permute(axes);
+        TchTensor::new(tensor)
+    }
+
+    pub fn expand<const D1: usize, const D2: usize>(
+        tensor: TchTensor<E, D1>,
+        shape: Shape<D2>,
+    ) -> TchTensor<E, D2> {
+        let shape_tch: TchShape<D2> = shape.into();
+
+        TchTensor::from_existing(tensor.tensor.expand(shape_tch.dims, false), tensor.storage)
+    }
+
+    pub fn flip<const D: usize>(tensor: TchTensor<E, D>, axes: &[usize]) -> TchTensor<E, D> {
+        let axes: Vec<i64> = axes.iter().map(|&axis| axis as i64).collect();
+        let tensor = tensor.tensor.flip(axes);
+        TchTensor::new(tensor)
+    }
+
+    pub fn roll<const D: usize>(
+        tensor: TchTensor<E, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> TchTensor<E, D> {
+        let dims: Vec<i64> = dims.iter().map(|&dim| dim as i64).collect();
+        let tensor = tensor.tensor.roll(shifts, dims);
+        TchTensor::new(tensor)
+    }
 }
@@ -117,4 +117,34 @@ impl<E: TchElement> BoolTensorOps<TchBackend<E>> for TchBackend<E> {
     ) -> <TchBackend<E> as Backend>::BoolTensorPrimitive<D> {
         TchOps::swap_dims(tensor, dim1, dim2)
     }
+
+    fn bool_permute<const D: usize>(
+        tensor: TchTensor<bool, D>,
+        axes: [usize; D],
+    ) -> TchTensor<bool, D> {
+        TchOps::permute(tensor, axes)
+    }
+
+    fn bool_expand<const D1: usize, const D2: usize>(
+        tensor: TchTensor<bool, D1>,
+        shape: Shape<D2>,
+    ) -> TchTensor<bool, D2> {
+        TchOps::expand(tensor, shape)
+    }
+
+    fn bool_any<const D: usize>(tensor: TchTensor<bool, D>) -> TchTensor<bool, 1> {
+        TchTensor::new(tensor.tensor.any())
+    }
+
+    fn bool_any_dim<const D: usize>(tensor: TchTensor<bool, D>, dim: usize) -> TchTensor<bool, D> {
+        TchTensor::new(tensor.tensor.any_dim(dim as i64, true))
+    }
+
+    fn bool_all<const D: usize>(tensor: TchTensor<bool, D>) -> TchTensor<bool, 1> {
+        TchTensor::new(tensor.tensor.all())
+    }
+
+    fn bool_all_dim<const D: usize>(tensor: TchTensor<bool, D>, dim: usize) -> TchTensor<bool, D> {
+        TchTensor::new(tensor.tensor.all_dim(dim as i64, true))
+    }
 }
@@ -200,6 +200,20 @@ impl<E: TchElement> IntTensorOps<TchBackend<E>> for TchBackend<E> {
         TchTensor::<i64, D>::new(output.tensor.to_dtype(tch::Kind::Int64, true, false))
     }
 
+    fn int_remainder<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchOps::remainder(lhs, rhs)
+    }
+
+    fn int_remainder_scalar<const D: usize>(lhs: TchTensor<i64, D>, rhs: i64) -> TchTensor<i64, D> {
+        lhs.unary_ops(
+            |mut tensor| tensor.f_remainder_(rhs).unwrap(),
+            |tensor| tensor.f_remainder(rhs).unwrap(),
+        )
+    }
+
     fn int_neg<const D: usize>(tensor: TchTensor<i64, D>) -> TchTensor<i64, D> {
         Self::int_mul_scalar(tensor, -1)
     }
@@ -247,6 +261,14 @@ impl<E: TchElement> IntTensorOps<TchBackend<E>> for TchBackend<E> {
         TchOps::sum_dim(tensor, dim)
     }
 
+    fn int_prod<const D: usize>(tensor: TchTensor<i64, D>) -> TchTensor<i64, 1> {
+        TchOps::prod(tensor)
+    }
+
+    fn int_prod_dim<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
+        TchOps::prod_dim(tensor, dim)
+    }
+
     fn int_mean<const D: usize>(tensor: TchTensor<i64, D>) -> TchTensor<i64, 1> {
         let tensor: TchTensor<f64, D> =
             TchTensor::new(tensor.tensor.to_dtype(tch::Kind::Float, true, false));
@@ -331,6 +353,57 @@ impl<E: TchElement> IntTensorOps<TchBackend<E>> for TchBackend<E> {
         TchOps::argmin(tensor, dim)
     }
 
+    fn int_cumsum<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
+        TchOps::cumsum(tensor, dim)
+    }
+
+    fn int_cumprod<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
+        TchOps::cumprod(tensor, dim)
+    }
+
+    fn int_cummax<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
+        TchOps::cummax_with_indices(tensor, dim).0
+    }
+
+    fn int_cummax_with_indices<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        dim: usize,
+    ) -> (TchTensor<i64, D>, TchTensor<i64, D>) {
+        TchOps::cummax_with_indices(tensor, dim)
+    }
+
+    fn int_sort<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<i64, D> {
+        TchOps::sort(tensor, dim, descending)
+    }
+
+    fn int_sort_with_indices<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (TchTensor<i64, D>, TchTensor<i64, D>) {
+        TchOps::sort_with_indices(tensor, dim, descending)
+    }
+
+    fn int_argsort<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<i64, D> {
+        TchOps::argsort(tensor, dim, descending)
+    }
+
+    fn int_topk_with_indices<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        dim: usize,
+        k: usize,
+    ) -> (TchTensor<i64, D>, TchTensor<i64, D>) {
+        TchOps::topk_with_indices(tensor, dim, k)
+    }
+
     fn int_max_dim<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
         TchOps::max_dim(tensor, dim)
     }
@@ -385,4 +458,30 @@ impl<E: TchElement> IntTensorOps<TchBackend<E>> for TchBackend<E> {
     ) -> <TchBackend<E> as Backend>::IntTensorPrimitive<D> {
         TchOps::swap_dims(tensor, dim1, dim2)
     }
+
+    fn int_permute<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        axes: [usize; D],
+    ) -> TchTensor<i64, D> {
+        TchOps::permute(tensor, axes)
+    }
+
+    fn int_expand<const D1: usize, const D2: usize>(
+        tensor: TchTensor<i64, D1>,
+        shape: Shape<D2>,
+    ) -> TchTensor<i64, D2> {
+        TchOps::expand(tensor, shape)
+    }
+
+    fn int_flip<const D: usize>(tensor: TchTensor<i64, D>, axes: &[usize]) -> TchTensor<i64, D> {
+        TchOps::flip(tensor, axes)
+    }
+
+    fn int_roll<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> TchTensor<i64, D> {
+        TchOps::roll(tensor, shifts, dims)
+    }
 }
@@ -0,0 +1,53 @@
+use crate::{element::TchElement, TchBackend, TchTensor};
+use burn_tensor::ops::LinalgOps;
+
+impl<E: TchElement> LinalgOps<TchBackend<E>> for TchBackend<E> {
+    fn cholesky<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.linalg_cholesky(false))
+    }
+
+    fn qr<const D: usize>(tensor: TchTensor<E, D>) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let (q, r) = tch::Tensor::linalg_qr(&tensor.tensor, "reduced");
+
+        // LAPACK doesn't enforce a non-negative diagonal for R.
+        let signs = r.diagonal(0, -2, -1).ge(0).to_kind(r.kind()) * 2.0 - 1.0;
+        let q = q * signs.unsqueeze(-2);
+        let r = r * signs.unsqueeze(-1);
+
+        (TchTensor::new(q), TchTensor::new(r))
+    }
+
+    fn eigh<const D: usize>(tensor: TchTensor<E, D>) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let (eigenvalues, eigenvectors) = tensor.tensor.linalg_eigh("L");
+
+        (
+            TchTensor::new(eigenvalues.unsqueeze(-2)),
+            TchTensor::new(eigenvectors),
+        )
+    }
+
+    fn svd<const D: usize>(
+        tensor: TchTensor<E, D>,
+    ) -> (TchTensor<E, D>, TchTensor<E, D>, TchTensor<E, D>) {
+        let (u, s, vh) = tch::Tensor::linalg_svd(&tensor.tensor, false, "");
+
+        (
+            TchTensor::new(u),
+            TchTensor::new(s.unsqueeze(-2)),
+            TchTensor::new(vh),
+        )
+    }
+
+    fn solve<const D: usize>(lhs: TchTensor<E, D>, rhs: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tch::Tensor::linalg_solve(&lhs.tensor, &rhs.tensor, true))
+    }
+
+    fn slogdet<const D: usize>(tensor: TchTensor<E, D>) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let (sign, logabsdet) = tch::Tensor::linalg_slogdet(&tensor.tensor);
+
+        (
+            TchTensor::new(sign.unsqueeze(-1).unsqueeze(-1)),
+            TchTensor::new(logabsdet.unsqueeze(-1).unsqueeze(-1)),
+        )
+    }
+}
@@ -2,7 +2,7 @@ use crate::{backend, element::TchElement, ops::TchOps, TchBackend, TchTensor};
 use burn_tensor::{
     ops::{
         ConvOptions, ConvTransposeOptions, MaxPool1dWithIndices, MaxPool2dBackward,
-        MaxPool2dWithIndices, ModuleOps, UnfoldOptions,
+        MaxPool2dWithIndices, MaxPool3dBackward, MaxPool3dWithIndices, ModuleOps, UnfoldOptions,
     },
     Shape,
 };
@@ -91,6 +91,45 @@ impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
         TchTensor::new(tensor)
     }
 
+    fn conv3d(
+        x: TchTensor<E, 5>,
+        weight: TchTensor<E, 5>,
+        bias: Option<TchTensor<E, 1>>,
+        options: ConvOptions<3>,
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::conv3d(
+            &x.tensor,
+            &weight.tensor,
+            bias.map(|t| t.tensor),
+            options.stride.map(|i| i as i64),
+            options.padding.map(|i| i as i64),
+            options.dilation.map(|i| i as i64),
+            options.groups as i64,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn conv_transpose3d(
+        x: TchTensor<E, 5>,
+        weight: TchTensor<E, 5>,
+        bias: Option<TchTensor<E, 1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::conv_transpose3d(
+            &x.tensor,
+            &weight.tensor,
+            bias.map(|t| t.tensor),
+            options.stride.map(|i| i as i64),
+            options.padding.map(|i| i as i64),
+            options.padding_out.map(|i| i as i64),
+            options.groups as i64,
+            options.dilation.map(|i| i as i64),
+        );
+
+        TchTensor::new(tensor)
+    }
+
     fn conv_transpose1d(
         x: TchTensor<E, 3>,
         weight: TchTensor<E, 3>,
@@ -232,6 +271,48 @@ impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
         TchTensor::new(tensor)
     }
 
+    fn avg_pool3d(
+        x: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::avg_pool3d(
+            &x.tensor,
+            kernel_size.map(|i| i as i64),
+            stride.map(|i| i as i64),
+            padding.map(|i| i as i64),
+            false,
+            count_include_pad,
+            None,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn avg_pool3d_backward(
+        x: TchTensor<E, 5>,
+        grad: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::avg_pool3d_backward(
+            &x.tensor,
+            &grad.tensor,
+            kernel_size.map(|i| i as i64),
+            stride.map(|i| i as i64),
+            padding.map(|i| i as i64),
+            false,
+            count_include_pad,
+            None,
+        );
+
+        TchTensor::new(tensor)
+    }
+
     fn max_pool1d(
         x: TchTensor<E, 3>,
         kernel_size: usize,
@@ -331,6 +412,67 @@ impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
         MaxPool2dBackward::new(TchTensor::new(grad))
     }
 
+    fn max_pool3d(
+        x: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::max_pool3d(
+            &x.tensor,
+            kernel_size.map(|i| i as i64),
+            stride.map(|i| i as i64),
+            padding.map(|i| i as i64),
+            dilation.map(|i| i as i64),
+            false,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn max_pool3d_with_indices(
+        x: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<TchBackend<E>> {
+        let (tensor, indices) = tch::Tensor::max_pool3d_with_indices(
+            &x.tensor,
+            kernel_size.map(|i| i as i64),
+            stride.map(|i| i as i64),
+            padding.map(|i| i as i64),
+            dilation.map(|i| i as i64),
+            false,
+        );
+
+        MaxPool3dWithIndices::new(TchTensor::new(tensor), TchTensor::new(indices))
+    }
+
+    fn max_pool3d_with_indices_backward(
+        x: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        output_grad: TchTensor<E, 5>,
+        indices: TchTensor<i64, 5>,
+    ) -> MaxPool3dBackward<TchBackend<E>> {
+        let grad = tch::Tensor::max_pool3d_with_indices_backward(
+            &x.tensor,
+            &output_grad.tensor,
+            kernel_size.map(|i| i as i64),
+            stride.map(|i| i as i64),
+            padding.map(|i| i as i64),
+            dilation.map(|i| i as i64),
+            false,
+            &indices.tensor,
+        );
+
+        MaxPool3dBackward::new(TchTensor::new(grad))
+    }
+
     fn adaptive_avg_pool2d(x: TchTensor<E, 4>, output_size: [usize; 2]) -> TchTensor<E, 4> {
         let tensor = tch::Tensor::adaptive_avg_pool2d(&x.tensor, output_size.map(|e| e as i64));
 
@@ -343,6 +485,18 @@ impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
         TchTensor::new(tensor)
     }
 
+    fn adaptive_avg_pool3d(x: TchTensor<E, 5>, output_size: [usize; 3]) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::adaptive_avg_pool3d(&x.tensor, output_size.map(|e| e as i64));
+
+        TchTensor::new(tensor)
+    }
+
+    fn adaptive_avg_pool3d_backward(x: TchTensor<E, 5>, grad: TchTensor<E, 5>) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::internal_adaptive_avg_pool3d_backward(&x.tensor, &grad.tensor);
+
+        TchTensor::new(tensor)
+    }
+
     fn adaptive_avg_pool1d(x: TchTensor<E, 3>, output_size: usize) -> TchTensor<E, 3> {
         let tensor = tch::Tensor::adaptive_avg_pool1d(&x.tensor, output_size as i64);
 
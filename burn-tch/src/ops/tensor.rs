@@ -160,6 +160,19 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         )
     }
 
+    fn remainder<const D: usize>(lhs: TchTensor<E, D>, rhs: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchOps::remainder(lhs, rhs)
+    }
+
+    fn remainder_scalar<const D: usize>(lhs: TchTensor<E, D>, rhs: E) -> TchTensor<E, D> {
+        let rhs: f64 = rhs.elem();
+
+        lhs.unary_ops(
+            |mut tensor| tensor.f_remainder_(rhs).unwrap(),
+            |tensor| tensor.f_remainder(rhs).unwrap(),
+        )
+    }
+
     fn matmul<const D: usize>(lhs: TchTensor<E, D>, rhs: TchTensor<E, D>) -> TchTensor<E, D> {
         let tensor = lhs.tensor.matmul(&rhs.tensor);
         TchTensor::new(tensor)
@@ -177,6 +190,29 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         TchOps::swap_dims(tensor, dim1, dim2)
     }
 
+    fn permute<const D: usize>(tensor: TchTensor<E, D>, axes: [usize; D]) -> TchTensor<E, D> {
+        TchOps::permute(tensor, axes)
+    }
+
+    fn expand<const D1: usize, const D2: usize>(
+        tensor: TchTensor<E, D1>,
+        shape: Shape<D2>,
+    ) -> TchTensor<E, D2> {
+        TchOps::expand(tensor, shape)
+    }
+
+    fn flip<const D: usize>(tensor: TchTensor<E, D>, axes: &[usize]) -> TchTensor<E, D> {
+        TchOps::flip(tensor, axes)
+    }
+
+    fn roll<const D: usize>(
+        tensor: TchTensor<E, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> TchTensor<E, D> {
+        TchOps::roll(tensor, shifts, dims)
+    }
+
     fn reshape<const D1: usize, const D2: usize>(
         tensor: TchTensor<E, D1>,
         shape: Shape<D2>,
@@ -318,6 +354,14 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         TchOps::sum_dim(tensor, dim)
     }
 
+    fn prod<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, 1> {
+        TchOps::prod(tensor)
+    }
+
+    fn prod_dim<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchOps::prod_dim(tensor, dim)
+    }
+
     fn to_full_precision<const D: usize>(tensor: &TchTensor<E, D>) -> TchTensor<f32, D> {
         let storage = tensor.storage.clone();
         let tensor = tensor.tensor.to_kind(tch::Kind::Float);
@@ -340,6 +384,61 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         TchOps::argmin(tensor, dim)
     }
 
+    fn cumsum<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchOps::cumsum(tensor, dim)
+    }
+
+    fn cumprod<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchOps::cumprod(tensor, dim)
+    }
+
+    fn cummax<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchOps::cummax_with_indices(tensor, dim).0
+    }
+
+    fn cummax_with_indices<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        TchOps::cummax_with_indices(tensor, dim)
+    }
+
+    fn logcumsumexp<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.logcumsumexp(dim as i64))
+    }
+
+    fn sort<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<E, D> {
+        TchOps::sort(tensor, dim, descending)
+    }
+
+    fn sort_with_indices<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        TchOps::sort_with_indices(tensor, dim, descending)
+    }
+
+    fn argsort<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<i64, D> {
+        TchOps::argsort(tensor, dim, descending)
+    }
+
+    fn topk_with_indices<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        k: usize,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        TchOps::topk_with_indices(tensor, dim, k)
+    }
+
     fn max_dim<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
         TchOps::max_dim(tensor, dim)
     }
@@ -405,6 +504,26 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         tensor.unary_ops(|mut tensor| tensor.erf_(), |tensor| tensor.erf())
     }
 
+    fn floor<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(|mut tensor| tensor.floor_(), |tensor| tensor.floor())
+    }
+
+    fn ceil<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(|mut tensor| tensor.ceil_(), |tensor| tensor.ceil())
+    }
+
+    fn round<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(|mut tensor| tensor.round_(), |tensor| tensor.round())
+    }
+
+    fn trunc<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(|mut tensor| tensor.trunc_(), |tensor| tensor.trunc())
+    }
+
+    fn sign<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(|mut tensor| tensor.sign_(), |tensor| tensor.sign())
+    }
+
     fn cat<const D: usize>(tensors: Vec<TchTensor<E, D>>, dim: usize) -> TchTensor<E, D> {
         TchOps::cat(tensors, dim)
     }
@@ -73,6 +73,22 @@ unsafe impl<E: tch::kind::Element, const D: usize> Send for TchTensor<E, D> {}
 unsafe impl<E: tch::kind::Element, const D: usize> Sync for TchTensor<E, D> {}
 
 impl<P: tch::kind::Element, const D: usize> TchTensor<P, D> {
+    /// Check if the tensor data can be reused, which isn't the case when it is shared with other
+    /// tensors or when multiple elements point to the same memory location, like in expanded
+    /// tensors.
+    fn can_mut(&self) -> bool {
+        if Rc::strong_count(&self.storage) > 1 {
+            return false;
+        }
+
+        !self
+            .tensor
+            .size()
+            .iter()
+            .zip(self.tensor.stride())
+            .any(|(size, stride)| *size > 1 && stride == 0)
+    }
+
     /// Execute an operation on a tensor if the data can be reused.
     pub fn mut_ops<
         F: Fn(&mut tch::Tensor) -> tch::Tensor,
@@ -82,7 +98,7 @@ impl<P: tch::kind::Element, const D: usize> TchTensor<P, D> {
         &mut self,
         func: F,
     ) -> Option<TchTensor<EOut, D_OUT>> {
-        if Rc::strong_count(&self.storage) > 1 {
+        if !self.can_mut() {
             return None;
         }
 
@@ -99,7 +115,7 @@ impl<P: tch::kind::Element, const D: usize> TchTensor<P, D> {
         FOwn: Fn(tch::Tensor) -> tch::Tensor,
         FRef: Fn(&tch::Tensor) -> tch::Tensor,
     {
-        if Rc::strong_count(&self.storage) > 1 {
+        if !self.can_mut() {
             return TchTensor::from_existing(fref(&self.tensor), self.storage);
         }
 
@@ -1,7 +1,7 @@
 use crate::backend::Backend;
 use crate::check::TensorCheck;
 use crate::{check, Tensor};
-use crate::{ElementPrecision, Precision};
+use crate::{ElementConversion, ElementPrecision, Precision};
 
 /// Applies the rectified linear unit function.
 pub fn relu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
@@ -13,6 +13,103 @@ pub fn gelu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
     Tensor::from_primitive(B::gelu(tensor.primitive))
 }
 
+/// Applies the Gaussian Error Linear Units function using the tanh approximation.
+///
+/// `gelu_tanh(x) = 0.5 * x * (1 + tanh(sqrt(2 / π) * (x + 0.044715 * x^3)))`
+pub fn gelu_tanh<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    Tensor::from_primitive(B::gelu_tanh(tensor.primitive))
+}
+
+/// Applies the leaky rectified linear unit function.
+///
+/// `leaky_relu(x) = max(0, x) + negative_slope * min(0, x)`
+pub fn leaky_relu<const D: usize, B: Backend>(
+    tensor: Tensor<B, D>,
+    negative_slope: f64,
+) -> Tensor<B, D> {
+    Tensor::from_primitive(B::leaky_relu(tensor.primitive, negative_slope.elem()))
+}
+
+/// Applies the parametric rectified linear unit function.
+///
+/// `prelu(x) = max(0, x) + alpha * min(0, x)`
+///
+/// # Notes
+///
+/// The `alpha` tensor either contains a single value shared by all channels, or one value per
+/// channel, where the channel dimension is the second dimension of the input tensor.
+pub fn prelu<const D: usize, B: Backend>(
+    tensor: Tensor<B, D>,
+    alpha: Tensor<B, 1>,
+) -> Tensor<B, D> {
+    let mut shape = [1; D];
+
+    if D >= 2 {
+        shape[1] = alpha.dims()[0];
+    }
+
+    let positive = tensor.clone().clamp_min(0.0);
+    let negative = tensor.clamp_max(0.0);
+
+    positive.add(negative.mul(alpha.reshape(shape)))
+}
+
+/// Applies the exponential linear unit function as described in the paper [Fast and Accurate Deep Network Learning by Exponential Linear Units (ELUs)](https://arxiv.org/abs/1511.07289).
+///
+/// `elu(x) = x if x > 0, alpha * (exp(x) - 1) otherwise`
+pub fn elu<const D: usize, B: Backend>(tensor: Tensor<B, D>, alpha: f64) -> Tensor<B, D> {
+    Tensor::from_primitive(B::elu(tensor.primitive, alpha.elem()))
+}
+
+/// Applies the scaled exponential linear unit function as described in the paper [Self-Normalizing Neural Networks](https://arxiv.org/abs/1706.02515).
+///
+/// `selu(x) = scale * elu(x, alpha)`, with `alpha ≈ 1.6733` and `scale ≈ 1.0507`
+pub fn selu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    const ALPHA: f64 = 1.673_263_242_354_377_3;
+    const SCALE: f64 = 1.050_700_987_355_480_5;
+
+    elu(tensor, ALPHA).mul_scalar(SCALE)
+}
+
+/// Applies the softplus function.
+///
+/// `softplus(x) = log(1 + exp(beta * x)) / beta`
+pub fn softplus<const D: usize, B: Backend>(tensor: Tensor<B, D>, beta: f64) -> Tensor<B, D> {
+    Tensor::from_primitive(B::softplus(tensor.primitive, beta.elem()))
+}
+
+/// Applies the softsign function.
+///
+/// `softsign(x) = x / (1 + |x|)`
+pub fn softsign<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor.clone().div(tensor.abs().add_scalar(1.0))
+}
+
+/// Applies the mish function as described in the paper [Mish: A Self Regularized Non-Monotonic Activation Function](https://arxiv.org/abs/1908.08681).
+///
+/// `mish(x) = x * tanh(softplus(x))`
+pub fn mish<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor.clone().mul(softplus(tensor, 1.0).tanh())
+}
+
+/// Applies the hard sigmoid function.
+///
+/// `hard_sigmoid(x) = max(0, min(1, alpha * x + beta))`
+pub fn hard_sigmoid<const D: usize, B: Backend>(
+    tensor: Tensor<B, D>,
+    alpha: f64,
+    beta: f64,
+) -> Tensor<B, D> {
+    tensor.mul_scalar(alpha).add_scalar(beta).clamp(0.0, 1.0)
+}
+
+/// Applies the hard swish function as described in the paper [Searching for MobileNetV3](https://arxiv.org/abs/1905.02244).
+///
+/// `hard_swish(x) = x * max(0, min(1, x / 6 + 0.5))`
+pub fn hard_swish<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor.clone().mul(hard_sigmoid(tensor, 1.0 / 6.0, 0.5))
+}
+
 /// Applies the softmax function on the input tensor along the given dimension.
 ///
 /// `softmax(x_i) = exp(x_i) / sum_j(exp(x_j))`
@@ -129,6 +129,65 @@ where
         Tensor::new(K::swap_dims(self.primitive, dim1, dim2))
     }
 
+    /// Permutes the dimensions of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `axes` - The new order of the dimensions, where the dimension `i` of the output is the
+    ///   dimension `axes[i]` of the input.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the dimensions permuted.
+    pub fn permute(self, axes: [usize; D]) -> Tensor<B, D, K> {
+        check!(TensorCheck::permute::<D>(&axes));
+
+        Tensor::new(K::permute(self.primitive, axes))
+    }
+
+    /// Expands the tensor to the given shape, following the broadcasting rules.
+    ///
+    /// The dimensions of the tensor are aligned with the last dimensions of the shape, and the
+    /// dimensions of size 1 are repeated to match it. Contrary to [repeat](Tensor::repeat), the
+    /// data is not copied when the backend supports zero strides, such as wgpu, tch and candle.
+    /// Other backends, such as ndarray, still copy the data to the expanded shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape to expand to.
+    ///
+    /// # Panics
+    ///
+    /// - If the shape has fewer dimensions than the tensor.
+    /// - If a dimension of the tensor is neither 1 nor equal to the matching dimension of the shape.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::ones([3, 1]);
+    ///     // The resulting tensor will have dimensions (2, 3, 4).
+    ///     let expanded = tensor.expand([2, 3, 4]);
+    ///     println!("{:?}", expanded.shape());
+    /// }
+    /// ```
+    pub fn expand<const D2: usize, S: Into<Shape<D2>>>(self, shape: S) -> Tensor<B, D2, K> {
+        let shape = shape.into();
+        check!(TensorCheck::expand(&self.shape(), &shape));
+
+        Tensor::new(K::expand(self.primitive, shape))
+    }
+
+    /// Broadcast the tensor to the given shape.
+    ///
+    /// Alias of [expand](Tensor::expand).
+    pub fn broadcast_to<const D2: usize, S: Into<Shape<D2>>>(self, shape: S) -> Tensor<B, D2, K> {
+        self.expand(shape)
+    }
+
     /// Flatten the tensor along a given range of dimensions.
     ///
     /// This function collapses the specified range of dimensions into a single dimension,
@@ -788,6 +847,33 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
         dim2: usize,
     ) -> Self::Primitive<D>;
 
+    /// Permutes the dimensions of a tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to permute the dimensions of.
+    /// * `axes` - The new order of the dimensions.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the dimensions permuted.
+    fn permute<const D: usize>(tensor: Self::Primitive<D>, axes: [usize; D]) -> Self::Primitive<D>;
+
+    /// Expands a tensor to the given shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to expand.
+    /// * `shape` - The shape to expand to.
+    ///
+    /// # Returns
+    ///
+    /// The expanded tensor.
+    fn expand<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        shape: Shape<D2>,
+    ) -> Self::Primitive<D2>;
+
     ///  Select tensor elements corresponding for the given ranges.
     ///
     /// # Arguments
@@ -1033,6 +1119,17 @@ impl<B: Backend> BasicOps<B> for Float {
         B::swap_dims(tensor, dim1, dim2)
     }
 
+    fn permute<const D: usize>(tensor: Self::Primitive<D>, axes: [usize; D]) -> Self::Primitive<D> {
+        B::permute(tensor, axes)
+    }
+
+    fn expand<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        shape: Shape<D2>,
+    ) -> Self::Primitive<D2> {
+        B::expand(tensor, shape)
+    }
+
     fn slice<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
         ranges: [Range<usize>; D2],
@@ -1120,6 +1217,17 @@ impl<B: Backend> BasicOps<B> for Int {
         B::int_swap_dims(tensor, dim1, dim2)
     }
 
+    fn permute<const D: usize>(tensor: Self::Primitive<D>, axes: [usize; D]) -> Self::Primitive<D> {
+        B::int_permute(tensor, axes)
+    }
+
+    fn expand<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        shape: Shape<D2>,
+    ) -> Self::Primitive<D2> {
+        B::int_expand(tensor, shape)
+    }
+
     fn slice<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
         ranges: [Range<usize>; D2],
@@ -1207,6 +1315,17 @@ impl<B: Backend> BasicOps<B> for Bool {
         B::bool_swap_dims(tensor, dim1, dim2)
     }
 
+    fn permute<const D: usize>(tensor: Self::Primitive<D>, axes: [usize; D]) -> Self::Primitive<D> {
+        B::bool_permute(tensor, axes)
+    }
+
+    fn expand<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        shape: Shape<D2>,
+    ) -> Self::Primitive<D2> {
+        B::bool_expand(tensor, shape)
+    }
+
     fn slice<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
         ranges: [Range<usize>; D2],
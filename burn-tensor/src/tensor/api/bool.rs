@@ -1,4 +1,4 @@
-use crate::{backend::Backend, Bool, Data, Int, Tensor};
+use crate::{backend::Backend, check, check::TensorCheck, Bool, Data, Int, Shape, Tensor};
 
 impl<B, const D: usize> Tensor<B, D, Bool>
 where
@@ -28,4 +28,116 @@ where
     pub fn bool_not(self) -> Self {
         Tensor::new(B::bool_not(self.primitive))
     }
+
+    /// Tests if any element in the tensor evaluates to true.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with a single element, true if any element in the tensor is true.
+    pub fn any(self) -> Tensor<B, 1, Bool> {
+        Tensor::new(B::bool_any(self.primitive))
+    }
+
+    /// Tests if any element in the tensor evaluates to true along the given *dimension* or *axis*.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with the size of `dim` set to 1, where each element is true if any element
+    /// along `dim` is true.
+    pub fn any_dim(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Any", dim));
+        Tensor::new(B::bool_any_dim(self.primitive, dim))
+    }
+
+    /// Tests if all elements in the tensor evaluate to true.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with a single element, true if all elements in the tensor are true.
+    pub fn all(self) -> Tensor<B, 1, Bool> {
+        Tensor::new(B::bool_all(self.primitive))
+    }
+
+    /// Tests if all elements in the tensor evaluate to true along the given *dimension* or *axis*.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with the size of `dim` set to 1, where each element is true if all elements
+    /// along `dim` are true.
+    pub fn all_dim(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("All", dim));
+        Tensor::new(B::bool_all_dim(self.primitive, dim))
+    }
+
+    /// Creates a mask for the lower triangular part of the last two dimensions of the given shape.
+    ///
+    /// The mask is `true` for the elements above the `diagonal`, which are the elements that should
+    /// be masked. A `diagonal` of zero corresponds to the main diagonal, a positive value to a
+    /// diagonal above it and a negative value to a diagonal below it.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the mask.
+    /// * `diagonal` - The diagonal above which the elements are masked.
+    /// * `device` - The device to create the mask on.
+    pub fn tril_mask<S: Into<Shape<D>>>(shape: S, diagonal: i64, device: &B::Device) -> Self {
+        check!(TensorCheck::triangular::<D>("TrilMask"));
+
+        Self::diagonal_offsets(shape.into(), device).greater_elem(diagonal)
+    }
+
+    /// Creates a mask for the upper triangular part of the last two dimensions of the given shape.
+    ///
+    /// The mask is `true` for the elements below the `diagonal`, which are the elements that should
+    /// be masked. A `diagonal` of zero corresponds to the main diagonal, a positive value to a
+    /// diagonal above it and a negative value to a diagonal below it.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the mask.
+    /// * `diagonal` - The diagonal below which the elements are masked.
+    /// * `device` - The device to create the mask on.
+    pub fn triu_mask<S: Into<Shape<D>>>(shape: S, diagonal: i64, device: &B::Device) -> Self {
+        check!(TensorCheck::triangular::<D>("TriuMask"));
+
+        Self::diagonal_offsets(shape.into(), device).lower_elem(diagonal)
+    }
+
+    /// Creates a mask for a diagonal of the last two dimensions of the given shape.
+    ///
+    /// The mask is `true` for every element that isn't on the `diagonal`, which are the elements
+    /// that should be masked. A `diagonal` of zero corresponds to the main diagonal, a positive
+    /// value to a diagonal above it and a negative value to a diagonal below it.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the mask.
+    /// * `diagonal` - The diagonal that isn't masked.
+    /// * `device` - The device to create the mask on.
+    pub fn diag_mask<S: Into<Shape<D>>>(shape: S, diagonal: i64, device: &B::Device) -> Self {
+        check!(TensorCheck::triangular::<D>("DiagMask"));
+
+        Self::diagonal_offsets(shape.into(), device)
+            .equal_elem(diagonal)
+            .bool_not()
+    }
+
+    /// Returns the offset of each element from the main diagonal of the last two dimensions,
+    /// i.e. `column - row`.
+    fn diagonal_offsets(shape: Shape<D>, device: &B::Device) -> Tensor<B, D, Int> {
+        let num_rows = shape.dims[D - 2];
+        let num_cols = shape.dims[D - 1];
+
+        let mut shape_rows = [1; D];
+        shape_rows[D - 2] = num_rows;
+        let mut shape_cols = [1; D];
+        shape_cols[D - 1] = num_cols;
+
+        let rows = Tensor::<B, 1, Int>::arange_device(0..num_rows, device).reshape(shape_rows);
+        let cols = Tensor::<B, 1, Int>::arange_device(0..num_cols, device).reshape(shape_cols);
+
+        Tensor::<B, D, Int>::zeros_device(shape, device)
+            .add(cols)
+            .sub(rows)
+    }
 }
@@ -1,4 +1,5 @@
-use crate::{backend::Backend, BasicOps, Shape, Tensor};
+use super::einsum::EinsumEquation;
+use crate::{backend::Backend, ops::PadMode, BasicOps, Shape, Tensor};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
@@ -214,6 +215,70 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn permute<const D: usize>(axes: &[usize; D]) -> Self {
+        let mut check = Self::Ok;
+
+        if axes.iter().any(|&axis| axis >= D) {
+            check = check.register(
+                "Permute",
+                TensorError::new("The permuted axes must be smaller than the tensor dimension.")
+                    .details(format!("Axes {axes:?} on tensor with ({D}) dimensions.")),
+            );
+        }
+
+        if (0..D).any(|axis| !axes.contains(&axis)) {
+            check = check.register(
+                "Permute",
+                TensorError::new("The permuted axes must contain every dimension exactly once.")
+                    .details(format!("Axes {axes:?} on tensor with ({D}) dimensions.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn expand<const D1: usize, const D2: usize>(
+        shape: &Shape<D1>,
+        target: &Shape<D2>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if D2 < D1 {
+            check = check.register(
+                "Expand",
+                TensorError::new("Can't expand a tensor to a shape with fewer dimensions.")
+                    .details(format!(
+                        "Current shape: {:?}, target shape: {:?}.",
+                        shape.dims, target.dims
+                    )),
+            );
+
+            return check;
+        }
+
+        let offset = D2 - D1;
+
+        for i in 0..D1 {
+            let dim = shape.dims[i];
+            let dim_target = target.dims[i + offset];
+
+            if dim != 1 && dim != dim_target {
+                check = check.register(
+                    "Expand",
+                    TensorError::new(
+                        "Only the dimensions of size 1 can be expanded to a different size.",
+                    )
+                    .details(format!(
+                        "Current shape: {:?}, target shape: {:?}, invalid dimension: {}.",
+                        shape.dims, target.dims, i
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
     pub(crate) fn matmul<B: Backend, const D: usize>(
         lhs: &Tensor<B, D>,
         rhs: &Tensor<B, D>,
@@ -543,6 +608,312 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn topk<const D: usize>(shape: &Shape<D>, dim: usize, k: usize) -> Self {
+        let mut check = Self::dim_ops::<D>("TopK", dim);
+
+        if dim < D && k > shape.dims[dim] {
+            check = check.register(
+                "TopK",
+                TensorError::new("Can't select more elements than the size of the dimension.")
+                    .details(format!(
+                        "Tensor shape: {:?}, dimension: '{dim}', k: '{k}'.",
+                        shape.dims
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn triangular<const D: usize>(ops: &str) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            check = check.register(
+                ops,
+                TensorError::new("Triangular operations require at least two dimensions.")
+                    .details(format!("Tensor rank: '{D}'.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn flip<const D: usize>(axes: &[usize]) -> Self {
+        let mut check = Self::Ok;
+
+        for &axis in axes {
+            if axis >= D {
+                check = check.register(
+                    "Flip",
+                    TensorError::new("Given dimension is higher than the tensor rank.")
+                        .details(format!("Tensor rank: '{D}', given dimension: '{axis}'.")),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn roll<const D: usize>(shifts: &[i64], dims: &[usize]) -> Self {
+        let mut check = Self::Ok;
+
+        if shifts.len() != dims.len() {
+            check = check.register(
+                "Roll",
+                TensorError::new("A shift must be given for each dimension.").details(format!(
+                    "Number of shifts: '{}', number of dimensions: '{}'.",
+                    shifts.len(),
+                    dims.len()
+                )),
+            );
+        }
+
+        for &dim in dims {
+            if dim >= D {
+                check = check.register(
+                    "Roll",
+                    TensorError::new("Given dimension is higher than the tensor rank.")
+                        .details(format!("Tensor rank: '{D}', given dimension: '{dim}'.")),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn pad<const D: usize>(
+        shape: &Shape<D>,
+        pads: &[(usize, usize); D],
+        mode: PadMode,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        for (dim, &(before, after)) in pads.iter().enumerate() {
+            let size = shape.dims[dim];
+            let pad = usize::max(before, after);
+
+            let invalid = match mode {
+                PadMode::Constant(_) => false,
+                PadMode::Reflect => pad > 0 && pad >= size,
+                PadMode::Replicate => pad > 0 && size == 0,
+                PadMode::Circular => pad > size,
+            };
+
+            if invalid {
+                check = check.register(
+                    "Pad",
+                    TensorError::new(
+                        "The padding is too large for the size of the dimension with this mode.",
+                    )
+                    .details(format!(
+                        "Tensor shape: {:?}, dimension: '{dim}', padding: ({before}, {after}), \
+                         mode: {mode:?}.",
+                        shape.dims
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn matrix<const D: usize>(ops: &str) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            check = check.register(
+                ops,
+                TensorError::new("Linear algebra operations require at least two dimensions.")
+                    .details(format!("Tensor rank: '{D}'.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn square_matrix<const D: usize>(ops: &str, shape: &Shape<D>) -> Self {
+        let mut check = Self::matrix::<D>(ops);
+
+        if D >= 2 && shape.dims[D - 2] != shape.dims[D - 1] {
+            check = check.register(
+                ops,
+                TensorError::new("The operation requires square matrices.")
+                    .details(format!("Tensor shape: {:?}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn linear_system<const D: usize>(
+        ops: &str,
+        shape_lhs: &Shape<D>,
+        shape_rhs: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::matrix::<D>(ops);
+
+        if D < 2 {
+            return check;
+        }
+
+        if shape_lhs.dims[..D - 2] != shape_rhs.dims[..D - 2] {
+            check = check.register(
+                ops,
+                TensorError::new("The batch dimensions of both tensors should be the same.")
+                    .details(format!(
+                        "Lhs shape: {:?}, rhs shape: {:?}.",
+                        shape_lhs.dims, shape_rhs.dims
+                    )),
+            );
+        }
+
+        if shape_lhs.dims[D - 2] != shape_rhs.dims[D - 2] {
+            check = check.register(
+                ops,
+                TensorError::new("Both tensors should have the same number of rows.").details(
+                    format!(
+                        "Lhs shape: {:?}, rhs shape: {:?}.",
+                        shape_lhs.dims, shape_rhs.dims
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn einsum_equation(equation: &str) -> Self {
+        let mut check = Self::Ok;
+        let parts = equation.split("->").collect::<Vec<_>>();
+
+        if equation.contains("...") {
+            check = check.register(
+                "Einsum",
+                TensorError::new("Ellipsis aren't supported in the equation.")
+                    .details(format!("Equation: '{equation}'.")),
+            );
+        }
+
+        if parts.len() > 2 {
+            check = check.register(
+                "Einsum",
+                TensorError::new("The equation can't contain more than one '->'.")
+                    .details(format!("Equation: '{equation}'.")),
+            );
+        }
+
+        if parts.len() == 2 && parts[1].contains(',') {
+            check = check.register(
+                "Einsum",
+                TensorError::new("The equation can't have more than one output.")
+                    .details(format!("Equation: '{equation}'.")),
+            );
+        }
+
+        if parts.iter().any(|part| {
+            part.chars()
+                .any(|c| c != ',' && !c.is_whitespace() && !c.is_ascii_alphabetic())
+        }) {
+            check = check.register(
+                "Einsum",
+                TensorError::new("The labels of the equation should be ASCII letters.")
+                    .details(format!("Equation: '{equation}'.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn einsum<const D: usize>(equation: &EinsumEquation, shapes: &[Vec<usize>]) -> Self {
+        let mut check = Self::Ok;
+
+        if equation.inputs.len() != shapes.len() {
+            check = check.register(
+                "Einsum",
+                TensorError::new(
+                    "The number of tensors should match the number of inputs of the equation.",
+                )
+                .details(format!(
+                    "Equation inputs: '{}', tensors: '{}'.",
+                    equation.inputs.len(),
+                    shapes.len()
+                )),
+            );
+        }
+
+        for (index, (labels, shape)) in equation.inputs.iter().zip(shapes).enumerate() {
+            if labels.len() != shape.len() {
+                check = check.register(
+                    "Einsum",
+                    TensorError::new(
+                        "The number of labels of each input should match the tensor rank.",
+                    )
+                    .details(format!(
+                        "Input {index} has {} labels, tensor rank: '{}'.",
+                        labels.len(),
+                        shape.len()
+                    )),
+                );
+            }
+        }
+
+        let output_rank = usize::max(equation.output.len(), 1);
+
+        if output_rank != D {
+            check = check.register(
+                "Einsum",
+                TensorError::new("The number of output labels should match the output rank.")
+                    .details(format!(
+                        "Output labels: '{}', output rank: '{D}'.",
+                        equation.output.len()
+                    )),
+            );
+        }
+
+        for (index, label) in equation.output.iter().enumerate() {
+            if equation.output[..index].contains(label) {
+                check = check.register(
+                    "Einsum",
+                    TensorError::new("The output labels should be unique.")
+                        .details(format!("Label '{label}' is repeated.")),
+                );
+            }
+
+            if !equation.inputs.iter().flatten().any(|l| l == label) {
+                check = check.register(
+                    "Einsum",
+                    TensorError::new("The output labels should appear in the inputs.")
+                        .details(format!("Label '{label}' isn't used by any input.")),
+                );
+            }
+        }
+
+        let mut sizes: Vec<(char, usize)> = Vec::new();
+
+        for (labels, shape) in equation.inputs.iter().zip(shapes) {
+            for (label, size) in labels.iter().zip(shape.iter()) {
+                match sizes.iter().find(|(l, _)| l == label) {
+                    Some((_, expected)) if expected != size => {
+                        check = check.register(
+                            "Einsum",
+                            TensorError::new(
+                                "The dimensions with the same label should have the same size.",
+                            )
+                            .details(format!(
+                                "Label '{label}' has sizes '{expected}' and '{size}'."
+                            )),
+                        );
+                    }
+                    Some(_) => {}
+                    None => sizes.push((*label, *size)),
+                }
+            }
+        }
+
+        check
+    }
+
     /// The goal is to minimize the cost of checks when there are no error, but it's way less
     /// important when an error occurred, crafting a comprehensive error message is more important
     /// than optimizing string manipulation.
@@ -0,0 +1,373 @@
+use crate::{backend::Backend, check, check::TensorCheck, Bool, Tensor};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Evaluates the Einstein summation convention on the given tensors.
+///
+/// The equation lists the labels of the dimensions of each input tensor separated by commas,
+/// optionally followed by `->` and the labels of the output dimensions. Labels are ASCII letters,
+/// whitespace is ignored.
+///
+/// - Labels shared by multiple inputs are multiplied together.
+/// - Labels missing from the output are summed over.
+/// - A label repeated in the same input takes the diagonal along those dimensions.
+/// - Without `->`, the output contains the labels appearing exactly once, in alphabetical order.
+/// - An empty output computes the sum of all the elements, returned as a tensor of shape `[1]`.
+///
+/// The computation is lowered to permutations, reshapes, sums and batched matrix
+/// multiplications, so it works on every backend and supports autodiff.
+///
+/// # Arguments
+///
+/// * `equation` - The equation, such as `"bhqd,bhkd->bhqk"`.
+/// * `tensors` - The input tensors, one for each input of the equation. Tensors with different
+///   ranks are given as a tuple, see [EinsumOperands].
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{einsum, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let query = Tensor::<B, 4>::ones([2, 4, 8, 16]);
+///     let key = Tensor::<B, 4>::ones([2, 4, 10, 16]);
+///
+///     // Attention scores of shape [2, 4, 8, 10].
+///     let scores: Tensor<B, 4> = einsum("bhqd,bhkd->bhqk", &[query, key]);
+///
+///     let matrix = Tensor::<B, 2>::ones([3, 4]);
+///     let vector = Tensor::<B, 1>::ones([4]);
+///
+///     // Matrix-vector product of shape [3].
+///     let product: Tensor<B, 1> = einsum("ij,j->i", (matrix, vector));
+/// }
+/// ```
+pub fn einsum<B: Backend, O: EinsumOperands<B>, const D: usize>(
+    equation: &str,
+    tensors: O,
+) -> Tensor<B, D> {
+    check!(TensorCheck::einsum_equation(equation));
+
+    let equation = EinsumEquation::parse(equation);
+    let tensors = tensors.into_operands();
+    let shapes = tensors
+        .iter()
+        .map(|tensor| tensor.dims.clone())
+        .collect::<Vec<_>>();
+
+    check!(TensorCheck::einsum::<D>(&equation, &shapes));
+
+    let mut operands = tensors
+        .into_iter()
+        .zip(equation.inputs.iter())
+        .map(|(tensor, labels)| Operand::new(tensor, labels))
+        .collect::<Vec<_>>()
+        .into_iter();
+
+    let mut output = operands.next().unwrap();
+    let mut remaining = equation.inputs[1..].iter();
+
+    // Contract the operands from left to right, only keeping the labels needed afterward.
+    output = output.reduce(&needed_labels(&equation.output, remaining.as_slice()));
+
+    for operand in operands {
+        remaining.next();
+
+        let keep = needed_labels(&equation.output, remaining.as_slice());
+        output = output.contract(operand, &keep);
+    }
+
+    let output = output.permute(&equation.output);
+    let dims: [usize; D] = match output.dims.is_empty() {
+        true => [1; D],
+        false => output.dims.try_into().unwrap(),
+    };
+
+    output.tensor.reshape(dims)
+}
+
+/// An input tensor of [einsum], whose rank is only known at runtime.
+pub struct EinsumOperand<B: Backend> {
+    tensor: Tensor<B, 1>,
+    dims: Vec<usize>,
+}
+
+impl<B: Backend, const D: usize> From<Tensor<B, D>> for EinsumOperand<B> {
+    fn from(tensor: Tensor<B, D>) -> Self {
+        let dims = tensor.dims().to_vec();
+
+        Self {
+            tensor: tensor.reshape([dims.iter().product::<usize>()]),
+            dims,
+        }
+    }
+}
+
+/// The input tensors of [einsum].
+///
+/// Tensors with the same rank can be given as a slice or an array, while tensors with different
+/// ranks are given as a tuple of up to six tensors, or as a vector of [operands](EinsumOperand).
+pub trait EinsumOperands<B: Backend> {
+    /// Converts the tensors into einsum operands.
+    fn into_operands(self) -> Vec<EinsumOperand<B>>;
+}
+
+impl<B: Backend, const D: usize> EinsumOperands<B> for &[Tensor<B, D>] {
+    fn into_operands(self) -> Vec<EinsumOperand<B>> {
+        self.iter().cloned().map(EinsumOperand::from).collect()
+    }
+}
+
+impl<B: Backend, const D: usize, const N: usize> EinsumOperands<B> for &[Tensor<B, D>; N] {
+    fn into_operands(self) -> Vec<EinsumOperand<B>> {
+        self.as_slice().into_operands()
+    }
+}
+
+impl<B: Backend> EinsumOperands<B> for Vec<EinsumOperand<B>> {
+    fn into_operands(self) -> Vec<EinsumOperand<B>> {
+        self
+    }
+}
+
+macro_rules! einsum_operands_tuple {
+    ($($tensor:ident),+) => {
+        impl<B: Backend, $($tensor: Into<EinsumOperand<B>>),+> EinsumOperands<B> for ($($tensor,)+) {
+            #[allow(non_snake_case)]
+            fn into_operands(self) -> Vec<EinsumOperand<B>> {
+                let ($($tensor,)+) = self;
+
+                alloc::vec![$($tensor.into()),+]
+            }
+        }
+    };
+}
+
+einsum_operands_tuple!(T1);
+einsum_operands_tuple!(T1, T2);
+einsum_operands_tuple!(T1, T2, T3);
+einsum_operands_tuple!(T1, T2, T3, T4);
+einsum_operands_tuple!(T1, T2, T3, T4, T5);
+einsum_operands_tuple!(T1, T2, T3, T4, T5, T6);
+
+/// The parsed labels of an einsum equation.
+pub(crate) struct EinsumEquation {
+    pub(crate) inputs: Vec<Vec<char>>,
+    pub(crate) output: Vec<char>,
+}
+
+impl EinsumEquation {
+    /// Parses an equation that passed the syntax check.
+    fn parse(equation: &str) -> Self {
+        let equation = equation
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>();
+        let (inputs, output) = match equation.split_once("->") {
+            Some((inputs, output)) => (inputs, Some(output)),
+            None => (equation.as_str(), None),
+        };
+
+        let inputs = inputs
+            .split(',')
+            .map(|labels| labels.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let output = match output {
+            Some(output) => output.chars().collect(),
+            None => {
+                let mut labels = inputs
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .filter(|label| inputs.iter().flatten().filter(|l| *l == label).count() == 1)
+                    .collect::<Vec<_>>();
+                labels.sort();
+                labels
+            }
+        };
+
+        Self { inputs, output }
+    }
+}
+
+/// A tensor flattened to a single dimension along with the labels and sizes of its logical
+/// dimensions, which makes it possible to apply operations whose rank isn't known at compile time.
+struct Operand<B: Backend> {
+    tensor: Tensor<B, 1>,
+    labels: Vec<char>,
+    dims: Vec<usize>,
+}
+
+impl<B: Backend> Operand<B> {
+    fn new(tensor: EinsumOperand<B>, labels: &[char]) -> Self {
+        let mut operand = Self {
+            tensor: tensor.tensor,
+            labels: labels.to_vec(),
+            dims: tensor.dims,
+        };
+
+        // Take the diagonal of the repeated labels.
+        let mut i = 0;
+        while i < operand.labels.len() {
+            match operand.labels[i + 1..]
+                .iter()
+                .position(|label| *label == operand.labels[i])
+            {
+                Some(offset) => operand = operand.diagonal(i, i + 1 + offset),
+                None => i += 1,
+            }
+        }
+
+        operand
+    }
+
+    /// Sums over the labels that aren't kept.
+    fn reduce(mut self, keep: &[char]) -> Self {
+        while let Some(dim) = self.labels.iter().position(|label| !keep.contains(label)) {
+            self = self.sum(dim);
+        }
+
+        self
+    }
+
+    /// Multiplies both operands, summing over the shared labels that aren't kept.
+    fn contract(self, other: Self, keep: &[char]) -> Self {
+        let lhs = self.reduce(&[keep, &other.labels].concat());
+        let rhs = other.reduce(&[keep, &lhs.labels].concat());
+
+        let shared = |keep_label: bool| {
+            lhs.labels
+                .iter()
+                .copied()
+                .filter(|label| rhs.labels.contains(label) && keep.contains(label) == keep_label)
+                .collect::<Vec<_>>()
+        };
+        let batch = shared(true);
+        let summed = shared(false);
+        let lhs_free = free_labels(&lhs.labels, &rhs.labels);
+        let rhs_free = free_labels(&rhs.labels, &lhs.labels);
+
+        let lhs = lhs.permute(&[batch.as_slice(), &lhs_free, &summed].concat());
+        let rhs = rhs.permute(&[batch.as_slice(), &summed, &rhs_free].concat());
+
+        let size = |operand: &Self, labels: &[char]| -> usize {
+            labels.iter().map(|label| operand.size(*label)).product()
+        };
+        let [batch_size, summed_size] = [size(&lhs, &batch), size(&lhs, &summed)];
+        let [lhs_size, rhs_size] = [size(&lhs, &lhs_free), size(&rhs, &rhs_free)];
+        let dims = [&batch, &lhs_free]
+            .iter()
+            .flat_map(|labels| labels.iter().map(|label| lhs.size(*label)))
+            .chain(rhs_free.iter().map(|label| rhs.size(*label)))
+            .collect::<Vec<_>>();
+
+        let lhs = lhs.tensor.reshape([batch_size, lhs_size, summed_size]);
+        let rhs = rhs.tensor.reshape([batch_size, summed_size, rhs_size]);
+        let output = lhs.matmul(rhs);
+
+        Self {
+            tensor: output.reshape([batch_size * lhs_size * rhs_size]),
+            labels: [batch, lhs_free, rhs_free].concat(),
+            dims,
+        }
+    }
+
+    /// Reorders the dimensions to match the given labels.
+    fn permute(mut self, labels: &[char]) -> Self {
+        for (i, label) in labels.iter().enumerate() {
+            let j = self.labels.iter().position(|l| l == label).unwrap();
+
+            if i != j {
+                self = self.swap(i, j);
+            }
+        }
+
+        self
+    }
+
+    /// Swaps the dimensions `i` and `j`, where `i < j`.
+    fn swap(mut self, i: usize, j: usize) -> Self {
+        let [before, middle, after] = self.sizes_around(i, j);
+        let num_elements = self.tensor.dims()[0];
+
+        self.tensor = self
+            .tensor
+            .reshape([before, self.dims[i], middle, self.dims[j], after])
+            .swap_dims(1, 3)
+            .reshape([num_elements]);
+        self.labels.swap(i, j);
+        self.dims.swap(i, j);
+
+        self
+    }
+
+    /// Sums over the dimension `i`.
+    fn sum(mut self, i: usize) -> Self {
+        let [before, _, after] = self.sizes_around(i, i);
+
+        self.tensor = self
+            .tensor
+            .reshape([before, self.dims[i], after])
+            .sum_dim(1)
+            .reshape([before * after]);
+        self.labels.remove(i);
+        self.dims.remove(i);
+
+        self
+    }
+
+    /// Takes the diagonal along the dimensions `i` and `j`, where `i < j`, removing `j`.
+    fn diagonal(mut self, i: usize, j: usize) -> Self {
+        let [before, middle, after] = self.sizes_around(i, j);
+        let size = self.dims[i];
+
+        let identity = Tensor::<B, 2, Bool>::diag_mask([size, size], 0, &self.tensor.device())
+            .bool_not()
+            .float()
+            .reshape([1, size, 1, size, 1]);
+
+        self.tensor = self
+            .tensor
+            .reshape([before, size, middle, size, after])
+            .mul(identity)
+            .sum_dim(3)
+            .reshape([before * size * middle * after]);
+        self.labels.remove(j);
+        self.dims.remove(j);
+
+        self
+    }
+
+    /// The number of elements before `i`, between `i` and `j`, and after `j`.
+    fn sizes_around(&self, i: usize, j: usize) -> [usize; 3] {
+        [
+            self.dims[..i].iter().product(),
+            self.dims[i + 1..j.max(i + 1)].iter().product(),
+            self.dims[j + 1..].iter().product(),
+        ]
+    }
+
+    fn size(&self, label: char) -> usize {
+        self.dims[self.labels.iter().position(|l| *l == label).unwrap()]
+    }
+}
+
+/// The labels of the output followed by the labels of the remaining inputs.
+fn needed_labels(output: &[char], remaining: &[Vec<char>]) -> Vec<char> {
+    output
+        .iter()
+        .chain(remaining.iter().flatten())
+        .copied()
+        .collect()
+}
+
+/// The labels that aren't shared with the other operand.
+fn free_labels(labels: &[char], other: &[char]) -> Vec<char> {
+    labels
+        .iter()
+        .copied()
+        .filter(|label| !other.contains(label))
+        .collect()
+}
@@ -53,6 +53,17 @@ where
         Self::new(B::log1p(self.primitive))
     }
 
+    /// Computes the logarithm of the cumulative sum of the exponentials of the elements along the
+    /// given *dimension* or *axis*.
+    ///
+    /// `y_i = log(sum_{j <= i} e^x_j)`
+    ///
+    /// The computation is numerically stable, even for large values.
+    pub fn logcumsumexp(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("LogCumSumExp", dim));
+        Self::new(B::logcumsumexp(self.primitive, dim))
+    }
+
     /// Applies the [error function](https://en.wikipedia.org/wiki/Error_function) element wise.
     ///
     /// `y = erf(x)`
@@ -60,6 +71,43 @@ where
         Self::new(B::erf(self.primitive))
     }
 
+    /// Applies element wise floor operation, rounding down to the nearest integer.
+    ///
+    /// `y = floor(x)`
+    pub fn floor(self) -> Self {
+        Self::new(B::floor(self.primitive))
+    }
+
+    /// Applies element wise ceil operation, rounding up to the nearest integer.
+    ///
+    /// `y = ceil(x)`
+    pub fn ceil(self) -> Self {
+        Self::new(B::ceil(self.primitive))
+    }
+
+    /// Applies element wise round operation, rounding to the nearest integer with ties rounded
+    /// to the nearest even integer.
+    ///
+    /// `y = round(x)`
+    pub fn round(self) -> Self {
+        Self::new(B::round(self.primitive))
+    }
+
+    /// Applies element wise truncation, rounding toward zero.
+    ///
+    /// `y = trunc(x)`
+    pub fn trunc(self) -> Self {
+        Self::new(B::trunc(self.primitive))
+    }
+
+    /// Applies element wise sign operation, returning `-1` for negative values, `1` for positive
+    /// values and `0` for zeros.
+    ///
+    /// `y = sign(x)`
+    pub fn sign(self) -> Self {
+        Self::new(B::sign(self.primitive))
+    }
+
     /// Applies element wise power operation.
     ///
     /// `y = x^a`
@@ -2,11 +2,13 @@ pub(crate) mod check;
 
 mod base;
 mod bool;
+mod einsum;
 mod float;
 mod int;
 mod kind;
 mod numeric;
 
 pub use base::*;
+pub use einsum::*;
 pub use kind::*;
 pub use numeric::*;
@@ -1,6 +1,6 @@
 use crate::{
-    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Element, ElementConversion, Float,
-    Int, Shape, Tensor, TensorKind,
+    backend::Backend, check, check::TensorCheck, ops::PadMode, BasicOps, Bool, Element,
+    ElementConversion, Float, Int, Shape, Tensor, TensorKind,
 };
 
 impl<B, const D: usize, K> Tensor<B, D, K>
@@ -80,6 +80,22 @@ where
     pub fn div_scalar<E: ElementConversion>(self, other: E) -> Self {
         Self::new(K::div_scalar(self.primitive, other))
     }
+
+    /// Applies element wise remainder operation, the result has the same sign as the divisor.
+    ///
+    /// `y = x1 - floor(x1 / x2) * x2`
+    pub fn remainder(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Remainder", &self, &other));
+        Self::new(K::remainder(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise remainder operation with a scalar, the result has the same sign as
+    /// the divisor.
+    ///
+    /// `y = x - floor(x / s) * s`
+    pub fn remainder_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(K::remainder_scalar(self.primitive, other))
+    }
     ///
     /// Applies element wise multiplication operation.
     ///
@@ -161,6 +177,61 @@ where
         Self::new(K::sum_dim(self.primitive, dim))
     }
 
+    /// Aggregate all elements in the tensor with the product operation.
+    pub fn prod(self) -> Tensor<B, 1, K> {
+        Tensor::new(K::prod(self.primitive))
+    }
+
+    /// Aggregate all elements along the given *dimension* or *axis* in the tensor with the product operation.
+    pub fn prod_dim(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("Prod", dim));
+        Self::new(K::prod_dim(self.primitive, dim))
+    }
+
+    /// Computes the cumulative sum of the elements along the given *dimension* or *axis*.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///     let tensor = tensor.cumsum(1);
+    ///     println!("{}", tensor);
+    ///     // [[1.0, 3.0, 6.0], [4.0, 9.0, 15.0]]
+    /// }
+    /// ```
+    pub fn cumsum(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("CumSum", dim));
+        Self::new(K::cumsum(self.primitive, dim))
+    }
+
+    /// Computes the cumulative product of the elements along the given *dimension* or *axis*.
+    pub fn cumprod(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("CumProd", dim));
+        Self::new(K::cumprod(self.primitive, dim))
+    }
+
+    /// Computes the cumulative maximum of the elements along the given *dimension* or *axis*.
+    pub fn cummax(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("CumMax", dim));
+        Self::new(K::cummax(self.primitive, dim))
+    }
+
+    /// Computes the cumulative maximum of the elements along the given *dimension* or *axis*.
+    ///
+    /// Also returns the indices of the maximum elements, the last index is used when the maximum
+    /// is reached multiple times.
+    pub fn cummax_with_indices(self, dim: usize) -> (Self, Tensor<B, D, Int>) {
+        check!(TensorCheck::dim_ops::<D>("CumMax", dim));
+
+        let (tensor, indices) = K::cummax_with_indices(self.primitive, dim);
+
+        (Self::new(tensor), Tensor::new(indices))
+    }
+
     /// Applies element wise equal comparison and returns a boolean tensor.
     pub fn equal_elem<E: Element>(self, other: E) -> Tensor<B, D, Bool> {
         K::equal_elem::<D>(self.primitive, other.elem())
@@ -242,6 +313,36 @@ where
         Self::new(K::mask_fill(self.primitive, mask, value.elem()))
     }
 
+    /// Returns the lower triangular part of the last two dimensions of the tensor, the other
+    /// elements are set to zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `diagonal` - The diagonal above which the elements are set to zero. Zero corresponds to
+    ///   the main diagonal, a positive value to a diagonal above it and a negative value to a
+    ///   diagonal below it.
+    pub fn tril(self, diagonal: i64) -> Self {
+        check!(TensorCheck::triangular::<D>("Tril"));
+
+        let mask = Tensor::<B, D, Bool>::tril_mask(self.shape(), diagonal, &self.device());
+        self.mask_fill(mask, 0)
+    }
+
+    /// Returns the upper triangular part of the last two dimensions of the tensor, the other
+    /// elements are set to zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `diagonal` - The diagonal below which the elements are set to zero. Zero corresponds to
+    ///   the main diagonal, a positive value to a diagonal above it and a negative value to a
+    ///   diagonal below it.
+    pub fn triu(self, diagonal: i64) -> Self {
+        check!(TensorCheck::triangular::<D>("Triu"));
+
+        let mask = Tensor::<B, D, Bool>::triu_mask(self.shape(), diagonal, &self.device());
+        self.mask_fill(mask, 0)
+    }
+
     /// Gather tensor elements corresponding to the given indices from the specified dim.
     ///
     /// Example using a 3D tensor:
@@ -462,6 +563,178 @@ where
     pub fn abs(self) -> Self {
         Self::new(K::abs(self.primitive))
     }
+
+    /// Sort the elements by value in ascending order along a given dimension.
+    ///
+    /// This sort is stable (i.e. the order of equal elements is preserved).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[3.0, 1.0, 2.0], [0.0, 5.0, 4.0]]);
+    ///     let tensor = tensor.sort(1);
+    ///     println!("{}", tensor);
+    ///     // [[1.0, 2.0, 3.0], [0.0, 4.0, 5.0]]
+    /// }
+    /// ```
+    pub fn sort(self, dim: usize) -> Tensor<B, D, K> {
+        check!(TensorCheck::dim_ops::<D>("Sort", dim));
+        Tensor::new(K::sort(self.primitive, dim, false))
+    }
+
+    /// Sort the elements by value in descending order along a given dimension.
+    ///
+    /// This sort is stable (i.e. the order of equal elements is preserved).
+    pub fn sort_descending(self, dim: usize) -> Tensor<B, D, K> {
+        check!(TensorCheck::dim_ops::<D>("Sort", dim));
+        Tensor::new(K::sort(self.primitive, dim, true))
+    }
+
+    /// Sort the elements by value in ascending order along a given dimension.
+    ///
+    /// Also returns the indices of the sorted elements in the original tensor.
+    pub fn sort_with_indices(self, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::dim_ops::<D>("Sort", dim));
+
+        let (tensor, indices) = K::sort_with_indices(self.primitive, dim, false);
+
+        (Tensor::new(tensor), Tensor::new(indices))
+    }
+
+    /// Sort the elements by value in descending order along a given dimension.
+    ///
+    /// Also returns the indices of the sorted elements in the original tensor.
+    pub fn sort_descending_with_indices(self, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::dim_ops::<D>("Sort", dim));
+
+        let (tensor, indices) = K::sort_with_indices(self.primitive, dim, true);
+
+        (Tensor::new(tensor), Tensor::new(indices))
+    }
+
+    /// Returns the indices that sort the elements by value in ascending order along a given dimension.
+    ///
+    /// This sort is stable (i.e. the order of equal elements is preserved).
+    pub fn argsort(self, dim: usize) -> Tensor<B, D, Int> {
+        check!(TensorCheck::dim_ops::<D>("Argsort", dim));
+        Tensor::new(K::argsort(self.primitive, dim, false))
+    }
+
+    /// Returns the indices that sort the elements by value in descending order along a given dimension.
+    ///
+    /// This sort is stable (i.e. the order of equal elements is preserved).
+    pub fn argsort_descending(self, dim: usize) -> Tensor<B, D, Int> {
+        check!(TensorCheck::dim_ops::<D>("Argsort", dim));
+        Tensor::new(K::argsort(self.primitive, dim, true))
+    }
+
+    /// Returns the `k` largest elements along a given dimension, sorted in descending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[3.0, 1.0, 2.0], [0.0, 5.0, 4.0]]);
+    ///     let tensor = tensor.topk(2, 1);
+    ///     println!("{}", tensor);
+    ///     // [[3.0, 2.0], [5.0, 4.0]]
+    /// }
+    /// ```
+    pub fn topk(self, k: usize, dim: usize) -> Tensor<B, D, K> {
+        check!(TensorCheck::topk::<D>(&self.shape(), dim, k));
+        Tensor::new(K::topk(self.primitive, dim, k))
+    }
+
+    /// Returns the `k` largest elements along a given dimension, sorted in descending order.
+    ///
+    /// Also returns the indices of the elements in the original tensor.
+    pub fn topk_with_indices(self, k: usize, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::topk::<D>(&self.shape(), dim, k));
+
+        let (tensor, indices) = K::topk_with_indices(self.primitive, dim, k);
+
+        (Tensor::new(tensor), Tensor::new(indices))
+    }
+
+    /// Reverses the order of the elements along the given axes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///     let tensor = tensor.flip(&[0, 1]);
+    ///     println!("{}", tensor);
+    ///     // [[6.0, 5.0, 4.0], [3.0, 2.0, 1.0]]
+    /// }
+    /// ```
+    pub fn flip(self, axes: &[usize]) -> Self {
+        check!(TensorCheck::flip::<D>(axes));
+        Self::new(K::flip(self.primitive, axes))
+    }
+
+    /// Shifts the elements along the given dimensions, the elements shifted past the end of a
+    /// dimension are wrapped around to its start.
+    ///
+    /// # Arguments
+    ///
+    /// * `shifts` - The number of places by which the elements are shifted for each dimension,
+    ///   negative values shift the elements toward the start.
+    /// * `dims` - The dimensions along which the elements are shifted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///     let tensor = tensor.roll(&[1], &[1]);
+    ///     println!("{}", tensor);
+    ///     // [[3.0, 1.0, 2.0], [6.0, 4.0, 5.0]]
+    /// }
+    /// ```
+    pub fn roll(self, shifts: &[i64], dims: &[usize]) -> Self {
+        check!(TensorCheck::roll::<D>(shifts, dims));
+        Self::new(K::roll(self.primitive, shifts, dims))
+    }
+
+    /// Pads the tensor, see [PadMode] for the available modes.
+    ///
+    /// # Arguments
+    ///
+    /// * `pads` - The number of elements added before and after each dimension.
+    /// * `mode` - How the padded elements are filled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::ops::PadMode;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///     let tensor = tensor.pad([(0, 0), (2, 1)], PadMode::Reflect);
+    ///     println!("{}", tensor);
+    ///     // [[3.0, 2.0, 1.0, 2.0, 3.0, 2.0], [6.0, 5.0, 4.0, 5.0, 6.0, 5.0]]
+    /// }
+    /// ```
+    pub fn pad(self, pads: [(usize, usize); D], mode: PadMode) -> Self {
+        check!(TensorCheck::pad::<D>(&self.shape(), &pads, mode));
+        Self::new(K::pad(self.primitive, pads, mode))
+    }
 }
 
 impl<B, K> Tensor<B, 2, K>
@@ -627,6 +900,55 @@ where
         rhs: E,
     ) -> Self::Primitive<D>;
 
+    /// Computes the remainder of the division of two tensors element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The dividend.
+    /// * `rhs` - The divisor.
+    ///
+    /// # Returns
+    ///
+    /// The remainder of the division, with the same sign as the divisor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the remainder of a division, users should prefer the
+    /// [Tensor::remainder](Tensor::remainder) function, which is more high-level and designed for public use.
+    fn remainder<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> Self::Primitive<D>;
+
+    /// Computes the remainder of the division of a tensor by a scalar element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The dividend.
+    /// * `rhs` - The divisor scalar.
+    ///
+    /// # Returns
+    ///
+    /// The remainder of the division, with the same sign as the divisor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the remainder of a division by a scalar, users should prefer the
+    /// [Tensor::remainder_scalar](Tensor::remainder_scalar) function, which is more high-level and
+    /// designed for public use.
+    fn remainder_scalar<const D: usize, E: ElementConversion>(
+        lhs: Self::Primitive<D>,
+        rhs: E,
+    ) -> Self::Primitive<D>;
+
     /// Multiplies two tensors.
     ///
     /// # Arguments
@@ -801,6 +1123,140 @@ where
     /// which is more high-level and designed for public use.
     fn sum_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
 
+    /// Computes the product of all the elements of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the product of.
+    ///
+    /// # Returns
+    ///
+    /// The product of all the elements of the tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the product of all the elements of a tensor, users should prefer the
+    /// [Tensor::prod](Tensor::prod) function, which is more high-level and designed for public use.
+    fn prod<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1>;
+
+    /// Computes the product of all the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the product of.
+    /// * `dim` - The dimension along which to compute the product.
+    ///
+    /// # Returns
+    ///
+    /// The product of all the elements of the tensor along the specified dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the product of all the elements of a tensor along a dimension, users should prefer
+    /// the [Tensor::prod_dim](Tensor::prod_dim) function, which is more high-level and designed for public use.
+    fn prod_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Computes the cumulative sum of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative sum of.
+    /// * `dim` - The dimension along which to compute the cumulative sum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the sum of all the
+    /// elements up to the same position along the specified dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative sum of a tensor along a dimension, users should prefer the
+    /// [Tensor::cumsum](Tensor::cumsum) function, which is more high-level and designed for public use.
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Computes the cumulative product of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative product of.
+    /// * `dim` - The dimension along which to compute the cumulative product.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the product of all
+    /// the elements up to the same position along the specified dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative product of a tensor along a dimension, users should prefer the
+    /// [Tensor::cumprod](Tensor::cumprod) function, which is more high-level and designed for public use.
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Computes the cumulative maximum of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative maximum of.
+    /// * `dim` - The dimension along which to compute the cumulative maximum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the maximum of all
+    /// the elements up to the same position along the specified dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative maximum of a tensor along a dimension, users should prefer the
+    /// [Tensor::cummax](Tensor::cummax) function, which is more high-level and designed for public use.
+    fn cummax<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Computes the cumulative maximum of the elements of the tensor along a dimension and the
+    /// indices of the maximum elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative maximum of.
+    /// * `dim` - The dimension along which to compute the cumulative maximum.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the cumulative maximum of the input tensor along the specified dimension and
+    /// the indices of the maximum elements.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative maximum of a tensor along a dimension, users should prefer the
+    /// [Tensor::cummax_with_indices](Tensor::cummax_with_indices) function, which is more high-level
+    /// and designed for public use.
+    fn cummax_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> (Self::Primitive<D>, B::IntTensorPrimitive<D>);
+
     /// Computes the mean of all the elements of the tensor.
     ///
     /// # Arguments
@@ -1503,6 +1959,214 @@ where
     /// For calculating abs of the elements of a tensor, users should prefer the [Tensor::abs](Tensor::abs) function,
     /// which is more high-level and designed for public use.
     fn abs<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D>;
+
+    /// Sorts the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The axis along which to sort.
+    /// * `descending` - If the elements should be sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where the elements are sorted along
+    /// the specified axis.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For sorting the elements of a tensor, users should prefer the [Tensor::sort](Tensor::sort)
+    /// and [Tensor::sort_descending](Tensor::sort_descending) functions, which are more high-level
+    /// and designed for public use.
+    fn sort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> Self::Primitive<D>;
+
+    /// Sorts the elements of a tensor along an axis and returns their indices in the input tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The axis along which to sort.
+    /// * `descending` - If the elements should be sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the sorted tensor and the indices of the sorted elements in the input tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For sorting the elements of a tensor, users should prefer the
+    /// [Tensor::sort_with_indices](Tensor::sort_with_indices) and
+    /// [Tensor::sort_descending_with_indices](Tensor::sort_descending_with_indices) functions,
+    /// which are more high-level and designed for public use.
+    fn sort_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (Self::Primitive<D>, B::IntTensorPrimitive<D>);
+
+    /// Gets the indices that would sort the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The axis along which to sort.
+    /// * `descending` - If the elements should be sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the indices that would sort the input tensor along the specified axis.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For getting the sorting indices of a tensor, users should prefer the
+    /// [Tensor::argsort](Tensor::argsort) and [Tensor::argsort_descending](Tensor::argsort_descending)
+    /// functions, which are more high-level and designed for public use.
+    fn argsort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D>;
+
+    /// Gets the `k` largest elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the largest elements from.
+    /// * `dim` - The axis along which to get the largest elements.
+    /// * `k` - The number of elements to keep.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the `k` largest elements of the input tensor along the specified axis,
+    /// sorted in descending order.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For getting the largest elements of a tensor, users should prefer the
+    /// [Tensor::topk](Tensor::topk) function, which is more high-level and designed for public use.
+    fn topk<const D: usize>(tensor: Self::Primitive<D>, dim: usize, k: usize)
+        -> Self::Primitive<D>;
+
+    /// Gets the `k` largest elements of a tensor along an axis and their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the largest elements from.
+    /// * `dim` - The axis along which to get the largest elements.
+    /// * `k` - The number of elements to keep.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the `k` largest elements of the input tensor along the specified axis, sorted
+    /// in descending order, and their indices in the input tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For getting the largest elements of a tensor, users should prefer the
+    /// [Tensor::topk_with_indices](Tensor::topk_with_indices) function, which is more high-level
+    /// and designed for public use.
+    fn topk_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        k: usize,
+    ) -> (Self::Primitive<D>, B::IntTensorPrimitive<D>);
+
+    /// Reverses the order of the elements of a tensor along the given axes.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to flip.
+    /// * `axes` - The axes to flip.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the elements reversed along the given axes.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For flipping a tensor, users should prefer the [Tensor::flip](Tensor::flip) function,
+    /// which is more high-level and designed for public use.
+    fn flip<const D: usize>(tensor: Self::Primitive<D>, axes: &[usize]) -> Self::Primitive<D>;
+
+    /// Shifts the elements of a tensor along the given dimensions, wrapping them around.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to roll.
+    /// * `shifts` - The number of places by which the elements are shifted for each dimension.
+    /// * `dims` - The dimensions along which the elements are shifted.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the elements shifted.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For rolling a tensor, users should prefer the [Tensor::roll](Tensor::roll) function,
+    /// which is more high-level and designed for public use.
+    fn roll<const D: usize>(
+        tensor: Self::Primitive<D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> Self::Primitive<D>;
+
+    /// Pads a tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to pad.
+    /// * `pads` - The number of elements added before and after each dimension.
+    /// * `mode` - How the padded elements are filled.
+    ///
+    /// # Returns
+    ///
+    /// The padded tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For padding a tensor, users should prefer the [Tensor::pad](Tensor::pad) function,
+    /// which is more high-level and designed for public use.
+    fn pad<const D: usize>(
+        tensor: Self::Primitive<D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> Self::Primitive<D>;
 }
 
 impl<B: Backend> Numeric<B> for Int {
@@ -1542,6 +2206,18 @@ impl<B: Backend> Numeric<B> for Int {
     ) -> Self::Primitive<D> {
         B::int_div_scalar(lhs, rhs.elem())
     }
+    fn remainder<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> Self::Primitive<D> {
+        B::int_remainder(lhs, rhs)
+    }
+    fn remainder_scalar<const D: usize, E: ElementConversion>(
+        lhs: Self::Primitive<D>,
+        rhs: E,
+    ) -> Self::Primitive<D> {
+        B::int_remainder_scalar(lhs, rhs.elem())
+    }
     fn mul<const D: usize>(
         lhs: Self::Primitive<D>,
         rhs: Self::Primitive<D>,
@@ -1576,6 +2252,33 @@ impl<B: Backend> Numeric<B> for Int {
     fn sum_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
         B::int_sum_dim(tensor, dim)
     }
+
+    fn prod<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
+        B::int_prod(tensor)
+    }
+
+    fn prod_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_prod_dim(tensor, dim)
+    }
+
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cumsum(tensor, dim)
+    }
+
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cumprod(tensor, dim)
+    }
+
+    fn cummax<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cummax(tensor, dim)
+    }
+
+    fn cummax_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
+        B::int_cummax_with_indices(tensor, dim)
+    }
     fn mean<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
         B::int_mean(tensor)
     }
@@ -1757,6 +2460,66 @@ impl<B: Backend> Numeric<B> for Int {
     fn abs<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
         B::int_abs(tensor)
     }
+
+    fn sort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> Self::Primitive<D> {
+        B::int_sort(tensor, dim, descending)
+    }
+
+    fn sort_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
+        B::int_sort_with_indices(tensor, dim, descending)
+    }
+
+    fn argsort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> <B as Backend>::IntTensorPrimitive<D> {
+        B::int_argsort(tensor, dim, descending)
+    }
+
+    fn topk<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        k: usize,
+    ) -> Self::Primitive<D> {
+        B::int_topk(tensor, dim, k)
+    }
+
+    fn topk_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        k: usize,
+    ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
+        B::int_topk_with_indices(tensor, dim, k)
+    }
+
+    fn flip<const D: usize>(tensor: Self::Primitive<D>, axes: &[usize]) -> Self::Primitive<D> {
+        B::int_flip(tensor, axes)
+    }
+
+    fn roll<const D: usize>(
+        tensor: Self::Primitive<D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> Self::Primitive<D> {
+        B::int_roll(tensor, shifts, dims)
+    }
+
+    fn pad<const D: usize>(
+        tensor: Self::Primitive<D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> Self::Primitive<D> {
+        B::int_pad(tensor, pads, mode)
+    }
 }
 
 impl<B: Backend> Numeric<B> for Float {
@@ -1796,6 +2559,18 @@ impl<B: Backend> Numeric<B> for Float {
     ) -> Self::Primitive<D> {
         B::div_scalar(lhs, rhs.elem())
     }
+    fn remainder<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> Self::Primitive<D> {
+        B::remainder(lhs, rhs)
+    }
+    fn remainder_scalar<const D: usize, E: ElementConversion>(
+        lhs: Self::Primitive<D>,
+        rhs: E,
+    ) -> Self::Primitive<D> {
+        B::remainder_scalar(lhs, rhs.elem())
+    }
     fn mul<const D: usize>(
         lhs: Self::Primitive<D>,
         rhs: Self::Primitive<D>,
@@ -1830,6 +2605,33 @@ impl<B: Backend> Numeric<B> for Float {
     fn sum_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
         B::sum_dim(tensor, dim)
     }
+
+    fn prod<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
+        B::prod(tensor)
+    }
+
+    fn prod_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::prod_dim(tensor, dim)
+    }
+
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::cumsum(tensor, dim)
+    }
+
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::cumprod(tensor, dim)
+    }
+
+    fn cummax<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::cummax(tensor, dim)
+    }
+
+    fn cummax_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
+        B::cummax_with_indices(tensor, dim)
+    }
     fn mean<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
         B::mean(tensor)
     }
@@ -2012,6 +2814,66 @@ impl<B: Backend> Numeric<B> for Float {
     fn abs<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
         B::abs(tensor)
     }
+
+    fn sort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> Self::Primitive<D> {
+        B::sort(tensor, dim, descending)
+    }
+
+    fn sort_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
+        B::sort_with_indices(tensor, dim, descending)
+    }
+
+    fn argsort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> <B as Backend>::IntTensorPrimitive<D> {
+        B::argsort(tensor, dim, descending)
+    }
+
+    fn topk<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        k: usize,
+    ) -> Self::Primitive<D> {
+        B::topk(tensor, dim, k)
+    }
+
+    fn topk_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        k: usize,
+    ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
+        B::topk_with_indices(tensor, dim, k)
+    }
+
+    fn flip<const D: usize>(tensor: Self::Primitive<D>, axes: &[usize]) -> Self::Primitive<D> {
+        B::flip(tensor, axes)
+    }
+
+    fn roll<const D: usize>(
+        tensor: Self::Primitive<D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> Self::Primitive<D> {
+        B::roll(tensor, shifts, dims)
+    }
+
+    fn pad<const D: usize>(
+        tensor: Self::Primitive<D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> Self::Primitive<D> {
+        B::pad(tensor, pads, mode)
+    }
 }
 
 impl<B, const D: usize, K> core::ops::Add<Self> for Tensor<B, D, K>
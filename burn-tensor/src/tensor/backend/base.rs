@@ -55,6 +55,7 @@ pub trait Backend:
     + IntTensorOps<Self>
     + ModuleOps<Self>
     + ActivationOps<Self>
+    + LinalgOps<Self>
     + Clone
     + Sized
     + Default
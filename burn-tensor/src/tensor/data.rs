@@ -454,6 +454,34 @@ impl<
     }
 }
 
+impl<
+        E: core::fmt::Debug + Copy,
+        const A: usize,
+        const B: usize,
+        const C: usize,
+        const D: usize,
+        const F: usize,
+    > From<[[[[[E; F]; D]; C]; B]; A]> for Data<E, 5>
+{
+    fn from(elems: [[[[[E; F]; D]; C]; B]; A]) -> Self {
+        let mut data = Vec::with_capacity(A * B * C * D * F);
+
+        for elem in elems.into_iter().take(A) {
+            for elem in elem.into_iter().take(B) {
+                for elem in elem.into_iter().take(C) {
+                    for elem in elem.into_iter().take(D) {
+                        for elem in elem.into_iter().take(F) {
+                            data.push(elem);
+                        }
+                    }
+                }
+            }
+        }
+
+        Data::new(data, Shape::new([A, B, C, D, F]))
+    }
+}
+
 impl<E: core::fmt::Debug, const D: usize> core::fmt::Display for Data<E, D> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str(format!("{:?}", &self.value).as_str())
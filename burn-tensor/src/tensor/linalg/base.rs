@@ -0,0 +1,251 @@
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{check, Bool, Tensor};
+
+/// The matrix norms supported by [matrix_norm].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixNorm {
+    /// The square root of the sum of the squares of the elements.
+    Frobenius,
+
+    /// The sum of the singular values.
+    Nuclear,
+
+    /// The largest singular value.
+    Spectral,
+}
+
+/// Computes the Cholesky decomposition of symmetric positive-definite matrices.
+///
+/// The matrices are formed by the last two dimensions of the tensor, the other dimensions are
+/// batch dimensions.
+///
+/// # Returns
+///
+/// The lower triangular matrices `L` such that `A = L L^T`. The result is undefined when the
+/// matrices aren't positive-definite.
+pub fn cholesky<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::square_matrix::<D>("Cholesky", &tensor.shape()));
+
+    Tensor::from_primitive(B::cholesky(tensor.into_primitive()))
+}
+
+/// Computes the reduced QR decomposition of matrices of shape `[..., m, n]`.
+///
+/// # Returns
+///
+/// The matrices `Q` of shape `[..., m, k]` with orthonormal columns and the upper triangular
+/// matrices `R` of shape `[..., k, n]` with a non-negative diagonal such that `A = Q R`, where
+/// `k = min(m, n)`.
+///
+/// # Notes
+///
+/// The gradient is only defined when `m >= n` and the matrices have full rank.
+pub fn qr<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::matrix::<D>("QR"));
+
+    let (q, r) = B::qr(tensor.into_primitive());
+
+    (Tensor::from_primitive(q), Tensor::from_primitive(r))
+}
+
+/// Computes the eigenvalues and the eigenvectors of symmetric matrices of shape `[..., n, n]`.
+///
+/// # Returns
+///
+/// The eigenvalues of shape `[..., 1, n]` in ascending order and the matrices of shape
+/// `[..., n, n]` whose columns are the corresponding eigenvectors.
+///
+/// # Notes
+///
+/// The gradient is only defined when the eigenvalues are distinct.
+pub fn eigh<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::square_matrix::<D>("Eigh", &tensor.shape()));
+
+    let (eigenvalues, eigenvectors) = B::eigh(tensor.into_primitive());
+
+    (
+        Tensor::from_primitive(eigenvalues),
+        Tensor::from_primitive(eigenvectors),
+    )
+}
+
+/// Computes the reduced singular value decomposition of matrices of shape `[..., m, n]`.
+///
+/// # Returns
+///
+/// The matrices `U` of shape `[..., m, k]`, the singular values `S` of shape `[..., 1, k]` in
+/// descending order and the matrices `Vh` of shape `[..., k, n]` such that `A = U diag(S) Vh`,
+/// where `k = min(m, n)`.
+///
+/// # Notes
+///
+/// The gradient is only defined when the singular values are distinct and non-zero.
+pub fn svd<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::matrix::<D>("SVD"));
+
+    let (u, s, vh) = B::svd(tensor.into_primitive());
+
+    (
+        Tensor::from_primitive(u),
+        Tensor::from_primitive(s),
+        Tensor::from_primitive(vh),
+    )
+}
+
+/// Solves the systems of linear equations `A X = B`, where `A` has shape `[..., n, n]` and `B`
+/// has shape `[..., n, k]`.
+///
+/// # Returns
+///
+/// The solutions `X` of shape `[..., n, k]`. The result is undefined when `A` is singular.
+pub fn solve<B: Backend, const D: usize>(lhs: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::square_matrix::<D>("Solve", &lhs.shape()));
+    check!(TensorCheck::linear_system::<D>(
+        "Solve",
+        &lhs.shape(),
+        &rhs.shape()
+    ));
+
+    Tensor::from_primitive(B::solve(lhs.into_primitive(), rhs.into_primitive()))
+}
+
+/// Computes the least-squares solutions of the systems of linear equations `A X = B`, where `A`
+/// has shape `[..., m, n]` and `B` has shape `[..., m, k]`.
+///
+/// The solutions with the minimal norm are returned for underdetermined or rank-deficient
+/// systems, the singular values smaller than the largest one times the machine precision are
+/// considered to be zero.
+///
+/// # Returns
+///
+/// The solutions `X` of shape `[..., n, k]`.
+pub fn lstsq<B: Backend, const D: usize>(lhs: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::linear_system::<D>(
+        "Lstsq",
+        &lhs.shape(),
+        &rhs.shape()
+    ));
+
+    let [m, n] = [lhs.dims()[D - 2], lhs.dims()[D - 1]];
+    let (u, s, vh) = svd(lhs);
+    let k = s.dims()[D - 1];
+
+    let cutoff = s
+        .clone()
+        .max_dim(D - 1)
+        .mul_scalar(f32::EPSILON * usize::max(m, n) as f32)
+        .repeat(D - 1, k);
+    let negligible = s.clone().lower_equal(cutoff);
+    let s_inv = s
+        .mask_fill(negligible.clone(), 1)
+        .powf(-1.0)
+        .mask_fill(negligible, 0);
+
+    let projected = u.transpose().matmul(rhs).mul(s_inv.transpose());
+
+    vh.transpose().matmul(projected)
+}
+
+/// Computes the sign and the natural logarithm of the absolute value of the determinant of square
+/// matrices of shape `[..., n, n]`.
+///
+/// This is more accurate than [det] for matrices whose determinant is very small or very large.
+///
+/// # Returns
+///
+/// The signs and the logarithms of the absolute values of the determinants, both of shape
+/// `[..., 1, 1]`. The sign is zero and the logarithm is negative infinity for singular matrices.
+pub fn slogdet<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::square_matrix::<D>("Slogdet", &tensor.shape()));
+
+    let (sign, logabsdet) = B::slogdet(tensor.into_primitive());
+
+    (
+        Tensor::from_primitive(sign),
+        Tensor::from_primitive(logabsdet),
+    )
+}
+
+/// Computes the determinant of square matrices of shape `[..., n, n]`.
+///
+/// # Returns
+///
+/// The determinants of shape `[..., 1, 1]`.
+pub fn det<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let (sign, logabsdet) = slogdet(tensor);
+
+    sign.mul(logabsdet.exp())
+}
+
+/// Computes the inverse of square matrices of shape `[..., n, n]`.
+///
+/// The result is undefined when the matrices are singular.
+pub fn inv<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::square_matrix::<D>("Inv", &tensor.shape()));
+
+    let identity = Tensor::<B, D, Bool>::diag_mask(tensor.shape(), 0, &tensor.device())
+        .bool_not()
+        .float();
+
+    solve(tensor, identity)
+}
+
+/// Computes the vector norm of the elements along the given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+/// * `ord` - The order of the norm, `f64::INFINITY` and `f64::NEG_INFINITY` give the largest and
+///   the smallest absolute value, zero gives the number of non-zero elements and any other value
+///   `p` gives `sum(abs(x)^p)^(1/p)`.
+/// * `dim` - The dimension along which the norm is computed.
+///
+/// # Returns
+///
+/// The norms, with the size of the given dimension reduced to one.
+pub fn norm<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    ord: f64,
+    dim: usize,
+) -> Tensor<B, D> {
+    check!(TensorCheck::dim_ops::<D>("Norm", dim));
+
+    if ord == f64::INFINITY {
+        tensor.abs().max_dim(dim)
+    } else if ord == f64::NEG_INFINITY {
+        tensor.abs().min_dim(dim)
+    } else if ord == 0.0 {
+        tensor.equal_elem(0).bool_not().float().sum_dim(dim)
+    } else if ord == 1.0 {
+        tensor.abs().sum_dim(dim)
+    } else if ord == 2.0 {
+        tensor.powf(2.0).sum_dim(dim).sqrt()
+    } else {
+        tensor
+            .abs()
+            .powf(ord as f32)
+            .sum_dim(dim)
+            .powf(1.0 / ord as f32)
+    }
+}
+
+/// Computes the norm of matrices of shape `[..., m, n]`.
+///
+/// # Returns
+///
+/// The norms of shape `[..., 1, 1]`.
+pub fn matrix_norm<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    ord: MatrixNorm,
+) -> Tensor<B, D> {
+    check!(TensorCheck::matrix::<D>("MatrixNorm"));
+
+    match ord {
+        MatrixNorm::Frobenius => tensor.powf(2.0).sum_dim(D - 1).sum_dim(D - 2).sqrt(),
+        MatrixNorm::Nuclear => svd(tensor).1.sum_dim(D - 1),
+        MatrixNorm::Spectral => svd(tensor).1.max_dim(D - 1),
+    }
+}
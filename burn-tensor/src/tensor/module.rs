@@ -1,7 +1,10 @@
 use crate::{
     backend::Backend,
-    ops::{ConvOptions, ConvTransposeOptions, UnfoldOptions},
-    Int, Tensor,
+    ops::{
+        AttentionOptions, ConvOptions, ConvTransposeOptions, InterpolateOptions, SegmentReduction,
+        UnfoldOptions,
+    },
+    Bool, Int, Tensor,
 };
 
 /// Applies the [embedding module](crate::ops::ModuleOps::embedding).
@@ -48,6 +51,24 @@ where
     ))
 }
 
+/// Applies a [3D convolution](crate::ops::ModuleOps::conv3d).
+pub fn conv3d<B>(
+    x: Tensor<B, 5>,
+    weight: Tensor<B, 5>,
+    bias: Option<Tensor<B, 1>>,
+    options: ConvOptions<3>,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::conv3d(
+        x.primitive,
+        weight.primitive,
+        bias.map(|b| b.primitive),
+        options,
+    ))
+}
+
 /// Applies a [1D transposed convolution](crate::ops::ModuleOps::conv_transpose1d).
 pub fn conv_transpose1d<B>(
     x: Tensor<B, 3>,
@@ -84,6 +105,24 @@ where
     ))
 }
 
+/// Applies a [3D transposed convolution](crate::ops::ModuleOps::conv_transpose3d).
+pub fn conv_transpose3d<B>(
+    x: Tensor<B, 5>,
+    weight: Tensor<B, 5>,
+    bias: Option<Tensor<B, 1>>,
+    options: ConvTransposeOptions<3>,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::conv_transpose3d(
+        x.primitive,
+        weight.primitive,
+        bias.map(|b| b.primitive),
+        options,
+    ))
+}
+
 /// Applies a [4D to 3D unfold](crate::ops::ModuleOps::unfold4d).
 pub fn unfold4d<B>(x: Tensor<B, 4>, kernel_size: [usize; 2], options: UnfoldOptions) -> Tensor<B, 3>
 where
@@ -219,3 +258,117 @@ where
 {
     Tensor::new(B::adaptive_avg_pool1d(x.primitive, output_size))
 }
+
+/// Applies a [3D max pooling](crate::ops::ModuleOps::max_pool3d).
+pub fn max_pool3d<B>(
+    x: Tensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::max_pool3d(
+        x.primitive,
+        kernel_size,
+        stride,
+        padding,
+        dilation,
+    ))
+}
+
+/// Applies a [3D max pooling with indices](crate::ops::ModuleOps::max_pool3d_with_indices).
+pub fn max_pool3d_with_indices<B>(
+    x: Tensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> (Tensor<B, 5>, Tensor<B, 5, Int>)
+where
+    B: Backend,
+{
+    let output = B::max_pool3d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+
+    (Tensor::new(output.output), Tensor::new(output.indices))
+}
+
+/// Applies a [3D avg pooling](crate::ops::ModuleOps::avg_pool3d).
+pub fn avg_pool3d<B>(
+    x: Tensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::avg_pool3d(
+        x.primitive,
+        kernel_size,
+        stride,
+        padding,
+        count_include_pad,
+    ))
+}
+
+/// Applies a [3D adaptive avg pooling](crate::ops::ModuleOps::adaptive_avg_pool3d).
+pub fn adaptive_avg_pool3d<B>(x: Tensor<B, 5>, output_size: [usize; 3]) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::adaptive_avg_pool3d(x.primitive, output_size))
+}
+
+/// Applies a [2D interpolation](crate::ops::ModuleOps::interpolate).
+pub fn interpolate<B>(
+    x: Tensor<B, 4>,
+    output_size: [usize; 2],
+    options: InterpolateOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(B::interpolate(x.primitive, output_size, options))
+}
+
+/// Applies the [scaled dot-product attention](crate::ops::ModuleOps::scaled_dot_product_attention).
+pub fn scaled_dot_product_attention<B>(
+    query: Tensor<B, 4>,
+    key: Tensor<B, 4>,
+    value: Tensor<B, 4>,
+    bias: Option<Tensor<B, 4>>,
+    mask: Option<Tensor<B, 4, Bool>>,
+    options: AttentionOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(B::scaled_dot_product_attention(
+        query.primitive,
+        key.primitive,
+        value.primitive,
+        bias.map(|bias| bias.primitive),
+        mask.map(|mask| mask.primitive),
+        options,
+    ))
+}
+
+/// Applies the [segment reduce](crate::ops::ModuleOps::segment_reduce) operation.
+pub fn segment_reduce<B>(
+    tensor: Tensor<B, 2>,
+    offsets: Tensor<B, 1, Int>,
+    reduction: SegmentReduction,
+) -> Tensor<B, 2>
+where
+    B: Backend,
+{
+    Tensor::new(B::segment_reduce(
+        tensor.primitive,
+        offsets.primitive,
+        reduction,
+    ))
+}
@@ -1,5 +1,5 @@
 use crate::{backend::Backend, ElementConversion};
-use core::f64::consts::SQRT_2;
+use core::f64::consts::{FRAC_2_SQRT_PI, SQRT_2};
 
 /// Activation function operations.
 ///
@@ -71,7 +71,44 @@ pub trait ActivationOps<B: Backend> {
         grad: B::TensorPrimitive<D>,
     ) -> B::TensorPrimitive<D> {
         // Derivative of the approximate gelu implementation based on tanh.
+        B::gelu_tanh_backward(x, grad)
+    }
+
+    /// Applies the Gelu activation function using the tanh approximation.
+    ///
+    /// `gelu_tanh(x) = 0.5 * x * (1 + tanh(sqrt(2 / π) * (x + 0.044715 * x^3)))`
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The output tensor.
+    fn gelu_tanh<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        let x3 = B::powf(tensor.clone(), 3.0);
+        let inner = B::add(tensor.clone(), B::mul_scalar(x3, 0.044715.elem()));
+        let inner = B::mul_scalar(inner, (FRAC_2_SQRT_PI / SQRT_2).elem());
+        let x = B::add_scalar(B::tanh(inner), 1i32.elem());
+        let x = B::mul(tensor, x);
+
+        B::mul_scalar(x, 0.5.elem())
+    }
 
+    /// Applies the Gelu activation function backward using the tanh approximation.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The tensor.
+    /// * `grad` - The gradient.
+    ///
+    /// # Returns
+    ///
+    /// The output tensor.
+    fn gelu_tanh_backward<const D: usize>(
+        x: B::TensorPrimitive<D>,
+        grad: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D> {
         let constant_1 = 0.0356774;
         let constant_2 = 0.797885;
         let constant_3 = 0.0535161;
@@ -100,4 +137,140 @@ pub trait ActivationOps<B: Backend> {
 
         B::mul(y, grad)
     }
+
+    /// Applies the LeakyReLU activation function.
+    ///
+    /// `leaky_relu(x) = max(0, x) + negative_slope * min(0, x)`
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `negative_slope` - The slope applied to negative values.
+    ///
+    /// # Returns
+    ///
+    /// The output tensor.
+    fn leaky_relu<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        negative_slope: B::FloatElem,
+    ) -> B::TensorPrimitive<D> {
+        let mask = B::lower_elem(tensor.clone(), 0.elem());
+        let negative = B::mul_scalar(tensor.clone(), negative_slope);
+
+        B::mask_where(tensor, mask, negative)
+    }
+
+    /// Applies the LeakyReLU activation function backward.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The input tensor.
+    /// * `grad` - The gradient.
+    /// * `negative_slope` - The slope applied to negative values.
+    ///
+    /// # Returns
+    ///
+    /// The gradient.
+    fn leaky_relu_backward<const D: usize>(
+        x: B::TensorPrimitive<D>,
+        grad: B::TensorPrimitive<D>,
+        negative_slope: B::FloatElem,
+    ) -> B::TensorPrimitive<D> {
+        let mask = B::lower_elem(x, 0.elem());
+        let negative = B::mul_scalar(grad.clone(), negative_slope);
+
+        B::mask_where(grad, mask, negative)
+    }
+
+    /// Applies the ELU activation function.
+    ///
+    /// `elu(x) = x if x > 0, alpha * (exp(x) - 1) otherwise`
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `alpha` - The saturation value for negative inputs.
+    ///
+    /// # Returns
+    ///
+    /// The output tensor.
+    fn elu<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        alpha: B::FloatElem,
+    ) -> B::TensorPrimitive<D> {
+        let mask = B::lower_equal_elem(tensor.clone(), 0.elem());
+        let negative = B::sub_scalar(B::exp(tensor.clone()), 1i32.elem());
+        let negative = B::mul_scalar(negative, alpha);
+
+        B::mask_where(tensor, mask, negative)
+    }
+
+    /// Applies the ELU activation function backward.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The input tensor.
+    /// * `grad` - The gradient.
+    /// * `alpha` - The saturation value for negative inputs.
+    ///
+    /// # Returns
+    ///
+    /// The gradient.
+    fn elu_backward<const D: usize>(
+        x: B::TensorPrimitive<D>,
+        grad: B::TensorPrimitive<D>,
+        alpha: B::FloatElem,
+    ) -> B::TensorPrimitive<D> {
+        let mask = B::lower_equal_elem(x.clone(), 0.elem());
+        let negative = B::mul(grad.clone(), B::mul_scalar(B::exp(x), alpha));
+
+        B::mask_where(grad, mask, negative)
+    }
+
+    /// Applies the Softplus activation function.
+    ///
+    /// `softplus(x) = log(1 + exp(beta * x)) / beta`
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `beta` - The sharpness of the transition.
+    ///
+    /// # Returns
+    ///
+    /// The output tensor.
+    fn softplus<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        beta: B::FloatElem,
+    ) -> B::TensorPrimitive<D> {
+        // Computed as `max(0, x) + log(1 + exp(-|x|))` to avoid overflows for large inputs.
+        let x = B::mul_scalar(tensor, beta);
+        let positive = B::relu(x.clone());
+        let x = B::log1p(B::exp(B::neg(B::abs(x))));
+
+        B::div_scalar(B::add(positive, x), beta)
+    }
+
+    /// Applies the Softplus activation function backward.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The input tensor.
+    /// * `grad` - The gradient.
+    /// * `beta` - The sharpness of the transition.
+    ///
+    /// # Returns
+    ///
+    /// The gradient.
+    fn softplus_backward<const D: usize>(
+        x: B::TensorPrimitive<D>,
+        grad: B::TensorPrimitive<D>,
+        beta: B::FloatElem,
+    ) -> B::TensorPrimitive<D> {
+        // The derivative is the sigmoid of `beta * x`.
+        let x = B::exp(B::neg(B::mul_scalar(x, beta)));
+        let x = B::add_scalar(x, 1i32.elem());
+
+        B::div(grad, x)
+    }
 }
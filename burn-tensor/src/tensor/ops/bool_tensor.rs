@@ -1,4 +1,4 @@
-use crate::{backend::Backend, tensor::Shape, Data};
+use crate::{backend::Backend, tensor::Shape, Data, ElementConversion};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
 use core::ops::Range;
@@ -269,4 +269,106 @@ pub trait BoolTensorOps<B: Backend> {
         dim1: usize,
         dim2: usize,
     ) -> B::BoolTensorPrimitive<D>;
+
+    /// Permutes the dimensions of a bool tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to permute the dimensions of.
+    /// * `axes` - The new order of the dimensions, where the dimension `i` of the output is the
+    ///   dimension `axes[i]` of the input.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the dimensions permuted.
+    fn bool_permute<const D: usize>(
+        tensor: B::BoolTensorPrimitive<D>,
+        axes: [usize; D],
+    ) -> B::BoolTensorPrimitive<D>;
+
+    /// Expands a bool tensor to the given shape.
+    ///
+    /// Backends supporting zero strides return a view sharing the data of the tensor, while the
+    /// others, such as ndarray, copy the data to the expanded shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to expand.
+    /// * `shape` - The shape to expand to. The dimensions of the tensor are aligned with the last
+    ///   dimensions of the shape, and those of size 1 are repeated to match it.
+    ///
+    /// # Returns
+    ///
+    /// The expanded tensor.
+    fn bool_expand<const D1: usize, const D2: usize>(
+        tensor: B::BoolTensorPrimitive<D1>,
+        shape: Shape<D2>,
+    ) -> B::BoolTensorPrimitive<D2>;
+
+    /// Tests if any element in the boolean tensor evaluates to true.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to test.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with a single element, true if any element in the tensor is true.
+    fn bool_any<const D: usize>(tensor: B::BoolTensorPrimitive<D>) -> B::BoolTensorPrimitive<1> {
+        let shape = B::bool_shape(&tensor);
+        let tensor = B::bool_reshape(tensor, Shape::new([shape.num_elements()]));
+
+        B::bool_any_dim(tensor, 0)
+    }
+
+    /// Tests if any element in the boolean tensor evaluates to true along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to test.
+    /// * `dim` - The axis along which to test.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with the size of `dim` set to 1, where each element is true if any
+    /// element along `dim` is true.
+    fn bool_any_dim<const D: usize>(
+        tensor: B::BoolTensorPrimitive<D>,
+        dim: usize,
+    ) -> B::BoolTensorPrimitive<D> {
+        let sum = B::int_sum_dim(B::bool_into_int(tensor), dim);
+
+        B::int_greater_elem(sum, 0.elem())
+    }
+
+    /// Tests if all elements in the boolean tensor evaluate to true.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to test.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with a single element, true if all elements in the tensor are true.
+    fn bool_all<const D: usize>(tensor: B::BoolTensorPrimitive<D>) -> B::BoolTensorPrimitive<1> {
+        B::bool_not(B::bool_any(B::bool_not(tensor)))
+    }
+
+    /// Tests if all elements in the boolean tensor evaluate to true along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to test.
+    /// * `dim` - The axis along which to test.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with the size of `dim` set to 1, where each element is true if all
+    /// elements along `dim` are true.
+    fn bool_all_dim<const D: usize>(
+        tensor: B::BoolTensorPrimitive<D>,
+        dim: usize,
+    ) -> B::BoolTensorPrimitive<D> {
+        B::bool_not(B::bool_any_dim(B::bool_not(tensor), dim))
+    }
 }
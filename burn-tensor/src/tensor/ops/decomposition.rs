@@ -0,0 +1,397 @@
+use crate::{backend::Backend, Bool, ElementConversion, Int, Tensor};
+use alloc::vec;
+use core::ops::Range;
+
+/// Maximum number of sweeps of the Jacobi algorithms, a sweep applies one rotation for every pair
+/// of columns and the algorithms converge quadratically.
+const JACOBI_MAX_SWEEPS: usize = 30;
+
+/// The Jacobi algorithms stop once the off-diagonal elements are below this tolerance, relative to
+/// the norm of the matrices for eigh and to the norms of the columns for svd.
+const JACOBI_TOLERANCE: f64 = 1e-6;
+
+/// Cholesky decomposition using the Cholesky–Banachiewicz algorithm, computing the factor one
+/// column at a time.
+///
+/// This is used by the default implementation of the cholesky operation.
+pub(crate) fn cholesky<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let dims = tensor.dims();
+    let n = dims[D - 1];
+    let mut factor = Tensor::zeros_device(dims, &tensor.device());
+
+    for j in 0..n {
+        let mut column = tensor.clone().slice(ranges(&dims, j..n, j..j + 1));
+
+        if j > 0 {
+            let left = factor.clone().slice(ranges(&dims, j..n, 0..j));
+            let row = factor.clone().slice(ranges(&dims, j..j + 1, 0..j));
+            column = column - left.matmul(row.transpose());
+        }
+
+        let diagonal = column.clone().slice(ranges(&dims, 0..1, 0..1)).sqrt();
+        factor = factor.slice_assign(ranges(&dims, j..n, j..j + 1), column.div(diagonal));
+    }
+
+    factor
+}
+
+/// QR decomposition using Householder reflections.
+///
+/// This is used by the default implementation of the qr operation.
+pub(crate) fn qr<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    let dims = tensor.dims();
+    let [m, n] = [dims[D - 2], dims[D - 1]];
+    let k = usize::min(m, n);
+    let mut q = eye(dims, m, &tensor.device());
+    let mut r = tensor;
+
+    for j in 0..k {
+        let x = r.clone().slice(ranges(&dims, j..m, j..j + 1));
+        let x_first = x.clone().slice(ranges(&dims, 0..1, 0..1));
+        let alpha = sign_non_zero(x_first.clone()).mul(norm_columns(x.clone()));
+
+        // Reflects x onto a multiple of the first basis vector, whose sign is chosen to avoid
+        // cancellation.
+        let v = x.slice_assign(ranges(&dims, 0..1, 0..1), x_first.add(alpha));
+        let v = normalize_columns(v);
+
+        let block = r.clone().slice(ranges(&dims, j..m, 0..n));
+        let projection = v
+            .clone()
+            .matmul(v.clone().transpose().matmul(block.clone()));
+        r = r.slice_assign(ranges(&dims, j..m, 0..n), block - projection.mul_scalar(2));
+
+        let block = q.clone().slice(ranges(&dims, 0..m, j..m));
+        let projection = block.clone().matmul(v.clone()).matmul(v.transpose());
+        q = q.slice_assign(ranges(&dims, 0..m, j..m), block - projection.mul_scalar(2));
+    }
+
+    let q = q.slice(ranges(&dims, 0..m, 0..k));
+    let r = r.slice(ranges(&dims, 0..k, 0..n)).triu(0);
+
+    // The decomposition is unique when the diagonal of R is non-negative.
+    let signs = sign_non_zero(diagonal(r.clone().slice(ranges(&dims, 0..k, 0..k))));
+
+    (q.mul(signs.clone()), r.mul(signs.transpose()))
+}
+
+/// Eigenvalue decomposition of symmetric matrices using the cyclic Jacobi eigenvalue algorithm.
+///
+/// This is used by the default implementation of the eigh operation.
+pub(crate) fn eigh<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let dims = tensor.dims();
+    let n = dims[D - 1];
+    let mut eigenvectors = eye(dims, n, &tensor.device());
+    let diagonal_mask = Tensor::<B, D, Bool>::diag_mask(dims, 0, &tensor.device()).bool_not();
+    // The rotations preserve the Frobenius norm, so it's only computed once.
+    let norm = tensor
+        .clone()
+        .powf(2.0)
+        .sum_dim(D - 1)
+        .sum_dim(D - 2)
+        .sqrt();
+    let norm = norm.clone().mask_fill(norm.equal_elem(0), 1);
+    let mut tensor = tensor;
+
+    for _ in 0..JACOBI_MAX_SWEEPS {
+        let off_diagonal = tensor.clone().mask_fill(diagonal_mask.clone(), 0).abs();
+        if max_below_tolerance(off_diagonal.div(norm.clone())) {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let (cos, sin) = jacobi_rotation(
+                    element(&tensor, p, p),
+                    element(&tensor, q, q),
+                    element(&tensor, p, q),
+                );
+
+                tensor = rotate_columns(tensor, p, q, cos.clone(), sin.clone());
+                tensor =
+                    rotate_columns(tensor.transpose(), p, q, cos.clone(), sin.clone()).transpose();
+                eigenvectors = rotate_columns(eigenvectors, p, q, cos, sin);
+            }
+        }
+    }
+
+    let (eigenvalues, indices) = diagonal(tensor).sort_with_indices(D - 1);
+    let eigenvectors = eigenvectors.gather(D - 1, indices.repeat(D - 2, n));
+
+    (eigenvalues, eigenvectors)
+}
+
+/// Singular value decomposition using the one-sided Jacobi algorithm, which orthogonalizes the
+/// columns of the matrices.
+///
+/// This is used by the default implementation of the svd operation.
+pub(crate) fn svd<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>, Tensor<B, D>) {
+    let dims = tensor.dims();
+    let [m, n] = [dims[D - 2], dims[D - 1]];
+
+    if m < n {
+        let (u, s, vh) = svd(tensor.transpose());
+        return (vh.transpose(), s, u.transpose());
+    }
+
+    let mut v = eye(dims, n, &tensor.device());
+    let diagonal_mask =
+        Tensor::<B, D, Bool>::diag_mask(eye_dims(dims, n), 0, &tensor.device()).bool_not();
+    let mut u = tensor;
+
+    for _ in 0..JACOBI_MAX_SWEEPS {
+        // The cosines of the angles between every pair of columns.
+        let norms = norm_columns(u.clone());
+        let norms = norms.clone().mask_fill(norms.clone().equal_elem(0), 1);
+        let cosines = u
+            .clone()
+            .transpose()
+            .matmul(u.clone())
+            .div(norms.clone().transpose().matmul(norms))
+            .mask_fill(diagonal_mask.clone(), 0)
+            .abs();
+        if max_below_tolerance(cosines) {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let column_p = u.clone().slice(ranges(&dims, 0..m, p..p + 1));
+                let column_q = u.clone().slice(ranges(&dims, 0..m, q..q + 1));
+
+                let (cos, sin) = jacobi_rotation(
+                    column_p.clone().powf(2.0).sum_dim(D - 2),
+                    column_q.clone().powf(2.0).sum_dim(D - 2),
+                    column_p.mul(column_q).sum_dim(D - 2),
+                );
+
+                u = rotate_columns(u, p, q, cos.clone(), sin.clone());
+                v = rotate_columns(v, p, q, cos, sin);
+            }
+        }
+    }
+
+    let (s, indices) = norm_columns(u.clone()).sort_descending_with_indices(D - 1);
+    let u = normalize_columns(u).gather(D - 1, indices.clone().repeat(D - 2, m));
+    let v = v.gather(D - 1, indices.repeat(D - 2, n));
+
+    (u, s, v.transpose())
+}
+
+/// Solves the systems of linear equations using Gauss-Jordan elimination.
+///
+/// This is used by the default implementation of the solve operation.
+pub(crate) fn solve<B: Backend, const D: usize>(
+    lhs: Tensor<B, D>,
+    rhs: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let n = lhs.dims()[D - 1];
+    let width = n + rhs.dims()[D - 1];
+    let (augmented, _, _) = gauss_jordan(Tensor::cat(vec![lhs, rhs], D - 1), n);
+    let dims = augmented.dims();
+
+    augmented.slice(ranges(&dims, 0..n, n..width))
+}
+
+/// Computes the sign and the logarithm of the absolute value of the determinant using
+/// Gauss-Jordan elimination.
+///
+/// This is used by the default implementation of the slogdet operation.
+pub(crate) fn slogdet<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let n = tensor.dims()[D - 1];
+    let (_, sign, logabsdet) = gauss_jordan(tensor, n);
+
+    (sign, logabsdet)
+}
+
+/// Reduces the first `n` columns of the augmented matrices to the identity with partial pivoting.
+///
+/// Returns the reduced matrices along with the sign and the logarithm of the absolute value of the
+/// determinant of their first `n` columns.
+fn gauss_jordan<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    n: usize,
+) -> (Tensor<B, D>, Tensor<B, D>, Tensor<B, D>) {
+    let dims = tensor.dims();
+    let width = dims[D - 1];
+    let device = tensor.device();
+
+    let mut scalar_dims = dims;
+    scalar_dims[D - 2] = 1;
+    scalar_dims[D - 1] = 1;
+    let mut sign = Tensor::ones_device(scalar_dims, &device);
+    let mut logabsdet = Tensor::zeros_device(scalar_dims, &device);
+
+    let mut column_dims = dims;
+    column_dims[D - 1] = 1;
+    let mut arange_dims = [1; D];
+    arange_dims[D - 2] = n;
+    let rows = Tensor::<B, D, Int>::zeros_device(column_dims, &device)
+        .add(Tensor::arange_device(0..n, &device).reshape(arange_dims));
+
+    let mut tensor = tensor;
+
+    for k in 0..n {
+        // Swaps the current row with the one having the largest pivot below it.
+        let pivot_row = tensor
+            .clone()
+            .slice(ranges(&dims, k..n, k..k + 1))
+            .abs()
+            .argmax(D - 2)
+            .add_scalar(k as i64);
+        let pivot_rows = pivot_row.clone().repeat(D - 2, n);
+        let permutation = rows
+            .clone()
+            .mask_where(rows.clone().equal_elem(k as i64), pivot_rows.clone())
+            .mask_fill(rows.clone().equal(pivot_rows), k as i64);
+        tensor = tensor.gather(D - 2, permutation.repeat(D - 1, width));
+
+        let permutation_sign = pivot_row
+            .equal_elem(k as i64)
+            .float()
+            .mul_scalar(2)
+            .sub_scalar(1);
+        let pivot = tensor.clone().slice(ranges(&dims, k..k + 1, k..k + 1));
+        sign = sign.mul(permutation_sign).mul(pivot.clone().sign());
+        logabsdet = logabsdet.add(pivot.clone().abs().log());
+
+        let pivot = pivot.clone().mask_fill(pivot.equal_elem(0), 1);
+        let row = tensor
+            .clone()
+            .slice(ranges(&dims, k..k + 1, 0..width))
+            .div(pivot);
+        let column = tensor.clone().slice(ranges(&dims, 0..n, k..k + 1));
+
+        tensor = tensor
+            .sub(column.matmul(row.clone()))
+            .slice_assign(ranges(&dims, k..k + 1, 0..width), row);
+    }
+
+    (tensor, sign, logabsdet)
+}
+
+/// Computes the cosine and the sine of the Jacobi rotation annihilating the off-diagonal elements
+/// of the symmetric matrices `[[app, apq], [apq, aqq]]`.
+fn jacobi_rotation<B: Backend, const D: usize>(
+    app: Tensor<B, D>,
+    aqq: Tensor<B, D>,
+    apq: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let diagonal = apq.clone().equal_elem(0);
+    let apq = apq.mask_fill(diagonal.clone(), 1);
+
+    let tau = aqq.sub(app).div(apq.mul_scalar(2));
+    let tan = sign_non_zero(tau.clone())
+        .div(tau.clone().abs().add(tau.powf(2.0).add_scalar(1).sqrt()))
+        .mask_fill(diagonal, 0);
+    let cos = tan.clone().powf(2.0).add_scalar(1).powf(-0.5);
+    let sin = tan.mul(cos.clone());
+
+    (cos, sin)
+}
+
+/// Multiplies the matrices on the right by the rotations of the columns `p` and `q`.
+fn rotate_columns<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    p: usize,
+    q: usize,
+    cos: Tensor<B, D>,
+    sin: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let dims = tensor.dims();
+    let rows = dims[D - 2];
+    let column_p = tensor.clone().slice(ranges(&dims, 0..rows, p..p + 1));
+    let column_q = tensor.clone().slice(ranges(&dims, 0..rows, q..q + 1));
+
+    let rotated_p = column_p.clone().mul(cos.clone()) - column_q.clone().mul(sin.clone());
+    let rotated_q = column_p.mul(sin) + column_q.mul(cos);
+
+    tensor
+        .slice_assign(ranges(&dims, 0..rows, p..p + 1), rotated_p)
+        .slice_assign(ranges(&dims, 0..rows, q..q + 1), rotated_q)
+}
+
+/// Identity matrices of size `n` with the batch dimensions of `dims`.
+fn eye<B: Backend, const D: usize>(dims: [usize; D], n: usize, device: &B::Device) -> Tensor<B, D> {
+    Tensor::<B, D, Bool>::diag_mask(eye_dims(dims, n), 0, device)
+        .bool_not()
+        .float()
+}
+
+/// The dimensions of square matrices of size `n` with the batch dimensions of `dims`.
+fn eye_dims<const D: usize>(dims: [usize; D], n: usize) -> [usize; D] {
+    let mut dims = dims;
+    dims[D - 2] = n;
+    dims[D - 1] = n;
+    dims
+}
+
+/// Whether all the elements are below the tolerance of the Jacobi algorithms, which requires
+/// reading the tensor data.
+fn max_below_tolerance<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> bool {
+    let max = B::into_data(tensor.max().into_primitive())
+        .read_sync()
+        .expect("Jacobi algorithms require the tensor data to be readable synchronously.")
+        .value[0];
+
+    max.elem::<f64>() <= JACOBI_TOLERANCE
+}
+
+/// The diagonals of square matrices, of shape `[..., 1, n]`.
+fn diagonal<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let mask = Tensor::<B, D, Bool>::diag_mask(tensor.shape(), 0, &tensor.device());
+
+    tensor.mask_fill(mask, 0).sum_dim(D - 2)
+}
+
+/// The element at the given row and column of the matrices, of shape `[..., 1, 1]`.
+fn element<B: Backend, const D: usize>(
+    tensor: &Tensor<B, D>,
+    row: usize,
+    col: usize,
+) -> Tensor<B, D> {
+    let dims = tensor.dims();
+
+    tensor
+        .clone()
+        .slice(ranges(&dims, row..row + 1, col..col + 1))
+}
+
+/// The Euclidean norms of the columns of the matrices, of shape `[..., 1, n]`.
+fn norm_columns<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor.powf(2.0).sum_dim(D - 2).sqrt()
+}
+
+/// Scales the columns of the matrices to unit norm, the zero columns are left unchanged.
+fn normalize_columns<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let norm = norm_columns(tensor.clone());
+    let norm = norm.clone().mask_fill(norm.equal_elem(0), 1);
+
+    tensor.div(norm)
+}
+
+/// The sign of the elements, where zero is considered positive.
+fn sign_non_zero<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor
+        .greater_equal_elem(0)
+        .float()
+        .mul_scalar(2)
+        .sub_scalar(1)
+}
+
+fn ranges<const D: usize>(
+    dims: &[usize; D],
+    rows: Range<usize>,
+    cols: Range<usize>,
+) -> [Range<usize>; D] {
+    let mut ranges = dims.map(|size| 0..size);
+    ranges[D - 2] = rows;
+    ranges[D - 1] = cols;
+    ranges
+}
@@ -1,4 +1,5 @@
-use crate::{backend::Backend, tensor::Shape, Data, ElementConversion};
+use super::{padding, scan::scan, sort::sort_data, PadMode};
+use crate::{backend::Backend, tensor::Shape, Data, ElementConversion, Int, Tensor};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
 use core::ops::Range;
@@ -632,6 +633,58 @@ pub trait IntTensorOps<B: Backend> {
         rhs: B::IntElem,
     ) -> B::IntTensorPrimitive<D>;
 
+    /// Elementwise remainder of the division.
+    ///
+    /// The result has the same sign as the divisor `rhs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The dividend.
+    /// * `rhs` - The divisor.
+    ///
+    /// # Returns
+    ///
+    /// The remainder of the division.
+    fn int_remainder<const D: usize>(
+        lhs: B::IntTensorPrimitive<D>,
+        rhs: B::IntTensorPrimitive<D>,
+    ) -> B::IntTensorPrimitive<D> {
+        // The remainder is first computed with the sign of the dividend, then shifted by the
+        // divisor so that it takes the sign of the divisor.
+        let quotient = B::int_div(lhs.clone(), rhs.clone());
+        let remainder = B::int_sub(lhs, B::int_mul(quotient, rhs.clone()));
+        let remainder = B::int_add(remainder, rhs.clone());
+        let quotient = B::int_div(remainder.clone(), rhs.clone());
+
+        B::int_sub(remainder, B::int_mul(quotient, rhs))
+    }
+
+    /// Elementwise remainder of the division with a scalar.
+    ///
+    /// The result has the same sign as the divisor `rhs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The dividend.
+    /// * `rhs` - The divisor scalar.
+    ///
+    /// # Returns
+    ///
+    /// The remainder of the division.
+    fn int_remainder_scalar<const D: usize>(
+        lhs: B::IntTensorPrimitive<D>,
+        rhs: B::IntElem,
+    ) -> B::IntTensorPrimitive<D> {
+        // The remainder is first computed with the sign of the dividend, then shifted by the
+        // divisor so that it takes the sign of the divisor.
+        let quotient = B::int_div_scalar(lhs.clone(), rhs);
+        let remainder = B::int_sub(lhs, B::int_mul_scalar(quotient, rhs));
+        let remainder = B::int_add_scalar(remainder, rhs);
+        let quotient = B::int_div_scalar(remainder.clone(), rhs);
+
+        B::int_sub(remainder, B::int_mul_scalar(quotient, rhs))
+    }
+
     /// Elementwise negation.
     ///
     /// # Arguments
@@ -714,6 +767,42 @@ pub trait IntTensorOps<B: Backend> {
         dim: usize,
     ) -> B::IntTensorPrimitive<D>;
 
+    /// Computes the product of all elements in the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the product of.
+    ///
+    /// # Returns
+    ///
+    /// The product of all elements in the tensor.
+    fn int_prod<const D: usize>(tensor: B::IntTensorPrimitive<D>) -> B::IntTensorPrimitive<1> {
+        let shape = B::int_shape(&tensor);
+        let tensor = B::int_reshape(tensor, Shape::new([shape.num_elements()]));
+
+        B::int_prod_dim(tensor, 0)
+    }
+
+    /// Computes the product of all elements in the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the product of.
+    /// * `dim` - The dimension to compute the product along.
+    ///
+    /// # Returns
+    ///
+    /// The product of all elements in the tensor along the dimension.
+    fn int_prod_dim<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+    ) -> B::IntTensorPrimitive<D> {
+        let mut ranges = B::int_shape(&tensor).dims.map(|d| 0..d);
+        ranges[dim] = ranges[dim].end - 1..ranges[dim].end;
+
+        B::int_slice(B::int_cumprod(tensor, dim), ranges)
+    }
+
     /// Computes the mean of all elements in the tensor.
     ///
     /// # Arguments
@@ -922,4 +1011,336 @@ pub trait IntTensorOps<B: Backend> {
         dim1: usize,
         dim2: usize,
     ) -> B::IntTensorPrimitive<D>;
+
+    /// Permutes the dimensions of an int tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to permute the dimensions of.
+    /// * `axes` - The new order of the dimensions, where the dimension `i` of the output is the
+    ///   dimension `axes[i]` of the input.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the dimensions permuted.
+    fn int_permute<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        axes: [usize; D],
+    ) -> B::IntTensorPrimitive<D>;
+
+    /// Expands an int tensor to the given shape.
+    ///
+    /// Backends supporting zero strides return a view sharing the data of the tensor, while the
+    /// others, such as ndarray, copy the data to the expanded shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to expand.
+    /// * `shape` - The shape to expand to. The dimensions of the tensor are aligned with the last
+    ///   dimensions of the shape, and those of size 1 are repeated to match it.
+    ///
+    /// # Returns
+    ///
+    /// The expanded tensor.
+    fn int_expand<const D1: usize, const D2: usize>(
+        tensor: B::IntTensorPrimitive<D1>,
+        shape: Shape<D2>,
+    ) -> B::IntTensorPrimitive<D2>;
+
+    /// Reverses the order of the elements along the given axes.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to flip.
+    /// * `axes` - The axes to flip.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the elements reversed along the given axes.
+    fn int_flip<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        axes: &[usize],
+    ) -> B::IntTensorPrimitive<D> {
+        padding::flip::<B, Int, D>(Tensor::from_primitive(tensor), axes).into_primitive()
+    }
+
+    /// Shifts the elements along the given dimensions, the elements shifted past the end of a
+    /// dimension are wrapped around to its start.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to roll.
+    /// * `shifts` - The number of places by which the elements are shifted for each dimension,
+    ///   negative values shift the elements toward the start.
+    /// * `dims` - The dimensions along which the elements are shifted.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the elements shifted.
+    fn int_roll<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> B::IntTensorPrimitive<D> {
+        padding::roll::<B, Int, D>(Tensor::from_primitive(tensor), shifts, dims).into_primitive()
+    }
+
+    /// Pads the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to pad.
+    /// * `pads` - The number of elements added before and after each dimension.
+    /// * `mode` - How the padded elements are filled.
+    ///
+    /// # Returns
+    ///
+    /// The padded tensor.
+    fn int_pad<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> B::IntTensorPrimitive<D> {
+        padding::pad::<B, Int, D>(Tensor::from_primitive(tensor), pads, mode).into_primitive()
+    }
+
+    /// Sorts the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - If the elements should be sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with its elements sorted along `dim`.
+    fn int_sort<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_sort_with_indices(tensor, dim, descending).0
+    }
+
+    /// Sorts the elements of a tensor along an axis and returns their original indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - If the elements should be sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the sorted elements of `tensor` along `dim` and their indices in `tensor`.
+    ///
+    /// # Notes
+    ///
+    /// The sort is stable. The default implementation reads the tensor data and sorts it on the
+    /// host, backends should override it when a native implementation is available.
+    fn int_sort_with_indices<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        let device = B::int_device(&tensor);
+        let data = B::int_into_data(tensor)
+            .read_sync()
+            .expect("Sorting requires the tensor data to be readable synchronously.");
+        let (values, indices) = sort_data(data, dim, descending);
+
+        (
+            B::int_from_data(values, &device),
+            B::int_from_data(indices.convert(), &device),
+        )
+    }
+
+    /// Gets the indices that would sort the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - If the elements should be sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the indices that would sort `tensor` along `dim`.
+    fn int_argsort<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_sort_with_indices(tensor, dim, descending).1
+    }
+
+    /// Gets the `k` largest elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the largest elements of.
+    /// * `dim` - The dimension along which to get the largest elements.
+    /// * `k` - The number of elements to keep.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the `k` largest elements of `tensor` along `dim`, sorted in descending order.
+    fn int_topk<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        k: usize,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_topk_with_indices(tensor, dim, k).0
+    }
+
+    /// Gets the `k` largest elements of a tensor along an axis and their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the largest elements of.
+    /// * `dim` - The dimension along which to get the largest elements.
+    /// * `k` - The number of elements to keep.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the `k` largest elements of `tensor` along `dim`, sorted in descending order,
+    /// and their indices in `tensor`.
+    fn int_topk_with_indices<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        k: usize,
+    ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        let mut ranges = B::int_shape(&tensor).dims.map(|d| 0..d);
+        ranges[dim] = 0..k;
+
+        let (values, indices) = B::int_sort_with_indices(tensor, dim, true);
+
+        (
+            B::int_slice(values, ranges.clone()),
+            B::int_slice(indices, ranges),
+        )
+    }
+
+    /// Computes the cumulative sum of the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative sum of.
+    /// * `dim` - The dimension along which to compute the cumulative sum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` where each element is the sum of all the elements
+    /// up to the same position along `dim`.
+    fn int_cumsum<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+    ) -> B::IntTensorPrimitive<D> {
+        let shape = B::int_shape(&tensor);
+
+        scan(tensor, &shape, dim, B::int_slice, B::int_cat, B::int_add)
+    }
+
+    /// Computes the cumulative product of the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative product of.
+    /// * `dim` - The dimension along which to compute the cumulative product.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` where each element is the product of all the
+    /// elements up to the same position along `dim`.
+    fn int_cumprod<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+    ) -> B::IntTensorPrimitive<D> {
+        let shape = B::int_shape(&tensor);
+
+        scan(tensor, &shape, dim, B::int_slice, B::int_cat, B::int_mul)
+    }
+
+    /// Computes the cumulative maximum of the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative maximum of.
+    /// * `dim` - The dimension along which to compute the cumulative maximum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` where each element is the maximum of all the
+    /// elements up to the same position along `dim`.
+    fn int_cummax<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+    ) -> B::IntTensorPrimitive<D> {
+        let shape = B::int_shape(&tensor);
+
+        scan(
+            tensor,
+            &shape,
+            dim,
+            B::int_slice,
+            B::int_cat,
+            |current, previous| {
+                let mask = B::int_lower(current.clone(), previous.clone());
+                B::int_mask_where(current, mask, previous)
+            },
+        )
+    }
+
+    /// Computes the cumulative maximum of the elements of a tensor along an axis and the
+    /// indices of the maximum elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative maximum of.
+    /// * `dim` - The dimension along which to compute the cumulative maximum.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the cumulative maximum of `tensor` along `dim` and the indices of the maximum
+    /// elements along `dim`. When the maximum is reached multiple times, the last index is used.
+    fn int_cummax_with_indices<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+    ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        let shape = B::int_shape(&tensor);
+        let device = B::int_device(&tensor);
+
+        let mut shape_indices = [1; D];
+        shape_indices[dim] = shape.dims[dim];
+        let indices = B::int_reshape(
+            B::arange(0..shape.dims[dim], &device),
+            Shape::new(shape_indices),
+        );
+        let indices = B::int_add(B::int_zeros(shape.clone(), &device), indices);
+
+        scan(
+            (tensor, indices),
+            &shape,
+            dim,
+            |(values, indices), ranges| {
+                (
+                    B::int_slice(values, ranges.clone()),
+                    B::int_slice(indices, ranges),
+                )
+            },
+            |tensors, dim| {
+                let (values, indices) = tensors.into_iter().unzip();
+                (B::int_cat(values, dim), B::int_cat(indices, dim))
+            },
+            |(values, indices), (values_previous, indices_previous)| {
+                let mask = B::int_lower(values.clone(), values_previous.clone());
+
+                (
+                    B::int_mask_where(values, mask.clone(), values_previous),
+                    B::int_mask_where(indices, mask, indices_previous),
+                )
+            },
+        )
+    }
 }
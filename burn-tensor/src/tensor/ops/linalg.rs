@@ -0,0 +1,122 @@
+use super::decomposition;
+use crate::{backend::Backend, Tensor};
+
+/// Linear algebra operations.
+///
+/// The operations are applied on the matrices formed by the last two dimensions of the tensors,
+/// the other dimensions are batch dimensions.
+///
+/// This trait let backend implementations override the linear algebra operations for better
+/// performance. The default implementations are built on top of the other tensor operations.
+pub trait LinalgOps<B: Backend> {
+    /// Computes the Cholesky decomposition of symmetric positive-definite matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The lower triangular matrices `L` of shape `[..., n, n]` such that `A = L L^T`.
+    fn cholesky<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        decomposition::cholesky::<B, D>(Tensor::from_primitive(tensor)).into_primitive()
+    }
+
+    /// Computes the reduced QR decomposition of matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The matrices `Q` of shape `[..., m, k]` with orthonormal columns and the upper triangular
+    /// matrices `R` of shape `[..., k, n]` with a non-negative diagonal such that `A = Q R`, where
+    /// `k = min(m, n)`.
+    fn qr<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+    ) -> (B::TensorPrimitive<D>, B::TensorPrimitive<D>) {
+        let (q, r) = decomposition::qr::<B, D>(Tensor::from_primitive(tensor));
+
+        (q.into_primitive(), r.into_primitive())
+    }
+
+    /// Computes the eigenvalue decomposition of symmetric matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The symmetric matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The eigenvalues of shape `[..., 1, n]` in ascending order and the matrices of shape
+    /// `[..., n, n]` whose columns are the corresponding eigenvectors.
+    fn eigh<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+    ) -> (B::TensorPrimitive<D>, B::TensorPrimitive<D>) {
+        let (eigenvalues, eigenvectors) =
+            decomposition::eigh::<B, D>(Tensor::from_primitive(tensor));
+
+        (eigenvalues.into_primitive(), eigenvectors.into_primitive())
+    }
+
+    /// Computes the reduced singular value decomposition of matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The matrices `U` of shape `[..., m, k]`, the singular values `S` of shape `[..., 1, k]` in
+    /// descending order and the matrices `Vh` of shape `[..., k, n]` such that `A = U diag(S) Vh`,
+    /// where `k = min(m, n)`.
+    fn svd<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+    ) -> (
+        B::TensorPrimitive<D>,
+        B::TensorPrimitive<D>,
+        B::TensorPrimitive<D>,
+    ) {
+        let (u, s, vh) = decomposition::svd::<B, D>(Tensor::from_primitive(tensor));
+
+        (u.into_primitive(), s.into_primitive(), vh.into_primitive())
+    }
+
+    /// Solves the systems of linear equations `A X = B`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The invertible matrices `A` of shape `[..., n, n]`.
+    /// * `rhs` - The matrices `B` of shape `[..., n, k]`.
+    ///
+    /// # Returns
+    ///
+    /// The solutions `X` of shape `[..., n, k]`.
+    fn solve<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D> {
+        decomposition::solve::<B, D>(Tensor::from_primitive(lhs), Tensor::from_primitive(rhs))
+            .into_primitive()
+    }
+
+    /// Computes the sign and the natural logarithm of the absolute value of the determinant of
+    /// square matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The signs and the logarithms of the absolute values of the determinants, both of shape
+    /// `[..., 1, 1]`. The sign is zero and the logarithm is negative infinity for singular matrices.
+    fn slogdet<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+    ) -> (B::TensorPrimitive<D>, B::TensorPrimitive<D>) {
+        let (sign, logabsdet) = decomposition::slogdet::<B, D>(Tensor::from_primitive(tensor));
+
+        (sign.into_primitive(), logabsdet.into_primitive())
+    }
+}
@@ -1,11 +1,18 @@
 mod activation;
 mod bool_tensor;
+mod decomposition;
 mod int_tensor;
+mod linalg;
 mod modules;
+mod padding;
+mod scan;
+mod sort;
 mod tensor;
 
 pub use activation::*;
 pub use bool_tensor::*;
 pub use int_tensor::*;
+pub use linalg::*;
 pub use modules::*;
+pub use padding::PadMode;
 pub use tensor::*;
@@ -0,0 +1,134 @@
+use super::{AttentionBackward, AttentionOptions};
+use crate::{activation, backend::Backend, Bool, Data, Shape, Tensor};
+use alloc::vec::Vec;
+
+/// Computes the scaled dot-product attention by materializing the attention weights.
+pub(crate) fn scaled_dot_product_attention_naive<B: Backend>(
+    query: B::TensorPrimitive<4>,
+    key: B::TensorPrimitive<4>,
+    value: B::TensorPrimitive<4>,
+    bias: Option<B::TensorPrimitive<4>>,
+    mask: Option<B::BoolTensorPrimitive<4>>,
+    options: AttentionOptions,
+) -> B::TensorPrimitive<4> {
+    let weights = attention_weights::<B>(
+        Tensor::from_primitive(query),
+        Tensor::from_primitive(key),
+        bias.map(Tensor::from_primitive),
+        mask.map(Tensor::from_primitive),
+        &options,
+    );
+
+    weights
+        .matmul(Tensor::from_primitive(value))
+        .into_primitive()
+}
+
+/// Computes the gradients of the scaled dot-product attention by materializing the attention
+/// weights.
+pub(crate) fn scaled_dot_product_attention_backward_naive<B: Backend>(
+    query: B::TensorPrimitive<4>,
+    key: B::TensorPrimitive<4>,
+    value: B::TensorPrimitive<4>,
+    bias: Option<B::TensorPrimitive<4>>,
+    mask: Option<B::BoolTensorPrimitive<4>>,
+    output_grad: B::TensorPrimitive<4>,
+    options: AttentionOptions,
+) -> AttentionBackward<B> {
+    let query = Tensor::<B, 4>::from_primitive(query);
+    let key = Tensor::<B, 4>::from_primitive(key);
+    let value = Tensor::<B, 4>::from_primitive(value);
+    let output_grad = Tensor::<B, 4>::from_primitive(output_grad);
+    let mask = mask.map(Tensor::<B, 4, Bool>::from_primitive);
+
+    let weights = attention_weights::<B>(
+        query.clone(),
+        key.clone(),
+        bias.map(Tensor::from_primitive),
+        mask.clone(),
+        &options,
+    );
+
+    let value_grad = weights.clone().transpose().matmul(output_grad.clone());
+    let weights_grad = output_grad.matmul(value.transpose());
+
+    // Backward of the softmax: dS = P * (dP - sum(dP * P)).
+    let sum = weights_grad.clone().mul(weights.clone()).sum_dim(3);
+    let scores_grad = weights.mul(weights_grad.sub(sum));
+    let scores_grad = match mask {
+        Some(mask) => scores_grad.mask_fill(mask, 0.0),
+        None => scores_grad,
+    };
+    let scores_grad = match dropout_factors(&scores_grad, &options) {
+        Some(factors) => scores_grad.mul(factors),
+        None => scores_grad,
+    };
+    let scores_grad = scores_grad.mul_scalar(options.scale);
+
+    let query_grad = scores_grad.clone().matmul(key);
+    let key_grad = scores_grad.transpose().matmul(query);
+
+    AttentionBackward::new(
+        query_grad.into_primitive(),
+        key_grad.into_primitive(),
+        value_grad.into_primitive(),
+    )
+}
+
+fn attention_weights<B: Backend>(
+    query: Tensor<B, 4>,
+    key: Tensor<B, 4>,
+    bias: Option<Tensor<B, 4>>,
+    mask: Option<Tensor<B, 4, Bool>>,
+    options: &AttentionOptions,
+) -> Tensor<B, 4> {
+    let mut scores = query.matmul(key.transpose()).mul_scalar(options.scale);
+
+    if let Some(bias) = bias {
+        scores = scores.add(bias);
+    }
+
+    if let Some(factors) = dropout_factors(&scores, options) {
+        scores = scores.mul(factors);
+    }
+
+    if let Some(mask) = mask {
+        scores = scores.mask_fill(mask, options.mask_value);
+    }
+
+    activation::softmax(scores, 3)
+}
+
+/// Materializes the dropout factors of the attention scores, if any.
+fn dropout_factors<B: Backend>(
+    scores: &Tensor<B, 4>,
+    options: &AttentionOptions,
+) -> Option<Tensor<B, 4>> {
+    if options.dropout == 0.0 {
+        return None;
+    }
+
+    let shape = scores.shape();
+    let factors = (0..shape.num_elements())
+        .map(|index| options.dropout_factor(index) as f32)
+        .collect::<Vec<_>>();
+    let data = Data::new(factors, Shape::new(shape.dims));
+
+    Some(Tensor::from_data_device(data.convert(), &scores.device()))
+}
+
+/// Hashes the index of an attention score with the seed of the dropout.
+///
+/// Only 32 bits integer operations are used, so that the same hash can be computed by shaders.
+pub(crate) fn dropout_hash(seed: u32, index: u32) -> u32 {
+    pcg_hash(index ^ pcg_hash(seed))
+}
+
+/// Permuted congruential generator hash, see
+/// [Hash Functions for GPU Rendering](https://jcgt.org/published/0009/03/02/).
+fn pcg_hash(input: u32) -> u32 {
+    let state = input.wrapping_mul(747796405).wrapping_add(2891336453);
+    let word = ((state >> ((state >> 28) + 4)) ^ state).wrapping_mul(277803737);
+
+    (word >> 22) ^ word
+}
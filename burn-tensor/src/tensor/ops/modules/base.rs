@@ -1,4 +1,4 @@
-use super::{conv, pool};
+use super::{attention, conv, interpolate, pool, segment};
 use crate::{backend::Backend, Shape};
 
 /// Gradient computed during the backward pass for each tensor used by [conv2d](ModuleOps::conv2d).
@@ -61,6 +61,50 @@ pub struct Conv1dBackward<B: Backend> {
     pub bias_grad: Option<B::TensorPrimitive<1>>,
 }
 
+/// Gradient computed during the backward pass for each tensor used by [conv3d](ModuleOps::conv3d).
+#[derive(new)]
+pub struct Conv3dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: B::TensorPrimitive<5>,
+
+    /// Weights gradient.
+    pub weights_grad: B::TensorPrimitive<5>,
+
+    /// Bias gradient.
+    pub bias_grad: Option<B::TensorPrimitive<1>>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by [max_pool3d](ModuleOps::max_pool3d).
+#[derive(new)]
+pub struct MaxPool3dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: B::TensorPrimitive<5>,
+}
+
+/// Results from [max_pool3d](ModuleOps::max_pool3d_with_indices).
+#[derive(new)]
+pub struct MaxPool3dWithIndices<B: Backend> {
+    /// The output tensor.
+    pub output: B::TensorPrimitive<5>,
+
+    /// The indices tensor.
+    pub indices: B::IntTensorPrimitive<5>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by
+/// [scaled_dot_product_attention](ModuleOps::scaled_dot_product_attention).
+#[derive(new)]
+pub struct AttentionBackward<B: Backend> {
+    /// Gradient of the queries.
+    pub query_grad: B::TensorPrimitive<4>,
+
+    /// Gradient of the keys.
+    pub key_grad: B::TensorPrimitive<4>,
+
+    /// Gradient of the values.
+    pub value_grad: B::TensorPrimitive<4>,
+}
+
 /// Convolution options.
 #[derive(new, Debug, Clone)]
 pub struct ConvOptions<const N: usize> {
@@ -112,6 +156,98 @@ pub struct UnfoldOptions {
     pub dilation: Option<[usize; 2]>,
 }
 
+/// Interpolation modes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpolateMode {
+    /// Takes the value of the nearest input element.
+    Nearest,
+
+    /// Linear interpolation between the two nearest input elements along each dimension.
+    Bilinear,
+
+    /// Cubic interpolation between the four nearest input elements along each dimension.
+    Bicubic,
+}
+
+/// Interpolation options.
+#[derive(new, Debug, Clone)]
+pub struct InterpolateOptions {
+    /// The interpolation mode.
+    pub mode: InterpolateMode,
+
+    /// Whether the centers of the corner elements of the input and the output are aligned, in
+    /// which case their values are preserved. Only used by the bilinear and bicubic modes.
+    pub align_corners: bool,
+}
+
+/// Scaled dot-product attention options.
+#[derive(new, Debug, Clone)]
+pub struct AttentionOptions {
+    /// The factor applied to the dot products of the queries and keys, usually `1 / sqrt(d_k)`.
+    pub scale: f64,
+
+    /// The value given to the masked attention scores before the softmax.
+    pub mask_value: f64,
+
+    /// The probability of zeroing each attention score before the mask and the softmax. Default: 0
+    #[new(default)]
+    pub dropout: f64,
+
+    /// The seed of the dropout, from which the dropped scores are derived, so that the same scores
+    /// are dropped by the forward and backward passes.
+    #[new(default)]
+    pub seed: u32,
+}
+
+impl AttentionOptions {
+    /// Drops each attention score with the given probability, using the given seed.
+    ///
+    /// The kept scores are scaled by `1 / (1 - dropout)`.
+    pub fn with_dropout(mut self, dropout: f64, seed: u32) -> Self {
+        self.dropout = dropout;
+        self.seed = seed;
+        self
+    }
+
+    /// The factor applied to the attention score at the given index of the scores of shape
+    /// `[batch_size, n_heads, seq_length_1, seq_length_2]`, which is either zero when the score is
+    /// dropped, or `1 / (1 - dropout)` otherwise.
+    ///
+    /// The dropped scores are derived from a hash of the seed and the index, which backends can
+    /// compute in their kernels with the [dropout threshold](AttentionOptions::dropout_threshold).
+    pub fn dropout_factor(&self, index: usize) -> f64 {
+        if self.dropout == 0.0 {
+            return 1.0;
+        }
+
+        let hash = attention::dropout_hash(self.seed, index as u32);
+
+        if hash >> 8 >= self.dropout_threshold() {
+            1.0 / (1.0 - self.dropout)
+        } else {
+            0.0
+        }
+    }
+
+    /// The threshold under which the 24 high bits of the dropout hash drop the score.
+    pub fn dropout_threshold(&self) -> u32 {
+        (self.dropout * (1 << 24) as f64) as u32
+    }
+}
+
+/// Reduction applied to each segment by [segment reduce](ModuleOps::segment_reduce).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentReduction {
+    /// Sum of the rows of the segment.
+    Sum,
+
+    /// Mean of the rows of the segment.
+    Mean,
+
+    /// Maximum of the rows of the segment, computed for each column.
+    Max,
+}
+
 /// Module operations trait.
 pub trait ModuleOps<B: Backend> {
     /// Embedding operation.
@@ -261,6 +397,57 @@ pub trait ModuleOps<B: Backend> {
         conv::conv_transpose2d_backward(x, weight, bias, output_grad, options)
     }
 
+    /// Three dimensional convolution.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels_in, depth, height, width]`,
+    /// weight: `[channels_out, channels_in, kernel_size_1, kernel_size_2, kernel_size_3]`,
+    /// bias:   `[channels_out]`,
+    fn conv3d(
+        x: B::TensorPrimitive<5>,
+        weight: B::TensorPrimitive<5>,
+        bias: Option<B::TensorPrimitive<1>>,
+        options: ConvOptions<3>,
+    ) -> B::TensorPrimitive<5> {
+        conv::conv3d_from_conv2d::<B>(x, weight, bias, options)
+    }
+    /// Backward pass for the [conv3d](ModuleOps::conv3d) operation.
+    fn conv3d_backward(
+        x: B::TensorPrimitive<5>,
+        weight: B::TensorPrimitive<5>,
+        bias: Option<B::TensorPrimitive<1>>,
+        output_grad: B::TensorPrimitive<5>,
+        options: ConvOptions<3>,
+    ) -> Conv3dBackward<B> {
+        conv::conv3d_backward(x, weight, bias, output_grad, options)
+    }
+    /// Three dimensional transposed convolution.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels_in, depth, height, width]`,
+    /// weight: `[channels_in, channels_out, kernel_size_1, kernel_size_2, kernel_size_3]`,
+    /// bias:   `[channels_out]`,
+    fn conv_transpose3d(
+        x: B::TensorPrimitive<5>,
+        weight: B::TensorPrimitive<5>,
+        bias: Option<B::TensorPrimitive<1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> B::TensorPrimitive<5> {
+        conv::conv_transpose3d_from_conv_transpose2d::<B>(x, weight, bias, options)
+    }
+    /// Backward pass for the [conv transpose 3d](ModuleOps::conv_transpose3d) operation.
+    fn conv_transpose3d_backward(
+        x: B::TensorPrimitive<5>,
+        weight: B::TensorPrimitive<5>,
+        bias: Option<B::TensorPrimitive<1>>,
+        output_grad: B::TensorPrimitive<5>,
+        options: ConvTransposeOptions<3>,
+    ) -> Conv3dBackward<B> {
+        conv::conv_transpose3d_backward(x, weight, bias, output_grad, options)
+    }
+
     /// Four-dimensional unfolding.
     ///
     /// # Shapes
@@ -440,4 +627,209 @@ pub trait ModuleOps<B: Backend> {
         output_grad: B::TensorPrimitive<4>,
         indices: B::IntTensorPrimitive<4>,
     ) -> MaxPool2dBackward<B>;
+
+    /// Three dimensional avg pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn avg_pool3d(
+        x: B::TensorPrimitive<5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> B::TensorPrimitive<5> {
+        pool::avg_pool3d_from_2d::<B>(x, kernel_size, stride, padding, count_include_pad)
+    }
+    /// Backward pass for the [avg pooling 3d](ModuleOps::avg_pool3d) operation.
+    fn avg_pool3d_backward(
+        x: B::TensorPrimitive<5>,
+        grad: B::TensorPrimitive<5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> B::TensorPrimitive<5> {
+        pool::avg_pool3d_backward_from_2d::<B>(
+            x,
+            grad,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+        )
+    }
+    /// Three dimensional adaptive avg pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn adaptive_avg_pool3d(
+        x: B::TensorPrimitive<5>,
+        output_size: [usize; 3],
+    ) -> B::TensorPrimitive<5> {
+        pool::adaptive_avg_pool3d_from_2d::<B>(x, output_size)
+    }
+    /// Backward pass for the [adaptive avg pooling 3d](ModuleOps::adaptive_avg_pool3d) operation.
+    fn adaptive_avg_pool3d_backward(
+        x: B::TensorPrimitive<5>,
+        grad: B::TensorPrimitive<5>,
+    ) -> B::TensorPrimitive<5> {
+        pool::adaptive_avg_pool3d_backward_from_2d::<B>(x, grad)
+    }
+
+    /// Three dimensional max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn max_pool3d(
+        x: B::TensorPrimitive<5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> B::TensorPrimitive<5> {
+        pool::max_pool3d_from_2d::<B>(x, kernel_size, stride, padding, dilation)
+    }
+
+    /// Three dimensional max pooling with indices.
+    ///
+    /// The indices are flattened over the depth, height and width of the input.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn max_pool3d_with_indices(
+        x: B::TensorPrimitive<5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<B> {
+        pool::max_pool3d_with_indices_from_2d::<B>(x, kernel_size, stride, padding, dilation)
+    }
+    /// Backward pass for the [max pooling 3d](ModuleOps::max_pool3d_with_indices) operation.
+    fn max_pool3d_with_indices_backward(
+        x: B::TensorPrimitive<5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        output_grad: B::TensorPrimitive<5>,
+        indices: B::IntTensorPrimitive<5>,
+    ) -> MaxPool3dBackward<B> {
+        pool::max_pool3d_with_indices_backward_from_indices::<B>(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            output_grad,
+            indices,
+        )
+    }
+
+    /// Two dimensional interpolation.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    fn interpolate(
+        x: B::TensorPrimitive<4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> B::TensorPrimitive<4> {
+        interpolate::interpolate_from_select::<B>(x, output_size, options)
+    }
+
+    /// Backward pass for the [interpolate](ModuleOps::interpolate) operation.
+    ///
+    /// The default implementation accumulates the gradient of each interpolation tap into the
+    /// input with [select_assign](crate::ops::TensorOps::select_assign), so it runs on the device
+    /// of the backend without a dedicated kernel.
+    fn interpolate_backward(
+        x: B::TensorPrimitive<4>,
+        grad: B::TensorPrimitive<4>,
+        options: InterpolateOptions,
+    ) -> B::TensorPrimitive<4> {
+        interpolate::interpolate_backward_from_select::<B>(x, grad, options)
+    }
+
+    /// Scaled dot-product attention.
+    ///
+    /// `softmax(query * key^T * scale + bias) * value`, where the masked scores are replaced by
+    /// the mask value before the softmax. The bias is considered constant and isn't
+    /// differentiated. When the [dropout](AttentionOptions::with_dropout) is set, the scores are
+    /// multiplied by their [dropout factor](AttentionOptions::dropout_factor) before being masked.
+    ///
+    /// # Shapes
+    ///
+    /// query: [batch_size, n_heads, seq_length_1, d_k],
+    /// key: [batch_size, n_heads, seq_length_2, d_k],
+    /// value: [batch_size, n_heads, seq_length_2, d_v],
+    /// bias: broadcastable to [batch_size, n_heads, seq_length_1, seq_length_2],
+    /// mask: broadcastable to [batch_size, n_heads, seq_length_1, seq_length_2],
+    /// output: [batch_size, n_heads, seq_length_1, d_v],
+    fn scaled_dot_product_attention(
+        query: B::TensorPrimitive<4>,
+        key: B::TensorPrimitive<4>,
+        value: B::TensorPrimitive<4>,
+        bias: Option<B::TensorPrimitive<4>>,
+        mask: Option<B::BoolTensorPrimitive<4>>,
+        options: AttentionOptions,
+    ) -> B::TensorPrimitive<4> {
+        attention::scaled_dot_product_attention_naive::<B>(query, key, value, bias, mask, options)
+    }
+
+    /// Backward pass for the [scaled dot-product attention](ModuleOps::scaled_dot_product_attention)
+    /// operation.
+    fn scaled_dot_product_attention_backward(
+        query: B::TensorPrimitive<4>,
+        key: B::TensorPrimitive<4>,
+        value: B::TensorPrimitive<4>,
+        bias: Option<B::TensorPrimitive<4>>,
+        mask: Option<B::BoolTensorPrimitive<4>>,
+        output_grad: B::TensorPrimitive<4>,
+        options: AttentionOptions,
+    ) -> AttentionBackward<B> {
+        attention::scaled_dot_product_attention_backward_naive::<B>(
+            query,
+            key,
+            value,
+            bias,
+            mask,
+            output_grad,
+            options,
+        )
+    }
+
+    /// Segment reduce operation.
+    ///
+    /// Reduces consecutive rows of the tensor into segments, where each segment starts at its
+    /// offset and ends at the next one. The first offset should be zero and the offsets should be
+    /// sorted. Empty segments have a zero output.
+    ///
+    /// # Shapes
+    ///
+    /// tensor: [num_rows, d],
+    /// offsets: [num_segments],
+    /// output: [num_segments, d],
+    fn segment_reduce(
+        tensor: B::TensorPrimitive<2>,
+        offsets: B::IntTensorPrimitive<1>,
+        reduction: SegmentReduction,
+    ) -> B::TensorPrimitive<2> {
+        segment::segment_reduce_naive::<B>(tensor, offsets, reduction)
+    }
+    /// Backward pass for the [segment reduce](ModuleOps::segment_reduce) operation.
+    fn segment_reduce_backward(
+        tensor: B::TensorPrimitive<2>,
+        offsets: B::IntTensorPrimitive<1>,
+        output_grad: B::TensorPrimitive<2>,
+        reduction: SegmentReduction,
+    ) -> B::TensorPrimitive<2> {
+        segment::segment_reduce_backward_naive::<B>(tensor, offsets, output_grad, reduction)
+    }
 }
@@ -1,6 +1,7 @@
 #![allow(clippy::single_range_in_vec_init)]
-use super::{Conv1dBackward, Conv2dBackward, ConvOptions, ConvTransposeOptions};
-use crate::{backend::Backend, Shape};
+use super::{Conv1dBackward, Conv2dBackward, Conv3dBackward, ConvOptions, ConvTransposeOptions};
+use crate::{backend::Backend, Data, ElementConversion, Shape};
+use alloc::vec::Vec;
 use libm::ceilf;
 
 /// Calculate the expected padding size required when applying a convolution.
@@ -274,6 +275,148 @@ pub(crate) fn conv_transpose1d_backward<B: Backend>(
     )
 }
 
+/// Calculate the [3D convolution](crate::ops::ModuleOps::conv3d) backward pass using convolutions.
+pub(crate) fn conv3d_backward<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    weight: B::TensorPrimitive<5>,
+    bias: Option<B::TensorPrimitive<1>>,
+    output_grad: B::TensorPrimitive<5>,
+    options: ConvOptions<3>,
+) -> Conv3dBackward<B> {
+    let weight_shape = B::shape(&weight);
+    let weight_device = B::device(&weight);
+
+    let [batch_size, _channels_in, depth_in, height_in, width_in] = B::shape(&x).dims;
+    let [_, _, depth_out, height_out, width_out] = B::shape(&output_grad).dims;
+    let [channels_out, _, kernel_size_1, kernel_size_2, kernel_size_3] = weight_shape.dims;
+
+    let padding_1_out = calculate_padding_out(
+        kernel_size_1,
+        options.stride[0],
+        options.padding[0],
+        options.dilation[0],
+        depth_in,
+        depth_out,
+    );
+    let padding_2_out = calculate_padding_out(
+        kernel_size_2,
+        options.stride[1],
+        options.padding[1],
+        options.dilation[1],
+        height_in,
+        height_out,
+    );
+    let padding_3_out = calculate_padding_out(
+        kernel_size_3,
+        options.stride[2],
+        options.padding[2],
+        options.dilation[2],
+        width_in,
+        width_out,
+    );
+
+    let x_grad = B::conv_transpose3d(
+        output_grad.clone(),
+        weight,
+        None,
+        ConvTransposeOptions::new(
+            options.stride,
+            options.padding,
+            [padding_1_out, padding_2_out, padding_3_out],
+            options.dilation,
+            options.groups,
+        ),
+    );
+
+    let weight_grad = match options.groups == 1 {
+        true => conv3d_weight_grad_no_groups::<B>(x, output_grad.clone(), weight_shape, options),
+        false => conv3d_weight_grad_groups::<B>(
+            x,
+            B::zeros(weight_shape, &weight_device),
+            output_grad.clone(),
+            options,
+        ),
+    };
+
+    Conv3dBackward::new(
+        x_grad,
+        weight_grad,
+        bias.map(|b| {
+            let grad = B::swap_dims(output_grad, 0, 1);
+            let grad = B::reshape(
+                grad,
+                Shape::new([
+                    channels_out,
+                    batch_size * depth_out * height_out * width_out,
+                ]),
+            );
+            let grad = B::sum_dim(grad, 1);
+
+            B::reshape(grad, B::shape(&b))
+        }),
+    )
+}
+
+/// Calculate the [3D convolution transpose](crate::ops::ModuleOps::conv_transpose3d) backward pass using convolutions.
+pub(crate) fn conv_transpose3d_backward<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    weight: B::TensorPrimitive<5>,
+    bias: Option<B::TensorPrimitive<1>>,
+    output_grad: B::TensorPrimitive<5>,
+    options: ConvTransposeOptions<3>,
+) -> Conv3dBackward<B> {
+    let weight_shape = B::shape(&weight);
+    let weight_device = B::device(&weight);
+
+    let [batch_size, _channels_in, _, _, _] = B::shape(&x).dims;
+    let [_, channels_out, depth_out, height_out, width_out] = B::shape(&output_grad).dims;
+
+    let x_grad = B::conv3d(
+        output_grad.clone(),
+        weight,
+        None,
+        ConvOptions::new(
+            options.stride,
+            options.padding,
+            options.dilation,
+            options.groups,
+        ),
+    );
+
+    let weight_grad = match options.groups == 1 {
+        true => conv_transpose3d_weight_grad_no_groups::<B>(
+            x,
+            output_grad.clone(),
+            weight_shape,
+            options,
+        ),
+        false => conv_transpose3d_weight_grad_groups::<B>(
+            x,
+            B::zeros(weight_shape, &weight_device),
+            output_grad.clone(),
+            options,
+        ),
+    };
+
+    Conv3dBackward::new(
+        x_grad,
+        weight_grad,
+        bias.map(|b| {
+            let grad = B::swap_dims(output_grad, 0, 1);
+            let grad = B::reshape(
+                grad,
+                Shape::new([
+                    channels_out,
+                    batch_size * depth_out * height_out * width_out,
+                ]),
+            );
+            let grad = B::sum_dim(grad, 1);
+
+            B::reshape(grad, B::shape(&b))
+        }),
+    )
+}
+
 /// Execute a 1D convolution using a 2D convolution.
 pub(crate) fn conv1d_from_conv2d<B: Backend>(
     x: B::TensorPrimitive<3>,
@@ -337,6 +480,247 @@ pub(crate) fn conv_transpose1d_from_conv_transpose2d<B: Backend>(
     B::reshape(tensor, Shape::from([batch_size, channels_out, height_out]))
 }
 
+/// Execute a 3D convolution using 2D convolutions.
+///
+/// Each element of the kernel along the depth is applied with a 2D convolution over the input
+/// slices it reads, with the depth folded into the batch, and the results are summed.
+pub(crate) fn conv3d_from_conv2d<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    weight: B::TensorPrimitive<5>,
+    bias: Option<B::TensorPrimitive<1>>,
+    options: ConvOptions<3>,
+) -> B::TensorPrimitive<5> {
+    let [channels_out, channels_per_group, kernel_size_1, kernel_size_2, kernel_size_3] =
+        B::shape(&weight).dims;
+    let [batch_size, channels_in, depth_in, height_in, width_in] = B::shape(&x).dims;
+    let device = B::device(&x);
+
+    let depth_out = calculate_conv_output_size(
+        kernel_size_1,
+        options.stride[0],
+        options.padding[0],
+        options.dilation[0],
+        depth_in,
+    );
+    let x = pad_depth::<B>(x, options.padding[0]);
+    let mut output: Option<B::TensorPrimitive<4>> = None;
+
+    for k in 0..kernel_size_1 {
+        let indices = depth_indices::<B>(
+            depth_out,
+            options.stride[0],
+            k * options.dilation[0],
+            &device,
+        );
+        let x = B::select(x.clone(), 2, indices);
+        let x = B::swap_dims(x, 1, 2);
+        let x = B::reshape(
+            x,
+            Shape::new([batch_size * depth_out, channels_in, height_in, width_in]),
+        );
+
+        let weight = B::slice(
+            weight.clone(),
+            [
+                0..channels_out,
+                0..channels_per_group,
+                k..k + 1,
+                0..kernel_size_2,
+                0..kernel_size_3,
+            ],
+        );
+        let weight = B::reshape(
+            weight,
+            Shape::new([
+                channels_out,
+                channels_per_group,
+                kernel_size_2,
+                kernel_size_3,
+            ]),
+        );
+
+        let value = B::conv2d(
+            x,
+            weight,
+            None,
+            ConvOptions::new(
+                [options.stride[1], options.stride[2]],
+                [options.padding[1], options.padding[2]],
+                [options.dilation[1], options.dilation[2]],
+                options.groups,
+            ),
+        );
+
+        output = Some(match output {
+            Some(output) => B::add(output, value),
+            None => value,
+        });
+    }
+
+    let output = output.unwrap();
+    let [_, _, height_out, width_out] = B::shape(&output).dims;
+    let output = B::reshape(
+        output,
+        Shape::new([batch_size, depth_out, channels_out, height_out, width_out]),
+    );
+    let output = B::swap_dims(output, 1, 2);
+
+    match bias {
+        Some(bias) => B::add(
+            output,
+            B::reshape(bias, Shape::new([1, channels_out, 1, 1, 1])),
+        ),
+        None => output,
+    }
+}
+
+/// Execute a 3D transposed convolution using 2D transposed convolutions.
+///
+/// Each element of the kernel along the depth is applied with a 2D transposed convolution, with
+/// the depth folded into the batch, and the results are accumulated into the output slices they
+/// write to.
+pub(crate) fn conv_transpose3d_from_conv_transpose2d<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    weight: B::TensorPrimitive<5>,
+    bias: Option<B::TensorPrimitive<1>>,
+    options: ConvTransposeOptions<3>,
+) -> B::TensorPrimitive<5> {
+    let [channels_in, channels_per_group, kernel_size_1, kernel_size_2, kernel_size_3] =
+        B::shape(&weight).dims;
+    let [batch_size, _channels_in, depth_in, height_in, width_in] = B::shape(&x).dims;
+    let channels_out = channels_per_group * options.groups;
+    let device = B::device(&x);
+
+    // The depth of the output before the padding is removed.
+    let depth_full = (depth_in - 1) * options.stride[0]
+        + options.dilation[0] * (kernel_size_1 - 1)
+        + options.padding_out[0]
+        + 1;
+    let depth_out = depth_full - 2 * options.padding[0];
+
+    let x = B::swap_dims(x, 1, 2);
+    let x = B::reshape(
+        x,
+        Shape::new([batch_size * depth_in, channels_in, height_in, width_in]),
+    );
+    let mut output: Option<B::TensorPrimitive<5>> = None;
+
+    for k in 0..kernel_size_1 {
+        let weight = B::slice(
+            weight.clone(),
+            [
+                0..channels_in,
+                0..channels_per_group,
+                k..k + 1,
+                0..kernel_size_2,
+                0..kernel_size_3,
+            ],
+        );
+        let weight = B::reshape(
+            weight,
+            Shape::new([
+                channels_in,
+                channels_per_group,
+                kernel_size_2,
+                kernel_size_3,
+            ]),
+        );
+
+        let value = B::conv_transpose2d(
+            x.clone(),
+            weight,
+            None,
+            ConvTransposeOptions::new(
+                [options.stride[1], options.stride[2]],
+                [options.padding[1], options.padding[2]],
+                [options.padding_out[1], options.padding_out[2]],
+                [options.dilation[1], options.dilation[2]],
+                options.groups,
+            ),
+        );
+        let [_, _, height_out, width_out] = B::shape(&value).dims;
+        let value = B::reshape(
+            value,
+            Shape::new([batch_size, depth_in, channels_out, height_out, width_out]),
+        );
+        let value = B::swap_dims(value, 1, 2);
+
+        let output_full = output.unwrap_or_else(|| {
+            B::zeros(
+                Shape::new([batch_size, channels_out, depth_full, height_out, width_out]),
+                &device,
+            )
+        });
+        let indices = depth_indices::<B>(
+            depth_in,
+            options.stride[0],
+            k * options.dilation[0],
+            &device,
+        );
+        output = Some(B::select_assign(output_full, 2, indices, value));
+    }
+
+    let output = output.unwrap();
+    let [_, _, _, height_out, width_out] = B::shape(&output).dims;
+    let output = B::slice(
+        output,
+        [
+            0..batch_size,
+            0..channels_out,
+            options.padding[0]..options.padding[0] + depth_out,
+            0..height_out,
+            0..width_out,
+        ],
+    );
+
+    match bias {
+        Some(bias) => B::add(
+            output,
+            B::reshape(bias, Shape::new([1, channels_out, 1, 1, 1])),
+        ),
+        None => output,
+    }
+}
+
+/// Pads the depth dimension of a 5D tensor with zeros on both sides.
+fn pad_depth<B: Backend>(x: B::TensorPrimitive<5>, padding: usize) -> B::TensorPrimitive<5> {
+    if padding == 0 {
+        return x;
+    }
+
+    let [batch_size, channels, depth, height, width] = B::shape(&x).dims;
+    let output = B::zeros(
+        Shape::new([batch_size, channels, depth + 2 * padding, height, width]),
+        &B::device(&x),
+    );
+
+    B::slice_assign(
+        output,
+        [
+            0..batch_size,
+            0..channels,
+            padding..padding + depth,
+            0..height,
+            0..width,
+        ],
+        x,
+    )
+}
+
+/// The indices `offset + i * stride` for `i` in `0..size`.
+fn depth_indices<B: Backend>(
+    size: usize,
+    stride: usize,
+    offset: usize,
+    device: &B::Device,
+) -> B::IntTensorPrimitive<1> {
+    let indices = (0..size)
+        .map(|i| ((offset + i * stride) as i64).elem())
+        .collect::<Vec<_>>();
+
+    B::int_from_data(Data::new(indices, Shape::new([size])), device)
+}
+
 fn conv1d_weight_grad_groups<B: Backend>(
     x: B::TensorPrimitive<3>,
     mut weight_grad: B::TensorPrimitive<3>,
@@ -640,6 +1024,196 @@ fn conv_transpose2d_weight_grad_no_groups<B: Backend>(
     weight_grad
 }
 
+fn conv3d_weight_grad_groups<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    mut weight_grad: B::TensorPrimitive<5>,
+    output_grad: B::TensorPrimitive<5>,
+    options: ConvOptions<3>,
+) -> B::TensorPrimitive<5> {
+    let [channels_out, increment_ci, kernel_size_1, kernel_size_2, kernel_size_3] =
+        B::shape(&weight_grad).dims;
+    let increment_co = channels_out / options.groups;
+
+    let x_swapped = B::swap_dims(x, 0, 1);
+    let output_grad_swapped = B::swap_dims(output_grad, 0, 1);
+
+    for g in 0..options.groups {
+        let start_idx_ci = g * increment_ci;
+        let end_idx_ci = (g + 1) * increment_ci;
+        let start_idx_co = g * increment_co;
+        let end_idx_co = (g + 1) * increment_co;
+
+        let x = B::slice(x_swapped.clone(), [start_idx_ci..end_idx_ci]);
+        let grad = B::slice(output_grad_swapped.clone(), [start_idx_co..end_idx_co]);
+        let mut weight_grad_tmp = B::conv3d(
+            x,
+            grad,
+            None,
+            ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+        );
+        weight_grad_tmp = B::swap_dims(weight_grad_tmp, 0, 1);
+        let [_, _, kernel_size_1_tmp, kernel_size_2_tmp, kernel_size_3_tmp] =
+            B::shape(&weight_grad_tmp).dims;
+
+        if kernel_size_1_tmp != kernel_size_1
+            || kernel_size_2_tmp != kernel_size_2
+            || kernel_size_3_tmp != kernel_size_3
+        {
+            weight_grad_tmp = B::slice(
+                weight_grad_tmp,
+                [
+                    0..increment_co,
+                    0..increment_ci,
+                    0..kernel_size_1,
+                    0..kernel_size_2,
+                    0..kernel_size_3,
+                ],
+            );
+        }
+
+        weight_grad = B::slice_assign(
+            weight_grad,
+            [
+                start_idx_co..end_idx_co,
+                0..increment_ci,
+                0..kernel_size_1,
+                0..kernel_size_2,
+                0..kernel_size_3,
+            ],
+            weight_grad_tmp,
+        );
+    }
+
+    weight_grad
+}
+
+fn conv_transpose3d_weight_grad_groups<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    mut weight_grad: B::TensorPrimitive<5>,
+    output_grad: B::TensorPrimitive<5>,
+    options: ConvTransposeOptions<3>,
+) -> B::TensorPrimitive<5> {
+    let [channels_in, increment_co, kernel_size_1, kernel_size_2, kernel_size_3] =
+        B::shape(&weight_grad).dims;
+    let increment_ci = channels_in / options.groups;
+
+    let x_swapped = B::swap_dims(x, 0, 1);
+    let output_grad_swapped = B::swap_dims(output_grad, 0, 1);
+
+    for g in 0..options.groups {
+        let start_idx_ci = g * increment_ci;
+        let end_idx_ci = (g + 1) * increment_ci;
+        let start_idx_co = g * increment_co;
+        let end_idx_co = (g + 1) * increment_co;
+
+        let x = B::slice(x_swapped.clone(), [start_idx_ci..end_idx_ci]);
+        let grad = B::slice(output_grad_swapped.clone(), [start_idx_co..end_idx_co]);
+        let mut weight_grad_tmp = B::conv3d(
+            grad,
+            x,
+            None,
+            ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+        );
+        weight_grad_tmp = B::swap_dims(weight_grad_tmp, 0, 1);
+        let [_, _, kernel_size_1_tmp, kernel_size_2_tmp, kernel_size_3_tmp] =
+            B::shape(&weight_grad_tmp).dims;
+
+        if kernel_size_1_tmp != kernel_size_1
+            || kernel_size_2_tmp != kernel_size_2
+            || kernel_size_3_tmp != kernel_size_3
+        {
+            weight_grad_tmp = B::slice(
+                weight_grad_tmp,
+                [
+                    0..increment_ci,
+                    0..increment_co,
+                    0..kernel_size_1,
+                    0..kernel_size_2,
+                    0..kernel_size_3,
+                ],
+            );
+        }
+
+        weight_grad = B::slice_assign(
+            weight_grad,
+            [
+                start_idx_ci..end_idx_ci,
+                0..increment_co,
+                0..kernel_size_1,
+                0..kernel_size_2,
+                0..kernel_size_3,
+            ],
+            weight_grad_tmp,
+        );
+    }
+
+    weight_grad
+}
+
+fn conv3d_weight_grad_no_groups<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    output_grad: B::TensorPrimitive<5>,
+    weight_shape: Shape<5>,
+    options: ConvOptions<3>,
+) -> B::TensorPrimitive<5> {
+    let x_swapped = B::swap_dims(x, 0, 1);
+    let output_grad_swapped = B::swap_dims(output_grad, 0, 1);
+    let weight_grad_swapped = B::conv3d(
+        x_swapped,
+        output_grad_swapped,
+        None,
+        ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+    );
+    let mut weight_grad = B::swap_dims(weight_grad_swapped, 0, 1);
+
+    if B::shape(&weight_grad) != weight_shape {
+        weight_grad = B::slice(
+            weight_grad,
+            [
+                0..weight_shape.dims[0],
+                0..weight_shape.dims[1],
+                0..weight_shape.dims[2],
+                0..weight_shape.dims[3],
+                0..weight_shape.dims[4],
+            ],
+        );
+    }
+    weight_grad
+}
+
+fn conv_transpose3d_weight_grad_no_groups<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    output_grad: B::TensorPrimitive<5>,
+    weight_shape: Shape<5>,
+    options: ConvTransposeOptions<3>,
+) -> B::TensorPrimitive<5> {
+    let x_swapped = B::swap_dims(x, 0, 1);
+    let output_grad_swapped = B::swap_dims(output_grad, 0, 1);
+    let weight_grad_swapped = B::conv3d(
+        output_grad_swapped,
+        x_swapped,
+        None,
+        ConvOptions::new(options.dilation, options.padding, options.stride, 1),
+    );
+    let mut weight_grad = B::swap_dims(weight_grad_swapped, 0, 1);
+
+    let grad_shape = B::shape(&weight_grad);
+
+    if grad_shape != weight_shape {
+        weight_grad = B::slice(
+            weight_grad,
+            [
+                0..weight_shape.dims[0],
+                0..weight_shape.dims[1],
+                0..weight_shape.dims[2],
+                0..weight_shape.dims[3],
+                0..weight_shape.dims[4],
+            ],
+        );
+    }
+    weight_grad
+}
+
 fn calculate_padding_out(
     kernel_size: usize,
     stride: usize,
@@ -0,0 +1,173 @@
+use super::{InterpolateMode, InterpolateOptions};
+use crate::{backend::Backend, Data, ElementConversion, Shape};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Coefficient of the cubic convolution kernel, the same as PyTorch.
+const CUBIC_COEFFICIENT: f64 = -0.75;
+
+/// Computes the input indices and weights used to compute each output element of a one
+/// dimensional interpolation.
+///
+/// Each output element is the weighted sum of the input elements at the returned indices. The
+/// number of (index, weight) pairs is the same for every output element: 1 for the nearest mode,
+/// 2 for the bilinear mode and 4 for the bicubic mode.
+pub fn interpolate_weights(
+    size_in: usize,
+    size_out: usize,
+    options: &InterpolateOptions,
+) -> Vec<Vec<(usize, f64)>> {
+    (0..size_out)
+        .map(|index| match options.mode {
+            InterpolateMode::Nearest => {
+                let scale = size_in as f64 / size_out as f64;
+                let source = libm::floor(index as f64 * scale) as usize;
+
+                vec![(usize::min(source, size_in - 1), 1.0)]
+            }
+            InterpolateMode::Bilinear => {
+                let source = source_coordinate(index, size_in, size_out, options.align_corners);
+                let source = f64::max(source, 0.0);
+                let index_0 = usize::min(libm::floor(source) as usize, size_in - 1);
+                let index_1 = usize::min(index_0 + 1, size_in - 1);
+                let lambda = source - index_0 as f64;
+
+                vec![(index_0, 1.0 - lambda), (index_1, lambda)]
+            }
+            InterpolateMode::Bicubic => {
+                let source = source_coordinate(index, size_in, size_out, options.align_corners);
+                let floor = libm::floor(source);
+                let weights = cubic_weights(source - floor);
+
+                weights
+                    .iter()
+                    .enumerate()
+                    .map(|(i, weight)| {
+                        let index = floor as i64 - 1 + i as i64;
+                        (index.clamp(0, size_in as i64 - 1) as usize, *weight)
+                    })
+                    .collect()
+            }
+        })
+        .collect()
+}
+
+/// The input coordinate sampled by the output element at the given index.
+fn source_coordinate(index: usize, size_in: usize, size_out: usize, align_corners: bool) -> f64 {
+    if align_corners {
+        if size_out <= 1 {
+            return 0.0;
+        }
+
+        return index as f64 * (size_in - 1) as f64 / (size_out - 1) as f64;
+    }
+
+    (index as f64 + 0.5) * size_in as f64 / size_out as f64 - 0.5
+}
+
+/// The weights of the four neighbors of a point located at `t` after the second one.
+fn cubic_weights(t: f64) -> [f64; 4] {
+    let a = CUBIC_COEFFICIENT;
+
+    // Convolution kernel for 1 < |x| < 2.
+    let far = |x: f64| ((a * x - 5.0 * a) * x + 8.0 * a) * x - 4.0 * a;
+    // Convolution kernel for |x| <= 1.
+    let near = |x: f64| ((a + 2.0) * x - (a + 3.0)) * x * x + 1.0;
+
+    [far(t + 1.0), near(t), near(1.0 - t), far(2.0 - t)]
+}
+
+pub(crate) fn interpolate_from_select<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    output_size: [usize; 2],
+    options: InterpolateOptions,
+) -> B::TensorPrimitive<4> {
+    let x = interpolate_dim::<B>(x, 2, output_size[0], &options);
+
+    interpolate_dim::<B>(x, 3, output_size[1], &options)
+}
+
+pub(crate) fn interpolate_backward_from_select<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    grad: B::TensorPrimitive<4>,
+    options: InterpolateOptions,
+) -> B::TensorPrimitive<4> {
+    let [_, _, height, width] = B::shape(&x).dims;
+
+    let grad = interpolate_dim_backward::<B>(grad, 3, width, &options);
+
+    interpolate_dim_backward::<B>(grad, 2, height, &options)
+}
+
+/// Interpolates along a single dimension by selecting the input elements used by each tap.
+fn interpolate_dim<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    dim: usize,
+    size_out: usize,
+    options: &InterpolateOptions,
+) -> B::TensorPrimitive<4> {
+    let size_in = B::shape(&x).dims[dim];
+    let device = B::device(&x);
+    let weights = interpolate_weights(size_in, size_out, options);
+    let mut output: Option<B::TensorPrimitive<4>> = None;
+
+    for tap in 0..weights[0].len() {
+        let (indices, tap_weights) = tap_tensors::<B>(&weights, tap, dim, &device);
+        let value = B::mul(B::select(x.clone(), dim, indices), tap_weights);
+
+        output = Some(match output {
+            Some(output) => B::add(output, value),
+            None => value,
+        });
+    }
+
+    output.unwrap()
+}
+
+/// Accumulates the gradient of each tap into the input elements it selected.
+fn interpolate_dim_backward<B: Backend>(
+    grad: B::TensorPrimitive<4>,
+    dim: usize,
+    size_in: usize,
+    options: &InterpolateOptions,
+) -> B::TensorPrimitive<4> {
+    let mut shape = B::shape(&grad);
+    let size_out = shape.dims[dim];
+    let device = B::device(&grad);
+    let weights = interpolate_weights(size_in, size_out, options);
+
+    shape.dims[dim] = size_in;
+    let mut output = B::zeros(shape, &device);
+
+    for tap in 0..weights[0].len() {
+        let (indices, tap_weights) = tap_tensors::<B>(&weights, tap, dim, &device);
+        let value = B::mul(grad.clone(), tap_weights);
+
+        output = B::select_assign(output, dim, indices, value);
+    }
+
+    output
+}
+
+/// The indices and the weights of a tap, with the weights shaped to broadcast along `dim`.
+fn tap_tensors<B: Backend>(
+    weights: &[Vec<(usize, f64)>],
+    tap: usize,
+    dim: usize,
+    device: &B::Device,
+) -> (B::IntTensorPrimitive<1>, B::TensorPrimitive<4>) {
+    let size = weights.len();
+    let indices = weights
+        .iter()
+        .map(|taps| (taps[tap].0 as i64).elem())
+        .collect();
+    let values = weights.iter().map(|taps| taps[tap].1.elem()).collect();
+
+    let mut shape = [1; 4];
+    shape[dim] = size;
+
+    let indices = B::int_from_data(Data::new(indices, Shape::new([size])), device);
+    let values = B::from_data(Data::new(values, Shape::new(shape)), device);
+
+    (indices, values)
+}
@@ -1,9 +1,18 @@
+/// Module with attention operations.
+pub mod attention;
+
 /// Module with convolution operations.
 pub mod conv;
 
+/// Module with interpolation operations.
+pub mod interpolate;
+
 /// Module with pooling operations.
 pub mod pool;
 
+/// Module with segment reduce operations.
+pub mod segment;
+
 mod base;
 
 pub use base::*;
@@ -1,6 +1,6 @@
-use crate::{backend::Backend, Shape};
+use crate::{backend::Backend, ElementConversion, Shape};
 
-use super::{MaxPool1dBackward, MaxPool1dWithIndices};
+use super::{MaxPool1dBackward, MaxPool1dWithIndices, MaxPool3dBackward, MaxPool3dWithIndices};
 
 pub(crate) fn avg_pool1d_from_2d<B: Backend>(
     x: B::TensorPrimitive<3>,
@@ -161,3 +161,289 @@ pub(crate) fn max_pool1d_with_indices_backward_from_2d<B: Backend>(
         Shape::from([batch_size, channels, length_in]),
     ))
 }
+
+// The 3D pooling operations are separable: pooling over the height and the width, then over the
+// depth, gives the same result as pooling over the three dimensions at once. Both steps are
+// executed with 2D pooling operations by folding the other dimensions together.
+
+pub(crate) fn avg_pool3d_from_2d<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> B::TensorPrimitive<5> {
+    let [batch_size, channels, depth, height, width] = B::shape(&x).dims;
+
+    let x = B::reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::avg_pool2d(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        count_include_pad,
+    );
+    let [_, _, height_out, width_out] = B::shape(&x).dims;
+
+    let x = B::reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+    );
+    let x = B::avg_pool2d(
+        x,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        count_include_pad,
+    );
+    let [_, _, depth_out, _] = B::shape(&x).dims;
+
+    B::reshape(
+        x,
+        Shape::from([batch_size, channels, depth_out, height_out, width_out]),
+    )
+}
+
+pub(crate) fn avg_pool3d_backward_from_2d<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    grad: B::TensorPrimitive<5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> B::TensorPrimitive<5> {
+    let [batch_size, channels, depth, height, width] = B::shape(&x).dims;
+    let [_, _, depth_out, height_out, width_out] = B::shape(&grad).dims;
+    let device = B::device(&x);
+
+    // The backward pass of the average pooling only depends on the shape of its input.
+    let x_depth = B::zeros(
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+        &device,
+    );
+    let grad = B::reshape(
+        grad,
+        Shape::from([batch_size, channels, depth_out, height_out * width_out]),
+    );
+    let grad = B::avg_pool2d_backward(
+        x_depth,
+        grad,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        count_include_pad,
+    );
+
+    let x = B::reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let grad = B::reshape(
+        grad,
+        Shape::from([batch_size, channels * depth, height_out, width_out]),
+    );
+    let grad = B::avg_pool2d_backward(
+        x,
+        grad,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        count_include_pad,
+    );
+
+    B::reshape(
+        grad,
+        Shape::from([batch_size, channels, depth, height, width]),
+    )
+}
+
+pub(crate) fn adaptive_avg_pool3d_from_2d<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    output_size: [usize; 3],
+) -> B::TensorPrimitive<5> {
+    let [batch_size, channels, depth, height, width] = B::shape(&x).dims;
+    let [depth_out, height_out, width_out] = output_size;
+
+    let x = B::reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::adaptive_avg_pool2d(x, [height_out, width_out]);
+
+    let x = B::reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+    );
+    let x = B::adaptive_avg_pool2d(x, [depth_out, height_out * width_out]);
+
+    B::reshape(
+        x,
+        Shape::from([batch_size, channels, depth_out, height_out, width_out]),
+    )
+}
+
+pub(crate) fn adaptive_avg_pool3d_backward_from_2d<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    grad: B::TensorPrimitive<5>,
+) -> B::TensorPrimitive<5> {
+    let [batch_size, channels, depth, height, width] = B::shape(&x).dims;
+    let [_, _, depth_out, height_out, width_out] = B::shape(&grad).dims;
+    let device = B::device(&x);
+
+    // The backward pass of the average pooling only depends on the shape of its input.
+    let x_depth = B::zeros(
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+        &device,
+    );
+    let grad = B::reshape(
+        grad,
+        Shape::from([batch_size, channels, depth_out, height_out * width_out]),
+    );
+    let grad = B::adaptive_avg_pool2d_backward(x_depth, grad);
+
+    let x = B::reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let grad = B::reshape(
+        grad,
+        Shape::from([batch_size, channels * depth, height_out, width_out]),
+    );
+    let grad = B::adaptive_avg_pool2d_backward(x, grad);
+
+    B::reshape(
+        grad,
+        Shape::from([batch_size, channels, depth, height, width]),
+    )
+}
+
+pub(crate) fn max_pool3d_from_2d<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> B::TensorPrimitive<5> {
+    let [batch_size, channels, depth, height, width] = B::shape(&x).dims;
+
+    let x = B::reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::max_pool2d(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        [dilation[1], dilation[2]],
+    );
+    let [_, _, height_out, width_out] = B::shape(&x).dims;
+
+    let x = B::reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+    );
+    let x = B::max_pool2d(
+        x,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        [dilation[0], 1],
+    );
+    let [_, _, depth_out, _] = B::shape(&x).dims;
+
+    B::reshape(
+        x,
+        Shape::from([batch_size, channels, depth_out, height_out, width_out]),
+    )
+}
+
+pub(crate) fn max_pool3d_with_indices_from_2d<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> MaxPool3dWithIndices<B> {
+    let [batch_size, channels, depth, height, width] = B::shape(&x).dims;
+
+    let x = B::reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::max_pool2d_with_indices(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        [dilation[1], dilation[2]],
+    );
+    let [_, _, height_out, width_out] = B::shape(&x.output).dims;
+    let size_plane = height_out * width_out;
+
+    let output = B::reshape(
+        x.output,
+        Shape::from([batch_size, channels, depth, size_plane]),
+    );
+    let output = B::max_pool2d_with_indices(
+        output,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        [dilation[0], 1],
+    );
+    let [_, _, depth_out, _] = B::shape(&output.output).dims;
+    let shape_out = Shape::from([batch_size, channels, depth_out, height_out, width_out]);
+
+    // The indices of the second step point to an element of the first step's output, which is
+    // where the index inside of the input plane is found.
+    let indices_depth = B::int_reshape(
+        output.indices,
+        Shape::from([batch_size, channels, depth_out * size_plane]),
+    );
+    let indices_plane = B::int_reshape(
+        x.indices,
+        Shape::from([batch_size, channels, depth * size_plane]),
+    );
+    let indices_plane = B::int_gather(2, indices_plane, indices_depth.clone());
+    let indices_depth = B::int_div_scalar(indices_depth, (size_plane as i64).elem());
+    let indices = B::int_add(
+        B::int_mul_scalar(indices_depth, ((height * width) as i64).elem()),
+        indices_plane,
+    );
+
+    MaxPool3dWithIndices::new(
+        B::reshape(output.output, shape_out.clone()),
+        B::int_reshape(indices, shape_out),
+    )
+}
+
+pub(crate) fn max_pool3d_with_indices_backward_from_indices<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    _kernel_size: [usize; 3],
+    _stride: [usize; 3],
+    _padding: [usize; 3],
+    _dilation: [usize; 3],
+    output_grad: B::TensorPrimitive<5>,
+    indices: B::IntTensorPrimitive<5>,
+) -> MaxPool3dBackward<B> {
+    let [batch_size, channels, depth, height, width] = B::shape(&x).dims;
+    let [_, _, depth_out, height_out, width_out] = B::shape(&output_grad).dims;
+    let size_out = depth_out * height_out * width_out;
+
+    let output_grad = B::reshape(output_grad, Shape::from([batch_size, channels, size_out]));
+    let indices = B::int_reshape(indices, Shape::from([batch_size, channels, size_out]));
+    let x_grad = B::zeros(
+        Shape::from([batch_size, channels, depth * height * width]),
+        &B::device(&x),
+    );
+    let x_grad = B::scatter(2, x_grad, indices, output_grad);
+
+    MaxPool3dBackward::new(B::reshape(
+        x_grad,
+        Shape::from([batch_size, channels, depth, height, width]),
+    ))
+}
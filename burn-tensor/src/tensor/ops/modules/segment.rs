@@ -0,0 +1,200 @@
+#![allow(clippy::single_range_in_vec_init)]
+
+use super::SegmentReduction;
+use crate::{backend::Backend, Bool, Int, Tensor};
+use alloc::vec;
+
+/// Computes the reduction of each segment using only tensor operations.
+pub(crate) fn segment_reduce_naive<B: Backend>(
+    tensor: B::TensorPrimitive<2>,
+    offsets: B::IntTensorPrimitive<1>,
+    reduction: SegmentReduction,
+) -> B::TensorPrimitive<2> {
+    let tensor = Tensor::<B, 2>::from_primitive(tensor);
+    let segments = Segments::<B>::new(Tensor::from_primitive(offsets), tensor.dims()[0]);
+
+    let output = match reduction {
+        SegmentReduction::Sum => segments.sum(tensor),
+        SegmentReduction::Mean => {
+            let counts = segments.counts();
+            segments.sum(tensor).div(counts)
+        }
+        SegmentReduction::Max => {
+            let (output, _) = segments.max_with_indices(tensor);
+            output
+        }
+    };
+
+    output.into_primitive()
+}
+
+/// Computes the gradient of the [segment reduce](super::ModuleOps::segment_reduce) operation
+/// using only tensor operations.
+pub(crate) fn segment_reduce_backward_naive<B: Backend>(
+    tensor: B::TensorPrimitive<2>,
+    offsets: B::IntTensorPrimitive<1>,
+    output_grad: B::TensorPrimitive<2>,
+    reduction: SegmentReduction,
+) -> B::TensorPrimitive<2> {
+    let tensor = Tensor::<B, 2>::from_primitive(tensor);
+    let output_grad = Tensor::<B, 2>::from_primitive(output_grad);
+    let segments = Segments::<B>::new(Tensor::from_primitive(offsets), tensor.dims()[0]);
+
+    let tensor_grad = match reduction {
+        SegmentReduction::Sum => output_grad.select(0, segments.ids),
+        SegmentReduction::Mean => {
+            let counts = segments.counts();
+            output_grad.div(counts).select(0, segments.ids)
+        }
+        SegmentReduction::Max => {
+            // Empty segments have a zero output, which doesn't depend on any element.
+            let output_grad = output_grad.mask_fill(segments.empty_mask(tensor.dims()[1]), 0.0);
+            let (_, indices) = segments.max_with_indices(tensor.clone());
+
+            Tensor::zeros_device(tensor.shape(), &tensor.device()).scatter(0, indices, output_grad)
+        }
+    };
+
+    tensor_grad.into_primitive()
+}
+
+/// The segments of the rows of a tensor, described by their offsets.
+struct Segments<B: Backend> {
+    /// The start of each segment.
+    starts: Tensor<B, 1, Int>,
+    /// The end (exclusive) of each segment.
+    ends: Tensor<B, 1, Int>,
+    /// The segment of each row.
+    ids: Tensor<B, 1, Int>,
+    num_rows: usize,
+    num_segments: usize,
+}
+
+impl<B: Backend> Segments<B> {
+    fn new(offsets: Tensor<B, 1, Int>, num_rows: usize) -> Self {
+        let device = offsets.device();
+        let [num_segments] = offsets.dims();
+
+        let ends = Tensor::cat(
+            vec![
+                offsets.clone(),
+                Tensor::full_device([1], num_rows as i64, &device),
+            ],
+            0,
+        )
+        .slice([1..num_segments + 1]);
+
+        // Each segment start increments the id of the following rows, so the ids of the rows are
+        // given by the cumulative sum of the segment starts. Empty segments share their start
+        // with the next segment and are skipped.
+        let ids = Tensor::<B, 1, Int>::zeros_device([num_rows + 1], &device)
+            .scatter(
+                0,
+                offsets.clone(),
+                Tensor::ones_device([num_segments], &device),
+            )
+            .cumsum(0)
+            .slice([0..num_rows])
+            .sub_scalar(1);
+
+        Self {
+            starts: offsets,
+            ends,
+            ids,
+            num_rows,
+            num_segments,
+        }
+    }
+
+    /// The number of rows of each segment, at least one to avoid divisions by zero, with shape
+    /// `[num_segments, 1]`.
+    fn counts(&self) -> Tensor<B, 2> {
+        self.ends
+            .clone()
+            .sub(self.starts.clone())
+            .float()
+            .clamp_min(1.0)
+            .reshape([self.num_segments, 1])
+    }
+
+    /// The empty segments, expanded to `[num_segments, num_columns]`.
+    fn empty_mask(&self, num_columns: usize) -> Tensor<B, 2, Bool> {
+        self.ends
+            .clone()
+            .equal(self.starts.clone())
+            .reshape([self.num_segments, 1])
+            .expand([self.num_segments, num_columns])
+    }
+
+    fn sum(&self, tensor: Tensor<B, 2>) -> Tensor<B, 2> {
+        let [_, num_columns] = tensor.dims();
+
+        Tensor::zeros_device([self.num_segments, num_columns], &tensor.device()).select_assign(
+            0,
+            self.ids.clone(),
+            tensor,
+        )
+    }
+
+    /// Computes the maximum of each segment along with the index of the row it comes from.
+    ///
+    /// The running maximum of each segment is computed with a parallel prefix scan, where each
+    /// step compares a row with the row `step` positions before when they belong to the same
+    /// segment. The last row of each segment then holds its maximum.
+    fn max_with_indices(&self, tensor: Tensor<B, 2>) -> (Tensor<B, 2>, Tensor<B, 2, Int>) {
+        let device = tensor.device();
+        let [num_rows, num_columns] = tensor.dims();
+        let shape = [num_rows, num_columns];
+
+        let ids = self.ids.clone().reshape([num_rows, 1]).expand(shape);
+        let mut values = tensor;
+        let mut indices = Tensor::<B, 1, Int>::arange_device(0..num_rows, &device)
+            .reshape([num_rows, 1])
+            .expand(shape);
+
+        let mut step = 1;
+        while step < self.num_rows {
+            let kept = 0..num_rows - step;
+            let previous_ids = Tensor::cat(
+                vec![
+                    Tensor::full_device([step, num_columns], -1, &device),
+                    ids.clone().slice([kept.clone()]),
+                ],
+                0,
+            );
+            let previous_values = Tensor::cat(
+                vec![
+                    Tensor::zeros_device([step, num_columns], &device),
+                    values.clone().slice([kept.clone()]),
+                ],
+                0,
+            );
+            let previous_indices = Tensor::cat(
+                vec![
+                    Tensor::zeros_device([step, num_columns], &device),
+                    indices.clone().slice([kept]),
+                ],
+                0,
+            );
+
+            // Infinite values can't be used to ignore the previous rows of other segments, since
+            // masking operations may multiply them by zero.
+            let replace = previous_values
+                .clone()
+                .greater(values.clone())
+                .int()
+                .mul(previous_ids.equal(ids.clone()).int())
+                .equal_elem(1);
+            values = values.mask_where(replace.clone(), previous_values);
+            indices = indices.mask_where(replace, previous_indices);
+            step *= 2;
+        }
+
+        let last = self.ends.clone().sub_scalar(1).clamp_min(0);
+        let empty = self.empty_mask(num_columns);
+        let output = values.select(0, last.clone()).mask_fill(empty, 0.0);
+        let indices = indices.select(0, last);
+
+        (output, indices)
+    }
+}
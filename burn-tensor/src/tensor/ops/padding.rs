@@ -0,0 +1,165 @@
+use crate::{backend::Backend, Element, ElementConversion, Int, Numeric, Tensor};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// The mode used to fill the padded elements of a tensor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadMode {
+    /// Pads with the given constant value.
+    Constant(f64),
+
+    /// Pads with the reflection of the tensor, excluding the edge value.
+    ///
+    /// `[1, 2, 3]` padded by two on both sides gives `[3, 2, 1, 2, 3, 2, 1]`.
+    Reflect,
+
+    /// Pads by repeating the edge value.
+    ///
+    /// `[1, 2, 3]` padded by two on both sides gives `[1, 1, 1, 2, 3, 3, 3]`.
+    Replicate,
+
+    /// Pads by wrapping around the tensor.
+    ///
+    /// `[1, 2, 3]` padded by two on both sides gives `[2, 3, 1, 2, 3, 1, 2]`.
+    Circular,
+}
+
+/// Reverses the order of the elements along the given axes using a select on each axis.
+///
+/// This is used by the default implementation of the flip operations.
+pub(crate) fn flip<B: Backend, K: Numeric<B>, const D: usize>(
+    tensor: Tensor<B, D, K>,
+    axes: &[usize],
+) -> Tensor<B, D, K>
+where
+    K::Elem: Element,
+{
+    let device = tensor.device();
+
+    axes.iter().fold(tensor, |tensor, &axis| {
+        let size = tensor.dims()[axis];
+        let indices = Tensor::<B, 1, Int>::arange_device(0..size, &device)
+            .mul_scalar(-1)
+            .add_scalar(size as i64 - 1);
+
+        tensor.select(axis, indices)
+    })
+}
+
+/// Shifts the elements along the given dimensions, wrapping around the ones that go past the end,
+/// by slicing and concatenating the tensor.
+///
+/// This is used by the default implementation of the roll operations.
+pub(crate) fn roll<B: Backend, K: Numeric<B>, const D: usize>(
+    tensor: Tensor<B, D, K>,
+    shifts: &[i64],
+    dims: &[usize],
+) -> Tensor<B, D, K>
+where
+    K::Elem: Element,
+{
+    shifts
+        .iter()
+        .zip(dims.iter())
+        .fold(tensor, |tensor, (&shift, &dim)| {
+            let size = tensor.dims()[dim];
+
+            if size == 0 {
+                return tensor;
+            }
+
+            let shift = shift.rem_euclid(size as i64) as usize;
+
+            if shift == 0 {
+                return tensor;
+            }
+
+            let tail = tensor
+                .clone()
+                .slice(ranges_dim(&tensor, dim, size - shift..size));
+            let head = tensor
+                .clone()
+                .slice(ranges_dim(&tensor, dim, 0..size - shift));
+
+            Tensor::cat(alloc::vec![tail, head], dim)
+        })
+}
+
+/// Pads the tensor with the given mode by composing slicing, flipping and concatenation.
+///
+/// This is used by the default implementation of the pad operations.
+pub(crate) fn pad<B: Backend, K: Numeric<B>, const D: usize>(
+    tensor: Tensor<B, D, K>,
+    pads: [(usize, usize); D],
+    mode: PadMode,
+) -> Tensor<B, D, K>
+where
+    K::Elem: Element,
+{
+    if let PadMode::Constant(value) = mode {
+        let mut shape = tensor.shape();
+        let mut ranges = shape.dims.map(|size| 0..size);
+
+        for (dim, (before, after)) in pads.into_iter().enumerate() {
+            shape.dims[dim] += before + after;
+            ranges[dim] = before..before + ranges[dim].end;
+        }
+
+        let output = Tensor::full_device(shape, value.elem::<K::Elem>(), &tensor.device());
+        return output.slice_assign(ranges, tensor);
+    }
+
+    pads.into_iter()
+        .enumerate()
+        .fold(tensor, |tensor, (dim, (before, after))| {
+            pad_dim(tensor, dim, before, after, mode)
+        })
+}
+
+fn pad_dim<B: Backend, K: Numeric<B>, const D: usize>(
+    tensor: Tensor<B, D, K>,
+    dim: usize,
+    before: usize,
+    after: usize,
+    mode: PadMode,
+) -> Tensor<B, D, K>
+where
+    K::Elem: Element,
+{
+    if before == 0 && after == 0 {
+        return tensor;
+    }
+
+    let size = tensor.dims()[dim];
+    let part = |range: Range<usize>| tensor.clone().slice(ranges_dim(&tensor, dim, range));
+
+    let head = (before > 0).then(|| match mode {
+        PadMode::Reflect => part(1..before + 1).flip(&[dim]),
+        PadMode::Replicate => part(0..1).repeat(dim, before),
+        PadMode::Circular => part(size - before..size),
+        PadMode::Constant(_) => unreachable!("Constant padding is applied on all dimensions"),
+    });
+    let tail = (after > 0).then(|| match mode {
+        PadMode::Reflect => part(size - 1 - after..size - 1).flip(&[dim]),
+        PadMode::Replicate => part(size - 1..size).repeat(dim, after),
+        PadMode::Circular => part(0..after),
+        PadMode::Constant(_) => unreachable!("Constant padding is applied on all dimensions"),
+    });
+
+    let tensors: Vec<_> = head.into_iter().chain([tensor]).chain(tail).collect();
+
+    Tensor::cat(tensors, dim)
+}
+
+fn ranges_dim<B: Backend, K: Numeric<B>, const D: usize>(
+    tensor: &Tensor<B, D, K>,
+    dim: usize,
+    range: Range<usize>,
+) -> [Range<usize>; D]
+where
+    K::Elem: Element,
+{
+    let mut ranges = tensor.dims().map(|size| 0..size);
+    ranges[dim] = range;
+    ranges
+}
@@ -0,0 +1,44 @@
+use crate::Shape;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Computes an inclusive scan of the tensor along the given dimension.
+///
+/// This is used by the default implementation of the cumulative operations. It only relies on
+/// `slice`, `cat` and the `combine` operation, so it runs on the device of the tensor using
+/// `log2(n)` steps, where `n` is the size of the dimension.
+///
+/// # Notes
+///
+/// The `combine` function receives the current elements first and the previous elements second,
+/// it must be associative.
+pub(crate) fn scan<T: Clone, const D: usize>(
+    tensor: T,
+    shape: &Shape<D>,
+    dim: usize,
+    slice: impl Fn(T, [Range<usize>; D]) -> T,
+    cat: impl Fn(Vec<T>, usize) -> T,
+    combine: impl Fn(T, T) -> T,
+) -> T {
+    let size = shape.dims[dim];
+    let ranges = |range: Range<usize>| {
+        let mut ranges = shape.dims.map(|d| 0..d);
+        ranges[dim] = range;
+        ranges
+    };
+
+    let mut output = tensor;
+    let mut offset = 1;
+
+    while offset < size {
+        let head = slice(output.clone(), ranges(0..offset));
+        let current = slice(output.clone(), ranges(offset..size));
+        let previous = slice(output, ranges(0..size - offset));
+
+        output = cat(vec![head, combine(current, previous)], dim);
+        offset *= 2;
+    }
+
+    output
+}
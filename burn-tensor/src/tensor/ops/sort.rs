@@ -0,0 +1,62 @@
+use crate::{Data, Element, Shape};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Sorts the data along the given dimension, returning the sorted values and the indices of the
+/// sorted values in the original data.
+///
+/// This is used by the default implementation of the sorting operations, which reads the
+/// tensor data and sorts it on the host. Backends can provide a native implementation instead.
+///
+/// # Notes
+///
+/// The sort is stable, equal elements keep their relative order, and `NaN` values are considered
+/// greater than every other value.
+pub(crate) fn sort_data<E: Element, const D: usize>(
+    data: Data<E, D>,
+    dim: usize,
+    descending: bool,
+) -> (Data<E, D>, Data<i64, D>) {
+    let shape = data.shape.clone();
+    let dim_size = shape.dims[dim];
+    let stride: usize = shape.dims[dim + 1..].iter().product();
+    let num_lanes = shape.num_elements() / usize::max(dim_size, 1);
+
+    let mut values = data.value.clone();
+    let mut indices = alloc::vec![0i64; values.len()];
+    let mut lane: Vec<usize> = Vec::with_capacity(dim_size);
+
+    for l in 0..num_lanes {
+        let offset = (l / stride) * stride * dim_size + l % stride;
+
+        lane.clear();
+        lane.extend(0..dim_size);
+        lane.sort_by(|a, b| {
+            let a = &data.value[offset + a * stride];
+            let b = &data.value[offset + b * stride];
+
+            match descending {
+                true => compare(b, a),
+                false => compare(a, b),
+            }
+        });
+
+        for (i, index) in lane.iter().enumerate() {
+            values[offset + i * stride] = data.value[offset + index * stride];
+            indices[offset + i * stride] = *index as i64;
+        }
+    }
+
+    (
+        Data::new(values, shape.clone()),
+        Data::new(indices, Shape::new(shape.dims)),
+    )
+}
+
+fn compare<E: Element>(a: &E, b: &E) -> Ordering {
+    let a = a.to_f64().unwrap_or(f64::NAN);
+    let b = b.to_f64().unwrap_or(f64::NAN);
+
+    a.partial_cmp(&b)
+        .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+}
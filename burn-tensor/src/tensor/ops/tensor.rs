@@ -1,4 +1,7 @@
-use crate::{backend::Backend, tensor::Shape, Data, Distribution, ElementConversion};
+use super::{padding, scan::scan, sort::sort_data, PadMode};
+use crate::{
+    backend::Backend, tensor::Shape, Data, Distribution, ElementConversion, Float, Tensor,
+};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
 use core::ops::Range;
@@ -470,6 +473,98 @@ pub trait TensorOps<B: Backend> {
         dim2: usize,
     ) -> B::TensorPrimitive<D>;
 
+    /// Permutes the dimensions of a tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to permute the dimensions of.
+    /// * `axes` - The new order of the dimensions, where the dimension `i` of the output is the
+    ///   dimension `axes[i]` of the input.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the dimensions permuted.
+    fn permute<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        axes: [usize; D],
+    ) -> B::TensorPrimitive<D>;
+
+    /// Expands a tensor to the given shape.
+    ///
+    /// Backends supporting zero strides return a view sharing the data of the tensor, while the
+    /// others, such as ndarray, copy the data to the expanded shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to expand.
+    /// * `shape` - The shape to expand to. The dimensions of the tensor are aligned with the last
+    ///   dimensions of the shape, and those of size 1 are repeated to match it.
+    ///
+    /// # Returns
+    ///
+    /// The expanded tensor.
+    fn expand<const D1: usize, const D2: usize>(
+        tensor: B::TensorPrimitive<D1>,
+        shape: Shape<D2>,
+    ) -> B::TensorPrimitive<D2>;
+
+    /// Reverses the order of the elements along the given axes.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to flip.
+    /// * `axes` - The axes to flip.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the elements reversed along the given axes.
+    fn flip<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        axes: &[usize],
+    ) -> B::TensorPrimitive<D> {
+        padding::flip::<B, Float, D>(Tensor::from_primitive(tensor), axes).into_primitive()
+    }
+
+    /// Shifts the elements along the given dimensions, the elements shifted past the end of a
+    /// dimension are wrapped around to its start.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to roll.
+    /// * `shifts` - The number of places by which the elements are shifted for each dimension,
+    ///   negative values shift the elements toward the start.
+    /// * `dims` - The dimensions along which the elements are shifted.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the elements shifted.
+    fn roll<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> B::TensorPrimitive<D> {
+        padding::roll::<B, Float, D>(Tensor::from_primitive(tensor), shifts, dims).into_primitive()
+    }
+
+    /// Pads the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to pad.
+    /// * `pads` - The number of elements added before and after each dimension.
+    /// * `mode` - How the padded elements are filled.
+    ///
+    /// # Returns
+    ///
+    /// The padded tensor.
+    fn pad<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> B::TensorPrimitive<D> {
+        padding::pad::<B, Float, D>(Tensor::from_primitive(tensor), pads, mode).into_primitive()
+    }
+
     /// Reshapes a tensor.
     ///
     /// # Arguments
@@ -818,6 +913,42 @@ pub trait TensorOps<B: Backend> {
     /// A tensor with the sum of all elements in `tensor` along `dim`.
     fn sum_dim<const D: usize>(tensor: B::TensorPrimitive<D>, dim: usize) -> B::TensorPrimitive<D>;
 
+    /// Product of all elements in a tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the product of.
+    ///
+    /// # Returns
+    ///
+    /// A scalar tensor with the product of all elements in `tensor`.
+    fn prod<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<1> {
+        let shape = B::shape(&tensor);
+        let tensor = B::reshape(tensor, Shape::new([shape.num_elements()]));
+
+        B::prod_dim(tensor, 0)
+    }
+
+    /// Product of all elements in a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the product of.
+    /// * `dim` - The dimension along which to compute the product.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the product of all elements in `tensor` along `dim`.
+    fn prod_dim<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+    ) -> B::TensorPrimitive<D> {
+        let mut ranges = B::shape(&tensor).dims.map(|d| 0..d);
+        ranges[dim] = ranges[dim].end - 1..ranges[dim].end;
+
+        B::slice(B::cumprod(tensor, dim), ranges)
+    }
+
     /// Mean of all elements in a tensor.
     ///
     /// # Arguments
@@ -982,6 +1113,110 @@ pub trait TensorOps<B: Backend> {
     /// A tensor with the same shape as `tensor` with error function values.
     fn erf<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
 
+    /// Returns a new tensor with the values rounded down to the nearest integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the floor of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with floor values.
+    fn floor<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
+    /// Returns a new tensor with the values rounded up to the nearest integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the ceiling of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with ceiling values.
+    fn ceil<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
+    /// Returns a new tensor with the values rounded to the nearest integer.
+    ///
+    /// Values exactly halfway between two integers are rounded to the nearest even integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to round.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with rounded values.
+    fn round<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
+    /// Returns a new tensor with the fractional part of the values removed, rounding toward zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to truncate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with truncated values.
+    fn trunc<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
+    /// Returns a new tensor with the sign of the values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the sign of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` containing `-1` for negative values, `1` for
+    /// positive values and `0` for zeros.
+    fn sign<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        let zero = 0.elem();
+        let positive = B::bool_into_float(B::greater_elem(tensor.clone(), zero));
+        let negative = B::bool_into_float(B::lower_elem(tensor, zero));
+
+        B::sub(positive, negative)
+    }
+
+    /// Computes the remainder of the division of two tensors, element-wise.
+    ///
+    /// The result has the same sign as the divisor `rhs`, i.e. `lhs - floor(lhs / rhs) * rhs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The dividend.
+    /// * `rhs` - The divisor.
+    ///
+    /// # Returns
+    ///
+    /// The remainder of the division.
+    fn remainder<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D> {
+        let quotient = B::floor(B::div(lhs.clone(), rhs.clone()));
+        B::sub(lhs, B::mul(quotient, rhs))
+    }
+
+    /// Computes the remainder of the division of a tensor by a scalar, element-wise.
+    ///
+    /// The result has the same sign as the divisor `rhs`, i.e. `lhs - floor(lhs / rhs) * rhs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The dividend.
+    /// * `rhs` - The divisor scalar.
+    ///
+    /// # Returns
+    ///
+    /// The remainder of the division.
+    fn remainder_scalar<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: B::FloatElem,
+    ) -> B::TensorPrimitive<D> {
+        let quotient = B::floor(B::div_scalar(lhs.clone(), rhs));
+        B::sub(lhs, B::mul_scalar(quotient, rhs))
+    }
+
     /// Catcatenates tensors along a dimension.
     ///
     /// # Arguments
@@ -1130,4 +1365,278 @@ pub trait TensorOps<B: Backend> {
 
         (values, index)
     }
+
+    /// Sorts the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - If the elements should be sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with its elements sorted along `dim`.
+    fn sort<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::TensorPrimitive<D> {
+        B::sort_with_indices(tensor, dim, descending).0
+    }
+
+    /// Sorts the elements of a tensor along an axis and returns their original indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - If the elements should be sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the sorted elements of `tensor` along `dim` and their indices in `tensor`.
+    ///
+    /// # Notes
+    ///
+    /// The sort is stable and `NaN` values are considered greater than any other value. The
+    /// default implementation reads the tensor data and sorts it on the host, backends should
+    /// override it when a native implementation is available.
+    fn sort_with_indices<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (B::TensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        let device = B::device(&tensor);
+        let data = B::into_data(tensor)
+            .read_sync()
+            .expect("Sorting requires the tensor data to be readable synchronously.");
+        let (values, indices) = sort_data(data, dim, descending);
+
+        (
+            B::from_data(values, &device),
+            B::int_from_data(indices.convert(), &device),
+        )
+    }
+
+    /// Gets the indices that would sort the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - If the elements should be sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the indices that would sort `tensor` along `dim`.
+    fn argsort<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D> {
+        B::sort_with_indices(tensor, dim, descending).1
+    }
+
+    /// Gets the `k` largest elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the largest elements of.
+    /// * `dim` - The dimension along which to get the largest elements.
+    /// * `k` - The number of elements to keep.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the `k` largest elements of `tensor` along `dim`, sorted in descending order.
+    fn topk<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+        k: usize,
+    ) -> B::TensorPrimitive<D> {
+        B::topk_with_indices(tensor, dim, k).0
+    }
+
+    /// Gets the `k` largest elements of a tensor along an axis and their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the largest elements of.
+    /// * `dim` - The dimension along which to get the largest elements.
+    /// * `k` - The number of elements to keep.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the `k` largest elements of `tensor` along `dim`, sorted in descending order,
+    /// and their indices in `tensor`.
+    fn topk_with_indices<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+        k: usize,
+    ) -> (B::TensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        let mut ranges = B::shape(&tensor).dims.map(|d| 0..d);
+        ranges[dim] = 0..k;
+
+        let (values, indices) = B::sort_with_indices(tensor, dim, true);
+
+        (
+            B::slice(values, ranges.clone()),
+            B::int_slice(indices, ranges),
+        )
+    }
+
+    /// Computes the cumulative sum of the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative sum of.
+    /// * `dim` - The dimension along which to compute the cumulative sum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` where each element is the sum of all the elements
+    /// up to the same position along `dim`.
+    fn cumsum<const D: usize>(tensor: B::TensorPrimitive<D>, dim: usize) -> B::TensorPrimitive<D> {
+        let shape = B::shape(&tensor);
+
+        scan(tensor, &shape, dim, B::slice, B::cat, B::add)
+    }
+
+    /// Computes the cumulative product of the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative product of.
+    /// * `dim` - The dimension along which to compute the cumulative product.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` where each element is the product of all the
+    /// elements up to the same position along `dim`.
+    fn cumprod<const D: usize>(tensor: B::TensorPrimitive<D>, dim: usize) -> B::TensorPrimitive<D> {
+        let shape = B::shape(&tensor);
+
+        scan(tensor, &shape, dim, B::slice, B::cat, B::mul)
+    }
+
+    /// Computes the cumulative maximum of the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative maximum of.
+    /// * `dim` - The dimension along which to compute the cumulative maximum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` where each element is the maximum of all the
+    /// elements up to the same position along `dim`.
+    fn cummax<const D: usize>(tensor: B::TensorPrimitive<D>, dim: usize) -> B::TensorPrimitive<D> {
+        let shape = B::shape(&tensor);
+
+        scan(
+            tensor,
+            &shape,
+            dim,
+            B::slice,
+            B::cat,
+            |current, previous| {
+                let mask = B::lower(current.clone(), previous.clone());
+                B::mask_where(current, mask, previous)
+            },
+        )
+    }
+
+    /// Computes the cumulative maximum of the elements of a tensor along an axis and the
+    /// indices of the maximum elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative maximum of.
+    /// * `dim` - The dimension along which to compute the cumulative maximum.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the cumulative maximum of `tensor` along `dim` and the indices of the maximum
+    /// elements along `dim`. When the maximum is reached multiple times, the last index is used.
+    fn cummax_with_indices<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+    ) -> (B::TensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        let shape = B::shape(&tensor);
+        let device = B::device(&tensor);
+
+        let mut shape_indices = [1; D];
+        shape_indices[dim] = shape.dims[dim];
+        let indices = B::int_reshape(
+            B::arange(0..shape.dims[dim], &device),
+            Shape::new(shape_indices),
+        );
+        let indices = B::int_add(B::int_zeros(shape.clone(), &device), indices);
+
+        scan(
+            (tensor, indices),
+            &shape,
+            dim,
+            |(values, indices), ranges| {
+                (
+                    B::slice(values, ranges.clone()),
+                    B::int_slice(indices, ranges),
+                )
+            },
+            |tensors, dim| {
+                let (values, indices) = tensors.into_iter().unzip();
+                (B::cat(values, dim), B::int_cat(indices, dim))
+            },
+            |(values, indices), (values_previous, indices_previous)| {
+                let mask = B::lower(values.clone(), values_previous.clone());
+
+                (
+                    B::mask_where(values, mask.clone(), values_previous),
+                    B::int_mask_where(indices, mask, indices_previous),
+                )
+            },
+        )
+    }
+
+    /// Computes the logarithm of the cumulative sum of the exponentials of the elements of a
+    /// tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative log-sum-exp of.
+    /// * `dim` - The dimension along which to compute the cumulative log-sum-exp.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` where each element is `log(sum(exp(x)))` of all
+    /// the elements up to the same position along `dim`.
+    ///
+    /// # Notes
+    ///
+    /// The computation is numerically stable, it never computes the exponential of a value
+    /// greater than zero.
+    fn logcumsumexp<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+    ) -> B::TensorPrimitive<D> {
+        let shape = B::shape(&tensor);
+
+        scan(
+            tensor,
+            &shape,
+            dim,
+            B::slice,
+            B::cat,
+            |current, previous| {
+                // log(exp(a) + exp(b)) = max(a, b) + log(1 + exp(-|a - b|))
+                let equal = B::equal(current.clone(), previous.clone());
+                let mask = B::lower(current.clone(), previous.clone());
+                let diff = B::sub(current.clone(), previous.clone());
+                let diff = B::mask_fill(diff, equal, 0.elem());
+                let max = B::mask_where(current, mask, previous);
+
+                B::add(max, B::log1p(B::exp(B::neg(B::abs(diff)))))
+            },
+        )
+    }
 }
@@ -18,4 +18,15 @@ mod tests {
         data_expected.assert_approx_eq(&data_actual, 2); // Low precision to allow approximation
                                                          // implementation using tanh
     }
+
+    #[test]
+    fn test_gelu_tanh() {
+        let data = Data::from([[-3.5, -1.2, -0.3], [0.0, 0.8, 4.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = activation::gelu_tanh(tensor).to_data();
+
+        let data_expected = Data::from([[-0.0006, -0.1383, -0.1146], [0.0, 0.6304, 3.9999]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
 }
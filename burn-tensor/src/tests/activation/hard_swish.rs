@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(hard_swish)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn test_hard_swish_d2() {
+        let data = Data::from([[-3.5, -1.2, -0.3], [0.0, 0.8, 4.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = activation::hard_swish(tensor).to_data();
+
+        let data_expected = Data::from([[0.0, -0.36, -0.135], [0.0, 0.5067, 4.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
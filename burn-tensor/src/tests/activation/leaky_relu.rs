@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(leaky_relu)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn test_leaky_relu_d2() {
+        let data = Data::from([[-3.5, -1.2, -0.3], [0.0, 0.8, 4.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = activation::leaky_relu(tensor, 0.1).to_data();
+
+        let data_expected = Data::from([[-0.35, -0.12, -0.03], [0.0, 0.8, 4.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
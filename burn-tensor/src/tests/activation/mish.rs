@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(mish)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn test_mish_d2() {
+        let data = Data::from([[-3.5, -1.2, -0.3], [0.0, 0.8, 4.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = activation::mish(tensor).to_data();
+
+        let data_expected = Data::from([[-0.1041, -0.3088, -0.1511], [0.0, 0.6597, 3.9974]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
@@ -1,6 +1,15 @@
+pub(crate) mod elu;
 pub(crate) mod gelu;
+pub(crate) mod hard_sigmoid;
+pub(crate) mod hard_swish;
+pub(crate) mod leaky_relu;
+pub(crate) mod mish;
+pub(crate) mod prelu;
 pub(crate) mod relu;
+pub(crate) mod selu;
 pub(crate) mod sigmoid;
 pub(crate) mod silu;
 pub(crate) mod softmax;
+pub(crate) mod softplus;
+pub(crate) mod softsign;
 pub(crate) mod tanh_activation;
@@ -0,0 +1,29 @@
+#[burn_tensor_testgen::testgen(prelu)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn test_prelu_shared_alpha() {
+        let data = Data::from([[-3.5, -1.2, -0.3], [0.0, 0.8, 4.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+        let alpha = Tensor::<TestBackend, 1>::from_floats([0.1]);
+
+        let data_actual = activation::prelu(tensor, alpha).to_data();
+
+        let data_expected = Data::from([[-0.35, -0.12, -0.03], [0.0, 0.8, 4.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_prelu_alpha_per_channel() {
+        let data = Data::from([[-3.5, -1.2, -0.3], [0.0, 0.8, 4.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+        let alpha = Tensor::<TestBackend, 1>::from_floats([0.1, 0.2, 0.3]);
+
+        let data_actual = activation::prelu(tensor, alpha).to_data();
+
+        let data_expected = Data::from([[-0.35, -0.24, -0.09], [0.0, 0.8, 4.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(selu)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn test_selu_d2() {
+        let data = Data::from([[-3.5, -1.2, -0.3], [0.0, 0.8, 4.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = activation::selu(tensor).to_data();
+
+        let data_expected = Data::from([[-1.705, -1.2286, -0.4557], [0.0, 0.8406, 4.2028]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(softplus)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn test_softplus_d2() {
+        let data = Data::from([[-3.5, -1.2, -0.3], [0.1, 0.8, 4.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = activation::softplus(tensor, 2.0).to_data();
+
+        let data_expected = Data::from([[0.0005, 0.0434, 0.2187], [0.3991, 0.892, 4.0002]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
@@ -14,24 +14,42 @@ macro_rules! testgen_all {
         burn_tensor::testgen_sigmoid!();
         burn_tensor::testgen_silu!();
         burn_tensor::testgen_tanh_activation!();
+        burn_tensor::testgen_leaky_relu!();
+        burn_tensor::testgen_prelu!();
+        burn_tensor::testgen_elu!();
+        burn_tensor::testgen_selu!();
+        burn_tensor::testgen_softplus!();
+        burn_tensor::testgen_softsign!();
+        burn_tensor::testgen_mish!();
+        burn_tensor::testgen_hard_sigmoid!();
+        burn_tensor::testgen_hard_swish!();
 
         // test module
         burn_tensor::testgen_module_forward!();
         burn_tensor::testgen_module_conv1d!();
         burn_tensor::testgen_module_conv2d!();
+        burn_tensor::testgen_module_conv3d!();
         burn_tensor::testgen_module_conv_transpose1d!();
         burn_tensor::testgen_module_conv_transpose2d!();
+        burn_tensor::testgen_module_conv_transpose3d!();
         burn_tensor::testgen_module_unfold4d!();
         burn_tensor::testgen_module_max_pool1d!();
         burn_tensor::testgen_module_max_pool2d!();
+        burn_tensor::testgen_module_max_pool3d!();
         burn_tensor::testgen_module_avg_pool1d!();
         burn_tensor::testgen_module_avg_pool2d!();
+        burn_tensor::testgen_module_avg_pool3d!();
         burn_tensor::testgen_module_adaptive_avg_pool1d!();
         burn_tensor::testgen_module_adaptive_avg_pool2d!();
+        burn_tensor::testgen_module_adaptive_avg_pool3d!();
+        burn_tensor::testgen_module_interpolate!();
+        burn_tensor::testgen_module_attention!();
+        burn_tensor::testgen_module_segment!();
 
         // test ops
         burn_tensor::testgen_add!();
         burn_tensor::testgen_aggregation!();
+        burn_tensor::testgen_any!();
         burn_tensor::testgen_arange!();
         burn_tensor::testgen_arange_step!();
         burn_tensor::testgen_arg!();
@@ -40,14 +58,19 @@ macro_rules! testgen_all {
         burn_tensor::testgen_clamp!();
         burn_tensor::testgen_cos!();
         burn_tensor::testgen_create_like!();
+        burn_tensor::testgen_cumulative!();
         burn_tensor::testgen_div!();
+        burn_tensor::testgen_einsum!();
         burn_tensor::testgen_erf!();
         burn_tensor::testgen_exp!();
+        burn_tensor::testgen_expand!();
         burn_tensor::testgen_flatten!();
+        burn_tensor::testgen_flip!();
         burn_tensor::testgen_full!();
         burn_tensor::testgen_gather_scatter!();
         burn_tensor::testgen_init!();
         burn_tensor::testgen_iter_dim!();
+        burn_tensor::testgen_linalg!();
         burn_tensor::testgen_log!();
         burn_tensor::testgen_log1p!();
         burn_tensor::testgen_map_comparison!();
@@ -57,19 +80,27 @@ macro_rules! testgen_all {
         burn_tensor::testgen_mul!();
         burn_tensor::testgen_neg!();
         burn_tensor::testgen_one_hot!();
+        burn_tensor::testgen_pad!();
+        burn_tensor::testgen_permute!();
         burn_tensor::testgen_powf!();
         burn_tensor::testgen_random!();
+        burn_tensor::testgen_remainder!();
         burn_tensor::testgen_repeat!();
         burn_tensor::testgen_reshape!();
+        burn_tensor::testgen_roll!();
+        burn_tensor::testgen_rounding!();
         burn_tensor::testgen_select!();
+        burn_tensor::testgen_sign!();
         burn_tensor::testgen_sin!();
         burn_tensor::testgen_slice!();
+        burn_tensor::testgen_sort!();
         burn_tensor::testgen_sqrt!();
         burn_tensor::testgen_abs!();
         burn_tensor::testgen_squeeze!();
         burn_tensor::testgen_sub!();
         burn_tensor::testgen_tanh!();
         burn_tensor::testgen_transpose!();
+        burn_tensor::testgen_triangular!();
 
         // test stats
         burn_tensor::testgen_var!();
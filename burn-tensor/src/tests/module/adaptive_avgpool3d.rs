@@ -0,0 +1,85 @@
+#[burn_tensor_testgen::testgen(module_adaptive_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_avg_pool3d;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_adaptive_avg_pool3d_simple() {
+        let test = AdaptiveAvgPool3dTestCase {
+            batch_size: 1,
+            channels: 2,
+            depth: 4,
+            height: 4,
+            width: 4,
+            depth_out: 2,
+            height_out: 2,
+            width_out: 2,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [[[10.5, 12.5], [18.5, 20.5]], [[42.5, 44.5], [50.5, 52.5]]],
+            [
+                [[74.5, 76.5], [82.5, 84.5]],
+                [[106.5, 108.5], [114.5, 116.5]],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_adaptive_avg_pool3d_dyn_filter_size() {
+        let test = AdaptiveAvgPool3dTestCase {
+            batch_size: 1,
+            channels: 2,
+            depth: 3,
+            height: 5,
+            width: 4,
+            depth_out: 2,
+            height_out: 3,
+            width_out: 2,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [[12.5, 14.5], [18.5, 20.5], [24.5, 26.5]],
+                [[32.5, 34.5], [38.5, 40.5], [44.5, 46.5]],
+            ],
+            [
+                [[72.5, 74.5], [78.5, 80.5], [84.5, 86.5]],
+                [[92.5, 94.5], [98.5, 100.5], [104.5, 106.5]],
+            ],
+        ]]));
+    }
+
+    struct AdaptiveAvgPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+        depth_out: usize,
+        height_out: usize,
+        width_out: usize,
+    }
+
+    impl AdaptiveAvgPool3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let x = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = adaptive_avg_pool3d(x, [self.depth_out, self.height_out, self.width_out]);
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
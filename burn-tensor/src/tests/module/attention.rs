@@ -0,0 +1,166 @@
+#[burn_tensor_testgen::testgen(module_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::scaled_dot_product_attention;
+    use burn_tensor::ops::AttentionOptions;
+    use burn_tensor::{activation, Bool, Data, Distribution, Shape, Tensor};
+
+    #[test]
+    fn test_scaled_dot_product_attention() {
+        let test = AttentionTestCase::new();
+
+        let output =
+            scaled_dot_product_attention(test.query, test.key, test.value, None, None, options(2));
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[
+                [[0.8560, 0.5641], [0.9202, 0.6446]],
+                [[0.7635, 0.4957], [0.6063, 0.5619]],
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention_with_broadcasted_bias_and_mask() {
+        let test = AttentionTestCase::new();
+        let bias = TestTensor::from_floats([[
+            [[0.0, -1.0, -2.0], [-1.0, 0.0, -1.0]],
+            [[0.0, -0.5, -1.0], [-0.5, 0.0, -0.5]],
+        ]]);
+        let mask = Tensor::<TestBackend, 4, Bool>::from_data(Data::from([[[
+            [false, true, true],
+            [false, false, true],
+        ]]]));
+
+        let output = scaled_dot_product_attention(
+            test.query,
+            test.key,
+            test.value,
+            Some(bias),
+            Some(mask),
+            options(2),
+        );
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[
+                [[1.0, 0.0], [0.4273, 0.5727]],
+                [[-1.0, 1.0], [-0.1952, 0.7317]],
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention_long_sequences() {
+        let [batch_size, n_heads, seq_length_1, seq_length_2, d_k, d_v] = [2, 3, 37, 75, 8, 5];
+        let query = TestTensor::random(
+            [batch_size, n_heads, seq_length_1, d_k],
+            Distribution::Default,
+        );
+        let key = TestTensor::random(
+            [batch_size, n_heads, seq_length_2, d_k],
+            Distribution::Default,
+        );
+        let value = TestTensor::random(
+            [batch_size, n_heads, seq_length_2, d_v],
+            Distribution::Default,
+        );
+        let bias = TestTensor::random(
+            [1, n_heads, seq_length_1, seq_length_2],
+            Distribution::Default,
+        );
+        let mask = TestTensor::random(
+            [batch_size, 1, seq_length_1, seq_length_2],
+            Distribution::Default,
+        )
+        .lower_elem(0.3);
+
+        let output = scaled_dot_product_attention(
+            query.clone(),
+            key.clone(),
+            value.clone(),
+            Some(bias.clone()),
+            Some(mask.clone()),
+            options(d_k),
+        );
+
+        let scores = query
+            .matmul(key.transpose())
+            .div_scalar((d_k as f32).sqrt())
+            .add(bias)
+            .mask_fill(mask, -1.0e4);
+        let expected = activation::softmax(scores, 3).matmul(value);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention_with_dropout() {
+        let shape_scores = [2, 3, 37, 75];
+        let query = TestTensor::random([2, 3, 37, 8], Distribution::Default);
+        let key = TestTensor::random([2, 3, 75, 8], Distribution::Default);
+        let value = TestTensor::random([2, 3, 75, 5], Distribution::Default);
+        let mask = TestTensor::random([2, 1, 37, 75], Distribution::Default).lower_elem(0.3);
+        let options = options(8).with_dropout(0.25, 42);
+
+        let output = scaled_dot_product_attention(
+            query.clone(),
+            key.clone(),
+            value.clone(),
+            None,
+            Some(mask.clone()),
+            options.clone(),
+        );
+
+        let num_scores = shape_scores.iter().product();
+        let factors = (0..num_scores)
+            .map(|index| options.dropout_factor(index) as f32)
+            .collect::<Vec<_>>();
+        let num_dropped = factors.iter().filter(|factor| **factor == 0.0).count();
+        assert!(num_dropped > num_scores / 5 && num_dropped < num_scores * 3 / 10);
+        let factors = TestTensor::from_data(Data::new(factors, Shape::new(shape_scores)).convert());
+
+        let scores = query
+            .matmul(key.transpose())
+            .div_scalar(8.0f32.sqrt())
+            .mul(factors)
+            .mask_fill(mask, -1.0e4);
+        let expected = activation::softmax(scores, 3).matmul(value);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    struct AttentionTestCase {
+        query: TestTensor<4>,
+        key: TestTensor<4>,
+        value: TestTensor<4>,
+    }
+
+    impl AttentionTestCase {
+        fn new() -> Self {
+            Self {
+                query: TestTensor::from_floats([[
+                    [[1.0, 0.0], [0.0, 1.0]],
+                    [[0.5, -0.5], [1.0, 1.0]],
+                ]]),
+                key: TestTensor::from_floats([[
+                    [[1.0, 2.0], [0.0, 1.0], [-1.0, 0.5]],
+                    [[0.2, 0.3], [1.0, -1.0], [0.0, 0.0]],
+                ]]),
+                value: TestTensor::from_floats([[
+                    [[1.0, 0.0], [0.0, 1.0], [2.0, 2.0]],
+                    [[-1.0, 1.0], [0.5, 0.5], [3.0, 0.0]],
+                ]]),
+            }
+        }
+    }
+
+    fn options(d_k: usize) -> AttentionOptions {
+        AttentionOptions::new(1.0 / (d_k as f64).sqrt(), -1.0e4)
+    }
+}
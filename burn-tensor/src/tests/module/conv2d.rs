@@ -69,6 +69,45 @@ mod tests {
         ]]));
     }
 
+    #[test]
+    fn test_conv2d_groups_multiple_channels_per_group() {
+        let test = Conv2dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 4,
+            kernel_size_1: 3,
+            kernel_size_2: 3,
+            padding_1: 0,
+            padding_2: 0,
+            stride_1: 1,
+            stride_2: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            groups: 2,
+            height: 5,
+            width: 5,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [[312., 348., 384.], [492., 528., 564.], [672., 708., 744.]],
+            [
+                [799., 916., 1033.],
+                [1384., 1501., 1618.],
+                [1969., 2086., 2203.],
+            ],
+            [
+                [6236., 6434., 6632.],
+                [7226., 7424., 7622.],
+                [8216., 8414., 8612.],
+            ],
+            [
+                [8748., 9027., 9306.],
+                [10143., 10422., 10701.],
+                [11538., 11817., 12096.],
+            ],
+        ]]));
+    }
+
     #[test]
     fn test_conv2d_complex() {
         let test = Conv2dTestCase {
@@ -0,0 +1,184 @@
+#[burn_tensor_testgen::testgen(module_conv3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::conv3d;
+    use burn_tensor::ops::ConvOptions;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_conv3d_simple() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 3,
+            kernel_size_2: 3,
+            kernel_size_3: 3,
+            padding_1: 1,
+            padding_2: 1,
+            padding_3: 1,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 1,
+            depth: 3,
+            height: 3,
+            width: 3,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [
+                    [13840., 20920., 14032.],
+                    [21264., 32082., 21480.],
+                    [14320., 21568., 14416.],
+                ],
+                [
+                    [22488., 33810., 22560.],
+                    [34002., 51039., 34002.],
+                    [22560., 33810., 22488.],
+                ],
+                [
+                    [14416., 21568., 14320.],
+                    [21480., 32082., 21264.],
+                    [14032., 20920., 13840.],
+                ],
+            ],
+            [
+                [
+                    [31121., 47489., 32177.],
+                    [49129., 74851., 50641.],
+                    [34193., 52025., 35153.],
+                ],
+                [
+                    [54241., 82411., 55609.],
+                    [84547., 128314., 86491.],
+                    [58201., 88243., 59425.],
+                ],
+                [
+                    [39473., 59801., 40241.],
+                    [61009., 92347., 62089.],
+                    [41681., 63041., 42353.],
+                ],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv3d_complex() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 4,
+            kernel_size_1: 2,
+            kernel_size_2: 3,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 0,
+            padding_3: 1,
+            stride_1: 2,
+            stride_2: 1,
+            stride_3: 2,
+            dilation_1: 1,
+            dilation_2: 2,
+            dilation_3: 1,
+            groups: 2,
+            depth: 3,
+            height: 6,
+            width: 3,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [[186., 432.], [267., 585.]],
+                [[1398., 2700.], [1506., 2898.]],
+            ],
+            [
+                [[403., 973.], [592., 1342.]],
+                [[3775., 7669.], [4099., 8299.]],
+            ],
+            [
+                [[5966., 12044.], [6263., 12629.]],
+                [[15872., 31754.], [16412., 32816.]],
+            ],
+            [
+                [[8127., 16473.], [8532., 17274.]],
+                [[22137., 44499.], [22893., 45993.]],
+            ],
+        ]]));
+    }
+
+    struct Conv3dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        kernel_size_3: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_3: usize,
+        stride_1: usize,
+        stride_2: usize,
+        stride_3: usize,
+        dilation_1: usize,
+        dilation_2: usize,
+        dilation_3: usize,
+        groups: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+    }
+
+    impl Conv3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels_in,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let shape_weight = Shape::new([
+                self.channels_out,
+                self.channels_in / self.groups,
+                self.kernel_size_1,
+                self.kernel_size_2,
+                self.kernel_size_3,
+            ]);
+            let weight = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_weight.num_elements())
+                    .reshape(shape_weight)
+                    .into_data()
+                    .convert(),
+            );
+            let bias = TestTensor::from_data(
+                TestTensorInt::arange(0..self.channels_out)
+                    .into_data()
+                    .convert(),
+            );
+            let x = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = conv3d(
+                x,
+                weight,
+                Some(bias),
+                ConvOptions::new(
+                    [self.stride_1, self.stride_2, self.stride_3],
+                    [self.padding_1, self.padding_2, self.padding_3],
+                    [self.dilation_1, self.dilation_2, self.dilation_3],
+                    self.groups,
+                ),
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
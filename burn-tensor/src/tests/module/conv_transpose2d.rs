@@ -211,6 +211,35 @@ mod tests {
         ]]));
     }
 
+    #[test]
+    fn test_conv_transpose2d_groups_multiple_channels_per_group() {
+        let test = ConvTranspose2dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 4,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            padding_1: 0,
+            padding_2: 0,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            stride_1: 1,
+            stride_2: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            groups: 2,
+            height: 2,
+            width: 2,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [[0., 0., 1.], [0., 4., 6.], [4., 12., 9.]],
+            [[1., 5., 6.], [9., 29., 23.], [13., 33., 22.]],
+            [[34., 78., 47.], [90., 206., 120.], [62., 138., 79.]],
+            [[51., 115., 68.], [131., 295., 169.], [87., 191., 108.]],
+        ]]));
+    }
+
     #[test]
     fn test_conv_transpose2d_groups_different_channels() {
         let test = ConvTranspose2dTestCase {
@@ -0,0 +1,266 @@
+#[burn_tensor_testgen::testgen(module_conv_transpose3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::conv_transpose3d;
+    use burn_tensor::ops::ConvTransposeOptions;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_conv_transpose3d_simple() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 0,
+            padding_2: 0,
+            padding_3: 0,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            padding_out_3: 0,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 1,
+            depth: 2,
+            height: 2,
+            width: 2,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [[128., 280., 154.], [304., 664., 364.], [184., 400., 218.]],
+                [[352., 768., 420.], [832., 1808., 984.], [496., 1072., 580.]],
+                [[256., 552., 298.], [592., 1272., 684.], [344., 736., 394.]],
+            ],
+            [
+                [[193., 425., 235.], [465., 1017., 557.], [281., 609., 331.]],
+                [
+                    [545., 1185., 645.],
+                    [1281., 2769., 1497.],
+                    [753., 1617., 869.],
+                ],
+                [
+                    [385., 825., 443.],
+                    [881., 1881., 1005.],
+                    [505., 1073., 571.],
+                ],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv_transpose3d_complex() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 4,
+            kernel_size_1: 2,
+            kernel_size_2: 3,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 0,
+            padding_3: 1,
+            padding_out_1: 1,
+            padding_out_2: 0,
+            padding_out_3: 0,
+            stride_1: 2,
+            stride_2: 1,
+            stride_3: 2,
+            dilation_1: 1,
+            dilation_2: 2,
+            dilation_3: 1,
+            groups: 2,
+            depth: 2,
+            height: 2,
+            width: 2,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [
+                    [0., 6.],
+                    [14., 18.],
+                    [0., 8.],
+                    [18., 24.],
+                    [0., 10.],
+                    [22., 30.],
+                ],
+                [
+                    [4., 0.],
+                    [6., 0.],
+                    [12., 10.],
+                    [18., 14.],
+                    [20., 20.],
+                    [30., 28.],
+                ],
+                [
+                    [28., 30.],
+                    [42., 42.],
+                    [36., 40.],
+                    [54., 56.],
+                    [44., 50.],
+                    [66., 70.],
+                ],
+            ],
+            [
+                [
+                    [1., 19.],
+                    [39., 55.],
+                    [1., 21.],
+                    [43., 61.],
+                    [1., 23.],
+                    [47., 67.],
+                ],
+                [
+                    [53., 61.],
+                    [79., 85.],
+                    [61., 71.],
+                    [91., 99.],
+                    [69., 81.],
+                    [103., 113.],
+                ],
+                [
+                    [77., 91.],
+                    [115., 127.],
+                    [85., 101.],
+                    [127., 141.],
+                    [93., 111.],
+                    [139., 155.],
+                ],
+            ],
+            [
+                [
+                    [250., 272.],
+                    [312., 332.],
+                    [266., 290.],
+                    [332., 354.],
+                    [282., 308.],
+                    [352., 376.],
+                ],
+                [
+                    [302., 314.],
+                    [352., 362.],
+                    [326., 340.],
+                    [380., 392.],
+                    [350., 366.],
+                    [408., 422.],
+                ],
+                [
+                    [374., 392.],
+                    [436., 452.],
+                    [398., 418.],
+                    [464., 482.],
+                    [422., 444.],
+                    [492., 512.],
+                ],
+            ],
+            [
+                [
+                    [347., 381.],
+                    [433., 465.],
+                    [363., 399.],
+                    [453., 487.],
+                    [379., 417.],
+                    [473., 509.],
+                ],
+                [
+                    [447., 471.],
+                    [521., 543.],
+                    [471., 497.],
+                    [549., 573.],
+                    [495., 523.],
+                    [577., 603.],
+                ],
+                [
+                    [519., 549.],
+                    [605., 633.],
+                    [543., 575.],
+                    [633., 663.],
+                    [567., 601.],
+                    [661., 693.],
+                ],
+            ],
+        ]]));
+    }
+
+    struct ConvTranspose3dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        kernel_size_3: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_3: usize,
+        padding_out_1: usize,
+        padding_out_2: usize,
+        padding_out_3: usize,
+        stride_1: usize,
+        stride_2: usize,
+        stride_3: usize,
+        dilation_1: usize,
+        dilation_2: usize,
+        dilation_3: usize,
+        groups: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+    }
+
+    impl ConvTranspose3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels_in,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let shape_weights = Shape::new([
+                self.channels_in,
+                self.channels_out / self.groups,
+                self.kernel_size_1,
+                self.kernel_size_2,
+                self.kernel_size_3,
+            ]);
+            let weights = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_weights.num_elements())
+                    .reshape(shape_weights)
+                    .into_data()
+                    .convert(),
+            );
+            let bias = TestTensor::from_data(
+                TestTensorInt::arange(0..self.channels_out)
+                    .into_data()
+                    .convert(),
+            );
+            let x = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = conv_transpose3d(
+                x,
+                weights,
+                Some(bias),
+                ConvTransposeOptions::new(
+                    [self.stride_1, self.stride_2, self.stride_3],
+                    [self.padding_1, self.padding_2, self.padding_3],
+                    [self.padding_out_1, self.padding_out_2, self.padding_out_3],
+                    [self.dilation_1, self.dilation_2, self.dilation_3],
+                    self.groups,
+                ),
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
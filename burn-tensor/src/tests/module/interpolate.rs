@@ -0,0 +1,181 @@
+#[burn_tensor_testgen::testgen(module_interpolate)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::interpolate;
+    use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_interpolate_nearest_upsample() {
+        let test = InterpolateTestCase {
+            height: 2,
+            width: 3,
+            height_out: 4,
+            width_out: 5,
+            mode: InterpolateMode::Nearest,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [0.0000, 0.0000, 1.0000, 1.0000, 2.0000],
+            [0.0000, 0.0000, 1.0000, 1.0000, 2.0000],
+            [3.0000, 3.0000, 4.0000, 4.0000, 5.0000],
+            [3.0000, 3.0000, 4.0000, 4.0000, 5.0000],
+        ]]]));
+    }
+
+    #[test]
+    fn test_interpolate_nearest_downsample() {
+        let test = InterpolateTestCase {
+            height: 4,
+            width: 6,
+            height_out: 3,
+            width_out: 4,
+            mode: InterpolateMode::Nearest,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [0.0000, 1.0000, 3.0000, 4.0000],
+            [6.0000, 7.0000, 9.0000, 10.0000],
+            [12.0000, 13.0000, 15.0000, 16.0000],
+        ]]]));
+    }
+
+    #[test]
+    fn test_interpolate_bilinear() {
+        let test = InterpolateTestCase {
+            height: 3,
+            width: 3,
+            height_out: 5,
+            width_out: 4,
+            mode: InterpolateMode::Bilinear,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [0.0000, 0.6250, 1.3750, 2.0000],
+            [1.2000, 1.8250, 2.5750, 3.2000],
+            [3.0000, 3.6250, 4.3750, 5.0000],
+            [4.8000, 5.4250, 6.1750, 6.8000],
+            [6.0000, 6.6250, 7.3750, 8.0000],
+        ]]]));
+    }
+
+    #[test]
+    fn test_interpolate_bilinear_align_corners() {
+        let test = InterpolateTestCase {
+            height: 3,
+            width: 3,
+            height_out: 5,
+            width_out: 4,
+            mode: InterpolateMode::Bilinear,
+            align_corners: true,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [0.0000, 0.6667, 1.3333, 2.0000],
+            [1.5000, 2.1667, 2.8333, 3.5000],
+            [3.0000, 3.6667, 4.3333, 5.0000],
+            [4.5000, 5.1667, 5.8333, 6.5000],
+            [6.0000, 6.6667, 7.3333, 8.0000],
+        ]]]));
+    }
+
+    #[test]
+    fn test_interpolate_bilinear_downsample() {
+        let test = InterpolateTestCase {
+            height: 3,
+            width: 4,
+            height_out: 2,
+            width_out: 3,
+            mode: InterpolateMode::Bilinear,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [1.1667, 2.5000, 3.8333],
+            [7.1667, 8.5000, 9.8333],
+        ]]]));
+    }
+
+    #[test]
+    fn test_interpolate_bicubic() {
+        let test = InterpolateTestCase {
+            height: 3,
+            width: 3,
+            height_out: 5,
+            width_out: 4,
+            mode: InterpolateMode::Bicubic,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [-0.3598, 0.2418, 1.1822, 1.7838],
+            [0.8762, 1.4778, 2.4182, 3.0198],
+            [2.9282, 3.5298, 4.4702, 5.0718],
+            [4.9802, 5.5818, 6.5222, 7.1238],
+            [6.2162, 6.8178, 7.7582, 8.3598],
+        ]]]));
+    }
+
+    #[test]
+    fn test_interpolate_bicubic_align_corners() {
+        let test = InterpolateTestCase {
+            height: 3,
+            width: 3,
+            height_out: 5,
+            width_out: 4,
+            mode: InterpolateMode::Bicubic,
+            align_corners: true,
+        };
+
+        test.assert_output(TestTensor::from_floats([[[
+            [0.0000, 0.5741, 1.4259, 2.0000],
+            [1.2188, 1.7928, 2.6447, 3.2188],
+            [3.0000, 3.5741, 4.4259, 5.0000],
+            [4.7812, 5.3553, 6.2072, 6.7812],
+            [6.0000, 6.5741, 7.4259, 8.0000],
+        ]]]));
+    }
+
+    #[test]
+    fn test_interpolate_batch_and_channels() {
+        let x =
+            TestTensor::from_floats([[[[1.0, 2.0]], [[3.0, 4.0]]], [[[5.0, 6.0]], [[7.0, 8.0]]]]);
+        let options = InterpolateOptions::new(InterpolateMode::Nearest, false);
+
+        let output = interpolate(x, [2, 2], options);
+
+        let y = Data::from([
+            [[[1.0, 2.0], [1.0, 2.0]], [[3.0, 4.0], [3.0, 4.0]]],
+            [[[5.0, 6.0], [5.0, 6.0]], [[7.0, 8.0], [7.0, 8.0]]],
+        ]);
+        y.assert_approx_eq(&output.into_data(), 3);
+    }
+
+    struct InterpolateTestCase {
+        height: usize,
+        width: usize,
+        height_out: usize,
+        width_out: usize,
+        mode: InterpolateMode,
+        align_corners: bool,
+    }
+
+    impl InterpolateTestCase {
+        fn assert_output(self, y: TestTensor<4>) {
+            let shape_x = Shape::new([1, 1, self.height, self.width]);
+            let x = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let options = InterpolateOptions::new(self.mode, self.align_corners);
+            let output = interpolate(x, [self.height_out, self.width_out], options);
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
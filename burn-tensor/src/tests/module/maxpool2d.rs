@@ -274,6 +274,18 @@ mod tests {
         assert_eq!(indices.value, output_indices.into_data().value);
     }
 
+    #[test]
+    fn test_max_pool2d_with_indices_non_square() {
+        let x = TestTensor::from_floats([[[[1.0, 5.0, 2.0, 0.0], [3.0, 4.0, 8.0, 6.0]]]]);
+        let indices = Data::<IntElem, 4>::from([[[[1, 6, 6]]]]);
+        let y = TestTensor::from_floats([[[[5.0, 8.0, 8.0]]]]);
+
+        let (output, output_indices) = max_pool2d_with_indices(x, [2, 2], [1, 1], [0, 0], [1, 1]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        assert_eq!(indices.value, output_indices.into_data().value);
+    }
+
     #[test]
     fn test_max_pool2d_complex() {
         let batch_size = 1;
@@ -0,0 +1,227 @@
+#[burn_tensor_testgen::testgen(module_max_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{max_pool3d, max_pool3d_with_indices};
+    use burn_tensor::{backend::Backend, Data, Tensor};
+
+    type IntElem = <TestBackend as Backend>::IntElem;
+
+    #[test]
+    fn test_max_pool3d_simple() {
+        let kernel_size = [2, 2, 2];
+        let stride = [1, 2, 2];
+        let padding = [1, 0, 1];
+        let dilation = [1, 1, 1];
+
+        let x = TestTensor::from_floats([[
+            [
+                [
+                    [0.2380, 0.5442, 0.3700, 0.6039],
+                    [0.6257, 0.0655, 0.0132, 0.8375],
+                    [0.2594, 0.2343, 0.9956, 0.4703],
+                    [0.8365, 0.4764, 0.6391, 0.1506],
+                ],
+                [
+                    [0.6349, 0.8680, 0.5232, 0.7413],
+                    [0.6714, 0.0640, 0.7582, 0.5911],
+                    [0.3013, 0.0310, 0.8655, 0.4727],
+                    [0.7188, 0.8788, 0.7141, 0.9211],
+                ],
+                [
+                    [0.3950, 0.8009, 0.4446, 0.9356],
+                    [0.8789, 0.0975, 0.1360, 0.2170],
+                    [0.9655, 0.4362, 0.6266, 0.3012],
+                    [0.5072, 0.3859, 0.3509, 0.5851],
+                ],
+            ],
+            [
+                [
+                    [0.5843, 0.9042, 0.6820, 0.9289],
+                    [0.8564, 0.9910, 0.6713, 0.1631],
+                    [0.8606, 0.9646, 0.9047, 0.5691],
+                    [0.7138, 0.2111, 0.8316, 0.5735],
+                ],
+                [
+                    [0.2850, 0.0635, 0.8539, 0.9898],
+                    [0.0885, 0.8006, 0.4105, 0.1508],
+                    [0.2939, 0.7688, 0.8728, 0.0442],
+                    [0.6145, 0.0449, 0.7184, 0.3310],
+                ],
+                [
+                    [0.8809, 0.9806, 0.5054, 0.9985],
+                    [0.3097, 0.0770, 0.5998, 0.0314],
+                    [0.1974, 0.4079, 0.6105, 0.1562],
+                    [0.0424, 0.8678, 0.3138, 0.9587],
+                ],
+            ],
+        ]]);
+        let y = TestTensor::from_floats([[
+            [
+                [[0.6257, 0.5442, 0.8375], [0.8365, 0.9956, 0.4703]],
+                [[0.6714, 0.8680, 0.8375], [0.8365, 0.9956, 0.9211]],
+                [[0.8789, 0.8680, 0.9356], [0.9655, 0.8788, 0.9211]],
+                [[0.8789, 0.8009, 0.9356], [0.9655, 0.6266, 0.5851]],
+            ],
+            [
+                [[0.8564, 0.9910, 0.9289], [0.8606, 0.9646, 0.5735]],
+                [[0.8564, 0.9910, 0.9898], [0.8606, 0.9646, 0.5735]],
+                [[0.8809, 0.9806, 0.9985], [0.6145, 0.8728, 0.9587]],
+                [[0.8809, 0.9806, 0.9985], [0.1974, 0.8678, 0.9587]],
+            ],
+        ]]);
+
+        let output = max_pool3d(x, kernel_size, stride, padding, dilation);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool3d_with_indices() {
+        let kernel_size = [2, 2, 2];
+        let stride = [1, 2, 2];
+        let padding = [1, 0, 1];
+        let dilation = [1, 1, 1];
+
+        let x = TestTensor::from_floats([[
+            [
+                [
+                    [0.2380, 0.5442, 0.3700, 0.6039],
+                    [0.6257, 0.0655, 0.0132, 0.8375],
+                    [0.2594, 0.2343, 0.9956, 0.4703],
+                    [0.8365, 0.4764, 0.6391, 0.1506],
+                ],
+                [
+                    [0.6349, 0.8680, 0.5232, 0.7413],
+                    [0.6714, 0.0640, 0.7582, 0.5911],
+                    [0.3013, 0.0310, 0.8655, 0.4727],
+                    [0.7188, 0.8788, 0.7141, 0.9211],
+                ],
+                [
+                    [0.3950, 0.8009, 0.4446, 0.9356],
+                    [0.8789, 0.0975, 0.1360, 0.2170],
+                    [0.9655, 0.4362, 0.6266, 0.3012],
+                    [0.5072, 0.3859, 0.3509, 0.5851],
+                ],
+            ],
+            [
+                [
+                    [0.5843, 0.9042, 0.6820, 0.9289],
+                    [0.8564, 0.9910, 0.6713, 0.1631],
+                    [0.8606, 0.9646, 0.9047, 0.5691],
+                    [0.7138, 0.2111, 0.8316, 0.5735],
+                ],
+                [
+                    [0.2850, 0.0635, 0.8539, 0.9898],
+                    [0.0885, 0.8006, 0.4105, 0.1508],
+                    [0.2939, 0.7688, 0.8728, 0.0442],
+                    [0.6145, 0.0449, 0.7184, 0.3310],
+                ],
+                [
+                    [0.8809, 0.9806, 0.5054, 0.9985],
+                    [0.3097, 0.0770, 0.5998, 0.0314],
+                    [0.1974, 0.4079, 0.6105, 0.1562],
+                    [0.0424, 0.8678, 0.3138, 0.9587],
+                ],
+            ],
+        ]]);
+        let y = TestTensor::from_floats([[
+            [
+                [[0.6257, 0.5442, 0.8375], [0.8365, 0.9956, 0.4703]],
+                [[0.6714, 0.8680, 0.8375], [0.8365, 0.9956, 0.9211]],
+                [[0.8789, 0.8680, 0.9356], [0.9655, 0.8788, 0.9211]],
+                [[0.8789, 0.8009, 0.9356], [0.9655, 0.6266, 0.5851]],
+            ],
+            [
+                [[0.8564, 0.9910, 0.9289], [0.8606, 0.9646, 0.5735]],
+                [[0.8564, 0.9910, 0.9898], [0.8606, 0.9646, 0.5735]],
+                [[0.8809, 0.9806, 0.9985], [0.6145, 0.8728, 0.9587]],
+                [[0.8809, 0.9806, 0.9985], [0.1974, 0.8678, 0.9587]],
+            ],
+        ]]);
+        let indices = Data::<IntElem, 5>::from([[
+            [
+                [[4, 1, 7], [12, 10, 11]],
+                [[20, 17, 7], [12, 10, 31]],
+                [[36, 17, 35], [40, 29, 31]],
+                [[36, 33, 35], [40, 42, 47]],
+            ],
+            [
+                [[4, 5, 3], [8, 9, 15]],
+                [[4, 5, 19], [8, 9, 15]],
+                [[32, 33, 35], [28, 26, 47]],
+                [[32, 33, 35], [40, 45, 47]],
+            ],
+        ]]);
+
+        let (output, output_indices) =
+            max_pool3d_with_indices(x, kernel_size, stride, padding, dilation);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        assert_eq!(indices.value, output_indices.into_data().value);
+    }
+
+    #[test]
+    fn test_max_pool3d_with_indices_dilation() {
+        let kernel_size = [2, 2, 2];
+        let stride = [1, 1, 1];
+        let padding = [0, 0, 0];
+        let dilation = [2, 1, 2];
+
+        let x = TestTensor::from_floats([[
+            [
+                [
+                    [0.2380, 0.5442, 0.3700, 0.6039],
+                    [0.6257, 0.0655, 0.0132, 0.8375],
+                    [0.2594, 0.2343, 0.9956, 0.4703],
+                    [0.8365, 0.4764, 0.6391, 0.1506],
+                ],
+                [
+                    [0.6349, 0.8680, 0.5232, 0.7413],
+                    [0.6714, 0.0640, 0.7582, 0.5911],
+                    [0.3013, 0.0310, 0.8655, 0.4727],
+                    [0.7188, 0.8788, 0.7141, 0.9211],
+                ],
+                [
+                    [0.3950, 0.8009, 0.4446, 0.9356],
+                    [0.8789, 0.0975, 0.1360, 0.2170],
+                    [0.9655, 0.4362, 0.6266, 0.3012],
+                    [0.5072, 0.3859, 0.3509, 0.5851],
+                ],
+            ],
+            [
+                [
+                    [0.5843, 0.9042, 0.6820, 0.9289],
+                    [0.8564, 0.9910, 0.6713, 0.1631],
+                    [0.8606, 0.9646, 0.9047, 0.5691],
+                    [0.7138, 0.2111, 0.8316, 0.5735],
+                ],
+                [
+                    [0.2850, 0.0635, 0.8539, 0.9898],
+                    [0.0885, 0.8006, 0.4105, 0.1508],
+                    [0.2939, 0.7688, 0.8728, 0.0442],
+                    [0.6145, 0.0449, 0.7184, 0.3310],
+                ],
+                [
+                    [0.8809, 0.9806, 0.5054, 0.9985],
+                    [0.3097, 0.0770, 0.5998, 0.0314],
+                    [0.1974, 0.4079, 0.6105, 0.1562],
+                    [0.0424, 0.8678, 0.3138, 0.9587],
+                ],
+            ],
+        ]]);
+        let y = TestTensor::from_floats([[
+            [[[0.8789, 0.9356], [0.9956, 0.8375], [0.9956, 0.5851]]],
+            [[[0.8809, 0.9985], [0.9047, 0.9910], [0.9047, 0.9646]]],
+        ]]);
+        let indices = Data::<IntElem, 5>::from([[
+            [[[36, 35], [10, 7], [10, 47]]],
+            [[[32, 35], [10, 5], [10, 9]]],
+        ]]);
+
+        let (output, output_indices) =
+            max_pool3d_with_indices(x, kernel_size, stride, padding, dilation);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        assert_eq!(indices.value, output_indices.into_data().value);
+    }
+}
@@ -1,12 +1,20 @@
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod adaptive_avgpool3d;
+mod attention;
 mod avgpool1d;
 mod avgpool2d;
+mod avgpool3d;
 mod conv1d;
 mod conv2d;
+mod conv3d;
 mod conv_transpose1d;
 mod conv_transpose2d;
+mod conv_transpose3d;
 mod forward;
+mod interpolate;
 mod maxpool1d;
 mod maxpool2d;
+mod maxpool3d;
+mod segment;
 mod unfold4d;
@@ -0,0 +1,85 @@
+#[burn_tensor_testgen::testgen(module_segment)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::segment_reduce;
+    use burn_tensor::ops::SegmentReduction;
+    use burn_tensor::{Data, Distribution, Int, Tensor};
+
+    #[test]
+    fn test_segment_reduce_sum() {
+        let test = SegmentTestCase::new();
+
+        let output = segment_reduce(test.tensor, test.offsets, SegmentReduction::Sum);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[4.0, 1.0], [0.0, 0.0], [4.0, 9.0], [0.0, 0.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_segment_reduce_mean() {
+        let test = SegmentTestCase::new();
+
+        let output = segment_reduce(test.tensor, test.offsets, SegmentReduction::Mean);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[2.0, 0.5], [0.0, 0.0], [1.3333, 3.0], [0.0, 0.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_segment_reduce_max() {
+        let test = SegmentTestCase::new();
+
+        let output = segment_reduce(test.tensor, test.offsets, SegmentReduction::Max);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[3.0, 2.0], [0.0, 0.0], [6.0, 5.0], [0.0, 0.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_segment_reduce_max_long_segments() {
+        let tensor = TestTensor::random([37, 3], Distribution::Default);
+        let offsets = Tensor::<TestBackend, 1, Int>::from_ints([0, 1, 20]);
+
+        let output = segment_reduce(tensor.clone(), offsets, SegmentReduction::Max);
+
+        let max = |range| {
+            tensor
+                .clone()
+                .slice([range])
+                .transpose()
+                .max_dim(1)
+                .transpose()
+        };
+        let expected = Tensor::cat(vec![max(0..1), max(1..20), max(20..37)], 0);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    struct SegmentTestCase {
+        tensor: TestTensor<2>,
+        offsets: Tensor<TestBackend, 1, Int>,
+    }
+
+    impl SegmentTestCase {
+        fn new() -> Self {
+            Self {
+                tensor: TestTensor::from_floats([
+                    [1.0, 2.0],
+                    [3.0, -1.0],
+                    [0.0, 5.0],
+                    [-2.0, 4.0],
+                    [6.0, 0.0],
+                ]),
+                // The second and the last segments are empty.
+                offsets: Tensor::from_ints([0, 2, 2, 5]),
+            }
+        }
+    }
+}
@@ -122,4 +122,58 @@ mod tests {
             Data::new(vec![5.0, 5.0, 3.0, 11.0, -3.0, 6.0], Shape::new([2, 1, 3]))
         );
     }
+
+    #[test]
+    fn test_should_prod() {
+        let tensor = TestTensor::from_data([[2.0, 1.0, 2.0], [3.0, 4.0, 0.5]]);
+
+        let data_actual = tensor.prod().to_data();
+
+        data_actual.assert_approx_eq(&Data::from([24.0]), 3);
+    }
+
+    #[test]
+    fn test_should_prod_int() {
+        let tensor = TestTensorInt::from_data([[2, 1, 2], [3, -4, 5]]);
+
+        let data_actual = tensor.prod().to_data();
+
+        assert_eq!(data_actual, Data::from([-240]));
+    }
+
+    #[test]
+    fn test_should_prod_last_dim() {
+        let tensor = TestTensor::from_data([[2.0, 1.0, 2.0], [3.0, 4.0, 0.0]]);
+
+        let data_actual = tensor.prod_dim(1).to_data();
+
+        data_actual.assert_approx_eq(&Data::from([[4.0], [0.0]]), 3);
+    }
+
+    #[test]
+    fn test_should_prod_first_dim_int() {
+        let tensor = TestTensorInt::from_data([[2, 1, 2], [3, -4, 5]]);
+
+        let data_actual = tensor.prod_dim(0).to_data();
+
+        assert_eq!(data_actual, Data::from([[6, -4, 10]]));
+    }
+
+    #[test]
+    fn test_should_prod_mid_dim_3d_non_contiguous() {
+        let tensor = TestTensor::from_data([
+            [[2.0, 4.0, 1.0], [7.0, -5.0, 3.0]],
+            [[3.0, 1.0, 2.0], [4.0, 2.0, 3.0]],
+        ]);
+
+        let data_actual = tensor.swap_dims(0, 2).prod_dim(1).into_data();
+
+        data_actual.assert_approx_eq(
+            &Data::new(
+                vec![14.0, 12.0, -20.0, 2.0, 3.0, 6.0],
+                Shape::new([3, 1, 2]),
+            ),
+            3,
+        );
+    }
 }
@@ -0,0 +1,86 @@
+#[burn_tensor_testgen::testgen(any)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Tensor};
+
+    #[test]
+    fn test_any() {
+        let tensor = Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([
+            [false, true, false],
+            [false, false, false],
+        ]));
+
+        let data_actual = tensor.any().into_data();
+
+        assert_eq!(data_actual, Data::from([true]));
+    }
+
+    #[test]
+    fn test_any_should_be_false() {
+        let tensor = Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([
+            [false, false, false],
+            [false, false, false],
+        ]));
+
+        let data_actual = tensor.any().into_data();
+
+        assert_eq!(data_actual, Data::from([false]));
+    }
+
+    #[test]
+    fn test_any_dim() {
+        let tensor = Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([
+            [false, true, false],
+            [false, false, false],
+        ]));
+
+        let data_dim_0 = tensor.clone().any_dim(0).into_data();
+        let data_dim_1 = tensor.any_dim(1).into_data();
+
+        assert_eq!(data_dim_0, Data::from([[false, true, false]]));
+        assert_eq!(data_dim_1, Data::from([[true], [false]]));
+    }
+
+    #[test]
+    fn test_all() {
+        let tensor = Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([
+            [true, true, true],
+            [true, false, true],
+        ]));
+
+        let data_actual = tensor.clone().all().into_data();
+        let data_actual_not = tensor.bool_not().all().into_data();
+
+        assert_eq!(data_actual, Data::from([false]));
+        assert_eq!(data_actual_not, Data::from([false]));
+    }
+
+    #[test]
+    fn test_all_should_be_true() {
+        let tensor = Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([
+            [true, true, true],
+            [true, true, true],
+        ]));
+
+        let data_actual = tensor.all().into_data();
+
+        assert_eq!(data_actual, Data::from([true]));
+    }
+
+    #[test]
+    fn test_all_dim() {
+        let tensor = Tensor::<TestBackend, 3, Bool>::from_bool(Data::from([
+            [[true, true], [true, false]],
+            [[false, true], [true, true]],
+        ]));
+
+        let data_dim_1 = tensor.clone().all_dim(1).into_data();
+        let data_dim_2 = tensor.all_dim(2).into_data();
+
+        assert_eq!(data_dim_1, Data::from([[[true, false]], [[false, true]]]));
+        assert_eq!(
+            data_dim_2,
+            Data::from([[[true], [false]], [[false], [true]]])
+        );
+    }
+}
@@ -0,0 +1,115 @@
+#[burn_tensor_testgen::testgen(cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn test_cumsum_2d_dim1() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let data_actual = tensor.cumsum(1).into_data();
+
+        let data_expected = Data::from([[1.0, 3.0, 6.0], [4.0, 9.0, 15.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_cumsum_3d_dim1() {
+        let tensor = TestTensor::from_floats([
+            [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]],
+            [[-1.0, 0.5], [2.0, -3.0], [0.0, 1.0]],
+        ]);
+
+        let data_actual = tensor.cumsum(1).into_data();
+
+        let data_expected = Data::from([
+            [[1.0, 2.0], [4.0, 6.0], [9.0, 12.0]],
+            [[-1.0, 0.5], [1.0, -2.5], [1.0, -1.5]],
+        ]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_cumsum_int_dim0() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[1, -2, 3], [4, 5, -6], [0, 1, 2]]);
+
+        let data_actual = tensor.cumsum(0).into_data();
+
+        assert_eq!(
+            Data::from([[1, -2, 3], [5, 3, -3], [5, 4, -1]]),
+            data_actual
+        );
+    }
+
+    #[test]
+    fn test_cumprod_2d_dim1() {
+        let tensor =
+            TestTensor::from_floats([[1.0, 2.0, 3.0, 4.0, 0.5], [4.0, 5.0, 6.0, 0.0, 1.0]]);
+
+        let data_actual = tensor.cumprod(1).into_data();
+
+        let data_expected = Data::from([[1.0, 2.0, 6.0, 24.0, 12.0], [4.0, 20.0, 120.0, 0.0, 0.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_cumprod_int_dim1() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[1, -2, 3], [2, 2, 2]]);
+
+        let data_actual = tensor.cumprod(1).into_data();
+
+        assert_eq!(Data::from([[1, -2, -6], [2, 4, 8]]), data_actual);
+    }
+
+    #[test]
+    fn test_cummax_2d_dim1() {
+        let tensor =
+            TestTensor::from_floats([[1.0, 3.0, 2.0, 5.0, 4.0], [-1.0, -2.0, 0.0, -3.0, 1.0]]);
+
+        let data_actual = tensor.cummax(1).into_data();
+
+        let data_expected = Data::from([[1.0, 3.0, 3.0, 5.0, 5.0], [-1.0, -1.0, 0.0, 0.0, 1.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_cummax_with_indices_should_use_last_index() {
+        let tensor = TestTensor::from_floats([[2.0, 1.0, 2.0, 1.0, 3.0]]);
+
+        let (values, indices) = tensor.cummax_with_indices(1);
+
+        let values_expected = Data::from([[2.0, 2.0, 2.0, 2.0, 3.0]]);
+        values_expected.assert_approx_eq(&values.into_data(), 3);
+        assert_eq!(Data::from([[0, 0, 2, 2, 4]]), indices.into_data());
+    }
+
+    #[test]
+    fn test_cummax_with_indices_int_dim0() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[1, 5], [3, 2], [2, 7]]);
+
+        let (values, indices) = tensor.cummax_with_indices(0);
+
+        assert_eq!(Data::from([[1, 5], [3, 5], [3, 7]]), values.into_data());
+        assert_eq!(Data::from([[0, 0], [1, 0], [1, 2]]), indices.into_data());
+    }
+
+    #[test]
+    fn test_logcumsumexp_2d_dim1() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0, 2.0], [-1.0, -1.0, -1.0]]);
+
+        let data_actual = tensor.logcumsumexp(1).into_data();
+
+        let data_expected = Data::from([[0.0, 1.31326, 2.40761], [-1.0, -0.30685, 0.09861]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_logcumsumexp_should_be_stable() {
+        let tensor = TestTensor::from_floats([[1000.0, 1000.0, -1000.0]]);
+
+        let data_actual = tensor.logcumsumexp(1).into_data();
+
+        let data_expected = Data::from([[1000.0, 1000.6931, 1000.6931]]);
+        data_expected.assert_approx_eq(&data_actual, 1);
+    }
+}
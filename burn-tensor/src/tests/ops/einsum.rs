@@ -0,0 +1,180 @@
+#[burn_tensor_testgen::testgen(einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::{einsum, Data, EinsumOperand, Int, Tensor};
+
+    #[test]
+    fn should_support_einsum_matmul() {
+        let lhs = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = TestTensor::from_data([[1.0, 0.0], [0.0, 1.0], [2.0, -1.0]]);
+
+        let output: TestTensor<2> = einsum("ij,jk->ik", &[lhs.clone(), rhs.clone()]);
+
+        assert_eq!(output.into_data(), lhs.matmul(rhs).into_data());
+    }
+
+    #[test]
+    fn should_support_einsum_attention_scores() {
+        let query = arange([2, 3, 4, 5]);
+        let key = arange([2, 3, 6, 5]).div_scalar(10);
+
+        let output: TestTensor<4> = einsum("bhqd,bhkd->bhqk", &[query.clone(), key.clone()]);
+
+        let data_expected = query.matmul(key.transpose()).into_data();
+        data_expected.assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_einsum_transpose() {
+        let tensor = arange([2, 3, 4]);
+
+        let data_expected = tensor.clone().swap_dims(1, 2).swap_dims(0, 1).into_data();
+
+        let output: TestTensor<3> = einsum("abc->cab", &[tensor]);
+
+        assert_eq!(output.into_data(), data_expected);
+    }
+
+    #[test]
+    fn should_support_einsum_sum() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let columns: TestTensor<1> = einsum("ij->j", core::slice::from_ref(&tensor));
+        let total: TestTensor<1> = einsum("ij->", &[tensor]);
+
+        assert_eq!(columns.into_data(), Data::from([5.0, 7.0, 9.0]));
+        assert_eq!(total.into_data(), Data::from([21.0]));
+    }
+
+    #[test]
+    fn should_support_einsum_diagonal_and_trace() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        let diagonal: TestTensor<1> = einsum("ii->i", core::slice::from_ref(&tensor));
+        let trace: TestTensor<1> = einsum("ii", &[tensor]);
+
+        assert_eq!(diagonal.into_data(), Data::from([1.0, 5.0, 9.0]));
+        assert_eq!(trace.into_data(), Data::from([15.0]));
+    }
+
+    #[test]
+    fn should_support_einsum_outer_product() {
+        let lhs = TestTensor::from_data([1.0, 2.0]);
+        let rhs = TestTensor::from_data([3.0, 4.0, 5.0]);
+
+        let output: TestTensor<2> = einsum("i,j->ij", &[lhs, rhs]);
+
+        let data_expected = Data::from([[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]]);
+        assert_eq!(output.into_data(), data_expected);
+    }
+
+    #[test]
+    fn should_support_einsum_implicit_output() {
+        let lhs = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::from_data([[5.0, 6.0], [7.0, 8.0]]);
+
+        // The output labels are sorted alphabetically, so this computes `rhs lhs`.
+        let output: TestTensor<2> = einsum("kj,ik", &[lhs.clone(), rhs.clone()]);
+
+        let data_expected = rhs.matmul(lhs).into_data();
+        assert_eq!(output.into_data(), data_expected);
+    }
+
+    #[test]
+    fn should_support_einsum_multiple_operands() {
+        let a = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+        let b = TestTensor::from_data([[0.0, 1.0], [1.0, 0.0]]);
+        let c = TestTensor::from_data([[2.0, 0.0], [1.0, 1.0]]);
+
+        let output: TestTensor<2> = einsum("ij,jk,kl->il", &[a.clone(), b.clone(), c.clone()]);
+
+        let data_expected = a.matmul(b).matmul(c).into_data();
+        assert_eq!(output.into_data(), data_expected);
+    }
+
+    #[test]
+    fn should_support_einsum_batched_bilinear() {
+        let x1 = TestTensor::from_data([[[1.0, 2.0]], [[0.0, 1.0]]]);
+        let weight = TestTensor::from_data([[[1.0, 0.0], [0.0, 1.0]], [[2.0, 1.0], [1.0, 0.0]]]);
+        let x2 = TestTensor::from_data([[[3.0, 1.0]], [[1.0, 1.0]]]);
+
+        // x1 is [batch, 1, i], weight is [out, i, j] and x2 is [batch, 1, j].
+        let output: TestTensor<2> = einsum("bxi,oij,byj->bo", &[x1, weight, x2]);
+
+        let data_expected = Data::from([[5.0, 13.0], [1.0, 1.0]]);
+        assert_eq!(output.into_data(), data_expected);
+    }
+
+    #[test]
+    fn should_support_einsum_matrix_vector() {
+        let matrix = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let vector = TestTensor::from_data([1.0, 0.0, 2.0]);
+
+        let output: TestTensor<1> = einsum("ij,j->i", (matrix, vector));
+
+        let data_expected = Data::from([7.0, 16.0]);
+        assert_eq!(output.into_data(), data_expected);
+    }
+
+    #[test]
+    fn should_support_einsum_mixed_rank_bilinear() {
+        let x1 = TestTensor::from_data([[1.0, 2.0], [0.0, 1.0]]);
+        let weight = TestTensor::from_data([[[1.0, 0.0], [0.0, 1.0]], [[2.0, 1.0], [1.0, 0.0]]]);
+        let x2 = TestTensor::from_data([[3.0, 1.0], [1.0, 1.0]]);
+
+        let output: TestTensor<2> = einsum("bi,oij,bj->bo", (x1, weight, x2));
+
+        let data_expected = Data::from([[5.0, 13.0], [1.0, 1.0]]);
+        assert_eq!(output.into_data(), data_expected);
+    }
+
+    #[test]
+    fn should_support_einsum_operands_vec() {
+        let matrix = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+        let vector = TestTensor::from_data([1.0, 1.0]);
+
+        let operands = vec![
+            EinsumOperand::from(vector.clone()),
+            matrix.into(),
+            vector.into(),
+        ];
+        let output: TestTensor<1> = einsum("i,ij,j->", operands);
+
+        let data_expected = Data::from([10.0]);
+        assert_eq!(output.into_data(), data_expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_einsum_labels_mismatch_rank() {
+        let matrix = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+        let vector = TestTensor::from_data([1.0, 1.0]);
+
+        let _output: TestTensor<1> = einsum("ij,jk->i", (matrix, vector));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_einsum_label_sizes_mismatch() {
+        let lhs = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::from_data([[1.0, 2.0, 3.0]]);
+
+        let _output: TestTensor<2> = einsum("ij,jk->ik", &[lhs, rhs]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_einsum_output_rank_mismatch() {
+        let tensor = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+
+        let _output: TestTensor<2> = einsum("ij->i", &[tensor]);
+    }
+
+    fn arange<const D: usize>(shape: [usize; D]) -> TestTensor<D> {
+        let num_elements = shape.iter().product::<usize>();
+
+        Tensor::<TestBackend, 1, Int>::arange(0..num_elements)
+            .reshape(shape)
+            .float()
+    }
+}
@@ -0,0 +1,145 @@
+#[burn_tensor_testgen::testgen(expand)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ops::PadMode, Bool, Data, Int, Tensor};
+
+    #[test]
+    fn should_support_expand() {
+        let tensor = TestTensor::from_floats([[1.0], [2.0], [3.0]]);
+
+        let data_actual = tensor.expand([2, 3, 2]).into_data();
+
+        let data_expected = Data::from([
+            [[1.0, 1.0], [2.0, 2.0], [3.0, 3.0]],
+            [[1.0, 1.0], [2.0, 2.0], [3.0, 3.0]],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_expand_same_shape() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        let data_actual = tensor.clone().expand([2, 2]).into_data();
+
+        assert_eq!(tensor.into_data(), data_actual);
+    }
+
+    #[test]
+    fn should_support_broadcast_to() {
+        let tensor = TestTensor::from_floats([1.0, 2.0]);
+
+        let data_actual = tensor.broadcast_to([2, 2]).into_data();
+
+        assert_eq!(Data::from([[1.0, 2.0], [1.0, 2.0]]), data_actual);
+    }
+
+    #[test]
+    fn should_support_ops_on_expanded_tensor() {
+        let tensor = TestTensor::from_floats([1.0, 2.0, 3.0]);
+        let other = TestTensor::from_floats([[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]]);
+
+        let expanded = tensor.expand([2, 3]);
+
+        assert_eq!(
+            expanded.clone().add(other).into_data(),
+            Data::from([[2.0, 3.0, 4.0], [3.0, 4.0, 5.0]])
+        );
+        assert_eq!(
+            expanded.clone().mul_scalar(2.0).into_data(),
+            Data::from([[2.0, 4.0, 6.0], [2.0, 4.0, 6.0]])
+        );
+        assert_eq!(
+            expanded.clone().reshape([6]).into_data(),
+            Data::from([1.0, 2.0, 3.0, 1.0, 2.0, 3.0])
+        );
+        assert_eq!(expanded.clone().sum().into_data(), Data::from([12.0]));
+        assert_eq!(
+            expanded.sum_dim(0).into_data(),
+            Data::from([[2.0, 4.0, 6.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_dim_ops_on_expanded_tensor() {
+        let tensor = TestTensor::from_floats([3.0, 1.0, 2.0]);
+        let indices = Tensor::<TestBackend, 1, Int>::from_data([2, 0]);
+
+        let expanded = tensor.expand([2, 3]);
+
+        assert_eq!(
+            expanded.clone().sort(1).into_data(),
+            Data::from([[1.0, 2.0, 3.0], [1.0, 2.0, 3.0]])
+        );
+        assert_eq!(
+            expanded.clone().cumsum(0).into_data(),
+            Data::from([[3.0, 1.0, 2.0], [6.0, 2.0, 4.0]])
+        );
+        assert_eq!(
+            expanded.clone().flip(&[1]).into_data(),
+            Data::from([[2.0, 1.0, 3.0], [2.0, 1.0, 3.0]])
+        );
+        assert_eq!(
+            expanded.clone().roll(&[1], &[1]).into_data(),
+            Data::from([[2.0, 3.0, 1.0], [2.0, 3.0, 1.0]])
+        );
+        assert_eq!(
+            expanded
+                .clone()
+                .pad([(0, 0), (1, 0)], PadMode::Constant(0.0))
+                .into_data(),
+            Data::from([[0.0, 3.0, 1.0, 2.0], [0.0, 3.0, 1.0, 2.0]])
+        );
+        assert_eq!(
+            expanded.gather(1, indices.expand([2, 2])).into_data(),
+            Data::from([[2.0, 3.0], [2.0, 3.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_expand_int() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data([[1, 2, 3]]);
+
+        let data_actual = tensor.expand([2, 3]).into_data();
+
+        let data_expected = Data::from([[1, 2, 3], [1, 2, 3]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_expand_bool() {
+        let tensor = Tensor::<TestBackend, 1, Bool>::from_data([true, false]);
+
+        let data_actual = tensor.expand([2, 2]).into_data();
+
+        let data_expected = Data::from([[true, false], [true, false]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_mask_fill_with_expanded_mask() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_data([[true], [false]]);
+
+        let data_actual = tensor.mask_fill(mask.expand([2, 2]), 0.0).into_data();
+
+        let data_expected = Data::from([[0.0, 0.0], [3.0, 4.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_expanding_non_unit_dimension() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        let _output = tensor.expand([2, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_expanding_to_fewer_dimensions() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0]]);
+
+        let _output: TestTensor<1> = tensor.expand([2]);
+    }
+}
@@ -0,0 +1,53 @@
+#[burn_tensor_testgen::testgen(flip)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_flip_one_axis() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let data_actual = tensor.flip(&[1]).into_data();
+
+        let data_expected = Data::from([[3.0, 2.0, 1.0], [6.0, 5.0, 4.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_flip_multiple_axes() {
+        let tensor = TestTensor::from_data([[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]]);
+
+        let data_actual = tensor.flip(&[0, 2]).into_data();
+
+        let data_expected = Data::from([[[6.0, 5.0], [8.0, 7.0]], [[2.0, 1.0], [4.0, 3.0]]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_flip_int_tensor() {
+        let tensor = TestTensorInt::from_data([[1, 2, 3], [4, 5, 6]]);
+
+        let data_actual = tensor.flip(&[0]).into_data();
+
+        let data_expected = Data::from([[4, 5, 6], [1, 2, 3]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_not_change_tensor_when_no_axes() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let data_actual = tensor.flip(&[]).into_data();
+
+        let data_expected = Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_axis_out_of_bounds() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let _ = tensor.flip(&[2]);
+    }
+}
@@ -0,0 +1,288 @@
+#[burn_tensor_testgen::testgen(linalg)]
+mod tests {
+    use super::*;
+    use burn_tensor::linalg::{self, MatrixNorm};
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn should_support_cholesky() {
+        let tensor = TestTensor::from_data([[4.0, 2.0], [2.0, 5.0]]);
+
+        let data_actual = linalg::cholesky(tensor).into_data();
+
+        let data_expected = Data::from([[2.0, 0.0], [1.0, 2.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_cholesky_batched() {
+        let tensor = TestTensor::from_data([
+            [[1.0, 0.0, 0.0], [0.0, 9.0, 0.0], [0.0, 0.0, 16.0]],
+            [[4.0, 2.0, 2.0], [2.0, 5.0, 3.0], [2.0, 3.0, 6.0]],
+        ]);
+
+        let factor = linalg::cholesky(tensor.clone());
+        let data_actual = factor
+            .clone()
+            .matmul(factor.clone().transpose())
+            .into_data();
+
+        tensor.into_data().assert_approx_eq(&data_actual, 3);
+        factor
+            .clone()
+            .into_data()
+            .assert_approx_eq(&factor.tril(0).into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_qr() {
+        let tensor = TestTensor::from_data([[3.0, 1.0], [4.0, 2.0], [0.0, 2.0]]);
+
+        let (q, r) = linalg::qr(tensor.clone());
+
+        assert_eq!(q.dims(), [3, 2]);
+        assert_eq!(r.dims(), [2, 2]);
+        Data::from([[5.0, 2.2], [0.0, 2.0396]]).assert_approx_eq(&r.clone().into_data(), 3);
+        tensor
+            .into_data()
+            .assert_approx_eq(&q.clone().matmul(r).into_data(), 3);
+        Data::from([[1.0, 0.0], [0.0, 1.0]])
+            .assert_approx_eq(&q.clone().transpose().matmul(q).into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_qr_wide() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let (q, r) = linalg::qr(tensor.clone());
+
+        assert_eq!(q.dims(), [2, 2]);
+        assert_eq!(r.dims(), [2, 3]);
+        tensor
+            .into_data()
+            .assert_approx_eq(&q.matmul(r.clone()).into_data(), 3);
+        r.clone()
+            .into_data()
+            .assert_approx_eq(&r.triu(0).into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_eigh() {
+        let tensor = TestTensor::from_data([[2.0, 1.0], [1.0, 2.0]]);
+
+        let (eigenvalues, eigenvectors) = linalg::eigh(tensor.clone());
+
+        Data::from([[1.0, 3.0]]).assert_approx_eq(&eigenvalues.clone().into_data(), 3);
+        let data_actual = eigenvectors
+            .clone()
+            .mul(eigenvalues)
+            .matmul(eigenvectors.transpose())
+            .into_data();
+        tensor.into_data().assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_eigh_batched() {
+        let tensor = TestTensor::from_data([
+            [[4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]],
+            [[-1.0, 0.0, 0.0], [0.0, 5.0, 0.0], [0.0, 0.0, 2.0]],
+        ]);
+
+        let (eigenvalues, eigenvectors) = linalg::eigh(tensor.clone());
+
+        Data::from([[[1.2679, 3.0, 4.7321]], [[-1.0, 2.0, 5.0]]])
+            .assert_approx_eq(&eigenvalues.clone().into_data(), 3);
+        let data_actual = eigenvectors
+            .clone()
+            .mul(eigenvalues)
+            .matmul(eigenvectors.transpose())
+            .into_data();
+        tensor.into_data().assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_svd() {
+        let tensor = TestTensor::from_data([[3.0, 0.0], [0.0, -4.0], [0.0, 0.0]]);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+
+        assert_eq!(u.dims(), [3, 2]);
+        assert_eq!(vh.dims(), [2, 2]);
+        Data::from([[4.0, 3.0]]).assert_approx_eq(&s.clone().into_data(), 3);
+        tensor
+            .into_data()
+            .assert_approx_eq(&u.mul(s).matmul(vh).into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_svd_wide() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+
+        assert_eq!(u.dims(), [2, 2]);
+        assert_eq!(vh.dims(), [2, 3]);
+        Data::from([[9.5080, 0.7729]]).assert_approx_eq(&s.clone().into_data(), 3);
+        tensor
+            .into_data()
+            .assert_approx_eq(&u.mul(s).matmul(vh.clone()).into_data(), 3);
+        Data::from([[1.0, 0.0], [0.0, 1.0]])
+            .assert_approx_eq(&vh.clone().matmul(vh.transpose()).into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_eigh_with_many_sweeps() {
+        // The Jacobi algorithm needs 6 sweeps to converge on this matrix.
+        let tensor = matrix(20, 20, |i, j| ((i + 1) * (j + 1) % 17) as f32 - 8.0);
+
+        let (eigenvalues, eigenvectors) = linalg::eigh(tensor.clone());
+
+        let data_actual = eigenvectors
+            .clone()
+            .mul(eigenvalues)
+            .matmul(eigenvectors.clone().transpose())
+            .into_data();
+        tensor.into_data().assert_approx_eq(&data_actual, 3);
+        matrix(20, 20, |i, j| (i == j) as u8 as f32)
+            .into_data()
+            .assert_approx_eq(
+                &eigenvectors
+                    .clone()
+                    .transpose()
+                    .matmul(eigenvectors)
+                    .into_data(),
+                3,
+            );
+    }
+
+    #[test]
+    fn should_support_svd_with_many_sweeps() {
+        // The one-sided Jacobi algorithm needs 7 sweeps to converge on this matrix.
+        let tensor = matrix(14, 10, |i, j| ((i + 1) * (j + 2) % 13) as f32 - 6.0);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+
+        tensor
+            .into_data()
+            .assert_approx_eq(&u.clone().mul(s).matmul(vh).into_data(), 3);
+        matrix(10, 10, |i, j| (i == j) as u8 as f32)
+            .into_data()
+            .assert_approx_eq(&u.clone().transpose().matmul(u).into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_solve() {
+        let lhs = TestTensor::from_data([[2.0, 1.0], [1.0, 3.0]]);
+        let rhs = TestTensor::from_data([[3.0, 1.0], [5.0, 0.0]]);
+
+        let data_actual = linalg::solve(lhs, rhs).into_data();
+
+        let data_expected = Data::from([[0.8, 0.6], [1.4, -0.2]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_solve_with_pivoting() {
+        let lhs = TestTensor::from_data([[0.0, 1.0, 0.0], [0.0, 0.0, 2.0], [4.0, 0.0, 0.0]]);
+        let rhs = TestTensor::from_data([[1.0], [2.0], [3.0]]);
+
+        let data_actual = linalg::solve(lhs, rhs).into_data();
+
+        let data_expected = Data::from([[0.75], [1.0], [1.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_lstsq() {
+        let lhs = TestTensor::from_data([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]]);
+        let rhs = TestTensor::from_data([[1.0], [2.0], [4.0]]);
+
+        let data_actual = linalg::lstsq(lhs, rhs).into_data();
+
+        let data_expected = Data::from([[0.8333], [1.5]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_lstsq_rank_deficient() {
+        let lhs = TestTensor::from_data([[1.0, 1.0], [1.0, 1.0]]);
+        let rhs = TestTensor::from_data([[2.0], [2.0]]);
+
+        let data_actual = linalg::lstsq(lhs, rhs).into_data();
+
+        let data_expected = Data::from([[1.0], [1.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_det() {
+        let tensor = TestTensor::from_data([
+            [[1.0, 2.0], [3.0, 4.0]],
+            [[0.0, 1.0], [1.0, 0.0]],
+            [[1.0, 2.0], [2.0, 4.0]],
+        ]);
+
+        let data_actual = linalg::det(tensor).into_data();
+
+        let data_expected = Data::from([[[-2.0]], [[-1.0]], [[0.0]]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_slogdet() {
+        let tensor = TestTensor::from_data([[0.0, 2.0, 0.0], [-3.0, 0.0, 0.0], [0.0, 0.0, 5.0]]);
+
+        let (sign, logabsdet) = linalg::slogdet(tensor);
+
+        Data::from([[1.0]]).assert_approx_eq(&sign.into_data(), 3);
+        Data::from([[3.4012]]).assert_approx_eq(&logabsdet.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_inv() {
+        let tensor = TestTensor::from_data([[4.0, 7.0], [2.0, 6.0]]);
+
+        let data_actual = linalg::inv(tensor).into_data();
+
+        let data_expected = Data::from([[0.6, -0.7], [-0.2, 0.4]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_vector_norm() {
+        let tensor = TestTensor::from_data([[3.0, -4.0, 0.0], [1.0, 1.0, -1.0]]);
+
+        let l2 = linalg::norm(tensor.clone(), 2.0, 1).into_data();
+        let l1 = linalg::norm(tensor.clone(), 1.0, 1).into_data();
+        let linf = linalg::norm(tensor.clone(), f64::INFINITY, 1).into_data();
+        let l0 = linalg::norm(tensor.clone(), 0.0, 1).into_data();
+        let l3 = linalg::norm(tensor, 3.0, 1).into_data();
+
+        Data::from([[5.0], [1.7321]]).assert_approx_eq(&l2, 3);
+        Data::from([[7.0], [3.0]]).assert_approx_eq(&l1, 3);
+        Data::from([[4.0], [1.0]]).assert_approx_eq(&linf, 3);
+        Data::from([[2.0], [3.0]]).assert_approx_eq(&l0, 3);
+        Data::from([[4.4979], [1.4422]]).assert_approx_eq(&l3, 3);
+    }
+
+    #[test]
+    fn should_support_matrix_norm() {
+        let tensor = TestTensor::from_data([[3.0, 0.0], [0.0, -4.0]]);
+
+        let frobenius = linalg::matrix_norm(tensor.clone(), MatrixNorm::Frobenius).into_data();
+        let nuclear = linalg::matrix_norm(tensor.clone(), MatrixNorm::Nuclear).into_data();
+        let spectral = linalg::matrix_norm(tensor, MatrixNorm::Spectral).into_data();
+
+        Data::from([[5.0]]).assert_approx_eq(&frobenius, 3);
+        Data::from([[7.0]]).assert_approx_eq(&nuclear, 3);
+        Data::from([[4.0]]).assert_approx_eq(&spectral, 3);
+    }
+
+    fn matrix(rows: usize, cols: usize, element: impl Fn(usize, usize) -> f32) -> TestTensor<2> {
+        let values = (0..rows * cols)
+            .map(|index| element(index / cols, index % cols))
+            .collect();
+
+        TestTensor::from_data(Data::new(values, Shape::new([rows, cols])).convert())
+    }
+}
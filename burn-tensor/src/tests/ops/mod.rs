@@ -1,6 +1,7 @@
 mod abs;
 mod add;
 mod aggregation;
+mod any;
 mod arange;
 mod arange_step;
 mod arg;
@@ -9,14 +10,19 @@ mod cat;
 mod clamp;
 mod cos;
 mod create_like;
+mod cumulative;
 mod div;
+mod einsum;
 mod erf;
 mod exp;
+mod expand;
 mod flatten;
+mod flip;
 mod full;
 mod gather_scatter;
 mod init;
 mod iter_dim;
+mod linalg;
 mod log;
 mod log1p;
 mod map_comparison;
@@ -26,15 +32,23 @@ mod maxmin;
 mod mul;
 mod neg;
 mod one_hot;
+mod pad;
+mod permute;
 mod powf;
 mod random;
+mod remainder;
 mod repeat;
 mod reshape;
+mod roll;
+mod rounding;
 mod select;
+mod sign;
 mod sin;
 mod slice;
+mod sort;
 mod sqrt;
 mod squeeze;
 mod sub;
 mod tanh;
 mod transpose;
+mod triangular;
@@ -0,0 +1,72 @@
+#[burn_tensor_testgen::testgen(pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ops::PadMode, Data, Tensor};
+
+    #[test]
+    fn should_pad_constant() {
+        let tensor = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+
+        let data_actual = tensor
+            .pad([(1, 0), (0, 2)], PadMode::Constant(-1.0))
+            .into_data();
+
+        let data_expected = Data::from([
+            [-1.0, -1.0, -1.0, -1.0],
+            [1.0, 2.0, -1.0, -1.0],
+            [3.0, 4.0, -1.0, -1.0],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_pad_reflect() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let data_actual = tensor.pad([(1, 1), (2, 1)], PadMode::Reflect).into_data();
+
+        let data_expected = Data::from([
+            [6.0, 5.0, 4.0, 5.0, 6.0, 5.0],
+            [3.0, 2.0, 1.0, 2.0, 3.0, 2.0],
+            [6.0, 5.0, 4.0, 5.0, 6.0, 5.0],
+            [3.0, 2.0, 1.0, 2.0, 3.0, 2.0],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_pad_replicate() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let data_actual = tensor.pad([(0, 1), (2, 1)], PadMode::Replicate).into_data();
+
+        let data_expected = Data::from([
+            [1.0, 1.0, 1.0, 2.0, 3.0, 3.0],
+            [4.0, 4.0, 4.0, 5.0, 6.0, 6.0],
+            [4.0, 4.0, 4.0, 5.0, 6.0, 6.0],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_pad_circular() {
+        let tensor = TestTensorInt::from_data([[1, 2, 3], [4, 5, 6]]);
+
+        let data_actual = tensor.pad([(1, 0), (2, 3)], PadMode::Circular).into_data();
+
+        let data_expected = Data::from([
+            [5, 6, 4, 5, 6, 4, 5, 6],
+            [2, 3, 1, 2, 3, 1, 2, 3],
+            [5, 6, 4, 5, 6, 4, 5, 6],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_reflect_padding_is_too_large() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let _ = tensor.pad([(0, 0), (3, 0)], PadMode::Reflect);
+    }
+}
@@ -0,0 +1,91 @@
+#[burn_tensor_testgen::testgen(permute)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Int, Tensor};
+
+    #[test]
+    fn should_support_permute() {
+        let tensor = TestTensor::from_floats([
+            [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+            [[6.0, 7.0, 8.0], [9.0, 10.0, 11.0]],
+        ]);
+
+        let data_actual = tensor.permute([2, 0, 1]).into_data();
+
+        let data_expected = Data::from([
+            [[0.0, 3.0], [6.0, 9.0]],
+            [[1.0, 4.0], [7.0, 10.0]],
+            [[2.0, 5.0], [8.0, 11.0]],
+        ]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_permute_4d_like_swap_dims() {
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..120)
+            .reshape([2, 3, 4, 5])
+            .float();
+
+        let output = tensor.clone().permute([0, 2, 3, 1]);
+
+        assert_eq!(output.dims(), [2, 4, 5, 3]);
+        let data_expected = tensor.swap_dims(1, 2).swap_dims(2, 3).into_data();
+        assert_eq!(output.into_data(), data_expected);
+    }
+
+    #[test]
+    fn should_support_reshape_after_permute() {
+        let tensor = TestTensor::from_floats([[[0.0, 1.0], [2.0, 3.0]], [[4.0, 5.0], [6.0, 7.0]]]);
+
+        let data_actual = tensor.permute([1, 2, 0]).reshape([8]).into_data();
+
+        let data_expected = Data::from([0.0, 4.0, 1.0, 5.0, 2.0, 6.0, 3.0, 7.0]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_permute_int() {
+        let tensor = Tensor::<TestBackend, 3, Int>::from_data([
+            [[0, 1, 2], [3, 4, 5]],
+            [[6, 7, 8], [9, 10, 11]],
+        ]);
+
+        let data_actual = tensor.permute([2, 0, 1]).into_data();
+
+        let data_expected = Data::from([[[0, 3], [6, 9]], [[1, 4], [7, 10]], [[2, 5], [8, 11]]]);
+        assert_eq!(&data_expected, &data_actual);
+    }
+
+    #[test]
+    fn should_support_permute_bool() {
+        let tensor = Tensor::<TestBackend, 3, Bool>::from_data([
+            [[false, true, false], [false, false, false]],
+            [[false, false, true], [false, false, true]],
+        ]);
+
+        let data_actual = tensor.permute([2, 0, 1]).into_data();
+
+        let data_expected = Data::from([
+            [[false, false], [false, false]],
+            [[true, false], [false, false]],
+            [[false, false], [true, true]],
+        ]);
+        assert_eq!(&data_expected, &data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_permute_axes_are_repeated() {
+        let tensor = TestTensor::from_floats([[[0.0, 1.0], [2.0, 3.0]]]);
+
+        let _output = tensor.permute([0, 1, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_permute_axis_is_out_of_bounds() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0], [2.0, 3.0]]);
+
+        let _output = tensor.permute([0, 2]);
+    }
+}
@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(remainder)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_remainder_ops_with_sign_of_divisor() {
+        let data_1 = Data::from([[5.0, -5.0, 5.0], [-5.0, 4.5, -6.0]]);
+        let data_2 = Data::from([[3.0, 3.0, -3.0], [-3.0, 2.0, 3.0]]);
+        let tensor_1 = Tensor::<TestBackend, 2>::from_data(data_1);
+        let tensor_2 = Tensor::<TestBackend, 2>::from_data(data_2);
+
+        let data_actual = tensor_1.remainder(tensor_2).into_data();
+
+        let data_expected = Data::from([[2.0, 1.0, -1.0], [-2.0, 0.5, 0.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_remainder_broadcast() {
+        let data_1 = Data::from([[-3.0, -2.0, -1.0], [1.0, 2.0, 3.0]]);
+        let data_2 = Data::from([[2.0, -2.0, 2.0]]);
+        let tensor_1 = Tensor::<TestBackend, 2>::from_data(data_1);
+        let tensor_2 = Tensor::<TestBackend, 2>::from_data(data_2);
+
+        let data_actual = tensor_1.remainder(tensor_2).into_data();
+
+        let data_expected = Data::from([[1.0, 0.0, 1.0], [1.0, 0.0, 1.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_remainder_scalar_ops() {
+        let data = Data::from([[-3.0, -2.5, -1.0], [0.0, 2.5, 3.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.remainder_scalar(2.0).into_data();
+
+        let data_expected = Data::from([[1.0, 1.5, 1.0], [0.0, 0.5, 1.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_remainder_ops_int() {
+        let data_1 = Data::from([[5, -5, 5], [-5, 6, -6]]);
+        let data_2 = Data::from([[3, 3, -3], [-3, 4, 4]]);
+        let tensor_1 = Tensor::<TestBackend, 2, Int>::from_data(data_1);
+        let tensor_2 = Tensor::<TestBackend, 2, Int>::from_data(data_2);
+
+        let data_actual = tensor_1.remainder(tensor_2).into_data();
+
+        let data_expected = Data::from([[2, 1, -1], [-2, 2, 2]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_remainder_scalar_ops_int() {
+        let data = Data::from([[-7, -3, -1], [0, 3, 7]]);
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data(data);
+
+        let data_actual = tensor.remainder_scalar(-3).into_data();
+
+        let data_expected = Data::from([[-1, 0, -1], [0, 0, -2]]);
+        assert_eq!(data_expected, data_actual);
+    }
+}
@@ -0,0 +1,53 @@
+#[burn_tensor_testgen::testgen(roll)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_roll_one_dim() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]);
+
+        let data_actual = tensor.roll(&[1], &[1]).into_data();
+
+        let data_expected = Data::from([[4.0, 1.0, 2.0, 3.0], [8.0, 5.0, 6.0, 7.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_roll_with_negative_shift() {
+        let tensor = TestTensorInt::from_data([1, 2, 3, 4, 5]);
+
+        let data_actual = tensor.roll(&[-2], &[0]).into_data();
+
+        let data_expected = Data::from([3, 4, 5, 1, 2]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_roll_with_shift_larger_than_size() {
+        let tensor = TestTensorInt::from_data([1, 2, 3, 4, 5]);
+
+        let data_actual = tensor.roll(&[7], &[0]).into_data();
+
+        let data_expected = Data::from([4, 5, 1, 2, 3]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_roll_multiple_dims() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        let data_actual = tensor.roll(&[1, -1], &[0, 1]).into_data();
+
+        let data_expected = Data::from([[8.0, 9.0, 7.0], [2.0, 3.0, 1.0], [5.0, 6.0, 4.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_shifts_and_dims_mismatch() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let _ = tensor.roll(&[1, 1], &[0]);
+    }
+}
@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(sign)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_sign_ops() {
+        let data = Data::from([[-2.5, -0.1, 0.0], [0.1, 1.0, 4.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.sign().into_data();
+
+        let data_expected = Data::from([[-1.0, -1.0, 0.0], [1.0, 1.0, 1.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+}
@@ -0,0 +1,92 @@
+#[burn_tensor_testgen::testgen(sort)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn test_sort_2d_dim1() {
+        let tensor = TestTensor::from_floats([[3.0, 1.0, 2.0], [0.0, 5.0, 4.0]]);
+
+        let data_actual = tensor.sort(1).into_data();
+
+        let data_expected = Data::from([[1.0, 2.0, 3.0], [0.0, 4.0, 5.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_sort_descending_3d_dim1() {
+        let tensor = TestTensor::from_floats([
+            [[1.0, 6.0], [4.0, 2.0], [3.0, 5.0]],
+            [[-1.0, 0.0], [7.0, -4.0], [2.0, 8.0]],
+        ]);
+
+        let data_actual = tensor.sort_descending(1).into_data();
+
+        let data_expected = Data::from([
+            [[4.0, 6.0], [3.0, 5.0], [1.0, 2.0]],
+            [[7.0, 8.0], [2.0, 0.0], [-1.0, -4.0]],
+        ]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_sort_with_indices_2d_dim0() {
+        let tensor = TestTensor::from_floats([[3.0, 1.0, 2.0], [0.0, 5.0, 4.0]]);
+
+        let (values, indices) = tensor.sort_with_indices(0);
+
+        let values_expected = Data::from([[0.0, 1.0, 2.0], [3.0, 5.0, 4.0]]);
+        values_expected.assert_approx_eq(&values.into_data(), 3);
+        assert_eq!(Data::from([[1, 0, 0], [0, 1, 1]]), indices.into_data());
+    }
+
+    #[test]
+    fn test_sort_should_be_stable() {
+        let tensor = TestTensor::from_floats([[2.0, 1.0, 2.0, 1.0, 2.0]]);
+
+        let ascending = tensor.clone().argsort(1).into_data();
+        let descending = tensor.argsort_descending(1).into_data();
+
+        assert_eq!(Data::from([[1, 3, 0, 2, 4]]), ascending);
+        assert_eq!(Data::from([[0, 2, 4, 1, 3]]), descending);
+    }
+
+    #[test]
+    fn test_sort_int() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[3, -1, 2], [0, 5, 4]]);
+
+        let (values, indices) = tensor.sort_descending_with_indices(1);
+
+        assert_eq!(Data::from([[3, 2, -1], [5, 4, 0]]), values.into_data());
+        assert_eq!(Data::from([[0, 2, 1], [1, 2, 0]]), indices.into_data());
+    }
+
+    #[test]
+    fn test_argsort_1d() {
+        let tensor = TestTensor::from_floats([5.0, -2.0, 3.5, 0.0]);
+
+        let data_actual = tensor.argsort(0).into_data();
+
+        assert_eq!(Data::from([1, 3, 2, 0]), data_actual);
+    }
+
+    #[test]
+    fn test_topk_2d_dim1() {
+        let tensor = TestTensor::from_floats([[1.0, 5.0, 3.0], [4.0, 2.0, 6.0]]);
+
+        let (values, indices) = tensor.topk_with_indices(2, 1);
+
+        let values_expected = Data::from([[5.0, 3.0], [6.0, 4.0]]);
+        values_expected.assert_approx_eq(&values.into_data(), 3);
+        assert_eq!(Data::from([[1, 2], [2, 0]]), indices.into_data());
+    }
+
+    #[test]
+    fn test_topk_int_dim0() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[1, 8], [7, 2], [4, 9]]);
+
+        let data_actual = tensor.topk(1, 0).into_data();
+
+        assert_eq!(Data::from([[7, 9]]), data_actual);
+    }
+}
@@ -0,0 +1,104 @@
+#[burn_tensor_testgen::testgen(triangular)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Int, Tensor};
+
+    #[test]
+    fn test_tril() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        let data_actual = tensor.tril(0).into_data();
+
+        let data_expected = Data::from([[1.0, 0.0, 0.0], [4.0, 5.0, 0.0], [7.0, 8.0, 9.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_tril_with_offsets() {
+        let tensor = TestTensorInt::from_data([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+
+        let data_above = tensor.clone().tril(1).into_data();
+        let data_below = tensor.tril(-1).into_data();
+
+        assert_eq!(
+            data_above,
+            Data::from([[1, 2, 0, 0], [5, 6, 7, 0], [9, 10, 11, 12]])
+        );
+        assert_eq!(
+            data_below,
+            Data::from([[0, 0, 0, 0], [5, 0, 0, 0], [9, 10, 0, 0]])
+        );
+    }
+
+    #[test]
+    fn test_triu() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        let data_actual = tensor.clone().triu(0).into_data();
+        let data_actual_offset = tensor.triu(1).into_data();
+
+        let data_expected = Data::from([[1.0, 2.0, 3.0], [0.0, 5.0, 6.0], [0.0, 0.0, 9.0]]);
+        let data_expected_offset = Data::from([[0.0, 2.0, 3.0], [0.0, 0.0, 6.0], [0.0, 0.0, 0.0]]);
+        assert_eq!(data_expected, data_actual);
+        assert_eq!(data_expected_offset, data_actual_offset);
+    }
+
+    #[test]
+    fn test_triu_batched() {
+        let tensor = Tensor::<TestBackend, 3, Int>::from_data([
+            [[1, 2], [3, 4], [5, 6]],
+            [[7, 8], [9, 10], [11, 12]],
+        ]);
+
+        let data_actual = tensor.triu(-1).into_data();
+
+        let data_expected = Data::from([[[1, 2], [3, 4], [0, 6]], [[7, 8], [9, 10], [0, 12]]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_tril_mask() {
+        let device = Default::default();
+
+        let data_actual = Tensor::<TestBackend, 2, Bool>::tril_mask([3, 3], 0, &device).into_data();
+
+        let data_expected = Data::from([
+            [false, true, true],
+            [false, false, true],
+            [false, false, false],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_triu_mask() {
+        let device = Default::default();
+
+        let data_actual = Tensor::<TestBackend, 2, Bool>::triu_mask([2, 3], 1, &device).into_data();
+
+        let data_expected = Data::from([[true, false, false], [true, true, false]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_diag_mask() {
+        let device = Default::default();
+
+        let data_main =
+            Tensor::<TestBackend, 3, Bool>::diag_mask([1, 3, 3], 0, &device).into_data();
+        let data_below = Tensor::<TestBackend, 2, Bool>::diag_mask([3, 2], -1, &device).into_data();
+
+        assert_eq!(
+            data_main,
+            Data::from([[
+                [false, true, true],
+                [true, false, true],
+                [true, true, false],
+            ]])
+        );
+        assert_eq!(
+            data_below,
+            Data::from([[true, true], [false, true], [true, false]])
+        );
+    }
+}
@@ -31,6 +31,15 @@ pub trait EventCollector: Send {
         direction: Direction,
         split: Split,
     ) -> Option<usize>;
+
+    /// Find the aggregated value of a metric for the given epoch from the collected data.
+    fn find_metric(
+        &mut self,
+        name: &str,
+        epoch: usize,
+        aggregate: Aggregate,
+        split: Split,
+    ) -> Option<f64>;
 }
 
 #[derive(Copy, Clone)]
@@ -130,5 +139,15 @@ pub mod test_utils {
         ) -> Option<usize> {
             self.info.find_epoch(name, aggregate, direction, split)
         }
+
+        fn find_metric(
+            &mut self,
+            name: &str,
+            epoch: usize,
+            aggregate: Aggregate,
+            split: Split,
+        ) -> Option<f64> {
+            self.info.find_metric(name, epoch, aggregate, split)
+        }
     }
 }
@@ -51,6 +51,18 @@ impl NumericMetricsAggregate {
         Some(mean)
     }
 
+    pub(crate) fn aggregate(
+        &mut self,
+        name: &str,
+        epoch: usize,
+        aggregate: Aggregate,
+        loggers: &mut [Box<dyn MetricLogger>],
+    ) -> Option<f64> {
+        match aggregate {
+            Aggregate::Mean => self.mean(name, epoch, loggers),
+        }
+    }
+
     pub(crate) fn find_epoch(
         &mut self,
         name: &str,
@@ -61,16 +73,8 @@ impl NumericMetricsAggregate {
         let mut data = Vec::new();
         let mut current_epoch = 1;
 
-        loop {
-            match aggregate {
-                Aggregate::Mean => match self.mean(name, current_epoch, loggers) {
-                    Some(value) => {
-                        data.push(value);
-                    }
-                    None => break,
-                },
-            };
-
+        while let Some(value) = self.aggregate(name, current_epoch, aggregate, loggers) {
+            data.push(value);
             current_epoch += 1;
         }
 
@@ -106,7 +110,10 @@ impl NumericMetricsAggregate {
 
 #[cfg(test)]
 mod tests {
-    use crate::{logger::FileMetricLogger, metric::MetricEntry};
+    use crate::{
+        logger::{FileMetricLogger, InMemoryMetricLogger},
+        metric::MetricEntry,
+    };
 
     use super::*;
 
@@ -160,4 +167,29 @@ mod tests {
 
         assert_eq!(value, 2);
     }
+
+    #[test]
+    fn should_aggregate_the_metric_of_an_epoch() {
+        let mut logger = InMemoryMetricLogger::default();
+        let mut aggregate = NumericMetricsAggregate::default();
+        let log = |logger: &mut InMemoryMetricLogger, num: f64| {
+            logger.log(&MetricEntry::new(
+                NAME.into(),
+                num.to_string(),
+                num.to_string(),
+            ))
+        };
+
+        log(&mut logger, 500.); // Epoch 1
+        logger.epoch(2);
+        log(&mut logger, 200.); // Epoch 2
+        log(&mut logger, 1000.); // Epoch 2
+        let loggers: &mut [Box<dyn MetricLogger>] = &mut [Box::new(logger)];
+
+        assert_eq!(
+            aggregate.aggregate(NAME, 2, Aggregate::Mean, loggers),
+            Some(600.)
+        );
+        assert_eq!(aggregate.aggregate(NAME, 3, Aggregate::Mean, loggers), None);
+    }
 }
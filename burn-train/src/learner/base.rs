@@ -1,5 +1,6 @@
 use crate::checkpoint::{Checkpointer, CheckpointingAction, CheckpointingStrategy};
 use crate::components::LearnerComponents;
+use crate::Aggregate;
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::Module;
 use burn_core::optim::Optimizer;
@@ -14,6 +15,7 @@ pub struct Learner<LC: LearnerComponents> {
     pub(crate) model: LC::Model,
     pub(crate) optim: LC::Optimizer,
     pub(crate) lr_scheduler: LC::LrScheduler,
+    pub(crate) lr_scheduler_metric: Option<(String, Aggregate)>,
     pub(crate) num_epochs: usize,
     pub(crate) checkpoint: Option<usize>,
     pub(crate) grad_accumulation: Option<usize>,
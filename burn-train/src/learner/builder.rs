@@ -48,6 +48,7 @@ where
     log_to_file: bool,
     num_loggers: usize,
     checkpointer_strategy: Box<dyn CheckpointingStrategy<AsyncEventCollector<T, V>>>,
+    lr_scheduler_metric: Option<(String, Aggregate)>,
 }
 
 impl<B, T, V, M, O, S> LearnerBuilder<B, T, V, M, O, S>
@@ -87,6 +88,7 @@ where
                     ))
                     .build(),
             ),
+            lr_scheduler_metric: Some((LossMetric::<B>::NAME.to_string(), Aggregate::Mean)),
         }
     }
 
@@ -115,6 +117,16 @@ where
         self.checkpointer_strategy = Box::new(strategy);
     }
 
+    /// The aggregated validation [metric](Metric) reported to the
+    /// [learning rate scheduler](LrScheduler) at the end of each epoch, which is the mean of the
+    /// validation loss by default.
+    ///
+    /// The metric should also be registered as a numeric validation metric.
+    pub fn lr_scheduler_metric<Me: Metric>(mut self, aggregate: Aggregate) -> Self {
+        self.lr_scheduler_metric = Some((Me::NAME.to_string(), aggregate));
+        self
+    }
+
     /// Replace the default CLI renderer with a custom one.
     ///
     /// # Arguments
@@ -304,6 +316,7 @@ where
             model,
             optim,
             lr_scheduler,
+            lr_scheduler_metric: self.lr_scheduler_metric,
             checkpointer,
             num_epochs: self.num_epochs,
             collector,
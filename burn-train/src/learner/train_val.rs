@@ -1,6 +1,7 @@
 use crate::components::LearnerComponents;
-use crate::{EventCollector, Learner, TrainEpoch, ValidEpoch};
+use crate::{EventCollector, Learner, Split, TrainEpoch, ValidEpoch};
 use burn_core::data::dataloader::DataLoader;
+use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::{ADModule, Module};
 use burn_core::optim::{GradientsParams, Optimizer};
 use burn_core::tensor::backend::ADBackend;
@@ -172,6 +173,15 @@ impl<LC: LearnerComponents> Learner<LC> {
             let epoch_valid = ValidEpoch::new(dataloader_valid.clone(), epoch, self.num_epochs);
             epoch_valid.run::<LC, OutputValid>(&self.model, &mut self.collector, &self.interrupter);
 
+            if let Some((name, aggregate)) = &self.lr_scheduler_metric {
+                if let Some(value) =
+                    self.collector
+                        .find_metric(name, epoch, *aggregate, Split::Valid)
+                {
+                    self.lr_scheduler.report_metric(value);
+                }
+            }
+
             if let Some(checkpointer) = &mut self.checkpointer {
                 checkpointer.checkpoint(
                     &self.model,
@@ -0,0 +1,399 @@
+use crate::{
+    compute::{StaticKernel, WgpuHandle},
+    element::WgpuElement,
+    kernel::{elemwise_workgroup, KernelSettings, WORKGROUP_DEFAULT},
+    kernel_wgsl,
+    ops::numeric::empty_device,
+    tensor::WgpuTensor,
+};
+use burn_tensor::{ops::AttentionOptions, Element, ElementConversion, Shape};
+
+kernel_wgsl!(
+    ScaledDotProductAttention,
+    "../template/attention/forward.wgsl"
+);
+kernel_wgsl!(
+    ScaledDotProductAttentionBackwardQuery,
+    "../template/attention/backward_query.wgsl"
+);
+kernel_wgsl!(
+    ScaledDotProductAttentionBackwardKeyValue,
+    "../template/attention/backward_key_value.wgsl"
+);
+
+pub(crate) fn scaled_dot_product_attention<E: WgpuElement + Element>(
+    query: WgpuTensor<E, 4>,
+    key: WgpuTensor<E, 4>,
+    value: WgpuTensor<E, 4>,
+    bias: Option<WgpuTensor<E, 4>>,
+    mask: Option<WgpuTensor<u32, 4>>,
+    options: AttentionOptions,
+) -> WgpuTensor<E, 4> {
+    let [batch_size, n_heads, seq_length_1, _] = query.shape.dims;
+    let [_, _, _, d_v] = value.shape.dims;
+
+    let output = empty_device(
+        query.client.clone(),
+        query.device.clone(),
+        Shape::new([batch_size, n_heads, seq_length_1, d_v]),
+    );
+
+    let info_handle = build_info(&query, &key, &value, &bias, &mask, None, &options);
+    let scalars_handle = scalars_handle(&query, &options);
+    let (bias_handle, mask_handle) = bias_and_mask_handles(&query, &bias, &mask);
+
+    let kernel = StaticKernel::<
+        KernelSettings<ScaledDotProductAttention, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+    >::new(elemwise_workgroup(
+        batch_size * n_heads * seq_length_1,
+        WORKGROUP_DEFAULT,
+    ));
+
+    query.client.execute(
+        Box::new(kernel),
+        &[
+            &query.handle,
+            &key.handle,
+            &value.handle,
+            &bias_handle,
+            &mask_handle,
+            &output.handle,
+            &scalars_handle,
+            &info_handle,
+        ],
+    );
+
+    output
+}
+
+pub(crate) fn scaled_dot_product_attention_backward<E: WgpuElement + Element>(
+    query: WgpuTensor<E, 4>,
+    key: WgpuTensor<E, 4>,
+    value: WgpuTensor<E, 4>,
+    bias: Option<WgpuTensor<E, 4>>,
+    mask: Option<WgpuTensor<u32, 4>>,
+    output_grad: WgpuTensor<E, 4>,
+    options: AttentionOptions,
+) -> [WgpuTensor<E, 4>; 3] {
+    let [batch_size, n_heads, seq_length_1, d_k] = query.shape.dims;
+    let [_, _, seq_length_2, d_v] = value.shape.dims;
+
+    let query_grad = empty_device(
+        query.client.clone(),
+        query.device.clone(),
+        Shape::new([batch_size, n_heads, seq_length_1, d_k]),
+    );
+    let key_grad = empty_device(
+        query.client.clone(),
+        query.device.clone(),
+        Shape::new([batch_size, n_heads, seq_length_2, d_k]),
+    );
+    let value_grad = empty_device(
+        query.client.clone(),
+        query.device.clone(),
+        Shape::new([batch_size, n_heads, seq_length_2, d_v]),
+    );
+
+    // The log of the softmax denominator and the sum of the weighted gradients of each query,
+    // used to recompute the gradients of the scores without materializing the attention weights.
+    let stats_handle = query
+        .client
+        .empty(2 * batch_size * n_heads * seq_length_1 * core::mem::size_of::<E>());
+
+    let info_handle = build_info(
+        &query,
+        &key,
+        &value,
+        &bias,
+        &mask,
+        Some(&output_grad),
+        &options,
+    );
+    let scalars_handle = scalars_handle(&query, &options);
+    let (bias_handle, mask_handle) = bias_and_mask_handles(&query, &bias, &mask);
+
+    let kernel = StaticKernel::<
+        KernelSettings<
+            ScaledDotProductAttentionBackwardQuery,
+            E,
+            i32,
+            WORKGROUP_DEFAULT,
+            WORKGROUP_DEFAULT,
+            1,
+        >,
+    >::new(elemwise_workgroup(
+        batch_size * n_heads * seq_length_1,
+        WORKGROUP_DEFAULT,
+    ));
+
+    query.client.execute(
+        Box::new(kernel),
+        &[
+            &query.handle,
+            &key.handle,
+            &value.handle,
+            &bias_handle,
+            &mask_handle,
+            &output_grad.handle,
+            &query_grad.handle,
+            &stats_handle,
+            &scalars_handle,
+            &info_handle,
+        ],
+    );
+
+    let kernel = StaticKernel::<
+        KernelSettings<
+            ScaledDotProductAttentionBackwardKeyValue,
+            E,
+            i32,
+            WORKGROUP_DEFAULT,
+            WORKGROUP_DEFAULT,
+            1,
+        >,
+    >::new(elemwise_workgroup(
+        batch_size * n_heads * seq_length_2,
+        WORKGROUP_DEFAULT,
+    ));
+
+    query.client.execute(
+        Box::new(kernel),
+        &[
+            &query.handle,
+            &key.handle,
+            &value.handle,
+            &bias_handle,
+            &mask_handle,
+            &output_grad.handle,
+            &stats_handle,
+            &key_grad.handle,
+            &value_grad.handle,
+            &scalars_handle,
+            &info_handle,
+        ],
+    );
+
+    [query_grad, key_grad, value_grad]
+}
+
+fn scalars_handle<E: WgpuElement + Element>(
+    query: &WgpuTensor<E, 4>,
+    options: &AttentionOptions,
+) -> WgpuHandle {
+    query.client.create(E::as_bytes(&[
+        options.scale.elem(),
+        options.mask_value.elem(),
+        (1.0 / (1.0 - options.dropout)).elem(),
+    ]))
+}
+
+/// The kernels always bind a bias and a mask, so a placeholder buffer is used when they are
+/// missing.
+fn bias_and_mask_handles<E: WgpuElement>(
+    query: &WgpuTensor<E, 4>,
+    bias: &Option<WgpuTensor<E, 4>>,
+    mask: &Option<WgpuTensor<u32, 4>>,
+) -> (WgpuHandle, WgpuHandle) {
+    let placeholder = || query.client.create(bytemuck::cast_slice(&[0u32]));
+
+    let bias_handle = match bias {
+        Some(bias) => bias.handle.clone(),
+        None => placeholder(),
+    };
+    let mask_handle = match mask {
+        Some(mask) => mask.handle.clone(),
+        None => placeholder(),
+    };
+
+    (bias_handle, mask_handle)
+}
+
+fn build_info<E: WgpuElement>(
+    query: &WgpuTensor<E, 4>,
+    key: &WgpuTensor<E, 4>,
+    value: &WgpuTensor<E, 4>,
+    bias: &Option<WgpuTensor<E, 4>>,
+    mask: &Option<WgpuTensor<u32, 4>>,
+    output_grad: Option<&WgpuTensor<E, 4>>,
+    options: &AttentionOptions,
+) -> WgpuHandle {
+    let [batch_size, n_heads, seq_length_1, d_k] = query.shape.dims;
+    let [_, _, seq_length_2, d_v] = value.shape.dims;
+    let shape_scores = [batch_size, n_heads, seq_length_1, seq_length_2];
+
+    let mut info: [u32; 34] = [0; 34];
+    info[0..4].copy_from_slice(&strides(&query.strides));
+    info[4..8].copy_from_slice(&strides(&key.strides));
+    info[8..12].copy_from_slice(&strides(&value.strides));
+
+    if let Some(bias) = bias {
+        info[12..16].copy_from_slice(&broadcast_strides(
+            &bias.shape.dims,
+            &bias.strides,
+            &shape_scores,
+        ));
+        info[30] = 1;
+    }
+
+    if let Some(mask) = mask {
+        info[16..20].copy_from_slice(&broadcast_strides(
+            &mask.shape.dims,
+            &mask.strides,
+            &shape_scores,
+        ));
+        info[31] = 1;
+    }
+
+    if let Some(output_grad) = output_grad {
+        info[20..24].copy_from_slice(&strides(&output_grad.strides));
+    }
+
+    info[24] = batch_size as u32;
+    info[25] = n_heads as u32;
+    info[26] = seq_length_1 as u32;
+    info[27] = seq_length_2 as u32;
+    info[28] = d_k as u32;
+    info[29] = d_v as u32;
+    info[32] = options.seed;
+    info[33] = options.dropout_threshold();
+
+    query.client.create(bytemuck::cast_slice(&info))
+}
+
+fn strides(strides: &[usize; 4]) -> [u32; 4] {
+    strides.map(|stride| stride as u32)
+}
+
+/// The strides of the broadcasted dimensions are set to zero, so that the same elements are read
+/// for every index of those dimensions.
+fn broadcast_strides(shape: &[usize; 4], strides: &[usize; 4], target: &[usize; 4]) -> [u32; 4] {
+    let mut output = [0; 4];
+
+    for i in 0..4 {
+        if shape[i] == target[i] {
+            output[i] = strides[i] as u32;
+        } else if shape[i] != 1 {
+            panic!(
+                "The bias and the mask should be broadcastable to the attention scores, \
+                 got shape {:?} for scores of shape {:?}",
+                shape, target
+            );
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{
+        module,
+        ops::{AttentionOptions, ModuleOps},
+        Bool, Distribution, Tensor,
+    };
+
+    #[test]
+    fn scaled_dot_product_attention_should_work_with_multiple_invocations() {
+        let test = AttentionTestCase::new();
+
+        let output = module::scaled_dot_product_attention(
+            test.query,
+            test.key,
+            test.value,
+            Some(test.bias),
+            Some(test.mask),
+            test.options.clone(),
+        );
+        let output_ref = module::scaled_dot_product_attention(
+            test.query_ref,
+            test.key_ref,
+            test.value_ref,
+            Some(test.bias_ref),
+            Some(test.mask_ref),
+            test.options,
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&output_ref.into_data(), 3);
+    }
+
+    #[test]
+    fn scaled_dot_product_attention_backward_should_work_with_multiple_invocations() {
+        let test = AttentionTestCase::new();
+        let output_grad = Tensor::<TestBackend, 4>::random([2, 3, 37, 5], Distribution::Default);
+        let output_grad_ref = Tensor::<ReferenceBackend, 4>::from_data(output_grad.to_data());
+
+        let grads = TestBackend::scaled_dot_product_attention_backward(
+            test.query.into_primitive(),
+            test.key.into_primitive(),
+            test.value.into_primitive(),
+            Some(test.bias.into_primitive()),
+            Some(test.mask.into_primitive()),
+            output_grad.into_primitive(),
+            test.options.clone(),
+        );
+        let grads_ref = ReferenceBackend::scaled_dot_product_attention_backward(
+            test.query_ref.into_primitive(),
+            test.key_ref.into_primitive(),
+            test.value_ref.into_primitive(),
+            Some(test.bias_ref.into_primitive()),
+            Some(test.mask_ref.into_primitive()),
+            output_grad_ref.into_primitive(),
+            test.options,
+        );
+
+        for (grad, grad_ref) in [
+            (grads.query_grad, grads_ref.query_grad),
+            (grads.key_grad, grads_ref.key_grad),
+            (grads.value_grad, grads_ref.value_grad),
+        ] {
+            Tensor::<TestBackend, 4>::from_primitive(grad)
+                .into_data()
+                .assert_approx_eq(
+                    &Tensor::<ReferenceBackend, 4>::from_primitive(grad_ref).into_data(),
+                    3,
+                );
+        }
+    }
+
+    struct AttentionTestCase {
+        query: Tensor<TestBackend, 4>,
+        key: Tensor<TestBackend, 4>,
+        value: Tensor<TestBackend, 4>,
+        bias: Tensor<TestBackend, 4>,
+        mask: Tensor<TestBackend, 4, Bool>,
+        query_ref: Tensor<ReferenceBackend, 4>,
+        key_ref: Tensor<ReferenceBackend, 4>,
+        value_ref: Tensor<ReferenceBackend, 4>,
+        bias_ref: Tensor<ReferenceBackend, 4>,
+        mask_ref: Tensor<ReferenceBackend, 4, Bool>,
+        options: AttentionOptions,
+    }
+
+    impl AttentionTestCase {
+        fn new() -> Self {
+            let query = Tensor::<TestBackend, 4>::random([2, 3, 37, 8], Distribution::Default);
+            let key = Tensor::<TestBackend, 4>::random([2, 3, 75, 8], Distribution::Default);
+            let value = Tensor::<TestBackend, 4>::random([2, 3, 75, 5], Distribution::Default);
+            let bias = Tensor::<TestBackend, 4>::random([1, 3, 37, 75], Distribution::Default);
+            let mask = Tensor::<TestBackend, 4>::random([2, 1, 37, 75], Distribution::Default)
+                .lower_elem(0.3);
+
+            Self {
+                query_ref: Tensor::from_data(query.to_data()),
+                key_ref: Tensor::from_data(key.to_data()),
+                value_ref: Tensor::from_data(value.to_data()),
+                bias_ref: Tensor::from_data(bias.to_data()),
+                mask_ref: Tensor::from_data(mask.to_data()),
+                query,
+                key,
+                value,
+                bias,
+                mask,
+                options: AttentionOptions::new(1.0 / 8.0f64.sqrt(), -1.0e4).with_dropout(0.1, 3),
+            }
+        }
+    }
+}
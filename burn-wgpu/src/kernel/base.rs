@@ -78,6 +78,20 @@ pub fn into_contiguous<E: WgpuElement, const D: usize>(
     output
 }
 
+/// Make a wgpu tensor contiguous only if it was expanded.
+///
+/// Kernels that index the input buffer directly instead of using the strides can handle any
+/// permutation of the dimensions, but not elements sharing the same memory location.
+pub(crate) fn into_contiguous_if_expanded<E: WgpuElement, const D: usize>(
+    tensor: WgpuTensor<E, D>,
+) -> WgpuTensor<E, D> {
+    if tensor.is_expanded() {
+        return into_contiguous(tensor);
+    }
+
+    tensor
+}
+
 /// Generates kernel source code by replacing some information using templating.
 pub struct KernelSettings<
     K: StaticKernelSource,
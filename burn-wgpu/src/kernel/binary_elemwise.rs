@@ -29,6 +29,25 @@ macro_rules! binary_elemwise {
             }
         }
     };
+
+    (
+        $struct:ident,
+        func $func:expr,
+        include $file:expr
+    ) => {
+        pub struct $struct;
+
+        impl $crate::kernel::StaticKernelSource for $struct {
+            fn source() -> $crate::kernel::SourceTemplate {
+                $crate::kernel::BinaryElemwiseRaw::source()
+                    .register(
+                        "body",
+                        format!("output[id] = {}(lhs[index_lhs], rhs[index_rhs]);", $func),
+                    )
+                    .add_template(include_str!($file))
+            }
+        }
+    };
 }
 
 /// Creates a binary elementwise inplace kernel.
@@ -49,6 +68,25 @@ macro_rules! binary_elemwise_inplace {
             }
         }
     };
+
+    (
+        $struct:ident,
+        func $func:expr,
+        include $file:expr
+    ) => {
+        pub struct $struct;
+
+        impl $crate::kernel::StaticKernelSource for $struct {
+            fn source() -> $crate::kernel::SourceTemplate {
+                $crate::kernel::BinaryElemwiseInplaceRaw::source()
+                    .register(
+                        "body",
+                        format!("lhs[id] = {}(lhs[id], rhs[index_rhs]);", $func),
+                    )
+                    .add_template(include_str!($file))
+            }
+        }
+    };
 }
 
 /// Execute a binary kernel using the default settings.
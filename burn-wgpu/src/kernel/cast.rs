@@ -1,4 +1,7 @@
-use super::{KernelSettings, SourceTemplate, StaticKernelSource, WORKGROUP_DEFAULT};
+use super::{
+    into_contiguous_if_expanded, KernelSettings, SourceTemplate, StaticKernelSource,
+    WORKGROUP_DEFAULT,
+};
 use crate::{
     compute::StaticKernel, element::WgpuElement, kernel::elemwise_workgroup, kernel_wgsl,
     tensor::WgpuTensor,
@@ -26,6 +29,8 @@ impl<InputElem: WgpuElement, OutputElem: WgpuElement> StaticKernelSource
 pub fn cast<InputElem: WgpuElement, OutputElem: WgpuElement, const D: usize>(
     tensor: WgpuTensor<InputElem, D>,
 ) -> WgpuTensor<OutputElem, D> {
+    let tensor = into_contiguous_if_expanded(tensor);
+
     if TypeId::of::<InputElem>() == TypeId::of::<OutputElem>() {
         return WgpuTensor::new(tensor.client, tensor.device, tensor.shape, tensor.handle);
     }
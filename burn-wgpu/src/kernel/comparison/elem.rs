@@ -1,7 +1,10 @@
 use crate::{
     compute::StaticKernel,
     element::WgpuElement,
-    kernel::{elemwise_workgroup, KernelSettings, StaticKernelSource, WORKGROUP_DEFAULT},
+    kernel::{
+        elemwise_workgroup, into_contiguous_if_expanded, KernelSettings, StaticKernelSource,
+        WORKGROUP_DEFAULT,
+    },
     kernel_wgsl,
     tensor::WgpuTensor,
 };
@@ -58,6 +61,7 @@ pub fn comparison_elem<K: StaticKernelSource, E: WgpuElement, const D: usize>(
     lhs: WgpuTensor<E, D>,
     rhs: E,
 ) -> WgpuTensor<u32, D> {
+    let lhs = into_contiguous_if_expanded(lhs);
     let num_elems = lhs.shape.num_elements();
 
     let handle = lhs.client.empty(num_elems * core::mem::size_of::<u32>());
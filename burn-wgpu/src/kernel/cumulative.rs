@@ -0,0 +1,171 @@
+use super::{build_info, KernelSettings, SourceTemplate, StaticKernelSource, WORKGROUP_DEFAULT};
+use crate::{
+    compute::StaticKernel, element::WgpuElement, kernel::elemwise_workgroup, kernel_wgsl,
+    ops::numeric::empty_device, tensor::WgpuTensor,
+};
+
+kernel_wgsl!(CumulativeRaw, "../template/cumulative.wgsl");
+kernel_wgsl!(CumMaxRaw, "../template/cummax.wgsl");
+
+struct CumSum;
+struct CumProd;
+struct CumMax;
+struct LogCumSumExp;
+
+impl StaticKernelSource for CumSum {
+    fn source() -> SourceTemplate {
+        CumulativeRaw::source().add_template(
+            "fn combine(acc: {{ elem }}, value: {{ elem }}) -> {{ elem }} {
+    return acc + value;
+}",
+        )
+    }
+}
+
+impl StaticKernelSource for CumProd {
+    fn source() -> SourceTemplate {
+        CumulativeRaw::source().add_template(
+            "fn combine(acc: {{ elem }}, value: {{ elem }}) -> {{ elem }} {
+    return acc * value;
+}",
+        )
+    }
+}
+
+impl StaticKernelSource for CumMax {
+    fn source() -> SourceTemplate {
+        CumulativeRaw::source().add_template(
+            "fn combine(acc: {{ elem }}, value: {{ elem }}) -> {{ elem }} {
+    return max(acc, value);
+}",
+        )
+    }
+}
+
+impl StaticKernelSource for LogCumSumExp {
+    fn source() -> SourceTemplate {
+        CumulativeRaw::source().add_template(
+            "fn combine(acc: {{ elem }}, value: {{ elem }}) -> {{ elem }} {
+    if acc == value {
+        return acc + log(2.0);
+    }
+    let max_value = max(acc, value);
+    let min_value = min(acc, value);
+    return max_value + log(1.0 + exp(min_value - max_value));
+}",
+        )
+    }
+}
+
+/// Execute the cumulative sum kernel.
+pub fn cumsum<E: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> WgpuTensor<E, D> {
+    cumulative::<CumSum, E, D>(input, dim)
+}
+
+/// Execute the cumulative product kernel.
+pub fn cumprod<E: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> WgpuTensor<E, D> {
+    cumulative::<CumProd, E, D>(input, dim)
+}
+
+/// Execute the cumulative maximum kernel.
+pub fn cummax<E: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> WgpuTensor<E, D> {
+    cumulative::<CumMax, E, D>(input, dim)
+}
+
+/// Execute the cumulative log-sum-exp kernel.
+pub fn logcumsumexp<E: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> WgpuTensor<E, D> {
+    cumulative::<LogCumSumExp, E, D>(input, dim)
+}
+
+/// Execute the cumulative maximum kernel, also returning the indices of the maximum elements.
+pub fn cummax_with_indices<E: WgpuElement, I: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> (WgpuTensor<E, D>, WgpuTensor<I, D>) {
+    let shape = input.shape.clone();
+    let num_lanes = shape.num_elements() / usize::max(shape.dims[dim], 1);
+    let output = empty_device(input.client.clone(), input.device.clone(), shape.clone());
+    let indices = empty_device(input.client.clone(), input.device.clone(), shape);
+
+    let kernel = StaticKernel::<
+        KernelSettings<CumMaxRaw, E, I, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+    >::new(elemwise_workgroup(num_lanes, WORKGROUP_DEFAULT));
+    let mut info = build_info(&[&input, &output]);
+    info.push(dim as u32);
+    info.push(num_lanes as u32);
+    let info_handle = input.client.create(bytemuck::cast_slice(&info));
+
+    input.client.execute(
+        Box::new(kernel),
+        &[&input.handle, &output.handle, &indices.handle, &info_handle],
+    );
+
+    (output, indices)
+}
+
+fn cumulative<K: StaticKernelSource, E: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> WgpuTensor<E, D> {
+    let shape = input.shape.clone();
+    let num_lanes = shape.num_elements() / usize::max(shape.dims[dim], 1);
+    let output = empty_device(input.client.clone(), input.device.clone(), shape);
+
+    let kernel =
+        StaticKernel::<KernelSettings<K, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>>::new(
+            elemwise_workgroup(num_lanes, WORKGROUP_DEFAULT),
+        );
+    let mut info = build_info(&[&input, &output]);
+    info.push(dim as u32);
+    info.push(num_lanes as u32);
+    let info_handle = input.client.create(bytemuck::cast_slice(&info));
+
+    input.client.execute(
+        Box::new(kernel),
+        &[&input.handle, &output.handle, &info_handle],
+    );
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn cumsum_should_work_with_multiple_invocations() {
+        let tensor = Tensor::<TestBackend, 3>::random([8, 3, 64], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data());
+
+        let val = Tensor::<TestBackend, 3>::from_primitive(cumsum(tensor.into_primitive(), 1));
+        let val_ref = tensor_ref.cumsum(1);
+
+        val_ref.into_data().assert_approx_eq(&val.into_data(), 3);
+    }
+
+    #[test]
+    fn logcumsumexp_should_work_with_multiple_invocations() {
+        let tensor = Tensor::<TestBackend, 2>::random([256, 12], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data());
+
+        let val =
+            Tensor::<TestBackend, 2>::from_primitive(logcumsumexp(tensor.into_primitive(), 1));
+        let val_ref = tensor_ref.logcumsumexp(1);
+
+        val_ref.into_data().assert_approx_eq(&val.into_data(), 3);
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{
+    compute::StaticKernel,
+    element::WgpuElement,
+    kernel::{build_info, elemwise_workgroup, KernelSettings, WORKGROUP_DEFAULT},
+    kernel_wgsl,
+    ops::numeric::empty_device,
+    tensor::WgpuTensor,
+};
+
+kernel_wgsl!(IndexFlip, "../../template/index/flip.wgsl");
+
+pub(crate) fn flip<E: WgpuElement, const D: usize>(
+    tensor: WgpuTensor<E, D>,
+    axes: &[usize],
+) -> WgpuTensor<E, D> {
+    let num_elems = tensor.shape.num_elements();
+    let output = empty_device(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+    );
+
+    let mut info = build_info(&[&tensor, &output]);
+
+    for dim in 0..D {
+        info.push(axes.contains(&dim) as u32);
+    }
+
+    let info_handle = output.client.create(bytemuck::cast_slice(&info));
+    let kernel = StaticKernel::<
+        KernelSettings<IndexFlip, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+    >::new(elemwise_workgroup(num_elems, WORKGROUP_DEFAULT));
+
+    tensor.client.execute(
+        Box::new(kernel),
+        &[&tensor.handle, &output.handle, &info_handle],
+    );
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn flip_should_work_with_multiple_workgroups() {
+        let tensor = Tensor::<TestBackend, 3>::random([6, 256, 3], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data());
+
+        let actual = flip(tensor.into_primitive(), &[0, 2]);
+        let expected = tensor_ref.flip(&[0, 2]);
+
+        expected.into_data().assert_approx_eq(
+            &Tensor::<TestBackend, 3>::from_primitive(actual).into_data(),
+            3,
+        );
+    }
+}
@@ -1,8 +1,10 @@
+mod flip;
 mod gather;
 mod scatter;
 mod select;
 mod slice;
 
+pub use flip::*;
 pub use gather::*;
 pub use scatter::*;
 pub use select::*;
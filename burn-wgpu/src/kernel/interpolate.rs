@@ -0,0 +1,230 @@
+use crate::{
+    compute::{StaticKernel, WgpuHandle},
+    element::WgpuElement,
+    kernel::{elemwise_workgroup, KernelSettings, StaticKernelSource, WORKGROUP_DEFAULT},
+    kernel_wgsl,
+    ops::numeric::empty_device,
+    tensor::WgpuTensor,
+};
+use burn_tensor::{
+    ops::{InterpolateMode, InterpolateOptions},
+    Shape,
+};
+
+kernel_wgsl!(InterpolateNearest, "../template/interpolate/nearest.wgsl");
+kernel_wgsl!(InterpolateBilinear, "../template/interpolate/bilinear.wgsl");
+kernel_wgsl!(InterpolateBicubic, "../template/interpolate/bicubic.wgsl");
+kernel_wgsl!(
+    InterpolateNearestBackward,
+    "../template/interpolate/nearest_backward.wgsl"
+);
+kernel_wgsl!(
+    InterpolateBilinearBackward,
+    "../template/interpolate/bilinear_backward.wgsl"
+);
+kernel_wgsl!(
+    InterpolateBicubicBackward,
+    "../template/interpolate/bicubic_backward.wgsl"
+);
+
+pub(crate) fn interpolate<E: WgpuElement>(
+    x: WgpuTensor<E, 4>,
+    output_size: [usize; 2],
+    options: InterpolateOptions,
+) -> WgpuTensor<E, 4> {
+    match options.mode {
+        InterpolateMode::Nearest => {
+            interpolate_kernel::<InterpolateNearest, E>(x, output_size, options)
+        }
+        InterpolateMode::Bilinear => {
+            interpolate_kernel::<InterpolateBilinear, E>(x, output_size, options)
+        }
+        InterpolateMode::Bicubic => {
+            interpolate_kernel::<InterpolateBicubic, E>(x, output_size, options)
+        }
+    }
+}
+
+pub(crate) fn interpolate_backward<E: WgpuElement>(
+    x: WgpuTensor<E, 4>,
+    grad: WgpuTensor<E, 4>,
+    options: InterpolateOptions,
+) -> WgpuTensor<E, 4> {
+    match options.mode {
+        InterpolateMode::Nearest => {
+            interpolate_backward_kernel::<InterpolateNearestBackward, E>(x, grad, options)
+        }
+        InterpolateMode::Bilinear => {
+            interpolate_backward_kernel::<InterpolateBilinearBackward, E>(x, grad, options)
+        }
+        InterpolateMode::Bicubic => {
+            interpolate_backward_kernel::<InterpolateBicubicBackward, E>(x, grad, options)
+        }
+    }
+}
+
+fn interpolate_kernel<K: StaticKernelSource, E: WgpuElement>(
+    x: WgpuTensor<E, 4>,
+    output_size: [usize; 2],
+    options: InterpolateOptions,
+) -> WgpuTensor<E, 4> {
+    let [batch_size, channels, _, _] = x.shape.dims;
+
+    let output_shape = Shape::new([batch_size, channels, output_size[0], output_size[1]]);
+    let output = empty_device(x.client.clone(), x.device.clone(), output_shape);
+
+    let kernel =
+        StaticKernel::<KernelSettings<K, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>>::new(
+            elemwise_workgroup(output.shape.num_elements(), WORKGROUP_DEFAULT),
+        );
+
+    let info_handle = build_info(&x, &output, options.align_corners);
+    x.client
+        .execute(Box::new(kernel), &[&x.handle, &output.handle, &info_handle]);
+
+    output
+}
+
+fn interpolate_backward_kernel<K: StaticKernelSource, E: WgpuElement>(
+    x: WgpuTensor<E, 4>,
+    grad: WgpuTensor<E, 4>,
+    options: InterpolateOptions,
+) -> WgpuTensor<E, 4> {
+    let output = empty_device(x.client.clone(), x.device.clone(), x.shape.clone());
+
+    let kernel =
+        StaticKernel::<KernelSettings<K, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>>::new(
+            elemwise_workgroup(output.shape.num_elements(), WORKGROUP_DEFAULT),
+        );
+
+    // Each invocation gathers the gradient of one input element from the output elements whose
+    // taps reach it, so the input comes first in the info like in the forward pass.
+    let info_handle = build_info(&output, &grad, options.align_corners);
+    x.client.execute(
+        Box::new(kernel),
+        &[&grad.handle, &output.handle, &info_handle],
+    );
+
+    output
+}
+
+fn build_info<E: WgpuElement>(
+    x: &WgpuTensor<E, 4>,
+    output: &WgpuTensor<E, 4>,
+    align_corners: bool,
+) -> WgpuHandle {
+    let mut info: [u32; 17] = [0; 17];
+    info[0] = x.strides[0] as u32;
+    info[1] = x.strides[1] as u32;
+    info[2] = x.strides[2] as u32;
+    info[3] = x.strides[3] as u32;
+    info[4] = x.shape.dims[0] as u32;
+    info[5] = x.shape.dims[1] as u32;
+    info[6] = x.shape.dims[2] as u32;
+    info[7] = x.shape.dims[3] as u32;
+
+    info[8] = output.strides[0] as u32;
+    info[9] = output.strides[1] as u32;
+    info[10] = output.strides[2] as u32;
+    info[11] = output.strides[3] as u32;
+    info[12] = output.shape.dims[0] as u32;
+    info[13] = output.shape.dims[1] as u32;
+    info[14] = output.shape.dims[2] as u32;
+    info[15] = output.shape.dims[3] as u32;
+
+    info[16] = align_corners as u32;
+
+    output.client.create(bytemuck::cast_slice(&info))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{
+        backend::Backend,
+        module,
+        ops::{InterpolateMode, InterpolateOptions, ModuleOps},
+        Distribution, Tensor,
+    };
+
+    #[test]
+    fn interpolate_should_work_with_multiple_invocations() {
+        let tensor = Tensor::<TestBackend, 4>::random([4, 8, 17, 23], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 4>::from_data(tensor.to_data());
+
+        for mode in [
+            InterpolateMode::Nearest,
+            InterpolateMode::Bilinear,
+            InterpolateMode::Bicubic,
+        ] {
+            for align_corners in [false, true] {
+                let options = InterpolateOptions::new(mode, align_corners);
+
+                let output = module::interpolate(tensor.clone(), [31, 12], options.clone());
+                let output_ref = module::interpolate(tensor_ref.clone(), [31, 12], options);
+
+                output
+                    .into_data()
+                    .assert_approx_eq(&output_ref.into_data(), 3);
+            }
+        }
+    }
+
+    #[test]
+    fn interpolate_backward_should_work_with_multiple_invocations() {
+        TestBackend::seed(0);
+        let tensor = Tensor::<TestBackend, 4>::random([4, 8, 17, 23], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 4>::from_data(tensor.to_data());
+        let grad_output = Tensor::<TestBackend, 4>::random([4, 8, 31, 12], Distribution::Default);
+        let grad_output_ref = Tensor::<ReferenceBackend, 4>::from_data(grad_output.to_data());
+
+        for mode in [
+            InterpolateMode::Nearest,
+            InterpolateMode::Bilinear,
+            InterpolateMode::Bicubic,
+        ] {
+            for align_corners in [false, true] {
+                let options = InterpolateOptions::new(mode, align_corners);
+
+                let grad: Tensor<TestBackend, 4> =
+                    Tensor::from_primitive(TestBackend::interpolate_backward(
+                        tensor.clone().into_primitive(),
+                        grad_output.clone().into_primitive(),
+                        options.clone(),
+                    ));
+                let grad_ref: Tensor<ReferenceBackend, 4> =
+                    Tensor::from_primitive(ReferenceBackend::interpolate_backward(
+                        tensor_ref.clone().into_primitive(),
+                        grad_output_ref.clone().into_primitive(),
+                        options,
+                    ));
+
+                grad.into_data().assert_approx_eq(&grad_ref.into_data(), 3);
+            }
+        }
+    }
+
+    #[test]
+    fn interpolate_backward_should_work_with_expanded_grad() {
+        let tensor = Tensor::<TestBackend, 4>::random([2, 3, 5, 7], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 4>::from_data(tensor.to_data());
+        let grad_output = Tensor::<TestBackend, 4>::random([2, 3, 1, 9], Distribution::Default);
+        let grad_output_ref = Tensor::<ReferenceBackend, 4>::from_data(grad_output.to_data());
+        let options = InterpolateOptions::new(InterpolateMode::Bilinear, false);
+
+        let grad: Tensor<TestBackend, 4> =
+            Tensor::from_primitive(TestBackend::interpolate_backward(
+                tensor.into_primitive(),
+                grad_output.expand([2, 3, 8, 9]).into_primitive(),
+                options.clone(),
+            ));
+        let grad_ref: Tensor<ReferenceBackend, 4> =
+            Tensor::from_primitive(ReferenceBackend::interpolate_backward(
+                tensor_ref.into_primitive(),
+                grad_output_ref.expand([2, 3, 8, 9]).into_primitive(),
+                options,
+            ));
+
+        grad.into_data().assert_approx_eq(&grad_ref.into_data(), 3);
+    }
+}
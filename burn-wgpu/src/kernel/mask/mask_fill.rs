@@ -26,7 +26,9 @@ pub fn mask_fill<E: WgpuElement, const D: usize>(
     let kernel = StaticKernel::<
         KernelSettings<MaskFill, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
     >::new(elemwise_workgroup(num_elems, WORKGROUP_DEFAULT));
-    let mask = WgpuTensor::new(mask.client, mask.device, mask.shape, mask.handle);
+    let strides = mask.strides;
+    let mut mask = WgpuTensor::new(mask.client, mask.device, mask.shape, mask.handle);
+    mask.strides = strides;
     let info = build_info(&[&input, &mask, &output]);
     let info_handle = input.client.create(bytemuck::cast_slice(&info));
 
@@ -54,7 +56,9 @@ pub fn mask_fill_inplace<E: WgpuElement, const D: usize>(
     let kernel = StaticKernel::<
         KernelSettings<MaskFillInplace, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
     >::new(elemwise_workgroup(num_elems, WORKGROUP_DEFAULT));
-    let mask = WgpuTensor::new(mask.client, mask.device, mask.shape, mask.handle);
+    let strides = mask.strides;
+    let mut mask = WgpuTensor::new(mask.client, mask.device, mask.shape, mask.handle);
+    mask.strides = strides;
     let info = build_info(&[&input, &mask]);
     let info_handle = input.client.create(bytemuck::cast_slice(&info));
 
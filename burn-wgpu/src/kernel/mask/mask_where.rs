@@ -25,7 +25,9 @@ pub fn mask_where<E: WgpuElement, const D: usize>(
     let kernel = StaticKernel::<
         KernelSettings<MaskWhere, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
     >::new(elemwise_workgroup(num_elems, WORKGROUP_DEFAULT));
-    let mask = WgpuTensor::new(mask.client, mask.device, mask.shape, mask.handle);
+    let strides = mask.strides;
+    let mut mask = WgpuTensor::new(mask.client, mask.device, mask.shape, mask.handle);
+    mask.strides = strides;
     let info = build_info(&[&input, &value, &mask, &output]);
     let info_handle = input.client.create(bytemuck::cast_slice(&info));
 
@@ -55,7 +57,9 @@ pub fn mask_where_inplace<E: WgpuElement, const D: usize>(
         input.shape.num_elements(),
         WORKGROUP_DEFAULT,
     ));
-    let mask = WgpuTensor::new(mask.client, mask.device, mask.shape, mask.handle);
+    let strides = mask.strides;
+    let mut mask = WgpuTensor::new(mask.client, mask.device, mask.shape, mask.handle);
+    mask.strides = strides;
     let mut info = build_info(&[&input, &value, &mask]);
     info.push(match reverse {
         true => 1,
@@ -445,14 +445,18 @@ pub(super) fn matmul_tiling_2d_launch<
     // kernel handles it without needing to turn it into contiguous.
     let round_lhs = pad_round(lhs, b_m, b_k);
     let lhs = match round_lhs {
-        PaddingOutput::Unchanged(tensor) if tensor.batch_swapped_with_row_col() => {
+        PaddingOutput::Unchanged(tensor)
+            if tensor.batch_swapped_with_row_col() || tensor.is_expanded() =>
+        {
             into_contiguous(tensor)
         }
         _ => round_lhs.into_tensor(),
     };
     let round_rhs = pad_round(rhs, b_k, b_n);
     let rhs = match round_rhs {
-        PaddingOutput::Unchanged(tensor) if tensor.batch_swapped_with_row_col() => {
+        PaddingOutput::Unchanged(tensor)
+            if tensor.batch_swapped_with_row_col() || tensor.is_expanded() =>
+        {
             into_contiguous(tensor)
         }
         _ => round_rhs.into_tensor(),
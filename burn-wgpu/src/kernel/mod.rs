@@ -1,11 +1,15 @@
+mod attention;
 mod base;
 mod binary_elemwise;
 mod cast;
 mod cat;
 mod comparison;
+mod cumulative;
 mod index;
+mod interpolate;
 mod mask;
 mod reduction;
+mod sort;
 mod source;
 mod unary;
 mod unary_scalar;
@@ -28,8 +32,12 @@ pub mod prng;
 /// Unfold kernel
 pub mod unfold;
 
+pub(crate) use attention::*;
 pub(crate) use cat::*;
 pub(crate) use comparison::*;
+pub(crate) use cumulative::*;
 pub(crate) use index::*;
+pub(crate) use interpolate::*;
 pub(crate) use mask::*;
 pub(crate) use reduction::*;
+pub(crate) use sort::*;
@@ -1,4 +1,7 @@
-use super::{build_info, KernelSettings, SourceTemplate, StaticKernelSource, WORKGROUP_DEFAULT};
+use super::{
+    build_info, into_contiguous_if_expanded, KernelSettings, SourceTemplate, StaticKernelSource,
+    WORKGROUP_DEFAULT,
+};
 use crate::{
     compute::StaticKernel, element::WgpuElement, kernel::elemwise_workgroup, kernel_wgsl,
     tensor::WgpuTensor,
@@ -13,10 +16,16 @@ pub struct ArgsMax;
 pub struct ArgsMin;
 pub struct SumDim;
 pub struct MeanDim;
+pub struct ProdDim;
+pub struct AnyDim;
+pub struct AllDim;
 
 impl StaticKernelSource for SumDim {
     fn source() -> SourceTemplate {
-        ReductionDimRaw::source().register("assign", "output[id] = sum;")
+        ReductionDimRaw::source()
+            .register("initial", "0")
+            .register("reduce", "accumulator + value")
+            .register("assign", "output[id] = accumulator;")
     }
 }
 
@@ -28,7 +37,36 @@ impl StaticKernelSource for MeanDim {
     return sum / {{ elem }}(dim);
 }",
             )
-            .register("assign", "output[id] = mean_dim(sum, shape_dim);")
+            .register("initial", "0")
+            .register("reduce", "accumulator + value")
+            .register("assign", "output[id] = mean_dim(accumulator, shape_dim);")
+    }
+}
+
+impl StaticKernelSource for ProdDim {
+    fn source() -> SourceTemplate {
+        ReductionDimRaw::source()
+            .register("initial", "1")
+            .register("reduce", "accumulator * value")
+            .register("assign", "output[id] = accumulator;")
+    }
+}
+
+impl StaticKernelSource for AnyDim {
+    fn source() -> SourceTemplate {
+        ReductionDimRaw::source()
+            .register("initial", "0")
+            .register("reduce", "accumulator | value")
+            .register("assign", "output[id] = accumulator;")
+    }
+}
+
+impl StaticKernelSource for AllDim {
+    fn source() -> SourceTemplate {
+        ReductionDimRaw::source()
+            .register("initial", "1")
+            .register("reduce", "accumulator & value")
+            .register("assign", "output[id] = accumulator;")
     }
 }
 
@@ -50,6 +88,7 @@ impl StaticKernelSource for ArgsMin {
 
 /// Sum all elements in the input buffer.
 pub fn sum<E: WgpuElement, const D: usize>(input: WgpuTensor<E, D>) -> WgpuTensor<E, 1> {
+    let input = into_contiguous_if_expanded(input);
     let mut input_handle = input.handle;
     let mut workgroup = elemwise_workgroup(input.shape.num_elements(), WORKGROUP_DEFAULT);
 
@@ -92,6 +131,24 @@ pub fn mean_dim<E: WgpuElement, const D: usize>(
     reduction_dim::<MeanDim, E, D>(input, dim)
 }
 
+/// Execute the prod dim kernel.
+pub fn prod_dim<E: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> WgpuTensor<E, D> {
+    reduction_dim::<ProdDim, E, D>(input, dim)
+}
+
+/// Execute the any dim kernel on a boolean tensor.
+pub fn any_dim<const D: usize>(input: WgpuTensor<u32, D>, dim: usize) -> WgpuTensor<u32, D> {
+    reduction_dim::<AnyDim, u32, D>(input, dim)
+}
+
+/// Execute the all dim kernel on a boolean tensor.
+pub fn all_dim<const D: usize>(input: WgpuTensor<u32, D>, dim: usize) -> WgpuTensor<u32, D> {
+    reduction_dim::<AllDim, u32, D>(input, dim)
+}
+
 fn reduction_dim<K: StaticKernelSource, E: WgpuElement, const D: usize>(
     input: WgpuTensor<E, D>,
     dim: usize,
@@ -212,4 +269,33 @@ mod tests {
 
         assert_eq!(val_ref.into_data().convert(), val.into_data());
     }
+
+    #[test]
+    fn reduction_prod_dim_should_work_with_multiple_invocations() {
+        let tensor = Tensor::<TestBackend, 2>::random([6, 1024], Distribution::Uniform(0.9, 1.1));
+        let tensor_ref = Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data());
+
+        let val = Tensor::<TestBackend, 2>::from_primitive(prod_dim(tensor.into_primitive(), 1));
+        let val_ref = tensor_ref.prod_dim(1);
+
+        val_ref.into_data().assert_approx_eq(&val.into_data(), 3);
+    }
+
+    #[test]
+    fn reduction_any_all_dim_should_work_with_multiple_invocations() {
+        let tensor = Tensor::<TestBackend, 2>::random([6, 1024], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data());
+
+        let mask = tensor.greater_elem(0.01);
+        let mask_ref = tensor_ref.greater_elem(0.01);
+
+        let any = mask.clone().bool_not().any_dim(1);
+        let all = mask.all_dim(1);
+
+        assert_eq!(
+            mask_ref.clone().bool_not().any_dim(1).into_data(),
+            any.into_data()
+        );
+        assert_eq!(mask_ref.all_dim(1).into_data(), all.into_data());
+    }
 }
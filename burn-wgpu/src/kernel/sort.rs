@@ -0,0 +1,91 @@
+use super::{build_info, KernelSettings, SourceTemplate, StaticKernelSource, WORKGROUP_DEFAULT};
+use crate::{
+    compute::StaticKernel, element::WgpuElement, kernel::elemwise_workgroup, kernel_wgsl,
+    ops::numeric::empty_device, tensor::WgpuTensor,
+};
+
+kernel_wgsl!(SortRaw, "../template/sort.wgsl");
+
+struct SortAscending;
+struct SortDescending;
+
+impl StaticKernelSource for SortAscending {
+    fn source() -> SourceTemplate {
+        SortRaw::source()
+            .register("cmp", "<")
+            .register("descending", "false")
+    }
+}
+
+impl StaticKernelSource for SortDescending {
+    fn source() -> SourceTemplate {
+        SortRaw::source()
+            .register("cmp", ">")
+            .register("descending", "true")
+    }
+}
+
+/// Execute the sort kernel along the given dimension, returning the sorted values and their
+/// indices in the input tensor.
+///
+/// Each invocation computes the rank of one element by comparing it with every other element of
+/// its lane, which is well suited for the relatively small dimensions sorting is used on.
+pub fn sort_with_indices<E: WgpuElement, I: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+    descending: bool,
+) -> (WgpuTensor<E, D>, WgpuTensor<I, D>) {
+    match descending {
+        true => sort_dim::<SortDescending, E, I, D>(input, dim),
+        false => sort_dim::<SortAscending, E, I, D>(input, dim),
+    }
+}
+
+fn sort_dim<K: StaticKernelSource, E: WgpuElement, I: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> (WgpuTensor<E, D>, WgpuTensor<I, D>) {
+    let shape = input.shape.clone();
+    let num_elems = shape.num_elements();
+    let values = empty_device(input.client.clone(), input.device.clone(), shape.clone());
+    let indices = empty_device(input.client.clone(), input.device.clone(), shape);
+
+    let kernel =
+        StaticKernel::<KernelSettings<K, E, I, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>>::new(
+            elemwise_workgroup(num_elems, WORKGROUP_DEFAULT),
+        );
+    let mut info = build_info(&[&input, &values]);
+    info.push(dim as u32);
+    info.push(num_elems as u32);
+    let info_handle = input.client.create(bytemuck::cast_slice(&info));
+
+    input.client.execute(
+        Box::new(kernel),
+        &[&input.handle, &values.handle, &indices.handle, &info_handle],
+    );
+
+    (values, indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{Distribution, Int, Tensor};
+
+    #[test]
+    fn sort_should_work_with_multiple_invocations() {
+        let tensor = Tensor::<TestBackend, 3>::random([4, 64, 12], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data());
+
+        let (values, indices) = sort_with_indices::<f32, i32, 3>(tensor.into_primitive(), 1, true);
+        let values = Tensor::<TestBackend, 3>::from_primitive(values);
+        let indices = Tensor::<TestBackend, 3, Int>::from_primitive(indices);
+        let (values_ref, indices_ref) = tensor_ref.sort_descending_with_indices(1);
+
+        values_ref
+            .into_data()
+            .assert_approx_eq(&values.into_data(), 3);
+        assert_eq!(indices_ref.into_data().convert(), indices.into_data());
+    }
+}
@@ -1,4 +1,7 @@
-use super::{elemwise_workgroup, KernelSettings, StaticKernelSource, WORKGROUP_DEFAULT};
+use super::{
+    elemwise_workgroup, into_contiguous_if_expanded, KernelSettings, StaticKernelSource,
+    WORKGROUP_DEFAULT,
+};
 use crate::{compute::StaticKernel, element::WgpuElement, kernel_wgsl, tensor::WgpuTensor};
 
 kernel_wgsl!(UnaryRaw, "../template/unary.wgsl");
@@ -131,6 +134,7 @@ pub fn unary_inplace<
 pub fn unary<K: StaticKernelSource, E: WgpuElement, const D: usize, const WORKGROUP: usize>(
     input: WgpuTensor<E, D>,
 ) -> WgpuTensor<E, D> {
+    let input = into_contiguous_if_expanded(input);
     let num_elems = input.shape.num_elements();
     let buffer = input.client.empty(num_elems * core::mem::size_of::<E>());
     let mut output = WgpuTensor::new(input.client.clone(), input.device, input.shape, buffer);
@@ -1,4 +1,7 @@
-use super::{elemwise_workgroup, KernelSettings, StaticKernelSource, WORKGROUP_DEFAULT};
+use super::{
+    elemwise_workgroup, into_contiguous_if_expanded, KernelSettings, StaticKernelSource,
+    WORKGROUP_DEFAULT,
+};
 use crate::{compute::StaticKernel, element::WgpuElement, kernel_wgsl, tensor::WgpuTensor};
 
 kernel_wgsl!(UnaryScalarRaw, "../template/unary_scalar.wgsl");
@@ -24,6 +27,19 @@ macro_rules! unary_scalar {
         }
     };
 
+    (
+        $struct:ident,
+        body $body:expr
+    ) => {
+        pub struct $struct;
+
+        impl $crate::kernel::StaticKernelSource for $struct {
+            fn source() -> $crate::kernel::SourceTemplate {
+                $crate::kernel::UnaryScalarRaw::source().register("body", $body)
+            }
+        }
+    };
+
     (
         $struct:ident,
         func $func:expr
@@ -134,6 +150,7 @@ pub fn unary_scalar<
     lhs: WgpuTensor<E, D>,
     scalar: E,
 ) -> WgpuTensor<E, D> {
+    let lhs = into_contiguous_if_expanded(lhs);
     let num_elems = lhs.shape.num_elements();
     let buffer = lhs.client.empty(num_elems * core::mem::size_of::<E>());
     let output = WgpuTensor::new(lhs.client.clone(), lhs.device, lhs.shape, buffer);
@@ -2,8 +2,10 @@ use burn_tensor::ops::ActivationOps;
 
 use crate::{
     element::{FloatElement, IntElement},
-    kernel::{unary_default, unary_inplace_default},
-    unary, unary_inplace, GraphicsApi, WgpuBackend,
+    kernel::{
+        unary_default, unary_inplace_default, unary_scalar_default, unary_scalar_inplace_default,
+    },
+    unary, unary_inplace, unary_scalar, unary_scalar_inplace, GraphicsApi, WgpuBackend,
 };
 
 use super::FloatTensor;
@@ -24,4 +26,41 @@ where
 
         unary_default::<Relu, F, D>(tensor)
     }
+
+    fn leaky_relu<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        negative_slope: F,
+    ) -> FloatTensor<Self, D> {
+        unary_scalar!(
+            LeakyRelu,
+            body "output[id] = select(lhs[id] * rhs, lhs[id], lhs[id] >= 0.0);"
+        );
+        unary_scalar_inplace!(
+            LeakyReluInplace,
+            body "lhs[id] = select(lhs[id] * rhs, lhs[id], lhs[id] >= 0.0);"
+        );
+
+        if tensor.can_mut() {
+            return unary_scalar_inplace_default::<LeakyReluInplace, F, D>(tensor, negative_slope);
+        }
+
+        unary_scalar_default::<LeakyRelu, F, D>(tensor, negative_slope)
+    }
+
+    fn elu<const D: usize>(tensor: FloatTensor<Self, D>, alpha: F) -> FloatTensor<Self, D> {
+        unary_scalar!(
+            Elu,
+            body "output[id] = select(rhs * (exp(lhs[id]) - 1.0), lhs[id], lhs[id] > 0.0);"
+        );
+        unary_scalar_inplace!(
+            EluInplace,
+            body "lhs[id] = select(rhs * (exp(lhs[id]) - 1.0), lhs[id], lhs[id] > 0.0);"
+        );
+
+        if tensor.can_mut() {
+            return unary_scalar_inplace_default::<EluInplace, F, D>(tensor, alpha);
+        }
+
+        unary_scalar_default::<Elu, F, D>(tensor, alpha)
+    }
 }
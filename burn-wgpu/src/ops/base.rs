@@ -78,6 +78,41 @@ pub fn swap_dims<E: WgpuElement, const D: usize>(
     tensor
 }
 
+pub fn permute<E: WgpuElement, const D: usize>(
+    mut tensor: WgpuTensor<E, D>,
+    axes: [usize; D],
+) -> WgpuTensor<E, D> {
+    let strides = tensor.strides;
+    let dims = tensor.shape.dims;
+
+    for (dim, axis) in axes.into_iter().enumerate() {
+        tensor.strides[dim] = strides[axis];
+        tensor.shape.dims[dim] = dims[axis];
+    }
+
+    tensor
+}
+
+pub fn expand<E: WgpuElement, const D1: usize, const D2: usize>(
+    tensor: WgpuTensor<E, D1>,
+    shape: Shape<D2>,
+) -> WgpuTensor<E, D2> {
+    // The new and the expanded dimensions have a stride of 0, so no data is copied.
+    let mut strides = [0; D2];
+    let offset = D2 - D1;
+
+    for i in 0..D1 {
+        if tensor.shape.dims[i] == shape.dims[i + offset] {
+            strides[i + offset] = tensor.strides[i];
+        }
+    }
+
+    let mut output = WgpuTensor::new(tensor.client, tensor.device, shape, tensor.handle);
+    output.strides = strides;
+
+    output
+}
+
 pub fn reshape<E: WgpuElement, const D1: usize, const D2: usize>(
     tensor: WgpuTensor<E, D1>,
     shape: Shape<D2>,
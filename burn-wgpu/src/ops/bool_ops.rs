@@ -46,7 +46,12 @@ where
 
     fn bool_into_int<const D: usize>(tensor: BoolTensor<Self, D>) -> IntTensor<Self, D> {
         if std::mem::size_of::<I>() == std::mem::size_of::<u32>() {
-            return WgpuTensor::new(tensor.client, tensor.device, tensor.shape, tensor.handle);
+            let strides = tensor.strides;
+            let mut output =
+                WgpuTensor::new(tensor.client, tensor.device, tensor.shape, tensor.handle);
+            output.strides = strides;
+
+            return output;
         }
 
         let device = Self::bool_device(&tensor);
@@ -109,6 +114,20 @@ where
         kernel::equal_elem(tensor, 0)
     }
 
+    fn bool_any_dim<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        kernel::any_dim(tensor, dim)
+    }
+
+    fn bool_all_dim<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        kernel::all_dim(tensor, dim)
+    }
+
     fn bool_into_float<const D: usize>(tensor: BoolTensor<Self, D>) -> FloatTensor<Self, D> {
         kernel::cast(tensor)
     }
@@ -123,4 +142,18 @@ where
 
         tensor
     }
+
+    fn bool_permute<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        axes: [usize; D],
+    ) -> BoolTensor<Self, D> {
+        super::permute(tensor, axes)
+    }
+
+    fn bool_expand<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<Self, D2> {
+        super::expand(tensor, shape)
+    }
 }
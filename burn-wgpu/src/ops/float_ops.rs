@@ -139,6 +139,20 @@ where
         numeric::div_scalar(lhs, rhs)
     }
 
+    fn remainder<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        numeric::remainder(lhs, rhs)
+    }
+
+    fn remainder_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        numeric::remainder_scalar(lhs, rhs)
+    }
+
     fn matmul<const D: usize>(
         lhs: FloatTensor<Self, D>,
         rhs: FloatTensor<Self, D>,
@@ -154,6 +168,24 @@ where
         super::swap_dims(tensor, dim1, dim2)
     }
 
+    fn permute<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        axes: [usize; D],
+    ) -> FloatTensor<Self, D> {
+        super::permute(tensor, axes)
+    }
+
+    fn expand<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        super::expand(tensor, shape)
+    }
+
+    fn flip<const D: usize>(tensor: FloatTensor<Self, D>, axes: &[usize]) -> FloatTensor<Self, D> {
+        kernel::flip(tensor, axes)
+    }
+
     fn reshape<const D1: usize, const D2: usize>(
         tensor: FloatTensor<Self, D1>,
         shape: Shape<D2>,
@@ -304,6 +336,10 @@ where
         kernel::sum_dim(tensor, dim)
     }
 
+    fn prod_dim<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        kernel::prod_dim(tensor, dim)
+    }
+
     fn mean_dim<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
         kernel::mean_dim(tensor, dim)
     }
@@ -438,6 +474,61 @@ where
         unary_default::<Erf, F, D>(tensor)
     }
 
+    fn floor<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Floor, func "floor");
+        unary_inplace!(FloorInplace, func "floor");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<FloorInplace, F, D>(tensor);
+        }
+
+        unary_default::<Floor, F, D>(tensor)
+    }
+
+    fn ceil<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Ceil, func "ceil");
+        unary_inplace!(CeilInplace, func "ceil");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<CeilInplace, F, D>(tensor);
+        }
+
+        unary_default::<Ceil, F, D>(tensor)
+    }
+
+    fn round<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Round, func "round");
+        unary_inplace!(RoundInplace, func "round");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<RoundInplace, F, D>(tensor);
+        }
+
+        unary_default::<Round, F, D>(tensor)
+    }
+
+    fn trunc<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Trunc, func "trunc");
+        unary_inplace!(TruncInplace, func "trunc");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<TruncInplace, F, D>(tensor);
+        }
+
+        unary_default::<Trunc, F, D>(tensor)
+    }
+
+    fn sign<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Sign, func "sign");
+        unary_inplace!(SignInplace, func "sign");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<SignInplace, F, D>(tensor);
+        }
+
+        unary_default::<Sign, F, D>(tensor)
+    }
+
     fn cat<const D: usize>(tensors: Vec<FloatTensor<Self, D>>, dim: usize) -> FloatTensor<Self, D> {
         kernel::cat(tensors, dim)
     }
@@ -450,6 +541,56 @@ where
         kernel::argmin(tensor, dim)
     }
 
+    fn cumsum<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        kernel::cumsum(tensor, dim)
+    }
+
+    fn cumprod<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        kernel::cumprod(tensor, dim)
+    }
+
+    fn cummax<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        kernel::cummax(tensor, dim)
+    }
+
+    fn cummax_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::cummax_with_indices(tensor, dim)
+    }
+
+    fn logcumsumexp<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        kernel::logcumsumexp(tensor, dim)
+    }
+
+    fn sort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> FloatTensor<Self, D> {
+        kernel::sort_with_indices::<_, I, D>(tensor, dim, descending).0
+    }
+
+    fn sort_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::sort_with_indices(tensor, dim, descending)
+    }
+
+    fn argsort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        kernel::sort_with_indices(tensor, dim, descending).1
+    }
+
     fn into_int<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<Self, D> {
         kernel::cast(tensor)
     }
@@ -247,6 +247,20 @@ where
         numeric::div_scalar(lhs, rhs)
     }
 
+    fn int_remainder<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        numeric::remainder(lhs, rhs)
+    }
+
+    fn int_remainder_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        numeric::remainder_scalar(lhs, rhs)
+    }
+
     fn int_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
         numeric::zeros::<G, I, D>(shape, device)
     }
@@ -263,6 +277,10 @@ where
         kernel::sum_dim(tensor, dim)
     }
 
+    fn int_prod_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        kernel::prod_dim(tensor, dim)
+    }
+
     fn int_mean_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
         kernel::mean_dim(tensor, dim)
     }
@@ -275,6 +293,49 @@ where
         kernel::argmin(tensor, dim)
     }
 
+    fn int_cumsum<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        kernel::cumsum(tensor, dim)
+    }
+
+    fn int_cumprod<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        kernel::cumprod(tensor, dim)
+    }
+
+    fn int_cummax<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        kernel::cummax(tensor, dim)
+    }
+
+    fn int_cummax_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::cummax_with_indices(tensor, dim)
+    }
+
+    fn int_sort<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        kernel::sort_with_indices::<_, I, D>(tensor, dim, descending).0
+    }
+
+    fn int_sort_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::sort_with_indices(tensor, dim, descending)
+    }
+
+    fn int_argsort<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        kernel::sort_with_indices(tensor, dim, descending).1
+    }
+
     // TODO implement clamp kernels (see https://github.com/burn-rs/burn/issues/549)
     // fn int_clamp_min<const D: usize>(
     //     tensor: IntTensor<Self, D>,
@@ -323,4 +384,22 @@ where
 
         tensor
     }
+
+    fn int_permute<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        axes: [usize; D],
+    ) -> IntTensor<Self, D> {
+        super::permute(tensor, axes)
+    }
+
+    fn int_expand<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> IntTensor<Self, D2> {
+        super::expand(tensor, shape)
+    }
+
+    fn int_flip<const D: usize>(tensor: IntTensor<Self, D>, axes: &[usize]) -> IntTensor<Self, D> {
+        kernel::flip(tensor, axes)
+    }
 }
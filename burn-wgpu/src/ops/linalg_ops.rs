@@ -0,0 +1,14 @@
+use burn_tensor::ops::LinalgOps;
+
+use crate::{
+    element::{FloatElement, IntElement},
+    GraphicsApi, WgpuBackend,
+};
+
+impl<G, F, I> LinalgOps<WgpuBackend<G, F, I>> for WgpuBackend<G, F, I>
+where
+    G: GraphicsApi + 'static,
+    F: FloatElement,
+    I: IntElement,
+{
+}
@@ -2,6 +2,7 @@ mod activation_ops;
 mod bool_ops;
 mod float_ops;
 mod int_ops;
+mod linalg_ops;
 mod module_ops;
 
 mod base;
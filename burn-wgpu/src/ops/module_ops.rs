@@ -1,7 +1,7 @@
 use burn_tensor::{
     ops::{
-        ConvOptions, ConvTransposeOptions, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
-        TensorOps, UnfoldOptions,
+        AttentionBackward, AttentionOptions, ConvOptions, ConvTransposeOptions, InterpolateOptions,
+        MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps, TensorOps, UnfoldOptions,
     },
     Shape,
 };
@@ -11,7 +11,7 @@ use crate::{
     kernel, GraphicsApi, WgpuBackend,
 };
 
-use super::{FloatTensor, IntTensor};
+use super::{BoolTensor, FloatTensor, IntTensor};
 
 impl<G, F, I> ModuleOps<WgpuBackend<G, F, I>> for WgpuBackend<G, F, I>
 where
@@ -127,4 +127,53 @@ where
     ) -> FloatTensor<Self, 4> {
         kernel::pool::adaptive_avg_pool2d_backward(x, grad)
     }
+
+    fn interpolate(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self, 4> {
+        kernel::interpolate(x, output_size, options)
+    }
+
+    fn interpolate_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self, 4> {
+        kernel::interpolate_backward(x, grad, options)
+    }
+
+    fn scaled_dot_product_attention(
+        query: FloatTensor<Self, 4>,
+        key: FloatTensor<Self, 4>,
+        value: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 4>>,
+        mask: Option<BoolTensor<Self, 4>>,
+        options: AttentionOptions,
+    ) -> FloatTensor<Self, 4> {
+        kernel::scaled_dot_product_attention(query, key, value, bias, mask, options)
+    }
+
+    fn scaled_dot_product_attention_backward(
+        query: FloatTensor<Self, 4>,
+        key: FloatTensor<Self, 4>,
+        value: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 4>>,
+        mask: Option<BoolTensor<Self, 4>>,
+        output_grad: FloatTensor<Self, 4>,
+        options: AttentionOptions,
+    ) -> AttentionBackward<WgpuBackend<G, F, I>> {
+        let [query_grad, key_grad, value_grad] = kernel::scaled_dot_product_attention_backward(
+            query,
+            key,
+            value,
+            bias,
+            mask,
+            output_grad,
+            options,
+        );
+
+        AttentionBackward::new(query_grad, key_grad, value_grad)
+    }
 }
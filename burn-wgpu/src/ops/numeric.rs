@@ -195,3 +195,39 @@ pub fn div_scalar<E: WgpuElement, const D: usize>(
 
     unary_scalar_default::<DivScalar, E, D>(lhs, rhs)
 }
+
+pub fn remainder<E: WgpuElement, const D: usize>(
+    lhs: WgpuTensor<E, D>,
+    rhs: WgpuTensor<E, D>,
+) -> WgpuTensor<E, D> {
+    binary_elemwise!(Remainder, func "remainder", include "../template/remainder.wgsl");
+    binary_elemwise_inplace!(
+        RemainderInplace,
+        func "remainder",
+        include "../template/remainder.wgsl"
+    );
+
+    if lhs.can_mut_broadcast(&rhs) {
+        return binary_elemwise_inplace_default::<RemainderInplace, E, D>(lhs, rhs);
+    }
+
+    binary_elemwise_default::<Remainder, E, D>(lhs, rhs)
+}
+
+pub fn remainder_scalar<E: WgpuElement, const D: usize>(
+    lhs: WgpuTensor<E, D>,
+    rhs: E,
+) -> WgpuTensor<E, D> {
+    unary_scalar!(RemainderScalar, func "remainder", include "../template/remainder.wgsl");
+    unary_scalar_inplace!(
+        RemainderScalarInplace,
+        func "remainder",
+        include "../template/remainder.wgsl"
+    );
+
+    if lhs.can_mut() {
+        return unary_scalar_inplace_default::<RemainderScalarInplace, E, D>(lhs, rhs);
+    }
+
+    unary_scalar_default::<RemainderScalar, E, D>(lhs, rhs)
+}
@@ -0,0 +1,171 @@
+@group(0)
+@binding(0)
+var<storage, read> query: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read> key: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> value: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read> bias: array<{{ elem }}>;
+
+@group(0)
+@binding(4)
+var<storage, read> mask: array<u32>;
+
+@group(0)
+@binding(5)
+var<storage, read> output_grad: array<{{ elem }}>;
+
+@group(0)
+@binding(6)
+var<storage, read> stats: array<{{ elem }}>;
+
+@group(0)
+@binding(7)
+var<storage, read_write> key_grad: array<{{ elem }}>;
+
+@group(0)
+@binding(8)
+var<storage, read_write> value_grad: array<{{ elem }}>;
+
+@group(0)
+@binding(9)
+var<storage, read> scalars: array<{{ elem }}, 3>;
+
+@group(0)
+@binding(10)
+var<storage, read> info: array<u32, 34>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    // Each invocation computes the gradients of one key and its value, recomputing the attention
+    // weights from the statistics computed by the query backward kernel.
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+
+    let heads = info[25];
+    let seq_length_1 = info[26];
+    let seq_length_2 = info[27];
+    let d_k = info[28];
+    let d_v = info[29];
+
+    if id >= info[24] * heads * seq_length_2 {
+        return;
+    }
+
+    let j = id % seq_length_2;
+    let h = id / seq_length_2 % heads;
+    let b = id / seq_length_2 / heads;
+    let index_key_grad = id * d_k;
+    let index_value_grad = id * d_v;
+
+    for (var c = 0u; c < d_k; c++) {
+        key_grad[index_key_grad + c] = 0.0;
+    }
+    for (var c = 0u; c < d_v; c++) {
+        value_grad[index_value_grad + c] = 0.0;
+    }
+
+    for (var i = 0u; i < seq_length_1; i++) {
+        let index_stats = 2u * ((b * heads + h) * seq_length_1 + i);
+        let weight = exp(attention_score(b, h, i, j) - stats[index_stats]);
+        let index_output_grad = b * info[20] + h * info[21] + i * info[22];
+
+        for (var c = 0u; c < d_v; c++) {
+            let grad = output_grad[index_output_grad + c * info[23]];
+            value_grad[index_value_grad + c] += weight * grad;
+        }
+
+        if is_masked(b, h, i, j) {
+            continue;
+        }
+
+        let score_grad = weight * (weight_grad(b, h, i, j) - stats[index_stats + 1u]) * scalars[0]
+            * dropout_factor(b, h, i, j);
+        let index_query = b * info[0] + h * info[1] + i * info[2];
+
+        for (var c = 0u; c < d_k; c++) {
+            key_grad[index_key_grad + c] += score_grad * query[index_query + c * info[3]];
+        }
+    }
+}
+
+fn is_masked(b: u32, h: u32, i: u32, j: u32) -> bool {
+    if info[31] == 0u {
+        return false;
+    }
+
+    let index = b * info[16] + h * info[17] + i * info[18] + j * info[19];
+    return mask[index] != 0u;
+}
+
+fn attention_score(b: u32, h: u32, i: u32, j: u32) -> {{ elem }} {
+    if is_masked(b, h, i, j) {
+        return scalars[1];
+    }
+
+    let d_k = info[28];
+    let index_query = b * info[0] + h * info[1] + i * info[2];
+    let index_key = b * info[4] + h * info[5] + j * info[6];
+    var score: {{ elem }} = 0.0;
+
+    for (var c = 0u; c < d_k; c++) {
+        score += query[index_query + c * info[3]] * key[index_key + c * info[7]];
+    }
+    score *= scalars[0];
+
+    if info[30] != 0u {
+        score += bias[b * info[12] + h * info[13] + i * info[14] + j * info[15]];
+    }
+
+    return score * dropout_factor(b, h, i, j);
+}
+
+fn weight_grad(b: u32, h: u32, i: u32, j: u32) -> {{ elem }} {
+    let d_v = info[29];
+    let index_output_grad = b * info[20] + h * info[21] + i * info[22];
+    let index_value = b * info[8] + h * info[9] + j * info[10];
+    var weight_grad: {{ elem }} = 0.0;
+
+    for (var c = 0u; c < d_v; c++) {
+        let grad = output_grad[index_output_grad + c * info[23]];
+        weight_grad += grad * value[index_value + c * info[11]];
+    }
+
+    return weight_grad;
+}
+
+fn dropout_factor(b: u32, h: u32, i: u32, j: u32) -> {{ elem }} {
+    if scalars[2] == 1.0 {
+        return 1.0;
+    }
+
+    // Same hash as the dropout factor of the attention options, so that the same scores are
+    // dropped by every backend.
+    let index = ((b * info[25] + h) * info[26] + i) * info[27] + j;
+    let hash = pcg_hash(index ^ pcg_hash(info[32]));
+
+    if hash >> 8u >= info[33] {
+        return scalars[2];
+    }
+
+    return 0.0;
+}
+
+fn pcg_hash(input: u32) -> u32 {
+    let state = input * 747796405u + 2891336453u;
+    let word = ((state >> ((state >> 28u) + 4u)) ^ state) * 277803737u;
+
+    return (word >> 22u) ^ word;
+}
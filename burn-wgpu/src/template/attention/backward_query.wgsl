@@ -0,0 +1,176 @@
+@group(0)
+@binding(0)
+var<storage, read> query: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read> key: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> value: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read> bias: array<{{ elem }}>;
+
+@group(0)
+@binding(4)
+var<storage, read> mask: array<u32>;
+
+@group(0)
+@binding(5)
+var<storage, read> output_grad: array<{{ elem }}>;
+
+@group(0)
+@binding(6)
+var<storage, read_write> query_grad: array<{{ elem }}>;
+
+@group(0)
+@binding(7)
+var<storage, read_write> stats: array<{{ elem }}>;
+
+@group(0)
+@binding(8)
+var<storage, read> scalars: array<{{ elem }}, 3>;
+
+@group(0)
+@binding(9)
+var<storage, read> info: array<u32, 34>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    // Each invocation computes the gradient of one query, as well as the statistics needed to
+    // recompute its attention weights when computing the gradients of the keys and values.
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+
+    let heads = info[25];
+    let seq_length_1 = info[26];
+    let seq_length_2 = info[27];
+    let d_k = info[28];
+
+    if id >= info[24] * heads * seq_length_1 {
+        return;
+    }
+
+    let i = id % seq_length_1;
+    let h = id / seq_length_1 % heads;
+    let b = id / seq_length_1 / heads;
+    let index_query_grad = id * d_k;
+
+    // Log of the softmax denominator, used to recompute the attention weights.
+    var max_score: {{ elem }} = -3.4028235e38;
+    var sum: {{ elem }} = 0.0;
+    for (var j = 0u; j < seq_length_2; j++) {
+        let score = attention_score(b, h, i, j);
+        if score > max_score {
+            sum *= exp(max_score - score);
+            max_score = score;
+        }
+        sum += exp(score - max_score);
+    }
+    let log_sum_exp = max_score + log(sum);
+
+    var weighted_sum: {{ elem }} = 0.0;
+    for (var j = 0u; j < seq_length_2; j++) {
+        let weight = exp(attention_score(b, h, i, j) - log_sum_exp);
+        weighted_sum += weight * weight_grad(b, h, i, j);
+    }
+
+    for (var c = 0u; c < d_k; c++) {
+        query_grad[index_query_grad + c] = 0.0;
+    }
+
+    for (var j = 0u; j < seq_length_2; j++) {
+        if is_masked(b, h, i, j) {
+            continue;
+        }
+
+        let weight = exp(attention_score(b, h, i, j) - log_sum_exp);
+        let score_grad = weight * (weight_grad(b, h, i, j) - weighted_sum) * scalars[0]
+            * dropout_factor(b, h, i, j);
+        let index_key = b * info[4] + h * info[5] + j * info[6];
+
+        for (var c = 0u; c < d_k; c++) {
+            query_grad[index_query_grad + c] += score_grad * key[index_key + c * info[7]];
+        }
+    }
+
+    stats[2u * id] = log_sum_exp;
+    stats[2u * id + 1u] = weighted_sum;
+}
+
+fn is_masked(b: u32, h: u32, i: u32, j: u32) -> bool {
+    if info[31] == 0u {
+        return false;
+    }
+
+    let index = b * info[16] + h * info[17] + i * info[18] + j * info[19];
+    return mask[index] != 0u;
+}
+
+fn attention_score(b: u32, h: u32, i: u32, j: u32) -> {{ elem }} {
+    if is_masked(b, h, i, j) {
+        return scalars[1];
+    }
+
+    let d_k = info[28];
+    let index_query = b * info[0] + h * info[1] + i * info[2];
+    let index_key = b * info[4] + h * info[5] + j * info[6];
+    var score: {{ elem }} = 0.0;
+
+    for (var c = 0u; c < d_k; c++) {
+        score += query[index_query + c * info[3]] * key[index_key + c * info[7]];
+    }
+    score *= scalars[0];
+
+    if info[30] != 0u {
+        score += bias[b * info[12] + h * info[13] + i * info[14] + j * info[15]];
+    }
+
+    return score * dropout_factor(b, h, i, j);
+}
+
+fn weight_grad(b: u32, h: u32, i: u32, j: u32) -> {{ elem }} {
+    let d_v = info[29];
+    let index_output_grad = b * info[20] + h * info[21] + i * info[22];
+    let index_value = b * info[8] + h * info[9] + j * info[10];
+    var weight_grad: {{ elem }} = 0.0;
+
+    for (var c = 0u; c < d_v; c++) {
+        let grad = output_grad[index_output_grad + c * info[23]];
+        weight_grad += grad * value[index_value + c * info[11]];
+    }
+
+    return weight_grad;
+}
+
+fn dropout_factor(b: u32, h: u32, i: u32, j: u32) -> {{ elem }} {
+    if scalars[2] == 1.0 {
+        return 1.0;
+    }
+
+    // Same hash as the dropout factor of the attention options, so that the same scores are
+    // dropped by every backend.
+    let index = ((b * info[25] + h) * info[26] + i) * info[27] + j;
+    let hash = pcg_hash(index ^ pcg_hash(info[32]));
+
+    if hash >> 8u >= info[33] {
+        return scalars[2];
+    }
+
+    return 0.0;
+}
+
+fn pcg_hash(input: u32) -> u32 {
+    let state = input * 747796405u + 2891336453u;
+    let word = ((state >> ((state >> 28u) + 4u)) ^ state) * 277803737u;
+
+    return (word >> 22u) ^ word;
+}
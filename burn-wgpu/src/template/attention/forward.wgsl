@@ -0,0 +1,160 @@
+@group(0)
+@binding(0)
+var<storage, read> query: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read> key: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> value: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read> bias: array<{{ elem }}>;
+
+@group(0)
+@binding(4)
+var<storage, read> mask: array<u32>;
+
+@group(0)
+@binding(5)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(6)
+var<storage, read> scalars: array<{{ elem }}, 3>;
+
+@group(0)
+@binding(7)
+var<storage, read> info: array<u32, 34>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+const BLOCK_SIZE = 32u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    // Each invocation computes the output of one query.
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+
+    let heads = info[25];
+    let seq_length_1 = info[26];
+    let seq_length_2 = info[27];
+    let d_v = info[29];
+
+    if id >= info[24] * heads * seq_length_1 {
+        return;
+    }
+
+    let i = id % seq_length_1;
+    let h = id / seq_length_1 % heads;
+    let b = id / seq_length_1 / heads;
+    let index_output = id * d_v;
+
+    for (var c = 0u; c < d_v; c++) {
+        output[index_output + c] = 0.0;
+    }
+
+    var block: array<{{ elem }}, BLOCK_SIZE>;
+    var max_score: {{ elem }} = -3.4028235e38;
+    var sum: {{ elem }} = 0.0;
+
+    // Online softmax: the accumulated values are rescaled each time the maximum score changes, so
+    // that only a block of scores is kept in memory.
+    for (var start = 0u; start < seq_length_2; start += BLOCK_SIZE) {
+        let end = min(start + BLOCK_SIZE, seq_length_2);
+        var block_max = max_score;
+
+        for (var j = start; j < end; j++) {
+            let score = attention_score(b, h, i, j);
+            block[j - start] = score;
+            block_max = max(block_max, score);
+        }
+
+        let correction = exp(max_score - block_max);
+        sum *= correction;
+
+        for (var j = start; j < end; j++) {
+            let weight = exp(block[j - start] - block_max);
+            block[j - start] = weight;
+            sum += weight;
+        }
+
+        for (var c = 0u; c < d_v; c++) {
+            var accumulated = output[index_output + c] * correction;
+
+            for (var j = start; j < end; j++) {
+                let index_value = b * info[8] + h * info[9] + j * info[10] + c * info[11];
+                accumulated += block[j - start] * value[index_value];
+            }
+
+            output[index_output + c] = accumulated;
+        }
+
+        max_score = block_max;
+    }
+
+    for (var c = 0u; c < d_v; c++) {
+        output[index_output + c] /= sum;
+    }
+}
+
+fn is_masked(b: u32, h: u32, i: u32, j: u32) -> bool {
+    if info[31] == 0u {
+        return false;
+    }
+
+    let index = b * info[16] + h * info[17] + i * info[18] + j * info[19];
+    return mask[index] != 0u;
+}
+
+fn attention_score(b: u32, h: u32, i: u32, j: u32) -> {{ elem }} {
+    if is_masked(b, h, i, j) {
+        return scalars[1];
+    }
+
+    let d_k = info[28];
+    let index_query = b * info[0] + h * info[1] + i * info[2];
+    let index_key = b * info[4] + h * info[5] + j * info[6];
+    var score: {{ elem }} = 0.0;
+
+    for (var c = 0u; c < d_k; c++) {
+        score += query[index_query + c * info[3]] * key[index_key + c * info[7]];
+    }
+    score *= scalars[0];
+
+    if info[30] != 0u {
+        score += bias[b * info[12] + h * info[13] + i * info[14] + j * info[15]];
+    }
+
+    return score * dropout_factor(b, h, i, j);
+}
+
+fn dropout_factor(b: u32, h: u32, i: u32, j: u32) -> {{ elem }} {
+    if scalars[2] == 1.0 {
+        return 1.0;
+    }
+
+    // Same hash as the dropout factor of the attention options, so that the same scores are
+    // dropped by every backend.
+    let index = ((b * info[25] + h) * info[26] + i) * info[27] + j;
+    let hash = pcg_hash(index ^ pcg_hash(info[32]));
+
+    if hash >> 8u >= info[33] {
+        return scalars[2];
+    }
+
+    return 0.0;
+}
+
+fn pcg_hash(input: u32) -> u32 {
+    let state = input * 747796405u + 2891336453u;
+    let word = ((state >> ((state >> 28u) + 4u)) ^ state) * 277803737u;
+
+    return (word >> 22u) ^ word;
+}
@@ -0,0 +1,75 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> indices: array<{{ int }}>;
+
+@group(0)
+@binding(3)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let rank: u32 = info[0];
+    let dim_scan = info[4u * rank + 1u];
+    let num_lanes = info[4u * rank + 2u];
+
+    if id >= num_lanes {
+        return;
+    }
+
+    // Each invocation scans one lane, the lane index is decomposed over all the other dimensions.
+    var lane = id;
+    var offset_input: u32 = 0u;
+    var offset_output: u32 = 0u;
+    var stride_input_dim: u32 = 0u;
+    var stride_output_dim: u32 = 0u;
+    var shape_dim: u32 = 0u;
+
+    for (var i: u32 = rank; i > 0u; i--) {
+        let stride_input = info[i];
+        let stride_output = info[i + rank];
+        let shape = info[i + 3u * rank];
+
+        if i - 1u == dim_scan {
+            stride_input_dim = stride_input;
+            stride_output_dim = stride_output;
+            shape_dim = shape;
+        } else {
+            let num_block = lane % shape;
+            lane = lane / shape;
+            offset_input += num_block * stride_input;
+            offset_output += num_block * stride_output;
+        }
+    }
+
+    var current_value = input[offset_input];
+    var index: u32 = 0u;
+
+    for (var j: u32 = 0u; j < shape_dim; j++) {
+        let value = input[offset_input + j * stride_input_dim];
+
+        // The last index is used when the maximum is reached multiple times.
+        if value >= current_value {
+            current_value = value;
+            index = j;
+        }
+
+        let index_output = offset_output + j * stride_output_dim;
+        output[index_output] = current_value;
+        indices[index_output] = {{ int }}(index);
+    }
+}
@@ -0,0 +1,112 @@
+@group(0)
+@binding(0)
+var<storage, read> x: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32, 17>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+
+    let input_stride_0 = info[0];
+    let input_stride_1 = info[1];
+    let input_stride_2 = info[2];
+    let input_stride_3 = info[3];
+    let input_shape_0 = info[4];
+    let input_shape_1 = info[5];
+    let input_shape_2 = info[6];
+    let input_shape_3 = info[7];
+
+    let output_stride_0 = info[8];
+    let output_stride_1 = info[9];
+    let output_stride_2 = info[10];
+    let output_stride_3 = info[11];
+    let output_shape_0 = info[12];
+    let output_shape_1 = info[13];
+    let output_shape_2 = info[14];
+    let output_shape_3 = info[15];
+
+    let align_corners = info[16];
+
+    let b = id / output_stride_0 % output_shape_0;
+    let c = id / output_stride_1 % output_shape_1;
+    let oh = id / output_stride_2 % output_shape_2;
+    let ow = id / output_stride_3 % output_shape_3;
+
+    let index_base = b * input_stride_0 + c * input_stride_1;
+
+    let h = source_coordinate(oh, input_shape_2, output_shape_2, align_corners);
+    let h_floor = floor(h);
+    var h_weights = cubic_weights(h - h_floor);
+
+    let w = source_coordinate(ow, input_shape_3, output_shape_3, align_corners);
+    let w_floor = floor(w);
+    var w_weights = cubic_weights(w - w_floor);
+
+    var sum = 0.0;
+
+    for (var i = 0; i < 4; i++) {
+        let ih = clamp_index(i32(h_floor) - 1 + i, input_shape_2);
+        var row = 0.0;
+
+        for (var j = 0; j < 4; j++) {
+            let iw = clamp_index(i32(w_floor) - 1 + j, input_shape_3);
+            row += f32(x[index_base + ih * input_stride_2 + iw * input_stride_3]) * w_weights[j];
+        }
+
+        sum += row * h_weights[i];
+    }
+
+    output[id] = {{ elem }}(sum);
+}
+
+fn clamp_index(index: i32, size: u32) -> u32 {
+    return u32(clamp(index, 0, i32(size) - 1));
+}
+
+// Weights of the four neighbors of a point located at `t` after the second one, using the cubic
+// convolution kernel with A = -0.75.
+fn cubic_weights(t: f32) -> array<f32, 4> {
+    return array<f32, 4>(
+        cubic_far(t + 1.0),
+        cubic_near(t),
+        cubic_near(1.0 - t),
+        cubic_far(2.0 - t),
+    );
+}
+
+// Cubic convolution kernel for |d| <= 1.
+fn cubic_near(d: f32) -> f32 {
+    let a = -0.75;
+    return ((a + 2.0) * d - (a + 3.0)) * d * d + 1.0;
+}
+
+// Cubic convolution kernel for 1 < |d| < 2.
+fn cubic_far(d: f32) -> f32 {
+    let a = -0.75;
+    return ((a * d - 5.0 * a) * d + 8.0 * a) * d - 4.0 * a;
+}
+
+fn source_coordinate(index: u32, size_in: u32, size_out: u32, align_corners: u32) -> f32 {
+    if align_corners == 1u {
+        if size_out <= 1u {
+            return 0.0;
+        }
+
+        return f32(index) * f32(size_in - 1u) / f32(size_out - 1u);
+    }
+
+    return (f32(index) + 0.5) * f32(size_in) / f32(size_out) - 0.5;
+}
@@ -0,0 +1,81 @@
+@group(0)
+@binding(0)
+var<storage, read> x: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32, 17>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+
+    let input_stride_0 = info[0];
+    let input_stride_1 = info[1];
+    let input_stride_2 = info[2];
+    let input_stride_3 = info[3];
+    let input_shape_0 = info[4];
+    let input_shape_1 = info[5];
+    let input_shape_2 = info[6];
+    let input_shape_3 = info[7];
+
+    let output_stride_0 = info[8];
+    let output_stride_1 = info[9];
+    let output_stride_2 = info[10];
+    let output_stride_3 = info[11];
+    let output_shape_0 = info[12];
+    let output_shape_1 = info[13];
+    let output_shape_2 = info[14];
+    let output_shape_3 = info[15];
+
+    let align_corners = info[16];
+
+    let b = id / output_stride_0 % output_shape_0;
+    let c = id / output_stride_1 % output_shape_1;
+    let oh = id / output_stride_2 % output_shape_2;
+    let ow = id / output_stride_3 % output_shape_3;
+
+    let index_base = b * input_stride_0 + c * input_stride_1;
+
+    let h = max(source_coordinate(oh, input_shape_2, output_shape_2, align_corners), 0.0);
+    let h0 = min(u32(floor(h)), input_shape_2 - 1u);
+    let h1 = min(h0 + 1u, input_shape_2 - 1u);
+    let h_lambda = h - f32(h0);
+
+    let w = max(source_coordinate(ow, input_shape_3, output_shape_3, align_corners), 0.0);
+    let w0 = min(u32(floor(w)), input_shape_3 - 1u);
+    let w1 = min(w0 + 1u, input_shape_3 - 1u);
+    let w_lambda = w - f32(w0);
+
+    let p_00 = f32(x[index_base + h0 * input_stride_2 + w0 * input_stride_3]);
+    let p_01 = f32(x[index_base + h0 * input_stride_2 + w1 * input_stride_3]);
+    let p_10 = f32(x[index_base + h1 * input_stride_2 + w0 * input_stride_3]);
+    let p_11 = f32(x[index_base + h1 * input_stride_2 + w1 * input_stride_3]);
+
+    let top = p_00 * (1.0 - w_lambda) + p_01 * w_lambda;
+    let bottom = p_10 * (1.0 - w_lambda) + p_11 * w_lambda;
+
+    output[id] = {{ elem }}(top * (1.0 - h_lambda) + bottom * h_lambda);
+}
+
+fn source_coordinate(index: u32, size_in: u32, size_out: u32, align_corners: u32) -> f32 {
+    if align_corners == 1u {
+        if size_out <= 1u {
+            return 0.0;
+        }
+
+        return f32(index) * f32(size_in - 1u) / f32(size_out - 1u);
+    }
+
+    return (f32(index) + 0.5) * f32(size_in) / f32(size_out) - 0.5;
+}
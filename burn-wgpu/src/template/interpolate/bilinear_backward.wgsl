@@ -0,0 +1,143 @@
+@group(0)
+@binding(0)
+var<storage, read> grad: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32, 17>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+// An input index is reached by the source coordinates within one index of it.
+const TAP_RADIUS = 1.0;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+
+    let input_stride_0 = info[0];
+    let input_stride_1 = info[1];
+    let input_stride_2 = info[2];
+    let input_stride_3 = info[3];
+    let input_shape_0 = info[4];
+    let input_shape_1 = info[5];
+    let input_shape_2 = info[6];
+    let input_shape_3 = info[7];
+
+    let grad_stride_0 = info[8];
+    let grad_stride_1 = info[9];
+    let grad_stride_2 = info[10];
+    let grad_stride_3 = info[11];
+    let grad_shape_0 = info[12];
+    let grad_shape_1 = info[13];
+    let grad_shape_2 = info[14];
+    let grad_shape_3 = info[15];
+
+    let align_corners = info[16];
+
+    let b = id / input_stride_0 % input_shape_0;
+    let c = id / input_stride_1 % input_shape_1;
+    let ih = id / input_stride_2 % input_shape_2;
+    let iw = id / input_stride_3 % input_shape_3;
+
+    let index_base = b * grad_stride_0 + c * grad_stride_1;
+
+    // Only the output indices whose taps reach the input index contribute to its gradient, the
+    // edges also receive the taps clamped outside of the input.
+    let oh_start = output_start(ih, input_shape_2, grad_shape_2, align_corners);
+    let oh_end = output_end(ih, input_shape_2, grad_shape_2, align_corners);
+    let ow_start = output_start(iw, input_shape_3, grad_shape_3, align_corners);
+    let ow_end = output_end(iw, input_shape_3, grad_shape_3, align_corners);
+
+    var grad_acc = 0.0;
+
+    for (var oh = oh_start; oh < oh_end; oh++) {
+        let h_weight = tap_weight(oh, ih, input_shape_2, grad_shape_2, align_corners);
+
+        if h_weight == 0.0 {
+            continue;
+        }
+
+        var row = 0.0;
+
+        for (var ow = ow_start; ow < ow_end; ow++) {
+            let w_weight = tap_weight(ow, iw, input_shape_3, grad_shape_3, align_corners);
+            row += f32(grad[index_base + oh * grad_stride_2 + ow * grad_stride_3]) * w_weight;
+        }
+
+        grad_acc += row * h_weight;
+    }
+
+    output[id] = {{ elem }}(grad_acc);
+}
+
+fn output_start(index: u32, size_in: u32, size_out: u32, align_corners: u32) -> u32 {
+    if index == 0u {
+        return 0u;
+    }
+
+    let coordinate = f32(index) - TAP_RADIUS;
+    let start = floor(output_coordinate(coordinate, size_in, size_out, align_corners)) - 1.0;
+
+    return u32(max(start, 0.0));
+}
+
+fn output_end(index: u32, size_in: u32, size_out: u32, align_corners: u32) -> u32 {
+    if index == size_in - 1u {
+        return size_out;
+    }
+
+    let coordinate = f32(index) + TAP_RADIUS;
+    let end = ceil(output_coordinate(coordinate, size_in, size_out, align_corners)) + 1.0;
+
+    return min(u32(max(end, 0.0)), size_out);
+}
+
+// Weight of the input index in the interpolation of the output index.
+fn tap_weight(index_out: u32, index_in: u32, size_in: u32, size_out: u32, align_corners: u32) -> f32 {
+    let source = max(source_coordinate(index_out, size_in, size_out, align_corners), 0.0);
+    let index_0 = min(u32(floor(source)), size_in - 1u);
+    let index_1 = min(index_0 + 1u, size_in - 1u);
+    let lambda = source - f32(index_0);
+
+    var weight = 0.0;
+
+    if index_0 == index_in {
+        weight += 1.0 - lambda;
+    }
+
+    if index_1 == index_in {
+        weight += lambda;
+    }
+
+    return weight;
+}
+
+// Inverse of the source coordinate, only used for the inner indices, so size_in > 1.
+fn output_coordinate(coordinate: f32, size_in: u32, size_out: u32, align_corners: u32) -> f32 {
+    if align_corners == 1u {
+        return coordinate * f32(size_out - 1u) / f32(size_in - 1u);
+    }
+
+    return (coordinate + 0.5) * f32(size_out) / f32(size_in) - 0.5;
+}
+
+fn source_coordinate(index: u32, size_in: u32, size_out: u32, align_corners: u32) -> f32 {
+    if align_corners == 1u {
+        if size_out <= 1u {
+            return 0.0;
+        }
+
+        return f32(index) * f32(size_in - 1u) / f32(size_out - 1u);
+    }
+
+    return (f32(index) + 0.5) * f32(size_in) / f32(size_out) - 0.5;
+}
@@ -0,0 +1,98 @@
+@group(0)
+@binding(0)
+var<storage, read> grad: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32, 17>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+
+    let input_stride_0 = info[0];
+    let input_stride_1 = info[1];
+    let input_stride_2 = info[2];
+    let input_stride_3 = info[3];
+    let input_shape_0 = info[4];
+    let input_shape_1 = info[5];
+    let input_shape_2 = info[6];
+    let input_shape_3 = info[7];
+
+    let grad_stride_0 = info[8];
+    let grad_stride_1 = info[9];
+    let grad_stride_2 = info[10];
+    let grad_stride_3 = info[11];
+    let grad_shape_0 = info[12];
+    let grad_shape_1 = info[13];
+    let grad_shape_2 = info[14];
+    let grad_shape_3 = info[15];
+
+    let b = id / input_stride_0 % input_shape_0;
+    let c = id / input_stride_1 % input_shape_1;
+    let ih = id / input_stride_2 % input_shape_2;
+    let iw = id / input_stride_3 % input_shape_3;
+
+    let index_base = b * grad_stride_0 + c * grad_stride_1;
+
+    // Only the output indices around the scaled input index may be sourced from it.
+    let oh_start = output_start(ih, input_shape_2, grad_shape_2);
+    let oh_end = output_end(ih, input_shape_2, grad_shape_2);
+    let ow_start = output_start(iw, input_shape_3, grad_shape_3);
+    let ow_end = output_end(iw, input_shape_3, grad_shape_3);
+
+    var grad_acc = 0.0;
+
+    for (var oh = oh_start; oh < oh_end; oh++) {
+        if source_index(oh, input_shape_2, grad_shape_2) != ih {
+            continue;
+        }
+
+        for (var ow = ow_start; ow < ow_end; ow++) {
+            if source_index(ow, input_shape_3, grad_shape_3) != iw {
+                continue;
+            }
+
+            grad_acc += f32(grad[index_base + oh * grad_stride_2 + ow * grad_stride_3]);
+        }
+    }
+
+    output[id] = {{ elem }}(grad_acc);
+}
+
+fn output_start(index: u32, size_in: u32, size_out: u32) -> u32 {
+    if index == 0u {
+        return 0u;
+    }
+
+    let start = floor(f32(index) * f32(size_out) / f32(size_in)) - 1.0;
+
+    return u32(max(start, 0.0));
+}
+
+fn output_end(index: u32, size_in: u32, size_out: u32) -> u32 {
+    if index == size_in - 1u {
+        return size_out;
+    }
+
+    let end = ceil(f32(index + 1u) * f32(size_out) / f32(size_in)) + 1.0;
+
+    return min(u32(end), size_out);
+}
+
+fn source_index(index: u32, size_in: u32, size_out: u32) -> u32 {
+    let scale = f32(size_in) / f32(size_out);
+    let source = u32(floor(f32(index) * scale));
+
+    return min(source, size_in - 1u);
+}
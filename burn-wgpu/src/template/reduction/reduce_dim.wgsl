@@ -42,11 +42,12 @@ fn main(
         }
     }
 
-    var sum = {{ elem }}(0);
+    var accumulator = {{ elem }}({{ initial }});
 
     for (var i = 0u; i < shape_dim; i++) {
         let index_input = i * stride_dim;
-        sum += input[index_input + index_offset];
+        let value = input[index_input + index_offset];
+        accumulator = {{ reduce }};
     }
 
     {{ assign }}
@@ -0,0 +1,10 @@
+fn remainder(lhs: {{ elem }}, rhs: {{ elem }}) -> {{ elem }} {
+    // The `%` operator keeps the sign of the dividend, the remainder takes the sign of the divisor.
+    let value = lhs % rhs;
+
+    if (value != {{ elem }}(0) && (value < {{ elem }}(0)) != (rhs < {{ elem }}(0))) {
+        return value + rhs;
+    }
+
+    return value;
+}
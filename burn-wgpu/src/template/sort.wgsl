@@ -0,0 +1,88 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> values: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> indices: array<{{ int }}>;
+
+@group(0)
+@binding(3)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let rank: u32 = info[0];
+    let dim_sort = info[4u * rank + 1u];
+    let num_elems = info[4u * rank + 2u];
+
+    if id >= num_elems {
+        return;
+    }
+
+    var index_input: u32 = 0u;
+    var position: u32 = 0u;
+    var stride_input_dim: u32 = 0u;
+    var stride_output_dim: u32 = 0u;
+    var shape_dim: u32 = 0u;
+
+    for (var i: u32 = 1u; i <= rank; i++) {
+        let stride_input = info[i];
+        let stride_output = info[i + rank];
+        let shape_output = info[i + 3u * rank];
+
+        let num_block = id / stride_output % shape_output;
+        index_input += num_block * stride_input;
+
+        if i - 1u == dim_sort {
+            position = num_block;
+            stride_input_dim = stride_input;
+            stride_output_dim = stride_output;
+            shape_dim = shape_output;
+        }
+    }
+
+    let offset_input = index_input - position * stride_input_dim;
+    let offset_output = id - position * stride_output_dim;
+    let value = input[index_input];
+    let value_nan = value != value;
+
+    // The rank of the element in its lane is the number of elements that come before it,
+    // equal elements are ordered by their position to keep the sort stable.
+    var sorted_position: u32 = 0u;
+
+    for (var j: u32 = 0u; j < shape_dim; j++) {
+        let other = input[offset_input + j * stride_input_dim];
+        let other_nan = other != other;
+        var before = false;
+
+        if other_nan || value_nan {
+            if other_nan && value_nan {
+                before = j < position;
+            } else {
+                before = other_nan == {{ descending }};
+            }
+        } else {
+            before = other {{ cmp }} value || (other == value && j < position);
+        }
+
+        if before {
+            sorted_position += 1u;
+        }
+    }
+
+    let index_output = offset_output + sorted_position * stride_output_dim;
+    values[index_output] = value;
+    indices[index_output] = {{ int }}(position);
+}
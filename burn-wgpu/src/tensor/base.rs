@@ -112,7 +112,7 @@ impl<E: WgpuElement, const D: usize> WgpuTensor<E, D> {
     }
 
     pub(crate) fn can_mut_broadcast(&self, tensor_other: &WgpuTensor<E, D>) -> bool {
-        if !self.handle.can_mut() {
+        if !self.can_mut() {
             return false;
         }
 
@@ -141,7 +141,7 @@ impl<E: WgpuElement, const D: usize> WgpuTensor<E, D> {
 
     /// Check if the tensor is safe to mutate.
     pub fn can_mut(&self) -> bool {
-        self.handle.can_mut()
+        self.handle.can_mut() && !self.is_expanded()
     }
 
     /// Assert that both tensors are on the same device.
@@ -156,6 +156,10 @@ impl<E: WgpuElement, const D: usize> WgpuTensor<E, D> {
 
     /// Check if the current tensor is contiguous.
     pub fn is_contiguous(&self) -> bool {
+        if self.is_expanded() {
+            return false;
+        }
+
         let mut current_stride = 0;
         for d in 0..D {
             let stride = self.strides[D - 1 - d];
@@ -170,6 +174,15 @@ impl<E: WgpuElement, const D: usize> WgpuTensor<E, D> {
         true
     }
 
+    /// Check if the current tensor was expanded, in which case multiple elements share the same
+    /// memory location.
+    pub fn is_expanded(&self) -> bool {
+        self.strides
+            .iter()
+            .zip(self.shape.dims.iter())
+            .any(|(stride, dim)| *stride == 0 && *dim > 1)
+    }
+
     pub(crate) fn batch_swapped_with_row_col(&self) -> bool {
         for d in 0..D - 2 {
             let stride = self.strides[d];